@@ -0,0 +1,66 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "testing")] {
+        use {
+            criterion::{criterion_group, criterion_main, Criterion},
+            livesplit_core::{
+                component::ComponentRegistry,
+                run::{parser::livesplit, saver},
+                settings::ImageCache,
+                testing::SyntheticRun,
+                Layout, Run, Timer,
+            },
+        };
+
+        criterion_main!(benches);
+        criterion_group!(benches, parse_large_splits, regenerate_comparisons, full_layout_state);
+
+        fn large_run() -> Run {
+            SyntheticRun {
+                segment_count: 100,
+                attempt_count: 2000,
+                ..Default::default()
+            }
+            .generate()
+        }
+
+        fn parse_large_splits(c: &mut Criterion) {
+            let mut buf = String::new();
+            saver::livesplit::save_run(&large_run(), &mut buf).unwrap();
+
+            c.bench_function("Parse Large Splits", move |b| {
+                b.iter(|| livesplit::parse(&buf).unwrap())
+            });
+        }
+
+        fn regenerate_comparisons(c: &mut Criterion) {
+            let mut run = large_run();
+
+            c.bench_function("Regenerate Comparisons", move |b| {
+                b.iter(|| run.regenerate_comparisons())
+            });
+        }
+
+        fn full_layout_state(c: &mut Criterion) {
+            let mut timer = Timer::new(large_run()).unwrap();
+            timer.start().unwrap();
+            for _ in 0..timer.run().len() / 2 {
+                timer.split().unwrap();
+            }
+
+            let registry = ComponentRegistry::new();
+            let mut layout = Layout::new();
+            for id in registry.ids().filter(|&id| id != "PageBreak") {
+                layout.push(registry.create(id).unwrap());
+            }
+
+            let mut image_cache = ImageCache::new();
+            let mut state = layout.state(&mut image_cache, &timer.snapshot());
+
+            c.bench_function("Full Layout State", move |b| {
+                b.iter(|| layout.update_state(&mut state, &mut image_cache, &timer.snapshot()))
+            });
+        }
+    } else {
+        fn main() {}
+    }
+}
@@ -4,6 +4,10 @@ export type ComponentStateJson =
     { DetailedTimer: DetailedTimerComponentStateJson } |
     { Graph: GraphComponentStateJson } |
     { KeyValue: KeyValueComponentStateJson } |
+    { PaceGraph: PaceGraphComponentStateJson } |
+    { PbProgression: PbProgressionComponentStateJson } |
+    { RunPrediction: RunPredictionComponentStateJson } |
+    { SegmentSparkline: SegmentSparklineComponentStateJson } |
     { Separator: null } |
     { Splits: SplitsComponentStateJson } |
     { Text: TextComponentStateJson } |
@@ -512,6 +516,150 @@ export interface KeyValueComponentStateJson {
     updates_frequently: boolean,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface PaceGraphComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the value. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /** The label to show. */
+    label: string,
+    /** The most recent prediction of the finish, formatted in local time. */
+    value: string,
+    /**
+     * The predicted wall clock times of the finish, from oldest to newest,
+     * specified as milliseconds since the Unix epoch.
+     */
+    finish_times: number[],
+    /**
+     * The predictions, normalized to the range 0..1. The earliest finish is 0
+     * and the latest finish is 1. If all the predictions are equal, they are
+     * all 0.5.
+     */
+    points: number[],
+    /** The color of the line connecting the points. */
+    line_color: Color,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface PbProgressionComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the value. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /** The label to show. */
+    label: string,
+    /**
+     * The current personal best, which is the time of the most recent
+     * improvement.
+     */
+    value: string,
+    /** The improvements of the personal best, from oldest to newest. */
+    improvements: PbProgressionComponentImprovementJson[],
+    /**
+     * The positions of the improvements on the chart, normalized to the range
+     * 0..1 on both axes. The horizontal position is based on when the attempts
+     * were started. Vertically the fastest time is 0 and the slowest time is 1.
+     */
+    points: number[][],
+    /** The color of the line connecting the points. */
+    line_color: Color,
+}
+
+/** A single improvement of the personal best. */
+export interface PbProgressionComponentImprovementJson {
+    /** The index of the attempt that improved the personal best. */
+    attempt_index: number,
+    /**
+     * When the attempt was started, specified as milliseconds since the Unix
+     * epoch. This is `null` if it is not known when the attempt was started.
+     */
+    date: number | null,
+    /** The final time of the attempt. */
+    time: string,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface RunPredictionComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * Specifies whether to display the name of each target and its value in
+     * two separate rows.
+     */
+    display_two_rows: boolean,
+    /** The state of each of the targets. */
+    targets: RunPredictionComponentTargetStateJson[],
+}
+
+/** The state of a single target shown by the Run Prediction Component. */
+export interface RunPredictionComponentTargetStateJson {
+    /** The name of the target. */
+    name: string,
+    /**
+     * The average segment time needed on the remaining segments in order to
+     * reach the target. This is a dash if the target can't be reached anymore.
+     */
+    value: string,
+    /** Specifies whether the target can still be reached. */
+    achievable: boolean,
+    /** The semantic coloring information the value carries. */
+    semantic_color: SemanticColor,
+    /** The color of the value. */
+    value_color: Color,
+    /**
+     * This value indicates whether the value is currently frequently being
+     * updated. This can be used for rendering optimizations.
+     */
+    updates_frequently: boolean,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface SegmentSparklineComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /** The label to show, which is the name of the segment. */
+    label: string,
+    /**
+     * The most recent segment times of the segment, from oldest to newest,
+     * normalized to the range 0..1. The fastest time shown is 0 and the slowest
+     * time shown is 1. If all the times are equal, they are all 0.5.
+     */
+    points: number[],
+    /** The index of the fastest of the segment times within the points. */
+    best_index: number | null,
+    /** The color of the line connecting the points. */
+    line_color: Color,
+    /** The color used to highlight the fastest of the segment times. */
+    best_time_color: Color,
+}
+
 /**
  * The state object describes the information to visualize for this component.
  * All the coordinates are in the range 0..1.
@@ -758,6 +906,7 @@ export type SettingsDescriptionValueJson =
     { Font: Font | null } |
     { DeltaGradient: DeltaGradient } |
     { LayoutBackground: LayoutBackground } |
+    { CompletionBasis: CompletionBasis } |
     { CustomCombobox: CustomCombobox };
 
 /** Describes the kind of a column. */
@@ -766,6 +915,13 @@ export type ColumnKind = "Time" | "Variable";
 /** Represents the possible backgrounds for a timer. */
 export type DeltaGradient = Gradient | "DeltaPlain" | "DeltaVertical" | "DeltaHorizontal";
 
+/**
+ * Describes whether the percentage of the Completion Component is based on the
+ * amount of segments that are done or on how much of the comparison's final
+ * time has elapsed.
+ */
+export type CompletionBasis = "SegmentCount" | "ComparisonTime";
+
 /** Describes the direction the components of a layout are laid out in. */
 export type LayoutDirection = "Vertical" | "Horizontal";
 
@@ -59,13 +59,15 @@ pub(crate) trait CommandSinkAndQuery: Send + Sync + 'static {
     fn dyn_resume_game_time(&self) -> Fut;
     fn dyn_set_loading_times(&self, time: TimeSpan) -> Fut;
     fn dyn_set_custom_variable(&self, name: &str, value: &str) -> Fut;
+    fn dyn_next_layout_page(&self) -> Fut;
+    fn dyn_toggle_wall_clock(&self) -> Fut;
 }
 
 type Fut = Pin<Box<dyn Future<Output = Result> + 'static>>;
 
 impl<T> CommandSinkAndQuery for T
 where
-    T: event::CommandSink + event::TimerQuery + Send + Sync + 'static,
+    T: event::TimerControl + Send + Sync + 'static,
 {
     fn dyn_query<'a>(&'a self) -> Box<dyn Deref<Target = Timer> + 'a> {
         Box::new(self.get_timer())
@@ -134,6 +136,12 @@ where
     fn dyn_set_custom_variable(&self, name: &str, value: &str) -> Fut {
         Box::pin(self.set_custom_variable(name, value))
     }
+    fn dyn_next_layout_page(&self) -> Fut {
+        Box::pin(self.next_layout_page())
+    }
+    fn dyn_toggle_wall_clock(&self) -> Fut {
+        Box::pin(self.toggle_wall_clock())
+    }
 }
 
 impl event::CommandSink for CommandSink {
@@ -227,6 +235,14 @@ impl event::CommandSink for CommandSink {
     ) -> impl Future<Output = Result> + 'static {
         self.0.dyn_set_custom_variable(name, value)
     }
+
+    fn next_layout_page(&self) -> impl Future<Output = Result> + 'static {
+        self.0.dyn_next_layout_page()
+    }
+
+    fn toggle_wall_clock(&self) -> impl Future<Output = Result> + 'static {
+        self.0.dyn_toggle_wall_clock()
+    }
 }
 
 impl event::TimerQuery for CommandSink {
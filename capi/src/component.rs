@@ -5,6 +5,8 @@ use livesplit_core::Component;
 
 /// type
 pub type OwnedComponent = Box<Component>;
+/// type
+pub type NullableOwnedComponent = Option<OwnedComponent>;
 
 /// drop
 #[no_mangle]
@@ -0,0 +1,47 @@
+//! A Component Registry creates components based on their ID. It starts out
+//! with all the components provided by livesplit-core, which use the names of
+//! their settings' variants as their IDs.
+
+use super::{output_str, str};
+use crate::component::NullableOwnedComponent;
+use livesplit_core::component::ComponentRegistry;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedComponentRegistry = Box<ComponentRegistry>;
+
+/// Creates a new registry that contains all the components provided by
+/// livesplit-core.
+#[no_mangle]
+pub extern "C" fn ComponentRegistry_new() -> OwnedComponentRegistry {
+    Box::new(ComponentRegistry::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ComponentRegistry_drop(this: OwnedComponentRegistry) {
+    drop(this);
+}
+
+/// Returns the amount of components that are registered.
+#[no_mangle]
+pub extern "C" fn ComponentRegistry_len(this: &ComponentRegistry) -> usize {
+    this.ids().count()
+}
+
+/// Returns the ID of the registered component with the specified index. You may
+/// not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn ComponentRegistry_id(this: &ComponentRegistry, index: usize) -> *const c_char {
+    output_str(this.ids().nth(index).unwrap())
+}
+
+/// Creates the component with the ID provided with its default settings.
+/// <NULL> is returned if there is no such component.
+#[no_mangle]
+pub unsafe extern "C" fn ComponentRegistry_create(
+    this: &ComponentRegistry,
+    id: *const c_char,
+) -> NullableOwnedComponent {
+    this.create(str(id)).map(Box::new)
+}
@@ -6,7 +6,7 @@ use super::{output_vec, Json};
 use crate::component::OwnedComponent;
 use crate::key_value_component_state::OwnedKeyValueComponentState;
 use livesplit_core::component::current_pace::Component as CurrentPaceComponent;
-use livesplit_core::Timer;
+use livesplit_core::{GeneralLayoutSettings, Timer};
 
 /// type
 pub type OwnedCurrentPaceComponent = Box<CurrentPaceComponent>;
@@ -37,17 +37,22 @@ pub extern "C" fn CurrentPaceComponent_into_generic(
 pub extern "C" fn CurrentPaceComponent_state_as_json(
     this: &mut CurrentPaceComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> Json {
     output_vec(|o| {
-        this.state(&timer.snapshot()).write_json(o).unwrap();
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
     })
 }
 
-/// Calculates the component's state based on the timer provided.
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
 #[no_mangle]
 pub extern "C" fn CurrentPaceComponent_state(
     this: &mut CurrentPaceComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> OwnedKeyValueComponentState {
-    Box::new(this.state(&timer.snapshot()))
+    Box::new(this.state(&timer.snapshot(), layout_settings))
 }
@@ -1,9 +1,9 @@
 //! The state object describes the information to visualize for a key value based component.
 
-use super::{output_str, output_vec};
+use super::{output_str, output_vec, Nullablec_char};
 use livesplit_core::component::key_value::State as KeyValueComponentState;
 use std::io::Write;
-use std::os::raw::c_char;
+use std::{os::raw::c_char, ptr};
 
 /// type
 pub type OwnedKeyValueComponentState = Box<KeyValueComponentState>;
@@ -33,3 +33,58 @@ pub extern "C" fn KeyValueComponentState_semantic_color(
 ) -> *const c_char {
     output_vec(|f| write!(f, "{:?}", this.semantic_color).unwrap())
 }
+
+/// The secondary value to visualize next to the value. This may be <NULL> if
+/// there is no secondary value.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_secondary_value(
+    this: &KeyValueComponentState,
+) -> *const Nullablec_char {
+    this.secondary_value
+        .as_ref()
+        .map_or_else(ptr::null, output_str)
+}
+
+/// The semantic coloring information the secondary value carries.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_secondary_semantic_color(
+    this: &KeyValueComponentState,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.secondary_semantic_color).unwrap())
+}
+
+/// The amount of additional rows to visualize below the key and the value.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_rows_len(this: &KeyValueComponentState) -> usize {
+    this.rows.len()
+}
+
+/// The key of the additional row with the specified index.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_row_key(
+    this: &KeyValueComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].key)
+}
+
+/// The value of the additional row with the specified index.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_row_value(
+    this: &KeyValueComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].value)
+}
+
+/// The semantic coloring information the value of the additional row with the
+/// specified index carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_row_semantic_color(
+    this: &KeyValueComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.rows[index].semantic_color).unwrap())
+}
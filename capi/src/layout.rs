@@ -4,6 +4,7 @@
 use super::{get_file, output_vec, str, Json};
 use crate::{component::OwnedComponent, layout_state::OwnedLayoutState, slice};
 use livesplit_core::{
+    component::ComponentRegistry,
     layout::{parser, LayoutSettings, LayoutState},
     settings::ImageCache,
     Layout, Timer,
@@ -54,6 +55,24 @@ pub unsafe extern "C" fn Layout_parse_json(settings: Json) -> NullableOwnedLayou
     }
 }
 
+/// Parses a layout from the given JSON description of its settings. Custom
+/// components are created through the registry provided. <NULL> is returned if
+/// it couldn't be parsed.
+#[no_mangle]
+pub unsafe extern "C" fn Layout_parse_json_with_registry(
+    settings: Json,
+    registry: &ComponentRegistry,
+) -> NullableOwnedLayout {
+    let settings = Cursor::new(str(settings).as_bytes());
+    if let Ok(settings) = LayoutSettings::from_json(settings) {
+        Some(Box::new(Layout::from_settings_with_registry(
+            settings, registry,
+        )))
+    } else {
+        None
+    }
+}
+
 /// Attempts to parse a layout from a given file. <NULL> is returned it couldn't
 /// be parsed. This will not close the file descriptor / handle.
 #[no_mangle]
@@ -91,7 +110,9 @@ pub extern "C" fn Layout_state(
     Box::new(this.state(image_cache, &timer.snapshot()))
 }
 
-/// Updates the layout's state based on the timer provided.
+/// Updates the layout's state based on the timer provided. This doesn't apply
+/// a scheduled start of the timer, so call Timer_apply_scheduled_start
+/// beforehand while a start is scheduled.
 #[no_mangle]
 pub extern "C" fn Layout_update_state(
     this: &mut Layout,
@@ -132,6 +153,20 @@ pub extern "C" fn Layout_state_as_json(
     })
 }
 
+/// Encodes the texts shown by the components of the layout state provided as a
+/// flat JSON object that maps string keys, such as `current_pace.value` or
+/// `timer.time`, to the texts. The keys are based on the types of the
+/// components, so they don't change when the labels change. This is useful for
+/// updating text sources without having to understand the structure of the
+/// layout state. The layout state needs to be one that got calculated by this
+/// layout.
+#[no_mangle]
+pub extern "C" fn Layout_text_map_json(this: &Layout, state: &LayoutState) -> Json {
+    output_vec(|o| {
+        serde_json::to_writer(o, &this.text_map(state)).unwrap();
+    })
+}
+
 /// Encodes the settings of the layout as JSON.
 #[no_mangle]
 pub extern "C" fn Layout_settings_as_json(this: &Layout) -> Json {
@@ -146,6 +181,21 @@ pub extern "C" fn Layout_push(this: &mut Layout, component: OwnedComponent) {
     this.push(*component);
 }
 
+/// Makes the next page of components in the layout the visible page. The first
+/// page becomes visible again after the last page.
+#[no_mangle]
+pub extern "C" fn Layout_next_page(this: &mut Layout) {
+    this.next_page();
+}
+
+/// Toggles all the Current Pace components of the layout between showing the
+/// predicted final time and the predicted wall clock time at which the attempt
+/// finishes.
+#[no_mangle]
+pub extern "C" fn Layout_toggle_wall_clock(this: &mut Layout) {
+    this.toggle_wall_clock();
+}
+
 /// Scrolls up all the components in the layout that can be scrolled up.
 #[no_mangle]
 pub extern "C" fn Layout_scroll_up(this: &mut Layout) {
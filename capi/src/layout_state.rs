@@ -11,11 +11,15 @@ use livesplit_core::{
     component::{
         blank_space::State as BlankSpaceComponentState,
         detailed_timer::State as DetailedTimerComponentState, graph::State as GraphComponentState,
-        key_value::State as KeyValueComponentState, separator::State as SeparatorComponentState,
-        splits::State as SplitsComponentState, text::State as TextComponentState,
-        timer::State as TimerComponentState, title::State as TitleComponentState,
+        key_value::State as KeyValueComponentState, pace_graph::State as PaceGraphComponentState,
+        pb_progression::State as PbProgressionComponentState,
+        run_prediction::State as RunPredictionComponentState,
+        segment_sparkline::State as SegmentSparklineComponentState,
+        separator::State as SeparatorComponentState, splits::State as SplitsComponentState,
+        text::State as TextComponentState, timer::State as TimerComponentState,
+        title::State as TitleComponentState,
     },
-    layout::{ComponentState, LayoutState},
+    layout::{ComponentState, LayoutState, UpdateHint},
 };
 use std::os::raw::c_char;
 
@@ -59,6 +63,10 @@ pub extern "C" fn LayoutState_component_type(this: &LayoutState, index: usize) -
         ComponentState::DetailedTimer(_) => "DetailedTimer\0",
         ComponentState::Graph(_) => "Graph\0",
         ComponentState::KeyValue(_) => "KeyValue\0",
+        ComponentState::PaceGraph(_) => "PaceGraph\0",
+        ComponentState::PbProgression(_) => "PbProgression\0",
+        ComponentState::RunPrediction(_) => "RunPrediction\0",
+        ComponentState::SegmentSparkline(_) => "SegmentSparkline\0",
         ComponentState::Separator(_) => "Separator\0",
         ComponentState::Splits(_) => "Splits\0",
         ComponentState::Text(_) => "Text\0",
@@ -69,6 +77,24 @@ pub extern "C" fn LayoutState_component_type(this: &LayoutState, index: usize) -
     .cast()
 }
 
+/// Returns a string describing how often the state of the Component at the
+/// specified index is expected to change. This is either `Static`, `OnSplit`,
+/// `EverySecond` or `EveryFrame`.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_update_hint(
+    this: &LayoutState,
+    index: usize,
+) -> *const c_char {
+    (match this.components[index].update_hint() {
+        UpdateHint::Static => "Static\0",
+        UpdateHint::OnSplit => "OnSplit\0",
+        UpdateHint::EverySecond => "EverySecond\0",
+        UpdateHint::EveryFrame => "EveryFrame\0",
+    })
+    .as_ptr()
+    .cast()
+}
+
 /// Gets the Blank Space component state at the specified index.
 #[no_mangle]
 pub extern "C" fn LayoutState_component_as_blank_space(
@@ -117,6 +143,54 @@ pub extern "C" fn LayoutState_component_as_key_value(
     }
 }
 
+/// Gets the Pace Graph component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_pace_graph(
+    this: &LayoutState,
+    index: usize,
+) -> &PaceGraphComponentState {
+    match &this.components[index] {
+        ComponentState::PaceGraph(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the PB Progression component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_pb_progression(
+    this: &LayoutState,
+    index: usize,
+) -> &PbProgressionComponentState {
+    match &this.components[index] {
+        ComponentState::PbProgression(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the Run Prediction component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_run_prediction(
+    this: &LayoutState,
+    index: usize,
+) -> &RunPredictionComponentState {
+    match &this.components[index] {
+        ComponentState::RunPrediction(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the Segment Sparkline component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_segment_sparkline(
+    this: &LayoutState,
+    index: usize,
+) -> &SegmentSparklineComponentState {
+    match &this.components[index] {
+        ComponentState::SegmentSparkline(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
 /// Gets the Separator component state at the specified index.
 #[no_mangle]
 pub extern "C" fn LayoutState_component_as_separator(
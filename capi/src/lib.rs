@@ -28,6 +28,7 @@ pub mod blank_space_component;
 pub mod blank_space_component_state;
 pub mod command_sink;
 pub mod component;
+pub mod component_registry;
 pub mod current_comparison_component;
 pub mod current_pace_component;
 pub mod delta_component;
@@ -46,8 +47,10 @@ pub mod layout_editor;
 pub mod layout_editor_state;
 pub mod layout_state;
 pub mod linked_layout;
+pub mod pace_graph_component_state;
 pub mod parse_run_result;
 pub mod pb_chance_component;
+pub mod pb_progression_component_state;
 pub mod possible_time_save_component;
 pub mod potential_clean_up;
 pub mod previous_segment_component;
@@ -58,10 +61,12 @@ pub mod run_metadata_custom_variable;
 pub mod run_metadata_custom_variables_iter;
 pub mod run_metadata_speedrun_com_variable;
 pub mod run_metadata_speedrun_com_variables_iter;
+pub mod run_prediction_component_state;
 pub mod segment;
 pub mod segment_history;
 pub mod segment_history_element;
 pub mod segment_history_iter;
+pub mod segment_sparkline_component_state;
 pub mod segment_time_component;
 pub mod separator_component;
 pub mod separator_component_state;
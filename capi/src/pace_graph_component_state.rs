@@ -0,0 +1,55 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::pace_graph::State as PaceGraphComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedPaceGraphComponentState = Box<PaceGraphComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn PaceGraphComponentState_drop(this: OwnedPaceGraphComponentState) {
+    drop(this);
+}
+
+/// The label to show.
+#[no_mangle]
+pub extern "C" fn PaceGraphComponentState_label(this: &PaceGraphComponentState) -> *const c_char {
+    output_str(&this.label)
+}
+
+/// The most recent prediction of the finish, formatted in local time.
+#[no_mangle]
+pub extern "C" fn PaceGraphComponentState_value(this: &PaceGraphComponentState) -> *const c_char {
+    output_str(&this.value)
+}
+
+/// Returns the amount of predictions to visualize. Connect all of them to
+/// visualize the graph.
+#[no_mangle]
+pub extern "C" fn PaceGraphComponentState_len(this: &PaceGraphComponentState) -> usize {
+    this.points.len()
+}
+
+/// Returns the predicted wall clock time of the finish specified as
+/// milliseconds since the Unix epoch. You may not provide an out of bounds
+/// index.
+#[no_mangle]
+pub extern "C" fn PaceGraphComponentState_finish_time(
+    this: &PaceGraphComponentState,
+    index: usize,
+) -> i64 {
+    this.finish_times[index]
+}
+
+/// Returns the prediction specified, normalized to the range 0..1. The earliest
+/// finish is 0 and the latest finish is 1. You may not provide an out of bounds
+/// index.
+#[no_mangle]
+pub extern "C" fn PaceGraphComponentState_point(
+    this: &PaceGraphComponentState,
+    index: usize,
+) -> f32 {
+    this.points[index]
+}
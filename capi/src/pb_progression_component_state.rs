@@ -0,0 +1,79 @@
+//! The state object describes the information to visualize for this component.
+//! All the coordinates are in the range 0..1.
+
+use super::output_str;
+use livesplit_core::component::pb_progression::State as PbProgressionComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedPbProgressionComponentState = Box<PbProgressionComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_drop(this: OwnedPbProgressionComponentState) {
+    drop(this);
+}
+
+/// The label to show.
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_label(
+    this: &PbProgressionComponentState,
+) -> *const c_char {
+    output_str(&this.label)
+}
+
+/// The current personal best, which is the time of the most recent
+/// improvement.
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_value(
+    this: &PbProgressionComponentState,
+) -> *const c_char {
+    output_str(&this.value)
+}
+
+/// Returns the amount of improvements of the personal best to visualize.
+/// Connect all of them to visualize the chart.
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_len(this: &PbProgressionComponentState) -> usize {
+    this.improvements.len()
+}
+
+/// Returns the index of the attempt that achieved the improvement specified.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_attempt_index(
+    this: &PbProgressionComponentState,
+    index: usize,
+) -> i32 {
+    this.improvements[index].attempt_index
+}
+
+/// Returns the final time of the attempt that achieved the improvement
+/// specified. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_time(
+    this: &PbProgressionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.improvements[index].time)
+}
+
+/// Returns the x coordinate of the improvement specified, which is based on
+/// when the attempt was started. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_point_x(
+    this: &PbProgressionComponentState,
+    index: usize,
+) -> f32 {
+    this.points[index][0]
+}
+
+/// Returns the y coordinate of the improvement specified. The fastest time is 0
+/// and the slowest time is 1. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbProgressionComponentState_point_y(
+    this: &PbProgressionComponentState,
+    index: usize,
+) -> f32 {
+    this.points[index][1]
+}
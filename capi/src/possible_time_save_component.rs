@@ -7,7 +7,7 @@ use super::{output_vec, Json};
 use crate::component::OwnedComponent;
 use crate::key_value_component_state::OwnedKeyValueComponentState;
 use livesplit_core::component::possible_time_save::Component as PossibleTimeSaveComponent;
-use livesplit_core::Timer;
+use livesplit_core::{GeneralLayoutSettings, Timer};
 
 /// type
 pub type OwnedPossibleTimeSaveComponent = Box<PossibleTimeSaveComponent>;
@@ -38,17 +38,22 @@ pub extern "C" fn PossibleTimeSaveComponent_into_generic(
 pub extern "C" fn PossibleTimeSaveComponent_state_as_json(
     this: &PossibleTimeSaveComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> Json {
     output_vec(|o| {
-        this.state(&timer.snapshot()).write_json(o).unwrap();
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
     })
 }
 
-/// Calculates the component's state based on the timer provided.
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
 #[no_mangle]
 pub extern "C" fn PossibleTimeSaveComponent_state(
     this: &PossibleTimeSaveComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> OwnedKeyValueComponentState {
-    Box::new(this.state(&timer.snapshot()))
+    Box::new(this.state(&timer.snapshot(), layout_settings))
 }
@@ -9,6 +9,7 @@ use crate::{
     sum_of_best_cleaner::OwnedSumOfBestCleaner,
 };
 use livesplit_core::{
+    run::editor::PastedSegmentTimes,
     settings::{Image, ImageCache},
     Run, RunEditor, TimingMethod,
 };
@@ -127,6 +128,22 @@ pub unsafe extern "C" fn RunEditor_set_game_icon(
     this.set_game_icon(Image::new(slice(data, length).into(), Image::ICON));
 }
 
+/// Sets the game's icon to the image stored in the file at the path provided.
+/// Returns <FALSE> if the file couldn't be read or doesn't contain an image.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_set_game_icon_from_file(
+    this: &mut RunEditor,
+    path: *const c_char,
+) -> bool {
+    match Image::load(str(path), Image::ICON) {
+        Ok(image) => {
+            this.set_game_icon(image);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
 /// Removes the game's icon.
 #[no_mangle]
 pub extern "C" fn RunEditor_remove_game_icon(this: &mut RunEditor) {
@@ -150,6 +167,21 @@ pub extern "C" fn RunEditor_remove_linked_layout(this: &mut RunEditor) {
     this.set_linked_layout(None);
 }
 
+/// Locks the Run to the timing method provided, for games where only one of the
+/// timing methods is meaningful. The other timing method is then never shown
+/// and its comparisons are not generated.
+#[no_mangle]
+pub extern "C" fn RunEditor_lock_timing_method(this: &mut RunEditor, method: TimingMethod) {
+    this.set_locked_timing_method(Some(method));
+}
+
+/// Unlocks the timing method of the Run, so both timing methods are
+/// considered meaningful again.
+#[no_mangle]
+pub extern "C" fn RunEditor_unlock_timing_method(this: &mut RunEditor) {
+    this.set_locked_timing_method(None);
+}
+
 /// Sets the speedrun.com Run ID of the run. You need to ensure that the
 /// record on speedrun.com matches up with the Personal Best of this run.
 /// This may be empty if there's no association.
@@ -290,6 +322,23 @@ pub unsafe extern "C" fn RunEditor_active_set_icon(
         .set_icon(Image::new(slice(data, length).into(), Image::ICON));
 }
 
+/// Sets the icon of the active segment to the image stored in the file at the
+/// path provided. Returns <FALSE> if the file couldn't be read or doesn't
+/// contain an image.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_active_set_icon_from_file(
+    this: &mut RunEditor,
+    path: *const c_char,
+) -> bool {
+    match Image::load(str(path), Image::ICON) {
+        Ok(image) => {
+            this.active_segment().set_icon(image);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
 /// Removes the icon of the active segment.
 #[no_mangle]
 pub extern "C" fn RunEditor_active_remove_icon(this: &mut RunEditor) {
@@ -383,8 +432,20 @@ pub unsafe extern "C" fn RunEditor_remove_comparison(
     this.remove_comparison(str(comparison));
 }
 
+/// Clears all the times of the chosen custom comparison, while keeping the
+/// comparison itself. You can't clear a Comparison Generator's Comparison or
+/// the Personal Best.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_clear_comparison(
+    this: &mut RunEditor,
+    comparison: *const c_char,
+) {
+    this.clear_comparison(str(comparison));
+}
+
 /// Renames a comparison. The comparison can't be renamed if the new name of
-/// the comparison starts with `[Race]` or it already exists.
+/// the comparison starts with `[Race]`, it already exists or it is the name of
+/// a Comparison Generator. You can't rename the Personal Best.
 #[no_mangle]
 pub unsafe extern "C" fn RunEditor_rename_comparison(
     this: &mut RunEditor,
@@ -425,6 +486,36 @@ pub unsafe extern "C" fn RunEditor_parse_and_generate_goal_comparison(
     this.parse_and_generate_goal_comparison(str(time)).is_ok()
 }
 
+/// Distributes the time of the selected segments evenly across them in the
+/// comparison provided. Each contiguous block of selected segments keeps the
+/// split time of its last segment, while the split times of the segments
+/// before it are spaced out evenly. Only the times of the selected timing
+/// method are modified. Returns <FALSE> if the comparison is not a custom
+/// comparison.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_distribute_time_across_selected_segments(
+    this: &mut RunEditor,
+    comparison: *const c_char,
+) -> bool {
+    this.distribute_time_across_selected_segments(str(comparison))
+        .is_ok()
+}
+
+/// Parses a target time and scales all the split times of the comparison
+/// provided, such that its final time matches the parsed value. Only the times
+/// of the selected timing method are modified. Returns <FALSE> if the time
+/// couldn't be parsed, the comparison is not a custom comparison or it doesn't
+/// have a final time.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_parse_and_scale_comparison(
+    this: &mut RunEditor,
+    comparison: *const c_char,
+    time: *const c_char,
+) -> bool {
+    this.parse_and_scale_comparison(str(comparison), str(time))
+        .is_ok()
+}
+
 /// Copies a comparison with the given name as a new custom comparison with the
 /// new name provided. It can't be added if it starts with `[Race]` or it
 /// already exists. The old comparison needs to exist.
@@ -444,6 +535,29 @@ pub extern "C" fn RunEditor_clear_history(this: &mut RunEditor) {
     this.clear_history();
 }
 
+/// Clears out the Attempt History, while keeping the Segment Histories and
+/// the Attempt Count. The segment times of the attempts that got cleared stay
+/// in the Segment Histories, but don't belong to any attempt anymore.
+#[no_mangle]
+pub extern "C" fn RunEditor_clear_attempt_history(this: &mut RunEditor) {
+    this.clear_attempt_history();
+}
+
+/// Clears out the Segment Histories of all the segments, while keeping the
+/// Attempt History, the Best Segment Times and all the comparisons.
+#[no_mangle]
+pub extern "C" fn RunEditor_clear_segment_history(this: &mut RunEditor) {
+    this.clear_segment_history();
+}
+
+/// Clears out the Best Segment Times of all the segments and recalculates
+/// them from the Segment Histories and the Personal Best. This drops all the
+/// Best Segment Times that are not backed by any of the times.
+#[no_mangle]
+pub extern "C" fn RunEditor_clear_best_segments(this: &mut RunEditor) {
+    this.clear_best_segments();
+}
+
 /// Clears out the Attempt History, the Segment Histories, all the times,
 /// sets the Attempt Count to 0 and clears the speedrun.com run id
 /// association. All Custom Comparisons other than `Personal Best` are
@@ -453,6 +567,19 @@ pub extern "C" fn RunEditor_clear_times(this: &mut RunEditor) {
     this.clear_times();
 }
 
+/// Creates, renames and removes segments such that the run has a segment for
+/// each of the lines of the text provided, named after the line. Empty lines
+/// and surrounding whitespace are ignored. What happens to the times is
+/// determined by the option provided.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_paste_segment_names(
+    this: &mut RunEditor,
+    names: *const c_char,
+    times: PastedSegmentTimes,
+) {
+    this.paste_segment_names(str(names), times);
+}
+
 /// Creates a Sum of Best Cleaner which allows you to interactively remove
 /// potential issues in the segment history that lead to an inaccurate Sum
 /// of Best. If you skip a split, whenever you will do the next split, the
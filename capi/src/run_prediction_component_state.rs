@@ -0,0 +1,81 @@
+//! The state object describes the information to visualize for this component.
+
+use super::{output_str, output_vec};
+use livesplit_core::component::run_prediction::State as RunPredictionComponentState;
+use std::{io::Write, os::raw::c_char};
+
+/// type
+pub type OwnedRunPredictionComponentState = Box<RunPredictionComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_drop(this: OwnedRunPredictionComponentState) {
+    drop(this);
+}
+
+/// Specifies whether to display the name of each target and its value in two
+/// separate rows.
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_display_two_rows(
+    this: &RunPredictionComponentState,
+) -> bool {
+    this.display_two_rows
+}
+
+/// The amount of targets to visualize.
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_len(this: &RunPredictionComponentState) -> usize {
+    this.targets.len()
+}
+
+/// The name of the target specified. You may not provide an out of bounds
+/// index.
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_name(
+    this: &RunPredictionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.targets[index].name)
+}
+
+/// The average segment time needed on the remaining segments in order to reach
+/// the target specified. This is a dash if the target can't be reached
+/// anymore. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_value(
+    this: &RunPredictionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.targets[index].value)
+}
+
+/// Specifies whether the target specified can still be reached. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_achievable(
+    this: &RunPredictionComponentState,
+    index: usize,
+) -> bool {
+    this.targets[index].achievable
+}
+
+/// The semantic coloring information the value of the target specified
+/// carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_semantic_color(
+    this: &RunPredictionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.targets[index].semantic_color).unwrap())
+}
+
+/// Specifies whether the value of the target specified is currently frequently
+/// being updated. This can be used for rendering optimizations. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunPredictionComponentState_updates_frequently(
+    this: &RunPredictionComponentState,
+    index: usize,
+) -> bool {
+    this.targets[index].updates_frequently
+}
@@ -0,0 +1,54 @@
+//! The state object describes the information to visualize for this component.
+//! All the coordinates are in the range 0..1.
+
+use super::output_str;
+use livesplit_core::component::segment_sparkline::State as SegmentSparklineComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedSegmentSparklineComponentState = Box<SegmentSparklineComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentSparklineComponentState_drop(this: OwnedSegmentSparklineComponentState) {
+    drop(this);
+}
+
+/// The label to show, which is the name of the segment.
+#[no_mangle]
+pub extern "C" fn SegmentSparklineComponentState_label(
+    this: &SegmentSparklineComponentState,
+) -> *const c_char {
+    output_str(&this.label)
+}
+
+/// Returns the amount of segment times to visualize. Connect all of them to
+/// visualize the sparkline.
+#[no_mangle]
+pub extern "C" fn SegmentSparklineComponentState_points_len(
+    this: &SegmentSparklineComponentState,
+) -> usize {
+    this.points.len()
+}
+
+/// Returns the normalized segment time specified, from oldest to newest. The
+/// fastest time shown is 0 and the slowest time shown is 1. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentSparklineComponentState_point(
+    this: &SegmentSparklineComponentState,
+    index: usize,
+) -> f32 {
+    this.points[index]
+}
+
+/// Specifies whether the segment time specified is the fastest of the segment
+/// times shown. Use the best time color for it, in that case. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentSparklineComponentState_point_is_best(
+    this: &SegmentSparklineComponentState,
+    index: usize,
+) -> bool {
+    this.best_index == Some(index)
+}
@@ -6,7 +6,7 @@ use super::{output_vec, Json};
 use crate::component::OwnedComponent;
 use crate::key_value_component_state::OwnedKeyValueComponentState;
 use livesplit_core::component::segment_time::Component as SegmentTimeComponent;
-use livesplit_core::Timer;
+use livesplit_core::{GeneralLayoutSettings, Timer};
 
 /// type
 pub type OwnedSegmentTimeComponent = Box<SegmentTimeComponent>;
@@ -37,17 +37,20 @@ pub extern "C" fn SegmentTimeComponent_into_generic(
 pub extern "C" fn SegmentTimeComponent_state_as_json(
     this: &SegmentTimeComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> Json {
     output_vec(|o| {
-        this.state(timer).write_json(o).unwrap();
+        this.state(timer, layout_settings).write_json(o).unwrap();
     })
 }
 
-/// Calculates the component's state based on the timer provided.
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
 #[no_mangle]
 pub extern "C" fn SegmentTimeComponent_state(
     this: &SegmentTimeComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> OwnedKeyValueComponentState {
-    Box::new(this.state(timer))
+    Box::new(this.state(timer, layout_settings))
 }
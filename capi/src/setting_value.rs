@@ -4,6 +4,8 @@
 use crate::{output_vec, str, Json};
 use livesplit_core::{
     component::{
+        completion::CompletionBasis,
+        current_pace::{NotRunningDisplay, WallClockRounding},
         splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
         timer::DeltaGradient,
     },
@@ -406,3 +408,53 @@ pub unsafe extern "C" fn SettingValue_from_background_image(
         .into(),
     ))
 }
+
+/// Creates a new setting value from the completion basis with the name
+/// provided. If it doesn't match a known completion basis, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_completion_basis(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "SegmentCount" => CompletionBasis::SegmentCount,
+        "ComparisonTime" => CompletionBasis::ComparisonTime,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the name of what the Current Pace
+/// Component shows while there's no active attempt. If it doesn't match a
+/// known value, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_not_running_display(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Automatic" => NotRunningDisplay::Automatic,
+        "FinalTime" => NotRunningDisplay::FinalTime,
+        "Dash" => NotRunningDisplay::Dash,
+        "Blank" => NotRunningDisplay::Blank,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the name of how the Current Pace Component
+/// rounds the predicted wall clock time. If it doesn't match a known value,
+/// <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_wall_clock_rounding(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Off" => WallClockRounding::Off,
+        "Minute" => WallClockRounding::Minute,
+        "FiveMinutes" => WallClockRounding::FiveMinutes,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
@@ -119,3 +119,11 @@ pub extern "C" fn SplitsComponent_set_separator_last_split(
 ) {
     this.settings_mut().separator_last_split = separator_last_split;
 }
+
+/// Specifies whether to show the icons of the segments. If the layout doesn't
+/// show icons anyway, disabling this avoids storing the images of the icons in
+/// the image cache.
+#[no_mangle]
+pub extern "C" fn SplitsComponent_set_show_icons(this: &mut SplitsComponent, show_icons: bool) {
+    this.settings_mut().show_icons = show_icons;
+}
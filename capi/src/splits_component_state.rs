@@ -26,6 +26,21 @@ pub extern "C" fn SplitsComponentState_len(this: &SplitsComponentState) -> usize
     this.splits.len()
 }
 
+/// Specifies whether the current run has any icons, even those that are not
+/// currently visible by the splits component. This is always false if icons are
+/// not supposed to be shown.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_has_icons(this: &SplitsComponentState) -> bool {
+    this.has_icons
+}
+
+/// The size of the icons relative to the space available for them in each row,
+/// ranging from 0 to 1.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_icon_size(this: &SplitsComponentState) -> f32 {
+    this.icon_size
+}
+
 /// The icon of the segment. The associated image can be looked up in the image
 /// cache. The image may be the empty image. This indicates that there is no
 /// icon.
@@ -100,6 +115,29 @@ pub extern "C" fn SplitsComponentState_is_current_split(
     this.splits[index].is_current_split
 }
 
+/// Describes if the segment with the specified index has a stable identifier.
+/// This is `false` for the blank rows that are used to fill up the component.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_has_segment_id(
+    this: &SplitsComponentState,
+    index: usize,
+) -> bool {
+    this.splits[index].id.is_some()
+}
+
+/// The stable identifier of the segment with the specified index. Unlike the
+/// index, it stays the same when segments are inserted, removed or reordered.
+/// You may not provide an out of bounds index or the index of a row without an
+/// identifier.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_segment_id(
+    this: &SplitsComponentState,
+    index: usize,
+) -> u64 {
+    this.splits[index].id.unwrap().value()
+}
+
 /// Describes if the columns have labels that are meant to be shown. If this is
 /// `false`, no labels are supposed to be visualized.
 #[no_mangle]
@@ -120,3 +158,19 @@ pub extern "C" fn SplitsComponentState_column_label(
         ""
     })
 }
+
+/// Returns the comparison and timing method that the column specified is
+/// resolved to. This is empty if the details are not supposed to be shown or
+/// if the column doesn't show times. The list is specified from right to left.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_column_label_details(
+    this: &SplitsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(if let Some(details) = &this.column_label_details {
+        &details[index]
+    } else {
+        ""
+    })
+}
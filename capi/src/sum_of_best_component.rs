@@ -10,7 +10,7 @@ use super::{output_vec, Json};
 use crate::component::OwnedComponent;
 use crate::key_value_component_state::OwnedKeyValueComponentState;
 use livesplit_core::component::sum_of_best::Component as SumOfBestComponent;
-use livesplit_core::Timer;
+use livesplit_core::{GeneralLayoutSettings, Timer};
 
 /// type
 pub type OwnedSumOfBestComponent = Box<SumOfBestComponent>;
@@ -39,17 +39,20 @@ pub extern "C" fn SumOfBestComponent_into_generic(this: OwnedSumOfBestComponent)
 pub extern "C" fn SumOfBestComponent_state_as_json(
     this: &SumOfBestComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> Json {
     output_vec(|o| {
-        this.state(timer).write_json(o).unwrap();
+        this.state(timer, layout_settings).write_json(o).unwrap();
     })
 }
 
-/// Calculates the component's state based on the timer provided.
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
 #[no_mangle]
 pub extern "C" fn SumOfBestComponent_state(
     this: &SumOfBestComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> OwnedKeyValueComponentState {
-    Box::new(this.state(timer))
+    Box::new(this.state(timer, layout_settings))
 }
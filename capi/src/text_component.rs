@@ -5,7 +5,7 @@
 use super::{output_vec, str, Json};
 use crate::component::OwnedComponent;
 use crate::text_component_state::OwnedTextComponentState;
-use livesplit_core::{component::text::Component as TextComponent, Timer};
+use livesplit_core::{component::text::Component as TextComponent, GeneralLayoutSettings, Timer};
 use std::os::raw::c_char;
 
 /// type
@@ -32,9 +32,13 @@ pub extern "C" fn TextComponent_into_generic(this: OwnedTextComponent) -> OwnedC
 
 /// Encodes the component's state information as JSON.
 #[no_mangle]
-pub extern "C" fn TextComponent_state_as_json(this: &TextComponent, timer: &Timer) -> Json {
+pub extern "C" fn TextComponent_state_as_json(
+    this: &TextComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
     output_vec(|o| {
-        this.state(timer).write_json(o).unwrap();
+        this.state(timer, layout_settings).write_json(o).unwrap();
     })
 }
 
@@ -59,11 +63,13 @@ pub unsafe extern "C" fn TextComponent_set_right(this: &mut TextComponent, text:
     this.settings_mut().text.set_right(str(text));
 }
 
-/// Calculates the component's state.
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
 #[no_mangle]
 pub extern "C" fn TextComponent_state(
     this: &TextComponent,
     timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
 ) -> OwnedTextComponentState {
-    Box::new(this.state(timer))
+    Box::new(this.state(timer, layout_settings))
 }
@@ -8,7 +8,7 @@ use crate::{
 use livesplit_core::{
     event::{Error, Event},
     run::saver::{self, livesplit::IoWrite},
-    Run, Time, TimeSpan, Timer, TimerPhase, TimingMethod,
+    AtomicDateTime, Run, Time, TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 use std::{os::raw::c_char, ptr};
 
@@ -105,6 +105,53 @@ pub extern "C" fn Timer_start(this: &mut Timer) -> i32 {
     convert(this.start())
 }
 
+/// Starts the Timer as if it got started at the point in time provided, if
+/// there is no attempt in progress. This allows multiple timers to be started
+/// at the exact same point in time. The point in time may be in the past or in
+/// the future.
+#[no_mangle]
+pub extern "C" fn Timer_start_at(this: &mut Timer, attempt_started: &AtomicDateTime) -> i32 {
+    convert(this.start_at(*attempt_started))
+}
+
+/// Schedules the Timer to start automatically at the point in time provided.
+/// The Timer doesn't start on its own, so Timer_apply_scheduled_start needs to
+/// be called regularly until then, such as before every Layout_update_state.
+/// Scheduling another start replaces the previous one. A start can't be
+/// scheduled while an attempt is in progress.
+#[no_mangle]
+pub extern "C" fn Timer_schedule_start(this: &mut Timer, start_at: &AtomicDateTime) -> i32 {
+    convert(this.schedule_start(*start_at))
+}
+
+/// Cancels the scheduled start of the Timer. Returns true if a start was
+/// scheduled.
+#[no_mangle]
+pub extern "C" fn Timer_cancel_scheduled_start(this: &mut Timer) -> bool {
+    this.cancel_scheduled_start().is_some()
+}
+
+/// Starts the Timer if the point in time it is scheduled to start at has been
+/// reached. This needs to be called regularly while a start is scheduled, such
+/// as before every Layout_update_state, as nothing else applies the scheduled
+/// start. Returns true if the Timer got started.
+#[no_mangle]
+pub extern "C" fn Timer_apply_scheduled_start(this: &mut Timer) -> bool {
+    this.apply_scheduled_start().is_some()
+}
+
+/// Sets the offset of the run such that an attempt that gets started at
+/// `attempt_started` reaches a time of 0 at `zero_at`. The offset can't be
+/// changed while an attempt is in progress.
+#[no_mangle]
+pub extern "C" fn Timer_set_run_offset_for_sync(
+    this: &mut Timer,
+    attempt_started: &AtomicDateTime,
+    zero_at: &AtomicDateTime,
+) -> i32 {
+    convert(this.set_run_offset_for_sync(*attempt_started, *zero_at))
+}
+
 /// If an attempt is in progress, stores the current time as the time of the
 /// current split. The attempt ends if the last split time is stored.
 #[no_mangle]
@@ -112,6 +159,14 @@ pub extern "C" fn Timer_split(this: &mut Timer) -> i32 {
     convert(this.split())
 }
 
+/// Records that the active attempt reached the checkpoint with the name
+/// provided within the current segment. Reaching a checkpoint doesn't split, so
+/// the split times and the segment history stay unaffected.
+#[no_mangle]
+pub unsafe extern "C" fn Timer_reach_checkpoint(this: &mut Timer, name: *const c_char) -> i32 {
+    convert(this.reach_checkpoint(str(name)))
+}
+
 /// Starts a new attempt or stores the current time as the time of the
 /// current split. The attempt ends if the last split time is stored.
 #[no_mangle]
@@ -134,6 +189,26 @@ pub extern "C" fn Timer_undo_split(this: &mut Timer) -> i32 {
     convert(this.undo_split())
 }
 
+/// Corrects the split time of the most recent split of the active attempt.
+/// This is the last split if the attempt is finished. Only the timing methods
+/// that have a time specified are changed. The new time can't be lower than the
+/// split times before it and, if the attempt is still in progress, can't exceed
+/// the current time.
+#[no_mangle]
+pub extern "C" fn Timer_set_current_split_time(this: &mut Timer, time: &Time) -> i32 {
+    convert(this.set_current_split_time(*time))
+}
+
+/// Starts practicing the segments from the first to the last index provided
+/// (both inclusive). Whenever the last of these segments is split, its segment
+/// times are stored in the Practice Histories of the segments and the timer
+/// restarts at the first segment. Practice attempts are never stored in the
+/// Attempt History.
+#[no_mangle]
+pub extern "C" fn Timer_start_practice(this: &mut Timer, first: usize, last: usize) -> i32 {
+    convert(this.start_practice(first, last))
+}
+
 /// Checks whether the current attempt has new best segment times in any of the
 /// segments (for both TimingMethods) or a new Personal Best (for the current
 /// TimingMethod). This can be used to ask the user whether to update the splits
@@ -235,6 +310,13 @@ pub unsafe extern "C" fn Timer_set_current_comparison(
     convert(this.set_current_comparison(str(comparison)))
 }
 
+/// Marks the start of a new session. From now on, the Session Median
+/// comparison is generated from the attempts that get started after this call.
+#[no_mangle]
+pub extern "C" fn Timer_mark_session_start(this: &mut Timer) {
+    this.mark_session_start();
+}
+
 /// Switches the current comparison to the next comparison in the list.
 #[no_mangle]
 pub extern "C" fn Timer_switch_to_next_comparison(this: &mut Timer) {
@@ -0,0 +1,62 @@
+//! Predicts the final time of the active attempt by adapting the Average
+//! Segments to the performance of the attempt so far. If the attempt is 2%
+//! faster than the average up until its most recent split, the remaining
+//! segments are predicted to be 2% faster than their averages as well.
+
+use crate::{comparison::average_segments, timing::Snapshot, TimeSpan, TimerPhase, TimingMethod};
+
+/// Predicts the final time of the active attempt for the timing method
+/// provided. The Average Segments are scaled by the ratio between the split
+/// time of the most recent split and the Average Segments at that split. If
+/// the current segment already took longer than the scaled prediction for it,
+/// the ratio is based on the current time instead. If there's no active
+/// attempt, the final time of the Average Segments is returned. The boolean
+/// indicates whether the prediction is frequently changing, as it is based on
+/// the current time.
+pub fn calculate(timer: &Snapshot<'_>, method: TimingMethod) -> (Option<TimeSpan>, bool) {
+    let segments = timer.run().segments();
+    let average = |index: usize| segments[index].comparison(average_segments::NAME)[method];
+    let Some(final_average) = segments.len().checked_sub(1).and_then(average) else {
+        return (None, false);
+    };
+
+    let phase = timer.current_phase();
+    let split_index = match phase {
+        TimerPhase::NotRunning => return (Some(final_average), false),
+        TimerPhase::Ended => return (segments[segments.len() - 1].split_time()[method], false),
+        TimerPhase::Running | TimerPhase::Paused => match timer.current_split_index() {
+            Some(split_index) => split_index,
+            None => return (None, false),
+        },
+    };
+
+    let mut ratio = segments[..split_index]
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, segment)| time_ratio(segment.split_time()[method]?, average(index)?))
+        .unwrap_or(1.0);
+
+    let mut is_live = false;
+
+    if let Some(live_ratio) =
+        catch! { time_ratio(timer.current_time()[method]?, average(split_index)?)? }
+    {
+        if live_ratio > ratio {
+            ratio = live_ratio;
+            is_live = true;
+        }
+    }
+
+    let prediction = TimeSpan::saturating_from_seconds(final_average.total_seconds() * ratio);
+
+    (
+        Some(prediction),
+        is_live && phase.updates_frequently(method),
+    )
+}
+
+fn time_ratio(time: TimeSpan, average: TimeSpan) -> Option<f64> {
+    let average = average.total_seconds();
+    (average > 0.0).then(|| time.total_seconds() / average)
+}
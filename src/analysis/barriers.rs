@@ -0,0 +1,65 @@
+//! Finds the round-number barriers a runner may be chasing, such as finishing
+//! a run sub 1:50:00. The nearest barrier below a predicted final time is the
+//! next one the runner needs to break, and the margin to it is the amount of
+//! time that needs to be saved in order to do so.
+
+use crate::{platform::Duration, TimeSpan};
+
+/// A round-number barrier below a time, along with the margin to it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Barrier {
+    /// The time of the barrier itself. This is always a multiple of the
+    /// interval between the barriers.
+    pub time: TimeSpan,
+    /// The amount of time the time is above the barrier. This is always
+    /// positive.
+    pub margin: TimeSpan,
+}
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Chooses the interval between the barriers based on the magnitude of the
+/// time provided, so the barriers are round numbers that are reasonably close
+/// to the time. Runs below a minute use barriers every second, runs below 10
+/// minutes every 10 seconds, runs below an hour every minute, runs below 10
+/// hours every 5 minutes and longer runs every hour.
+pub fn interval_for(time: TimeSpan) -> TimeSpan {
+    let seconds = match time.total_seconds() {
+        t if t < 60.0 => 1,
+        t if t < 10.0 * 60.0 => 10,
+        t if t < 60.0 * 60.0 => 60,
+        t if t < 10.0 * 60.0 * 60.0 => 5 * 60,
+        _ => 60 * 60,
+    };
+    Duration::seconds(seconds).into()
+}
+
+/// Finds the nearest barrier strictly below the time provided. The barriers
+/// are the multiples of the interval provided. If no interval is provided, it
+/// is chosen based on the magnitude of the time with [`interval_for`]. Returns
+/// `None` if there is no positive barrier below the time or the interval isn't
+/// positive.
+pub fn below(time: TimeSpan, interval: Option<TimeSpan>) -> Option<Barrier> {
+    let interval = interval.unwrap_or_else(|| interval_for(time));
+    let nanos = |time: TimeSpan| time.to_duration().whole_nanoseconds();
+
+    let step = nanos(interval);
+    if step <= 0 {
+        return None;
+    }
+
+    let barrier = (nanos(time) - 1).div_euclid(step) * step;
+    if barrier <= 0 {
+        return None;
+    }
+
+    let barrier = TimeSpan::from(Duration::new(
+        (barrier / NANOS_PER_SEC).try_into().ok()?,
+        (barrier % NANOS_PER_SEC) as i32,
+    ));
+
+    Some(Barrier {
+        time: barrier,
+        margin: time - barrier,
+    })
+}
@@ -0,0 +1,76 @@
+//! Calculates the Best Possible Time of the active attempt by rebalancing the
+//! Sum of Best Segments with the segment times the attempt already achieved.
+//! Comparing against the Best Segments comparison only takes the best segment
+//! times into account that were stored when the splits were last updated. Golds
+//! achieved earlier in the attempt and combined segments the attempt can't
+//! take anymore are therefore not reflected. This instead treats the completed
+//! part of the attempt as fixed and only predicts the remainder of the run.
+
+use crate::{
+    analysis::sum_of_segments::{best, calculate_best},
+    platform::prelude::*,
+    timing::Snapshot,
+    TimeSpan, TimerPhase, TimingMethod,
+};
+
+/// Calculates the Best Possible Time of the active attempt for the timing
+/// method provided. This is the fastest final time the attempt can still
+/// achieve, based on its split times so far and the best segment times for the
+/// remainder of the run. If the current segment already took longer than its
+/// best segment time, the live segment time is used instead. If there's no
+/// active attempt, the Sum of Best Segments is returned. The boolean indicates
+/// whether the value is frequently changing, as it is based on the live segment
+/// time.
+pub fn calculate(timer: &Snapshot<'_>, method: TimingMethod) -> (Option<TimeSpan>, bool) {
+    let segments = timer.run().segments();
+    let phase = timer.current_phase();
+
+    match phase {
+        TimerPhase::NotRunning => (calculate_best(segments, false, false, method), false),
+        TimerPhase::Ended => (catch! { segments.last()?.split_time()[method]? }, false),
+        TimerPhase::Running | TimerPhase::Paused => {
+            let Some(split_index) = timer.current_split_index() else {
+                return (None, false);
+            };
+
+            let (start_index, start_time) = segments[..split_index]
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(index, segment)| Some((index + 1, segment.split_time()[method]?)))
+                .unwrap_or((0, TimeSpan::zero()));
+
+            let mut predictions = vec![None; segments.len() + 1];
+            let mut best_time = best::calculate_from(
+                segments,
+                &mut predictions,
+                start_index,
+                start_time,
+                false,
+                false,
+                method,
+            );
+
+            let mut is_live = false;
+
+            if let Some(current_time) = timer.current_time()[method] {
+                predictions.fill(None);
+                let live_time = best::calculate_from(
+                    segments,
+                    &mut predictions,
+                    split_index + 1,
+                    current_time,
+                    false,
+                    false,
+                    method,
+                );
+                if catch! { live_time? > best_time? }.unwrap_or(false) {
+                    best_time = live_time;
+                    is_live = true;
+                }
+            }
+
+            (best_time, is_live && phase.updates_frequently(method))
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Calculates how often attempts end in each of the segments of a
+//! [`Run`](crate::Run), which is also known as the choke rate of a segment.
+//! Segments with a high choke rate are the ones in which attempts are usually
+//! reset, whether it is because the runner died or because the attempt was
+//! going badly. The segment an attempt ended in is the first segment that
+//! doesn't have a time in the segment history for the attempt.
+
+use crate::{platform::prelude::*, Run};
+
+/// Describes how often attempts ended in a segment.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChokeRate {
+    /// The amount of attempts in the Attempt History that reached the segment.
+    pub reached: usize,
+    /// The amount of attempts that reached the segment, but ended without
+    /// completing it.
+    pub ended: usize,
+}
+
+impl ChokeRate {
+    /// The fraction of the attempts that reached the segment, but ended
+    /// without completing it, between 0 and 1. This is `None` if no attempt
+    /// reached the segment.
+    pub fn rate(self) -> Option<f64> {
+        (self.reached != 0).then(|| self.ended as f64 / self.reached as f64)
+    }
+}
+
+/// Calculates the choke rate of each segment of the run, based on the attempts
+/// in the Attempt History.
+pub fn calculate(run: &Run) -> Vec<ChokeRate> {
+    let mut choke_rates = vec![ChokeRate::default(); run.len()];
+
+    for attempt in run.attempt_history() {
+        let completed = run
+            .segments()
+            .iter()
+            .take_while(|segment| segment.segment_history().get(attempt.index()).is_some())
+            .count();
+
+        for choke_rate in &mut choke_rates[..completed] {
+            choke_rate.reached += 1;
+        }
+        if let Some(choke_rate) = choke_rates.get_mut(completed) {
+            choke_rate.reached += 1;
+            choke_rate.ended += 1;
+        }
+    }
+
+    choke_rates
+}
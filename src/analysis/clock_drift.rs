@@ -0,0 +1,29 @@
+//! Detects whether the system clock drifted away from the monotonic clock that
+//! the timer is based on during the active attempt. The timer itself is
+//! unaffected by this, but everything relating the attempt to the wall clock,
+//! such as the predicted wall clock time of the finish, becomes meaningless
+//! when the system clock jumps, for example because it got adjusted or the
+//! system got suspended.
+
+use crate::{timing::Snapshot, AtomicDateTime, TimeSpan, TimerPhase};
+
+/// Calculates how far the system clock drifted away from the monotonic clock
+/// since the active attempt started. A positive drift means that more wall
+/// clock time passed than actually passed according to the timer. `None` is
+/// returned if there is no attempt in progress.
+pub fn calculate(timer: &Snapshot<'_>) -> Option<TimeSpan> {
+    if matches!(
+        timer.current_phase(),
+        TimerPhase::NotRunning | TimerPhase::Ended
+    ) {
+        return None;
+    }
+    let wall_clock_duration = AtomicDateTime::now() - timer.get_start_time()?;
+    Some(wall_clock_duration - timer.current_attempt_duration())
+}
+
+/// Checks whether the system clock drifted away from the monotonic clock by
+/// more than the threshold provided, in either direction.
+pub fn exceeds_threshold(timer: &Snapshot<'_>, threshold: TimeSpan) -> bool {
+    calculate(timer).is_some_and(|drift| drift > threshold || -drift > threshold)
+}
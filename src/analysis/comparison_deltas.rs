@@ -0,0 +1,67 @@
+use crate::{platform::prelude::*, Run, Time, TimeSpan, TimingMethod};
+
+/// The deltas between two comparisons of a [`Run`], as calculated by
+/// [`compare_comparisons`]. All the deltas describe how much longer the first
+/// comparison takes than the second one, so negative deltas mean that the
+/// first comparison is faster.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComparisonDeltas {
+    /// The delta of each of the segments. A segment that doesn't have a split
+    /// time in either of the comparisons is combined with the segments after
+    /// it, so its delta is empty and the delta of the next segment with split
+    /// times in both comparisons covers it.
+    pub segments: Vec<Time>,
+    /// The delta of the final times of the comparisons. It is empty if either
+    /// of the comparisons doesn't have a final time.
+    pub total: Time,
+}
+
+impl ComparisonDeltas {
+    /// Returns the index of the segment where the first comparison loses the
+    /// most time to the second comparison, for the timing method provided.
+    /// When comparing the Personal Best to the Best Segments, this is where
+    /// the Personal Best is the weakest. `None` is returned if no segment has
+    /// a delta.
+    pub fn largest_loss(&self, method: TimingMethod) -> Option<usize> {
+        self.segments
+            .iter()
+            .enumerate()
+            .filter_map(|(index, delta)| Some((index, delta[method]?)))
+            .max_by_key(|&(_, delta)| delta)
+            .map(|(index, _)| index)
+    }
+}
+
+/// Compares the comparison `a` of the run to the comparison `b` for both
+/// timing methods, yielding the deltas of each of the segments as well as of
+/// the final times. Comparing the Personal Best to the Best Segments for
+/// example shows how much time each segment of the Personal Best could save.
+pub fn compare_comparisons(run: &Run, a: &str, b: &str) -> ComparisonDeltas {
+    let mut deltas = ComparisonDeltas {
+        segments: vec![Time::default(); run.len()],
+        total: Time::default(),
+    };
+
+    for method in TimingMethod::all() {
+        let mut previous = (TimeSpan::zero(), TimeSpan::zero());
+
+        for (segment, delta) in run.segments().iter().zip(&mut deltas.segments) {
+            let split_times = (
+                segment.comparison_timing_method(a, method),
+                segment.comparison_timing_method(b, method),
+            );
+            if let (Some(split_a), Some(split_b)) = split_times {
+                delta[method] = Some((split_a - previous.0) - (split_b - previous.1));
+                previous = (split_a, split_b);
+            }
+        }
+
+        deltas.total[method] = catch! {
+            let last_segment = run.segments().last()?;
+            last_segment.comparison_timing_method(a, method)?
+                - last_segment.comparison_timing_method(b, method)?
+        };
+    }
+
+    deltas
+}
@@ -0,0 +1,93 @@
+//! Calculates how much of the run the active attempt has completed, either by
+//! the amount of segments that are done or by how much of the comparison's
+//! final time has elapsed.
+
+use crate::{timing::Snapshot, TimerPhase, TimingMethod};
+
+/// Calculates the fraction of the segments the active attempt has completed,
+/// in the range from `0` to `1`. A run without any segments is considered to
+/// not be completed at all.
+pub fn by_segments(timer: &Snapshot<'_>) -> f64 {
+    let len = timer.run().len();
+    if len == 0 {
+        return 0.0;
+    }
+    let completed = match timer.current_phase() {
+        TimerPhase::NotRunning => 0,
+        TimerPhase::Ended => len,
+        TimerPhase::Running | TimerPhase::Paused => timer.current_split_index().unwrap_or(0),
+    };
+    completed as f64 / len as f64
+}
+
+/// Calculates the fraction of the comparison's final time the active attempt
+/// has completed, in the range from `0` to `1`. The progress within the
+/// current segment is based on how much of the comparison's segment time has
+/// elapsed, but never exceeds the comparison's segment time. Additionally a
+/// value is returned that indicates whether the fraction is actively changing
+/// at the moment. `None` is returned if the comparison has no final time.
+pub fn by_comparison_time(timer: &Snapshot<'_>, comparison: &str) -> (Option<f64>, bool) {
+    by_comparison_time_for_timing_method(timer, comparison, timer.current_timing_method())
+}
+
+/// Calculates the fraction of the comparison's final time the active attempt
+/// has completed, just like [`by_comparison_time`], but for the timing method
+/// provided instead of the timer's current timing method.
+pub fn by_comparison_time_for_timing_method(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    method: TimingMethod,
+) -> (Option<f64>, bool) {
+    let segments = timer.run().segments();
+    let Some(last_segment) = segments.last() else {
+        return (None, false);
+    };
+    let Some(final_time) = last_segment.comparison(comparison)[method] else {
+        return (None, false);
+    };
+    let final_time = final_time.total_seconds();
+
+    let phase = timer.current_phase();
+    let split_index = match phase {
+        TimerPhase::NotRunning => return (Some(0.0), false),
+        TimerPhase::Ended => return (Some(1.0), false),
+        TimerPhase::Running | TimerPhase::Paused => match timer.current_split_index() {
+            Some(split_index) => split_index,
+            None => return (None, false),
+        },
+    };
+
+    let Some(current_time) = timer.current_time()[method] else {
+        return (None, false);
+    };
+
+    let (previous_comparison, previous_split) = segments[..split_index]
+        .iter()
+        .rev()
+        .find_map(|s| Some((s.comparison(comparison)[method]?, s.split_time()[method]?)))
+        .map_or((0.0, 0.0), |(c, s)| (c.total_seconds(), s.total_seconds()));
+
+    let next_comparison = segments[split_index..]
+        .iter()
+        .find_map(|s| s.comparison(comparison)[method])
+        .map_or(final_time, |c| c.total_seconds());
+
+    let segment_time = next_comparison - previous_comparison;
+    let elapsed = current_time.total_seconds() - previous_split;
+    let segment_fraction = if segment_time > 0.0 {
+        (elapsed / segment_time).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+
+    let completed = if final_time > 0.0 {
+        ((previous_comparison + segment_fraction * segment_time) / final_time).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+
+    (
+        Some(completed),
+        segment_fraction < 1.0 && phase.updates_frequently(method),
+    )
+}
@@ -0,0 +1,123 @@
+//! Provides a tracker that turns the changes of the timer into semantic cues,
+//! such as going ahead of the comparison or getting a best segment. Frontends
+//! can use these to play sounds or show notifications without having to
+//! implement the detection logic themselves.
+
+use crate::{
+    analysis::{self, check_best_segment, delta, live_segment_time},
+    comparison::personal_best,
+    platform::prelude::*,
+    timing::Snapshot,
+    TimeSpan, TimerPhase,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// A semantic cue that describes a noteworthy change during an attempt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cue {
+    /// The attempt went ahead of the comparison.
+    WentAhead,
+    /// The attempt went behind the comparison.
+    WentBehind,
+    /// The segment with the index provided was completed with a new best
+    /// segment time.
+    BestSegment(usize),
+    /// The segment with the index provided took longer than its best segment
+    /// time, so it can't become a best segment anymore.
+    LostGoldPace(usize),
+    /// The final split was reached while being ahead of the personal best.
+    PersonalBestPaceAtFinalSplit,
+    /// The attempt finished with a new personal best.
+    PersonalBest,
+}
+
+/// Tracks the state of the timer across updates to detect [`Cue`]s. The
+/// tracker needs to be updated regularly, such as whenever the layout is
+/// updated. Each cue is only reported once, when the change happens.
+#[derive(Clone, Debug, Default)]
+pub struct CueTracker {
+    split_index: usize,
+    is_ahead: Option<bool>,
+    lost_gold_pace: bool,
+}
+
+impl CueTracker {
+    /// Creates a new tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the tracker with the current state of the timer and returns
+    /// the cues that occurred since the last update. The deltas are
+    /// calculated against the comparison provided.
+    pub fn update(&mut self, timer: &Snapshot<'_>, comparison: &str) -> Vec<Cue> {
+        let mut cues = Vec::new();
+        let method = timer.current_timing_method();
+        let phase = timer.current_phase();
+
+        if phase == TimerPhase::NotRunning {
+            *self = Self::default();
+            return cues;
+        }
+
+        let previous_split_index = self.split_index;
+        let current_split_index = timer.current_split_index().unwrap();
+
+        if current_split_index != previous_split_index {
+            self.lost_gold_pace = false;
+        }
+
+        for segment_index in previous_split_index..current_split_index {
+            if check_best_segment(timer, segment_index, method) {
+                cues.push(Cue::BestSegment(segment_index));
+            }
+        }
+
+        if current_split_index > previous_split_index {
+            let last_index = timer.run().len() - 1;
+            if phase == TimerPhase::Ended {
+                let final_time = timer.run().segment(last_index).split_time()[method];
+                let pb_time = timer.run().segment(last_index).personal_best_split_time()[method];
+                if catch! { final_time? < pb_time? }.unwrap_or(final_time.is_some()) {
+                    cues.push(Cue::PersonalBest);
+                }
+            } else if current_split_index == last_index {
+                let delta = analysis::last_delta(
+                    timer.run(),
+                    current_split_index,
+                    personal_best::NAME,
+                    method,
+                );
+                if delta.is_some_and(|d| d < TimeSpan::zero()) {
+                    cues.push(Cue::PersonalBestPaceAtFinalSplit);
+                }
+            }
+        }
+
+        if phase != TimerPhase::Ended && !self.lost_gold_pace {
+            let best_segment = timer.run().segment(current_split_index).best_segment_time()[method];
+            let live_segment = live_segment_time(timer, current_split_index, method);
+            if catch! { live_segment? > best_segment? }.unwrap_or(false) {
+                self.lost_gold_pace = true;
+                cues.push(Cue::LostGoldPace(current_split_index));
+            }
+        }
+
+        let (delta, _) = delta::calculate_for_timing_method(timer, comparison, method);
+        if let Some(delta) = delta.filter(|&d| d != TimeSpan::zero()) {
+            let is_ahead = delta < TimeSpan::zero();
+            if self.is_ahead != Some(is_ahead) {
+                cues.push(if is_ahead {
+                    Cue::WentAhead
+                } else {
+                    Cue::WentBehind
+                });
+            }
+            self.is_ahead = Some(is_ahead);
+        }
+
+        self.split_index = current_split_index;
+
+        cues
+    }
+}
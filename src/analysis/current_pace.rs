@@ -2,40 +2,42 @@
 //! provided. If there's no active attempt, the final time of the comparison is
 //! returned instead.
 
-use crate::{analysis, timing::Snapshot, TimeSpan, TimerPhase, AtomicDateTime};
+use crate::{
+    analysis, settings::SemanticColor, timing::Snapshot, AtomicDateTime, TimeSpan, TimerPhase,
+    TimingMethod,
+};
 
 /// Calculates the current pace of the active attempt based on the comparison
 /// provided. If there's no active attempt, the final time of the comparison is
-/// returned instead.
+/// returned instead. The boolean indicates whether the current pace is
+/// frequently changing. This is the case while the current segment takes
+/// longer than the comparison and the time for the timing method is advancing.
 pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, bool) {
-    let timing_method = timer.current_timing_method();
-    let last_segment = timer.run().segments().last().unwrap();
+    calculate_for_timing_method(timer, comparison, timer.current_timing_method())
+}
+
+/// Calculates the current pace of the active attempt based on the comparison
+/// provided, just like [`calculate`], but for the timing method provided
+/// instead of the timer's current timing method.
+pub fn calculate_for_timing_method(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    timing_method: TimingMethod,
+) -> (Option<TimeSpan>, bool) {
+    let Some(last_segment) = timer.run().segments().last() else {
+        return (None, false);
+    };
     let phase = timer.current_phase();
 
     match phase {
         TimerPhase::Running | TimerPhase::Paused => {
-            let mut delta = analysis::last_delta(
-                timer.run(),
-                timer.current_split_index().unwrap(),
-                comparison,
-                timing_method,
-            )
-            .unwrap_or_default();
-
-            let mut is_live = false;
-
-            catch! {
-                let live_delta = timer.current_time()[timing_method]?
-                    - timer.current_split().unwrap().comparison(comparison)[timing_method]?;
-
-                if live_delta > delta {
-                    delta = live_delta;
-                    is_live = true;
-                }
+            let Some(split_index) = timer.current_split_index() else {
+                return (None, false);
             };
+            let (delta, is_live) = pace_delta(timer, split_index, comparison, timing_method);
 
             let value = catch! {
-                last_segment.comparison(comparison)[timing_method]? + delta
+                last_segment.comparison(comparison)[timing_method]?.checked_add(delta)?
             };
 
             (
@@ -48,16 +50,102 @@ pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, b
     }
 }
 
-pub fn predict_wall_clock_time(timer: &Snapshot<'_>, comparison: &str) -> (Option<AtomicDateTime>, bool) {
-    if let (Some(cp), _) = calculate(timer, comparison) {
-        let start = timer.get_start_time().unwrap_or_else(|| AtomicDateTime::now());
-        let pause_time = timer.get_pause_time().unwrap_or_else(|| TimeSpan::zero()).to_duration();
-        let finish = AtomicDateTime {
-            time: start.time + cp.to_duration() + pause_time,
-            synced_with_atomic_clock: start.synced_with_atomic_clock,
-        };
-        return (Some(finish), true); // TODO: is it correct to claim that it updates frequently?
-    } else {
+/// Calculates by how much the active attempt is predicted to be ahead or
+/// behind the comparison for all the remaining segments. This is the delta of
+/// the most recent split, unless the current segment already took long enough
+/// for the attempt to be even further behind. The boolean indicates whether
+/// the delta comes from the current segment and thus keeps changing.
+pub(crate) fn pace_delta(
+    timer: &Snapshot<'_>,
+    split_index: usize,
+    comparison: &str,
+    timing_method: TimingMethod,
+) -> (TimeSpan, bool) {
+    let mut delta = analysis::last_delta(timer.run(), split_index, comparison, timing_method)
+        .unwrap_or_default();
+
+    let mut is_live = false;
+
+    catch! {
+        let live_delta = timer.current_time()[timing_method]?.checked_sub(
+            timer.current_split()?.comparison(comparison)[timing_method]?,
+        )?;
+
+        if live_delta > delta {
+            delta = live_delta;
+            is_live = true;
+        }
+    };
+
+    (delta, is_live)
+}
+
+/// Chooses a semantic color for the current pace provided, based on whether it
+/// is ahead or behind the final time of the comparison. If either of the times
+/// is missing or they are equal, the default color is returned.
+pub fn semantic_color(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    timing_method: TimingMethod,
+    current_pace: Option<TimeSpan>,
+) -> SemanticColor {
+    let final_time =
+        catch! { timer.run().segments().last()?.comparison(comparison)[timing_method]? };
+
+    analysis::split_color::delta_color(catch! { current_pace?.checked_sub(final_time?)? })
+}
+
+/// Predicts the wall clock time at which the active attempt finishes, if it
+/// keeps the current pace calculated by [`calculate`] for the remainder of the
+/// run. Without an active attempt, the prediction is based on starting an
+/// attempt right now.
+///
+/// The boolean indicates whether the prediction is frequently changing, so
+/// renderers know whether they need to refresh it continuously. This is the
+/// case if any of the following applies:
+///
+/// - There's no active attempt, as the prediction moves along with the wall
+///   clock.
+/// - The attempt is paused, as the time spent paused delays the finish.
+/// - The current pace itself is frequently changing, because the current
+///   segment is taking longer than the comparison.
+///
+/// In all other cases, the prediction only changes when splitting or pausing
+/// the attempt.
+pub fn predict_wall_clock_time(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+) -> (Option<AtomicDateTime>, bool) {
+    let (current_pace, is_live) = calculate(timer, comparison);
+    predict_wall_clock_time_for_pace(timer, current_pace, is_live)
+}
+
+/// Predicts the wall clock time at which the active attempt finishes, just
+/// like [`predict_wall_clock_time`], but based on a prediction of the final
+/// time that was calculated by other means. The boolean provided indicates
+/// whether that prediction is frequently changing.
+pub fn predict_wall_clock_time_for_pace(
+    timer: &Snapshot<'_>,
+    current_pace: Option<TimeSpan>,
+    is_live: bool,
+) -> (Option<AtomicDateTime>, bool) {
+    let Some(current_pace) = current_pace else {
         return (None, false);
-    }
+    };
+
+    let phase = timer.current_phase();
+    let start = timer.get_start_time().unwrap_or_else(AtomicDateTime::now);
+    let pause_time = timer.get_pause_time().unwrap_or_default();
+    let finish = AtomicDateTime {
+        time: start.time + current_pace.to_duration() + pause_time.to_duration(),
+        synced_with_atomic_clock: start.synced_with_atomic_clock,
+    };
+
+    let updates_frequently = match phase {
+        TimerPhase::NotRunning | TimerPhase::Paused => true,
+        TimerPhase::Running => is_live,
+        TimerPhase::Ended => false,
+    };
+
+    (Some(finish), updates_frequently)
 }
@@ -4,7 +4,7 @@
 //! the moment. This may be the case when the current attempt is slower than the
 //! comparison at the current split.
 
-use crate::{analysis, timing::Snapshot, TimeSpan, TimerPhase};
+use crate::{analysis, timing::Snapshot, TimeSpan, TimerPhase, TimingMethod};
 
 /// Calculates the delta of the current attempt to the comparison provided.
 /// Additionally a value is returned that indicates whether the delta value is a
@@ -12,23 +12,34 @@ use crate::{analysis, timing::Snapshot, TimeSpan, TimerPhase};
 /// the moment. This may be the case when the current attempt is slower than the
 /// comparison at the current split.
 pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, bool) {
-    let timing_method = timer.current_timing_method();
-    let last_segment = timer.run().segments().last().unwrap();
+    calculate_for_timing_method(timer, comparison, timer.current_timing_method())
+}
+
+/// Calculates the delta of the current attempt to the comparison provided,
+/// just like [`calculate`], but for the timing method provided instead of the
+/// timer's current timing method.
+pub fn calculate_for_timing_method(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    timing_method: TimingMethod,
+) -> (Option<TimeSpan>, bool) {
+    let Some(last_segment) = timer.run().segments().last() else {
+        return (None, false);
+    };
 
     let mut use_live_delta = false;
 
     let time = match timer.current_phase() {
         TimerPhase::Running | TimerPhase::Paused => {
-            let mut delta = analysis::last_delta(
-                timer.run(),
-                timer.current_split_index().unwrap(),
-                comparison,
-                timing_method,
-            );
+            let Some(split_index) = timer.current_split_index() else {
+                return (None, false);
+            };
+            let mut delta =
+                analysis::last_delta(timer.run(), split_index, comparison, timing_method);
 
             catch! {
                 let live_delta = timer.current_time()[timing_method]?
-                    - timer.current_split().unwrap().comparison(comparison)[timing_method]?;
+                    - timer.current_split()?.comparison(comparison)[timing_method]?;
 
                 if live_delta > delta.unwrap_or_default() {
                     delta = Some(live_delta);
@@ -0,0 +1,70 @@
+//! Calculates the distribution of how long finished attempts took on the wall
+//! clock, based on the Attempt History. Unlike the comparisons, this is based
+//! on how long attempts actually took, including pauses and the offset, so it
+//! can be used to estimate when an attempt that starts at a certain point in
+//! time is likely going to finish, such as when submitting estimates for a
+//! marathon schedule.
+
+use crate::{platform::prelude::*, DateTime, Run, TimeSpan};
+
+/// The distribution of how long finished attempts took on the wall clock.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FinishDistribution {
+    durations: Vec<TimeSpan>,
+}
+
+impl FinishDistribution {
+    /// Accesses the durations of all the finished attempts, sorted from the
+    /// shortest to the longest.
+    pub fn durations(&self) -> &[TimeSpan] {
+        &self.durations
+    }
+
+    /// Returns the amount of finished attempts that the distribution is based
+    /// on.
+    pub fn len(&self) -> usize {
+        self.durations.len()
+    }
+
+    /// Returns whether there are no finished attempts that the distribution
+    /// could be based on.
+    pub fn is_empty(&self) -> bool {
+        self.durations.is_empty()
+    }
+
+    /// Returns the duration that the fraction of finished attempts provided
+    /// took at most. The fraction is clamped to be between 0 and 1, so `0.5`
+    /// results in the median and `0.9` means that 90% of all the finished
+    /// attempts took at most the duration returned. `None` is returned if there
+    /// are no finished attempts.
+    pub fn percentile(&self, fraction: f64) -> Option<TimeSpan> {
+        let last = self.durations.len().checked_sub(1)?;
+        let index = (fraction.clamp(0.0, 1.0) * self.durations.len() as f64).ceil() as usize;
+        Some(self.durations[index.saturating_sub(1).min(last)])
+    }
+
+    /// Returns the point in time at which an attempt that starts at the point
+    /// in time provided finishes, if it takes as long as the percentile
+    /// provided. `None` is returned if there are no finished attempts.
+    pub fn finish_at(&self, start: DateTime, fraction: f64) -> Option<DateTime> {
+        Some(start + self.percentile(fraction)?.to_duration())
+    }
+}
+
+/// Calculates the distribution of how long the finished attempts in the
+/// Attempt History of the run took on the wall clock.
+pub fn calculate(run: &Run) -> FinishDistribution {
+    let mut durations = run
+        .attempt_history()
+        .iter()
+        .filter(|attempt| {
+            let time = attempt.time();
+            time.real_time.is_some() || time.game_time.is_some()
+        })
+        .filter_map(|attempt| attempt.duration())
+        .collect::<Vec<_>>();
+
+    durations.sort_unstable();
+
+    FinishDistribution { durations }
+}
@@ -0,0 +1,45 @@
+//! Determines the attempts in which the best segment times of a
+//! [`Run`](crate::Run) were achieved. This allows visualizing how old each of
+//! the best segments is, and helps finding best segments that are unlikely to
+//! be achieved again, such as the ones from very old attempts.
+
+use crate::{platform::prelude::*, run::Attempt, AtomicDateTime, Run, TimingMethod};
+
+/// Describes the attempt in which a best segment time was achieved.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GoldOrigin {
+    /// The index of the [`Attempt`](crate::run::Attempt) in which the best
+    /// segment time was achieved.
+    pub attempt_index: i32,
+    /// The point in time the attempt was started at. This is `None` if it is
+    /// not known when the attempt was started.
+    pub achieved: Option<AtomicDateTime>,
+}
+
+/// Calculates for each segment of the run in which attempt its best segment
+/// time was achieved, for the timing method provided. The best segment time is
+/// looked up in the segment history. If it was achieved multiple times, the
+/// earliest attempt is used. A segment has no origin if it doesn't have a best
+/// segment time or if the best segment time doesn't originate from an actual
+/// attempt in the segment history, such as when it was edited manually.
+pub fn calculate(run: &Run, method: TimingMethod) -> Vec<Option<GoldOrigin>> {
+    run.segments()
+        .iter()
+        .map(|segment| {
+            let best_segment_time = segment.best_segment_time()[method]?;
+            let &(attempt_index, _) = segment
+                .segment_history()
+                .iter_actual_runs()
+                .find(|(_, time)| time[method] == Some(best_segment_time))?;
+            let achieved = run
+                .attempt_history()
+                .iter()
+                .find(|attempt| attempt.index() == attempt_index)
+                .and_then(Attempt::started);
+            Some(GoldOrigin {
+                attempt_index,
+                achieved,
+            })
+        })
+        .collect()
+}
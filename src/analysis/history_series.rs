@@ -0,0 +1,87 @@
+//! Provides the segment times of a [`Run`](crate::Run) as a chronological time
+//! series. Each point of the series is a segment time achieved in an attempt,
+//! along with the index of the attempt and when it was started. This is the
+//! basis for visualizing how a runner's segment times progressed over time,
+//! such as in sparklines or external graphing tools.
+
+use crate::{platform::prelude::*, run::Attempt, AtomicDateTime, Run, TimeSpan, TimingMethod};
+
+/// A single point of a time series.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HistoryPoint {
+    /// The index of the [`Attempt`](crate::run::Attempt) the segment time was
+    /// achieved in.
+    pub attempt_index: i32,
+    /// The point in time the attempt was started at. This is `None` if it is
+    /// not known when the attempt was started.
+    pub date: Option<AtomicDateTime>,
+    /// The segment time achieved in the attempt.
+    pub time: TimeSpan,
+}
+
+/// Calculates the time series of the segment times of the segment with the
+/// index provided, for the timing method provided. The points are ordered
+/// chronologically by their attempt index. Attempts that skipped the segment or
+/// didn't reach it are not part of the series. If the segment before was
+/// skipped, the segment time stored covers both segments, so it isn't part of
+/// the series either. Segment times that don't belong to any actual attempt,
+/// such as the ones created by editing the splits, are excluded as well.
+///
+/// # Panics
+///
+/// This panics if the segment index is out of bounds.
+pub fn history_series(run: &Run, segment_index: usize, method: TimingMethod) -> Vec<HistoryPoint> {
+    let attempts = run.attempt_history();
+    let previous_history = segment_index
+        .checked_sub(1)
+        .map(|index| run.segment(index).segment_history());
+
+    run.segment(segment_index)
+        .segment_history()
+        .iter_actual_runs()
+        .filter_map(|&(attempt_index, time)| {
+            let time = time[method]?;
+            if previous_history
+                .and_then(|history| history.get(attempt_index))
+                .is_some_and(|previous| previous[method].is_none())
+            {
+                return None;
+            }
+            let date = attempts
+                .binary_search_by_key(&attempt_index, Attempt::index)
+                .ok()
+                .and_then(|index| attempts[index].started());
+            Some(HistoryPoint {
+                attempt_index,
+                date,
+                time,
+            })
+        })
+        .collect()
+}
+
+/// Smooths a time series with a trailing moving average. Each point's time is
+/// replaced by the average of its own time and the times of the points before
+/// it, up to the window size provided. The attempt indices and dates are kept,
+/// so the smoothed series can be plotted the same way as the original one. A
+/// window size of 0 or 1 keeps the times as they are.
+pub fn smooth(series: &[HistoryPoint], window: usize) -> Vec<HistoryPoint> {
+    let window = window.max(1);
+    let mut sum = 0.0;
+
+    series
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            sum += point.time.total_seconds();
+            if let Some(oldest) = index.checked_sub(window) {
+                sum -= series[oldest].time.total_seconds();
+            }
+            let count = (index + 1).min(window);
+            HistoryPoint {
+                time: TimeSpan::from_seconds(sum / count as f64),
+                ..*point
+            }
+        })
+        .collect()
+}
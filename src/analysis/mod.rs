@@ -1,16 +1,35 @@
 //! The analysis module provides a variety of functions for calculating
 //! information about a [`Run`](crate::run::Run).
 
+pub mod adaptive_pace;
+pub mod barriers;
+pub mod best_possible_time;
+pub mod choke_rate;
+pub mod clock_drift;
+mod comparison_deltas;
+pub mod completion;
+pub mod cues;
 pub mod current_pace;
 pub mod delta;
+pub mod finish_distribution;
+pub mod gold_origins;
+pub mod history_series;
 pub mod pb_chance;
 pub mod possible_time_save;
+pub mod remaining_time;
+pub mod reset_report;
 mod skill_curve;
+pub mod skipped_splits;
+pub mod split_color;
 pub mod state_helper;
 pub mod sum_of_segments;
+pub mod targets;
 pub mod total_playtime;
 
+pub use self::comparison_deltas::{compare_comparisons, ComparisonDeltas};
+pub use self::history_series::history_series;
 pub use self::skill_curve::SkillCurve;
+pub use self::split_color::split_color;
 pub use self::state_helper::*;
 
 #[cfg(test)]
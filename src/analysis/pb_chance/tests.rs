@@ -1,8 +1,6 @@
 use super::for_timer;
 use crate::{
-    util::tests_helper::{
-        create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run,
-    },
+    testing::{create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run},
     Timer, TimerPhase,
 };
 
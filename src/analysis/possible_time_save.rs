@@ -4,7 +4,7 @@
 //! theoretically perfect segment times, this information is only an
 //! approximation of how much time can actually be saved.
 
-use crate::{analysis, timing::Snapshot, TimeSpan};
+use crate::{analysis, timing::Snapshot, TimeSpan, TimingMethod};
 
 /// Calculates how much time could be saved on the given segment with the given
 /// comparison. This information is based on the best segments. Considering the
@@ -21,9 +21,27 @@ pub fn calculate(
     segment_index: usize,
     comparison: &str,
     live: bool,
+) -> (Option<TimeSpan>, bool) {
+    calculate_for_timing_method(
+        timer,
+        segment_index,
+        comparison,
+        timer.current_timing_method(),
+        live,
+    )
+}
+
+/// Calculates how much time could be saved on the given segment with the given
+/// comparison, just like [`calculate`], but for the timing method provided
+/// instead of the timer's current timing method.
+pub fn calculate_for_timing_method(
+    timer: &Snapshot<'_>,
+    segment_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+    live: bool,
 ) -> (Option<TimeSpan>, bool) {
     let segments = timer.run().segments();
-    let method = timer.current_timing_method();
     let mut prev_time = TimeSpan::zero();
     let segment = timer.run().segment(segment_index);
 
@@ -79,12 +97,30 @@ pub fn calculate_total(
     timer: &Snapshot<'_>,
     segment_index: usize,
     comparison: &str,
+) -> (TimeSpan, bool) {
+    calculate_total_for_timing_method(
+        timer,
+        segment_index,
+        comparison,
+        timer.current_timing_method(),
+    )
+}
+
+/// Calculates how much time could be saved on the remainder of the run with the
+/// given comparison, just like [`calculate_total`], but for the timing method
+/// provided instead of the timer's current timing method.
+pub fn calculate_total_for_timing_method(
+    timer: &Snapshot<'_>,
+    segment_index: usize,
+    comparison: &str,
+    method: TimingMethod,
 ) -> (TimeSpan, bool) {
     let mut total = TimeSpan::zero();
     let mut updates_frequently = false;
 
     for index in segment_index..timer.run().len() {
-        let (time_save, changing) = calculate(timer, index, comparison, true);
+        let (time_save, changing) =
+            calculate_for_timing_method(timer, index, comparison, method, true);
         updates_frequently |= changing;
         if let Some(time_save) = time_save {
             total += time_save;
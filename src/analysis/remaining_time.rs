@@ -0,0 +1,122 @@
+//! Calculates how much time is left in the active attempt. Next to the time
+//! that is expected to be left based on the chosen comparison, the time that
+//! is left in the best case, based on the best segments, and in the worst case,
+//! based on the worst segments ever observed, is calculated as well.
+
+use super::current_pace;
+use crate::{
+    comparison::{best_segments, worst_segments},
+    timing::Snapshot,
+    TimeSpan, TimerPhase, TimingMethod,
+};
+
+/// The breakdown of the time that is left in the active attempt.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RemainingTime {
+    /// The time that is expected to be left if the remainder of the attempt
+    /// matches the comparison.
+    pub expected: Option<TimeSpan>,
+    /// The time that is left if all the remaining segments match the best
+    /// segments.
+    pub best_possible: Option<TimeSpan>,
+    /// The time that is left if all the remaining segments match the worst
+    /// segments ever observed.
+    pub worst_observed: Option<TimeSpan>,
+    /// Specifies whether any of the times are actively changing at the moment.
+    pub updates_frequently: bool,
+}
+
+/// Calculates how much time is left in the active attempt, based on the
+/// comparison provided, the best segments and the worst segments. If there's
+/// no active attempt, the times of the full run are returned instead. If the
+/// attempt ended, no time is left.
+pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> RemainingTime {
+    let (expected, expected_is_live) = remaining(timer, comparison);
+    let (best_possible, best_is_live) = remaining(timer, best_segments::NAME);
+    let (worst_observed, worst_is_live) = remaining(timer, worst_segments::NAME);
+
+    RemainingTime {
+        expected,
+        best_possible,
+        worst_observed,
+        updates_frequently: expected_is_live || best_is_live || worst_is_live,
+    }
+}
+
+/// Calculates how much time is left in the active attempt if the remainder of
+/// the attempt matches the comparison provided. Additionally a value is
+/// returned that indicates whether the time is actively changing at the
+/// moment. If the active attempt is already behind the comparison in the
+/// current segment, the time left stays the same, as the prediction of the
+/// final time keeps growing along with the current time.
+pub fn remaining(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, bool) {
+    remaining_for_timing_method(timer, comparison, timer.current_timing_method())
+}
+
+/// Calculates how much time is left in the active attempt if the remainder of
+/// the attempt matches the comparison provided, just like [`remaining`], but
+/// for the timing method provided instead of the timer's current timing
+/// method.
+pub fn remaining_for_timing_method(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    method: TimingMethod,
+) -> (Option<TimeSpan>, bool) {
+    let phase = timer.current_phase();
+
+    match phase {
+        TimerPhase::NotRunning => {
+            current_pace::calculate_for_timing_method(timer, comparison, method)
+        }
+        TimerPhase::Ended => (Some(TimeSpan::zero()), false),
+        TimerPhase::Running | TimerPhase::Paused => {
+            let (final_time, is_live) =
+                current_pace::calculate_for_timing_method(timer, comparison, method);
+            let value = catch! {
+                (final_time? - timer.current_time()[method]?).max(TimeSpan::zero())
+            };
+            (
+                value,
+                !is_live && phase.updates_frequently(method) && value.is_some(),
+            )
+        }
+    }
+}
+
+/// Calculates how much time is left in the active attempt until the estimated
+/// duration of the run, such as the one a marathon schedules for it, is used
+/// up. Additionally a value is returned that indicates whether the time is
+/// actively changing at the moment. If the run has no estimate, `None` is
+/// returned. Once the attempt is over its estimate, the time left stays at
+/// zero.
+pub fn remaining_until_estimate(timer: &Snapshot<'_>) -> (Option<TimeSpan>, bool) {
+    remaining_until_estimate_for_timing_method(timer, timer.current_timing_method())
+}
+
+/// Calculates how much time is left in the active attempt until the estimated
+/// duration of the run is used up, just like [`remaining_until_estimate`], but
+/// for the timing method provided instead of the timer's current timing
+/// method.
+pub fn remaining_until_estimate_for_timing_method(
+    timer: &Snapshot<'_>,
+    method: TimingMethod,
+) -> (Option<TimeSpan>, bool) {
+    let Some(estimate) = timer.run().metadata().estimate() else {
+        return (None, false);
+    };
+    let phase = timer.current_phase();
+
+    match phase {
+        TimerPhase::NotRunning => (Some(estimate), false),
+        TimerPhase::Ended => (Some(TimeSpan::zero()), false),
+        TimerPhase::Running | TimerPhase::Paused => {
+            let value = catch! {
+                (estimate - timer.current_time()[method]?).max(TimeSpan::zero())
+            };
+            (
+                value,
+                phase.updates_frequently(method) && value.is_some_and(|v| v > TimeSpan::zero()),
+            )
+        }
+    }
+}
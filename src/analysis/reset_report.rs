@@ -0,0 +1,58 @@
+//! Summarizes what would be lost or kept by resetting the active attempt right
+//! now. This includes the best segments that the attempt achieved so far and
+//! whether the attempt would still beat the Personal Best if the remainder of
+//! it matched the comparison.
+
+use super::{check_best_segment, current_pace};
+use crate::{platform::prelude::*, timing::Snapshot, TimeSpan, TimerPhase};
+
+/// Describes what would be lost or kept by resetting the active attempt.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResetReport {
+    /// The indices of the segments in which the active attempt achieved a new
+    /// best segment time.
+    pub new_best_segments: Vec<usize>,
+    /// The final time the active attempt would end up with if the remainder of
+    /// it matched the comparison.
+    pub predicted_final_time: Option<TimeSpan>,
+    /// The final time of the Personal Best. This is `None` if there is no
+    /// Personal Best yet.
+    pub personal_best_time: Option<TimeSpan>,
+    /// Specifies whether the active attempt would beat the Personal Best if the
+    /// remainder of it matched the comparison. If the attempt already ended,
+    /// this specifies whether it is a new Personal Best.
+    pub would_beat_personal_best: bool,
+}
+
+/// Summarizes what would be lost or kept by resetting the active attempt right
+/// now, based on the comparison provided. `None` is returned if there is no
+/// active attempt.
+pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> Option<ResetReport> {
+    if timer.current_phase() == TimerPhase::NotRunning {
+        return None;
+    }
+
+    let method = timer.current_timing_method();
+
+    let new_best_segments = (0..timer.run().len())
+        .filter(|&index| check_best_segment(timer, index, method))
+        .collect();
+
+    let (predicted_final_time, _) = current_pace::calculate(timer, comparison);
+    let personal_best_time = catch! {
+        timer.run().segments().last()?.personal_best_split_time()[method]?
+    };
+
+    let would_beat_personal_best = match (predicted_final_time, personal_best_time) {
+        (Some(predicted), Some(personal_best)) => predicted < personal_best,
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+
+    Some(ResetReport {
+        new_best_segments,
+        predicted_final_time,
+        personal_best_time,
+        would_beat_personal_best,
+    })
+}
@@ -225,5 +225,5 @@ fn interpolate(
     let weight_diff_recip = (weight_right - weight_left).recip();
     let perc_down = (weight_right - perc) * time_left.total_seconds() * weight_diff_recip;
     let perc_up = (perc - weight_left) * time_right.total_seconds() * weight_diff_recip;
-    TimeSpan::from_seconds(perc_up + perc_down)
+    TimeSpan::saturating_from_seconds(perc_up + perc_down)
 }
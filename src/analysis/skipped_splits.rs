@@ -0,0 +1,129 @@
+//! Provides functionality for including or excluding attempts with skipped
+//! splits from statistics, based on the reasons the splits got skipped. A split
+//! is considered skipped in an attempt if its segment history contains an
+//! empty time for the attempt.
+
+use crate::{run::SkipReason, Run, Time};
+
+/// Describes which skipped splits are acceptable for statistics. Attempts with
+/// a skipped split that is not acceptable are excluded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SkipFilter {
+    /// Whether splits skipped because of a missed input are acceptable.
+    pub missed_input: bool,
+    /// Whether splits skipped because of the route are acceptable.
+    pub route_skip: bool,
+    /// Whether splits skipped because the auto splitter missed them are
+    /// acceptable.
+    pub auto_splitter_miss: bool,
+    /// Whether splits skipped without a reason are acceptable.
+    pub unspecified: bool,
+}
+
+impl SkipFilter {
+    /// A filter that accepts all skipped splits, so no attempt is excluded.
+    pub const ALL: Self = Self {
+        missed_input: true,
+        route_skip: true,
+        auto_splitter_miss: true,
+        unspecified: true,
+    };
+
+    /// A filter that doesn't accept any skipped splits, so all attempts with
+    /// skipped splits are excluded.
+    pub const NONE: Self = Self {
+        missed_input: false,
+        route_skip: false,
+        auto_splitter_miss: false,
+        unspecified: false,
+    };
+
+    /// Returns whether a split skipped for the reason provided is acceptable.
+    pub const fn includes(self, reason: Option<SkipReason>) -> bool {
+        match reason {
+            Some(SkipReason::MissedInput) => self.missed_input,
+            Some(SkipReason::RouteSkip) => self.route_skip,
+            Some(SkipReason::AutoSplitterMiss) => self.auto_splitter_miss,
+            None => self.unspecified,
+        }
+    }
+}
+
+impl Default for SkipFilter {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// The amount of times a segment got skipped in the history, grouped by the
+/// reason.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SkipCounts {
+    /// The amount of times it got skipped because of a missed input.
+    pub missed_input: u32,
+    /// The amount of times it got skipped because of the route.
+    pub route_skip: u32,
+    /// The amount of times it got skipped because the auto splitter missed
+    /// it.
+    pub auto_splitter_miss: u32,
+    /// The amount of times it got skipped without a reason.
+    pub unspecified: u32,
+}
+
+impl SkipCounts {
+    /// Returns the total amount of times the segment got skipped.
+    pub const fn total(&self) -> u32 {
+        self.missed_input + self.route_skip + self.auto_splitter_miss + self.unspecified
+    }
+}
+
+const fn is_skipped(time: Time) -> bool {
+    time.real_time.is_none() && time.game_time.is_none()
+}
+
+/// Counts how often the segment with the index provided got skipped in the
+/// attempts stored in the history, grouped by the reason.
+pub fn count(run: &Run, segment_index: usize) -> SkipCounts {
+    let segment = run.segment(segment_index);
+    let mut counts = SkipCounts::default();
+    for &(index, time) in segment.segment_history().iter_actual_runs() {
+        if is_skipped(time) {
+            let count = match segment.skip_reason_for_attempt(index) {
+                Some(SkipReason::MissedInput) => &mut counts.missed_input,
+                Some(SkipReason::RouteSkip) => &mut counts.route_skip,
+                Some(SkipReason::AutoSplitterMiss) => &mut counts.auto_splitter_miss,
+                None => &mut counts.unspecified,
+            };
+            *count += 1;
+        }
+    }
+    counts
+}
+
+/// Returns whether the attempt with the index provided is acceptable for
+/// statistics, which is the case if none of its skipped splits are excluded by
+/// the filter.
+pub fn includes_attempt(run: &Run, attempt_index: i32, filter: SkipFilter) -> bool {
+    run.segments().iter().all(|segment| {
+        !segment
+            .segment_history()
+            .get(attempt_index)
+            .is_some_and(is_skipped)
+            || filter.includes(segment.skip_reason_for_attempt(attempt_index))
+    })
+}
+
+/// Iterates over the segment history of the segment with the index provided,
+/// leaving out the times of all the attempts that are excluded by the filter.
+/// The times that don't belong to an attempt are always included.
+pub fn filtered_segment_history(
+    run: &Run,
+    segment_index: usize,
+    filter: SkipFilter,
+) -> impl Iterator<Item = (i32, Time)> + '_ {
+    run.segment(segment_index)
+        .segment_history()
+        .iter()
+        .copied()
+        .filter(move |&(index, _)| index <= 0 || includes_attempt(run, index, filter))
+}
@@ -0,0 +1,125 @@
+//! The split color module decides how times and deltas get colored. Instead of
+//! choosing actual colors, the functions in here return a [`SemanticColor`]
+//! that describes what is happening, such as the runner being ahead of the
+//! comparison and gaining even more time. The [`SemanticColor`] can then be
+//! turned into the color configured in the
+//! [`GeneralLayoutSettings`](crate::GeneralLayoutSettings) with
+//! [`SemanticColor::visualize`]. Components should use these functions rather
+//! than coloring times on their own, so the colors are consistent across the
+//! whole layout.
+
+use super::{check_best_segment, last_delta};
+use crate::{settings::SemanticColor, TimeSpan, Timer, TimerPhase, TimingMethod};
+
+/// Chooses the semantic color of a delta that is associated with a split. This
+/// is what the splits and most other components use for coloring their
+/// deltas.
+///
+/// - `timer`: The current [`Timer`].
+/// - `time_difference`: The delta that you want to find a color for.
+/// - `segment_index`: The split number that is associated with this delta.
+/// - `show_segment_deltas`: Can show ahead gaining and behind losing colors if
+///   true.
+/// - `show_best_segments`: Can show the best segment color if true.
+/// - `comparison`: The comparison that you are comparing this delta to.
+/// - `method`: The [`TimingMethod`] of this delta.
+///
+/// Returns the chosen color. It can be turned into an actual color with
+/// [`SemanticColor::visualize`].
+pub fn split_color(
+    timer: &Timer,
+    time_difference: Option<TimeSpan>,
+    segment_index: usize,
+    show_segment_deltas: bool,
+    show_best_segments: bool,
+    comparison: &str,
+    method: TimingMethod,
+) -> SemanticColor {
+    if show_best_segments && check_best_segment(timer, segment_index, method) {
+        SemanticColor::BestSegment
+    } else if let Some(time_difference) = time_difference.filter(|t| t != &TimeSpan::zero()) {
+        let last_delta = segment_index
+            .checked_sub(1)
+            .and_then(|n| last_delta(timer.run(), n, comparison, method));
+        if time_difference < TimeSpan::zero() {
+            if show_segment_deltas && last_delta.is_some_and(|d| time_difference > d) {
+                SemanticColor::AheadLosingTime
+            } else {
+                SemanticColor::AheadGainingTime
+            }
+        } else if show_segment_deltas && last_delta.is_some_and(|d| time_difference < d) {
+            SemanticColor::BehindGainingTime
+        } else {
+            SemanticColor::BehindLosingTime
+        }
+    } else {
+        SemanticColor::Default
+    }
+}
+
+/// Chooses the semantic color of a delta that isn't associated with any
+/// specific split. A negative delta is shown as ahead and a positive delta as
+/// behind. There's no color for deltas of zero or missing deltas.
+pub fn delta_color(delta: Option<TimeSpan>) -> SemanticColor {
+    match delta {
+        Some(delta) if delta < TimeSpan::zero() => SemanticColor::AheadGainingTime,
+        Some(delta) if delta > TimeSpan::zero() => SemanticColor::BehindLosingTime,
+        _ => SemanticColor::Default,
+    }
+}
+
+/// Chooses the semantic color of the total time of the current attempt, the
+/// way the timer shows it.
+///
+/// - `timer`: The current [`Timer`].
+/// - `time`: The current time of the attempt.
+/// - `comparison`: The comparison that you are comparing the time to.
+/// - `method`: The [`TimingMethod`] of the time.
+///
+/// While the attempt is running, the time is colored based on the delta to
+/// the comparison at the current split. Once the attempt is finished, it's
+/// either colored as a new Personal Best or as behind the comparison.
+pub fn timer_color(
+    timer: &Timer,
+    time: TimeSpan,
+    comparison: &str,
+    method: TimingMethod,
+) -> SemanticColor {
+    match timer.current_phase() {
+        TimerPhase::Running if time >= TimeSpan::zero() => {
+            let split_index = timer.current_split_index().unwrap();
+            let split_time = timer.run().segment(split_index).comparison(comparison)[method];
+
+            if let Some(split_time) = split_time {
+                split_color(
+                    timer,
+                    Some(time - split_time),
+                    split_index,
+                    true,
+                    false,
+                    comparison,
+                    method,
+                )
+                .or(SemanticColor::AheadGainingTime)
+            } else {
+                SemanticColor::AheadGainingTime
+            }
+        }
+        TimerPhase::Paused => SemanticColor::Paused,
+        TimerPhase::Ended => {
+            let final_time = timer
+                .run()
+                .segments()
+                .last()
+                .unwrap()
+                .comparison(comparison)[method];
+
+            if final_time.map_or(true, |t| time < t) {
+                SemanticColor::PersonalBest
+            } else {
+                SemanticColor::BehindLosingTime
+            }
+        }
+        _ => SemanticColor::NotRunning,
+    }
+}
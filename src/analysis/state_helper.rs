@@ -1,8 +1,9 @@
 //! Provides different helper functions.
 
+pub use super::split_color::split_color;
 use crate::{
-    comparison::best_segments, settings::SemanticColor, timing::Snapshot, Run, Segment, TimeSpan,
-    Timer, TimerPhase, TimingMethod,
+    comparison::best_segments, timing::Snapshot, Run, Segment, TimeSpan, Timer, TimerPhase,
+    TimingMethod,
 };
 
 /// Gets the last non-live delta in the [`Run`] starting from `segment_index`.
@@ -293,50 +294,6 @@ pub fn check_live_delta(
     None
 }
 
-/// Chooses a split color from the
-/// [`LayoutSettings`](crate::layout::LayoutSettings) based on the current run.
-///
-/// - `timer`: The current [`Timer`].
-/// - `time_difference`: The delta that you want to find a color for.
-/// - `segment_index`: The split number that is associated with this delta.
-/// - `show_segment_deltas`: Can show ahead gaining and behind losing colors if
-///    true.
-/// - `show_best_segments`: Can show the best segment color if true.
-/// - `comparison`: The comparison that you are comparing this delta to.
-/// - `method`: The [`TimingMethod`] of this delta.
-///
-/// Returns the chosen color.
-pub fn split_color(
-    timer: &Timer,
-    time_difference: Option<TimeSpan>,
-    segment_index: usize,
-    show_segment_deltas: bool,
-    show_best_segments: bool,
-    comparison: &str,
-    method: TimingMethod,
-) -> SemanticColor {
-    if show_best_segments && check_best_segment(timer, segment_index, method) {
-        SemanticColor::BestSegment
-    } else if let Some(time_difference) = time_difference.filter(|t| t != &TimeSpan::zero()) {
-        let last_delta = segment_index
-            .checked_sub(1)
-            .and_then(|n| last_delta(timer.run(), n, comparison, method));
-        if time_difference < TimeSpan::zero() {
-            if show_segment_deltas && last_delta.is_some_and(|d| time_difference > d) {
-                SemanticColor::AheadLosingTime
-            } else {
-                SemanticColor::AheadGainingTime
-            }
-        } else if show_segment_deltas && last_delta.is_some_and(|d| time_difference < d) {
-            SemanticColor::BehindGainingTime
-        } else {
-            SemanticColor::BehindLosingTime
-        }
-    } else {
-        SemanticColor::Default
-    }
-}
-
 /// Calculates whether or not the Split Times for the indicated split qualify as
 /// a Best Segment.
 ///
@@ -102,7 +102,6 @@ fn populate_predictions(
 /// means that the predictions buffer needs to have one more element than the
 /// list of segments provided, so that you can properly query the total Sum of
 /// Best Segments. This value is also the value that is being returned.
-#[allow(clippy::needless_range_loop)]
 pub fn calculate(
     segments: &[Segment],
     predictions: &mut [Option<Prediction>],
@@ -110,9 +109,39 @@ pub fn calculate(
     use_current_run: bool,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    predictions[0] = Some(Prediction::default());
+    calculate_from(
+        segments,
+        predictions,
+        0,
+        TimeSpan::zero(),
+        simple_calculation,
+        use_current_run,
+        method,
+    )
+}
+
+/// Calculates the fastest time possible to complete a [`Run`](crate::Run),
+/// starting at the segment with the index provided, which is reached at the
+/// time provided. This works just like [`calculate`], except that only the
+/// predictions from the starting segment onwards get populated. This is useful
+/// for determining the best time that is still possible to achieve when a part
+/// of the run is already completed.
+#[allow(clippy::needless_range_loop)]
+pub fn calculate_from(
+    segments: &[Segment],
+    predictions: &mut [Option<Prediction>],
+    start_index: usize,
+    start_time: TimeSpan,
+    simple_calculation: bool,
+    use_current_run: bool,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    predictions[start_index] = Some(Prediction {
+        time: start_time,
+        predecessor: start_index.saturating_sub(1),
+    });
     let end_index = segments.len();
-    for segment_index in 0..end_index {
+    for segment_index in start_index..end_index {
         populate_predictions(
             segments,
             predictions[segment_index],
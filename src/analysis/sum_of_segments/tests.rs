@@ -1,7 +1,7 @@
 use super::{best, Prediction};
 use crate::{
     comparison::best_segments,
-    util::tests_helper::{create_timer, run_with_splits_opt, span},
+    testing::{create_timer, run_with_splits_opt, span},
     Timer, TimingMethod,
 };
 
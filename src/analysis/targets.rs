@@ -0,0 +1,92 @@
+//! Calculates what is needed to finish the active attempt below a certain
+//! target time. For each target, it is determined whether the target is still
+//! achievable based on the best segments and which average segment time is
+//! needed for the remaining segments in order to reach it.
+
+use crate::{
+    analysis::sum_of_segments::best, platform::prelude::*, timing::Snapshot, TimeSpan, TimerPhase,
+    TimingMethod,
+};
+
+/// Describes what is needed to finish an attempt below a target time.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TargetPrediction {
+    /// Specifies whether the target can still be reached if the remainder of
+    /// the attempt consists of nothing but best segments.
+    pub achievable: bool,
+    /// The amount of time that is left for the remaining segments, measured
+    /// from the last split of the active attempt. This is `None` if there is no
+    /// remaining segment.
+    pub time_left: Option<TimeSpan>,
+    /// The average segment time needed on each of the remaining segments in
+    /// order to reach the target. This is `None` if there is no remaining
+    /// segment.
+    pub required_pace: Option<TimeSpan>,
+    /// Specifies whether the prediction is currently actively changing as time
+    /// is being lost on the current segment.
+    pub updates_frequently: bool,
+}
+
+/// Calculates what is needed to finish the active attempt below the target
+/// time provided, for the timing method specified. If there's no active
+/// attempt, the prediction describes a whole attempt from the beginning.
+pub fn calculate(timer: &Snapshot<'_>, target: TimeSpan, method: TimingMethod) -> TargetPrediction {
+    let segments = timer.run().segments();
+
+    let phase = timer.current_phase();
+    if phase == TimerPhase::Ended {
+        let final_time = segments.last().and_then(|s| s.split_time()[method]);
+        return TargetPrediction {
+            achievable: final_time.is_some_and(|t| t <= target),
+            ..Default::default()
+        };
+    }
+
+    let current_split_index = timer.current_split_index().unwrap_or(0);
+
+    let (last_split_time, remaining_segments) = segments[..current_split_index]
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(i, s)| Some((s.split_time()[method]?, segments.len() - i - 1)))
+        .unwrap_or((TimeSpan::zero(), segments.len()));
+
+    let (time_left, required_pace) = if remaining_segments != 0 {
+        let time_left = target - last_split_time;
+        let required_pace = TimeSpan::saturating_from_seconds(
+            time_left.total_seconds() / remaining_segments as f64,
+        );
+        (Some(time_left), Some(required_pace))
+    } else {
+        (None, None)
+    };
+
+    let remaining = &segments[segments.len() - remaining_segments..];
+    let mut predictions = vec![None; remaining.len() + 1];
+    let mut best_possible_time = best::calculate(remaining, &mut predictions, false, false, method)
+        .map(|t| last_split_time + t);
+    let mut updates_frequently = false;
+
+    if phase != TimerPhase::NotRunning {
+        let live_best_possible_time = catch! {
+            let mut time = timer.current_time()[method]?;
+            for segment in &segments[current_split_index + 1..] {
+                time += segment.best_segment_time()[method]?;
+            }
+            time
+        };
+        if let Some(live_time) = live_best_possible_time {
+            if best_possible_time.map_or(true, |t| live_time > t) {
+                best_possible_time = Some(live_time);
+                updates_frequently = phase.updates_frequently(method);
+            }
+        }
+    }
+
+    TargetPrediction {
+        achievable: best_possible_time.is_some_and(|t| t <= target),
+        time_left,
+        required_pace,
+        updates_frequently,
+    }
+}
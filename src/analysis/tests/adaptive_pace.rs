@@ -0,0 +1,59 @@
+use crate::{
+    analysis::adaptive_pace,
+    testing::{create_timer, run_with_splits, set_game_time, split_at, start_run},
+    Timer, TimingMethod,
+};
+
+fn adaptive_pace(timer: &Timer) -> Option<f64> {
+    let (time, _) = adaptive_pace::calculate(&timer.snapshot(), TimingMethod::GameTime);
+    time.map(|t| (t.total_seconds() * 1000.0).round() / 1000.0)
+}
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    timer
+}
+
+#[test]
+fn uses_the_average_without_an_attempt() {
+    let timer = timer();
+    assert_eq!(adaptive_pace(&timer), Some(30.0));
+}
+
+#[test]
+fn scales_the_remaining_segments_by_the_performance_so_far() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    assert_eq!(adaptive_pace(&timer), Some(30.0));
+
+    split_at(&mut timer, 9.0);
+    assert_eq!(adaptive_pace(&timer), Some(27.0));
+
+    split_at(&mut timer, 22.0);
+    assert_eq!(adaptive_pace(&timer), Some(33.0));
+}
+
+#[test]
+fn slow_current_segment_is_taken_into_account() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    split_at(&mut timer, 9.0);
+
+    set_game_time(&mut timer, 15.0);
+    assert_eq!(adaptive_pace(&timer), Some(27.0));
+
+    set_game_time(&mut timer, 25.0);
+    assert_eq!(adaptive_pace(&timer), Some(37.5));
+}
+
+#[test]
+fn uses_the_final_time_once_finished() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    for split in [9.0, 22.0, 31.0] {
+        split_at(&mut timer, split);
+    }
+    assert_eq!(adaptive_pace(&timer), Some(31.0));
+}
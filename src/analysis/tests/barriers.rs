@@ -0,0 +1,40 @@
+use crate::{
+    analysis::barriers::{below, interval_for, Barrier},
+    testing::span,
+};
+
+fn barrier(time: f64, margin: f64) -> Option<Barrier> {
+    Some(Barrier {
+        time: span(time),
+        margin: span(margin),
+    })
+}
+
+#[test]
+fn chooses_round_intervals_based_on_the_magnitude() {
+    assert_eq!(interval_for(span(42.5)), span(1.0));
+    assert_eq!(interval_for(span(5.0 * 60.0)), span(10.0));
+    assert_eq!(interval_for(span(25.0 * 60.0)), span(60.0));
+    assert_eq!(interval_for(span(111.0 * 60.0)), span(5.0 * 60.0));
+    assert_eq!(interval_for(span(20.0 * 60.0 * 60.0)), span(60.0 * 60.0));
+}
+
+#[test]
+fn finds_the_nearest_barrier_below() {
+    assert_eq!(below(span(6690.0), None), barrier(6600.0, 90.0));
+    assert_eq!(below(span(6690.0), Some(span(60.0))), barrier(6660.0, 30.0));
+    assert_eq!(below(span(42.5), None), barrier(42.0, 0.5));
+}
+
+#[test]
+fn a_time_on_a_barrier_still_needs_to_break_it() {
+    assert_eq!(below(span(6600.0), None), barrier(6300.0, 300.0));
+}
+
+#[test]
+fn there_is_no_barrier_below_the_first_interval() {
+    assert_eq!(below(span(0.5), None), None);
+    assert_eq!(below(span(1.0), None), None);
+    assert_eq!(below(span(-5.0), None), None);
+    assert_eq!(below(span(90.0), Some(span(0.0))), None);
+}
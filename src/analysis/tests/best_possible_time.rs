@@ -0,0 +1,71 @@
+use crate::{
+    analysis::{best_possible_time, current_pace},
+    comparison::best_segments,
+    testing::{
+        create_timer, run_with_splits, run_with_splits_opt, set_game_time, span, split_at,
+        start_run,
+    },
+    Timer, TimingMethod,
+};
+
+fn best_possible_time(timer: &Timer) -> (Option<f64>, bool) {
+    let (time, is_live) = best_possible_time::calculate(&timer.snapshot(), TimingMethod::GameTime);
+    (time.map(|t| t.total_seconds()), is_live)
+}
+
+#[test]
+fn uses_the_sum_of_best_without_an_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits_opt(&mut timer, &[None, Some(15.0), Some(25.0)]);
+
+    assert_eq!(best_possible_time(&timer), (Some(25.0), false));
+}
+
+#[test]
+fn combined_segments_the_attempt_can_not_take_are_excluded() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits_opt(&mut timer, &[None, Some(15.0), Some(25.0)]);
+
+    start_run(&mut timer);
+    split_at(&mut timer, 10.0);
+
+    let (current_pace, _) = current_pace::calculate_for_timing_method(
+        &timer.snapshot(),
+        best_segments::NAME,
+        TimingMethod::GameTime,
+    );
+    assert_eq!(current_pace, Some(span(25.0)));
+    assert_eq!(best_possible_time(&timer), (Some(30.0), false));
+}
+
+#[test]
+fn golds_of_the_attempt_are_taken_into_account() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+    assert_eq!(best_possible_time(&timer), (Some(25.0), false));
+
+    timer.skip_split().unwrap();
+    assert_eq!(best_possible_time(&timer), (Some(25.0), false));
+
+    split_at(&mut timer, 27.0);
+    assert_eq!(best_possible_time(&timer), (Some(27.0), false));
+}
+
+#[test]
+fn slow_live_segments_are_taken_into_account() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    start_run(&mut timer);
+    split_at(&mut timer, 10.0);
+    set_game_time(&mut timer, 18.0);
+    assert_eq!(best_possible_time(&timer), (Some(30.0), false));
+
+    set_game_time(&mut timer, 25.0);
+    assert_eq!(best_possible_time(&timer), (Some(35.0), true));
+}
@@ -0,0 +1,44 @@
+use crate::{
+    analysis::choke_rate::{calculate, ChokeRate},
+    testing::{create_timer, run_with_splits, run_with_splits_opt},
+};
+
+fn choke_rate(reached: usize, ended: usize) -> ChokeRate {
+    ChokeRate { reached, ended }
+}
+
+#[test]
+fn counts_the_attempts_that_ended_in_each_segment() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    let choke_rates = calculate(timer.run());
+    assert_eq!(
+        choke_rates,
+        [choke_rate(5, 1), choke_rate(4, 2), choke_rate(2, 1)]
+    );
+    assert_eq!(choke_rates[1].rate(), Some(0.5));
+}
+
+#[test]
+fn skipped_segments_are_reached() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits_opt(&mut timer, &[None, Some(20.0)]);
+
+    assert_eq!(
+        calculate(timer.run()),
+        [choke_rate(1, 0), choke_rate(1, 0), choke_rate(1, 1)]
+    );
+}
+
+#[test]
+fn segments_without_attempts_have_no_rate() {
+    let timer = create_timer(&["A", "B"]);
+    let choke_rates = calculate(timer.run());
+    assert_eq!(choke_rates, [ChokeRate::default(); 2]);
+    assert_eq!(choke_rates[0].rate(), None);
+}
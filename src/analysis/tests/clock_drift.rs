@@ -0,0 +1,28 @@
+use crate::{
+    analysis::clock_drift,
+    testing::{create_timer, start_run},
+    TimeSpan,
+};
+
+#[test]
+fn no_drift_without_attempt() {
+    let mut timer = create_timer(&["A"]);
+    assert_eq!(clock_drift::calculate(&timer.snapshot()), None);
+
+    start_run(&mut timer);
+    timer.split().unwrap();
+    assert_eq!(clock_drift::calculate(&timer.snapshot()), None);
+}
+
+#[test]
+fn clocks_agree_during_attempt() {
+    let mut timer = create_timer(&["A"]);
+    start_run(&mut timer);
+
+    let snapshot = timer.snapshot();
+    assert!(clock_drift::calculate(&snapshot).is_some());
+    assert!(!clock_drift::exceeds_threshold(
+        &snapshot,
+        TimeSpan::from_seconds(1.0)
+    ));
+}
@@ -0,0 +1,59 @@
+use crate::{
+    analysis::compare_comparisons,
+    comparison::{best_segments, personal_best},
+    testing::{create_timer, run_with_splits, span},
+    Run, Segment, Time, TimingMethod,
+};
+
+#[test]
+fn personal_best_compared_to_best_segments() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0, 35.0]);
+
+    let deltas = compare_comparisons(timer.run(), personal_best::NAME, best_segments::NAME);
+    let game_time = |t: Time| t.game_time;
+
+    assert_eq!(
+        deltas
+            .segments
+            .iter()
+            .copied()
+            .map(game_time)
+            .collect::<Vec<_>>(),
+        [Some(span(0.0)), Some(span(2.0)), Some(span(0.0))],
+    );
+    assert_eq!(deltas.total.game_time, Some(span(2.0)));
+    assert_eq!(deltas.largest_loss(TimingMethod::GameTime), Some(1));
+}
+
+#[test]
+fn segments_without_split_times_are_combined() {
+    let mut run = Run::new();
+    for (name, a, b) in [
+        ("A", Some(10.0), 12.0),
+        ("B", None, 20.0),
+        ("C", Some(30.0), 33.0),
+    ] {
+        let mut segment = Segment::new(name);
+        segment.comparison_mut("a").real_time = a.map(span);
+        segment.comparison_mut("b").real_time = Some(span(b));
+        run.push_segment(segment);
+    }
+
+    let deltas = compare_comparisons(&run, "a", "b");
+    let real_time = |t: Time| t.real_time;
+
+    assert_eq!(
+        deltas
+            .segments
+            .iter()
+            .copied()
+            .map(real_time)
+            .collect::<Vec<_>>(),
+        [Some(span(-2.0)), None, Some(span(-1.0))],
+    );
+    assert_eq!(deltas.total.real_time, Some(span(-3.0)));
+    assert_eq!(deltas.total.game_time, None);
+    assert_eq!(deltas.largest_loss(TimingMethod::RealTime), Some(2));
+}
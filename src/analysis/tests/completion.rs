@@ -0,0 +1,88 @@
+use crate::{
+    analysis::completion,
+    comparison::personal_best,
+    testing::{create_timer, run_with_splits, span, start_run},
+    Time, TimingMethod,
+};
+
+#[test]
+fn by_segments() {
+    let mut timer = create_timer(&["A", "B"]);
+    assert_eq!(completion::by_segments(&timer.snapshot()), 0.0);
+
+    start_run(&mut timer);
+    timer.split().unwrap();
+    assert_eq!(completion::by_segments(&timer.snapshot()), 0.5);
+
+    timer.split().unwrap();
+    assert_eq!(completion::by_segments(&timer.snapshot()), 1.0);
+}
+
+#[test]
+fn by_comparison_time_progresses_within_segment() {
+    let mut timer = create_timer(&["A", "B"]);
+    assert_eq!(
+        completion::by_comparison_time(&timer.snapshot(), personal_best::NAME),
+        (None, false),
+    );
+
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    assert_eq!(
+        completion::by_comparison_time(&timer.snapshot(), personal_best::NAME),
+        (Some(0.0), false),
+    );
+
+    start_run(&mut timer);
+    timer.set_game_time(span(6.0)).unwrap();
+    assert_eq!(
+        completion::by_comparison_time(&timer.snapshot(), personal_best::NAME),
+        (Some(0.2), true),
+    );
+
+    timer.set_game_time(span(12.0)).unwrap();
+    let (completed, is_live) =
+        completion::by_comparison_time(&timer.snapshot(), personal_best::NAME);
+    assert_eq!(completed, Some(1.0 / 3.0));
+    assert!(!is_live);
+
+    timer.split().unwrap();
+    timer.set_game_time(span(22.0)).unwrap();
+    let (completed, is_live) =
+        completion::by_comparison_time(&timer.snapshot(), personal_best::NAME);
+    assert_eq!(completed, Some(2.0 / 3.0));
+    assert!(is_live);
+
+    timer.split().unwrap();
+    assert_eq!(
+        completion::by_comparison_time(&timer.snapshot(), personal_best::NAME),
+        (Some(1.0), false),
+    );
+}
+
+#[test]
+fn by_comparison_time_uses_the_timing_method_provided() {
+    let mut timer = create_timer(&["A", "B"]);
+    let mut run = timer.run().clone();
+    run.segments_mut()[1]
+        .set_personal_best_split_time(Time::new().with_game_time(Some(span(30.0))));
+    timer.set_run(run).unwrap();
+
+    start_run(&mut timer);
+    timer.set_game_time(span(6.0)).unwrap();
+    assert_eq!(
+        completion::by_comparison_time_for_timing_method(
+            &timer.snapshot(),
+            personal_best::NAME,
+            TimingMethod::GameTime,
+        ),
+        (Some(0.2), true),
+    );
+    assert_eq!(
+        completion::by_comparison_time_for_timing_method(
+            &timer.snapshot(),
+            personal_best::NAME,
+            TimingMethod::RealTime,
+        ),
+        (None, false),
+    );
+}
@@ -0,0 +1,53 @@
+use crate::{
+    analysis::cues::{Cue, CueTracker},
+    comparison::personal_best,
+    testing::{create_timer, run_with_splits, set_game_time, split_at, start_run},
+    Timer,
+};
+
+fn update(tracker: &mut CueTracker, timer: &Timer) -> Vec<Cue> {
+    tracker.update(&timer.snapshot(), personal_best::NAME)
+}
+
+#[test]
+fn reports_each_cue_once() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    let mut tracker = CueTracker::new();
+
+    start_run(&mut timer);
+    assert_eq!(update(&mut tracker, &timer), []);
+
+    set_game_time(&mut timer, 5.0);
+    assert_eq!(update(&mut tracker, &timer), []);
+
+    set_game_time(&mut timer, 12.0);
+    assert_eq!(
+        update(&mut tracker, &timer),
+        [Cue::LostGoldPace(0), Cue::WentBehind]
+    );
+    assert_eq!(update(&mut tracker, &timer), []);
+
+    split_at(&mut timer, 12.0);
+    assert_eq!(update(&mut tracker, &timer), []);
+
+    split_at(&mut timer, 19.0);
+    assert_eq!(
+        update(&mut tracker, &timer),
+        [
+            Cue::BestSegment(1),
+            Cue::PersonalBestPaceAtFinalSplit,
+            Cue::WentAhead
+        ]
+    );
+
+    split_at(&mut timer, 28.0);
+    assert_eq!(
+        update(&mut tracker, &timer),
+        [Cue::BestSegment(2), Cue::PersonalBest]
+    );
+    assert_eq!(update(&mut tracker, &timer), []);
+
+    timer.reset(true).unwrap();
+    assert_eq!(update(&mut tracker, &timer), []);
+}
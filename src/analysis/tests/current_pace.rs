@@ -1,16 +1,17 @@
 use crate::{
     analysis,
-    util::tests_helper::{
+    comparison::{best_segments, personal_best},
+    settings::SemanticColor,
+    testing::{
         create_timer,
         run_with_splits,
+        start_run,
+        FakeClock,
     },
-    comparison::best_segments,
+    TimeSpan, TimingMethod,
 };
 
-use std::{
-    thread,
-    time::Duration,
-};
+use std::time::Duration;
 
 const COMPARISON: &str = best_segments::NAME;
 
@@ -29,12 +30,43 @@ fn predict_wall_clock_time() {
 
     let finish = start.time + current_pace.unwrap().to_duration();
 
-    assert_eq!(uf1, true);
+    assert!(!uf1);
     assert_eq!(finish, predicted_time.unwrap().time);
 }
 
+#[test]
+fn predicted_time_updates_frequently_only_while_moving() {
+    let clock = FakeClock::install();
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[30.0, 60.0]);
+    timer.set_current_timing_method(TimingMethod::RealTime);
+
+    let updates_frequently = |timer: &crate::Timer| {
+        analysis::current_pace::predict_wall_clock_time(&timer.snapshot(), personal_best::NAME).1
+    };
+
+    assert!(updates_frequently(&timer));
+
+    timer.start().unwrap();
+    assert!(!updates_frequently(&timer));
+
+    timer.pause().unwrap();
+    assert!(updates_frequently(&timer));
+    timer.resume().unwrap();
+
+    clock.advance(TimeSpan::from_seconds(40.0));
+    assert!(updates_frequently(&timer));
+
+    timer.split().unwrap();
+    assert!(!updates_frequently(&timer));
+
+    timer.split().unwrap();
+    assert!(!updates_frequently(&timer));
+}
+
 #[test]
 fn predicted_time_doesnt_change_while_running() {
+    let clock = FakeClock::install();
     let mut timer = create_timer(&["A"]);
     run_with_splits(&mut timer, &[60.0]);
 
@@ -47,7 +79,7 @@ fn predicted_time_doesnt_change_while_running() {
     let d0 = predicted_time0.unwrap().time - start;
     assert_eq!(d0, Duration::from_secs(60));
 
-    thread::sleep(Duration::from_secs(5));
+    clock.advance(TimeSpan::from_seconds(5.0));
 
     let snap1 = timer.snapshot();
     let (predicted_time1, _) = analysis::current_pace::predict_wall_clock_time(&snap1, COMPARISON);
@@ -60,6 +92,7 @@ fn predicted_time_doesnt_change_while_running() {
 
 #[test]
 fn predicted_time_change_while_paused() {
+    let clock = FakeClock::install();
     let mut timer = create_timer(&["A"]);
     run_with_splits(&mut timer, &[60.0]);
 
@@ -70,17 +103,18 @@ fn predicted_time_change_while_paused() {
 
     timer.pause().unwrap();
 
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
 
     let snap1 = timer.snapshot();
     let (predicted_time1, _) = analysis::current_pace::predict_wall_clock_time(&snap1, COMPARISON);
 
     let d = predicted_time1.unwrap().time - predicted_time0.unwrap().time;
-    assert!(d >= Duration::from_secs(1));
+    assert_eq!(d, Duration::from_secs(1));
 }
 
 #[test]
 fn predicted_time_has_changed_after_resume() {
+    let clock = FakeClock::install();
     let mut timer = create_timer(&["A"]);
     run_with_splits(&mut timer, &[60.0]);
 
@@ -90,18 +124,19 @@ fn predicted_time_has_changed_after_resume() {
     let (predicted_time0, _) = analysis::current_pace::predict_wall_clock_time(&snap0, COMPARISON);
 
     timer.pause().unwrap();
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
     timer.resume().unwrap();
 
     let snap1 = timer.snapshot();
     let (predicted_time1, _) = analysis::current_pace::predict_wall_clock_time(&snap1, COMPARISON);
 
     let d = predicted_time1.unwrap().time - predicted_time0.unwrap().time;
-    assert!(d >= Duration::from_secs(1));
+    assert_eq!(d, Duration::from_secs(1));
 }
 
 #[test]
 fn predicted_time_change_while_paused_a_second_time() {
+    let clock = FakeClock::install();
     let mut timer = create_timer(&["A"]);
     run_with_splits(&mut timer, &[60.0]);
 
@@ -111,23 +146,22 @@ fn predicted_time_change_while_paused_a_second_time() {
     let (predicted_time0, _) = analysis::current_pace::predict_wall_clock_time(&snap0, COMPARISON);
 
     timer.pause().unwrap();
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
     timer.resume().unwrap();
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
     timer.pause().unwrap();
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
 
     let snap1 = timer.snapshot();
     let (predicted_time1, _) = analysis::current_pace::predict_wall_clock_time(&snap1, COMPARISON);
 
     let d = predicted_time1.unwrap().time - predicted_time0.unwrap().time;
-    println!("{:?}", d);
-    assert!(d >= Duration::from_secs(2));
-    assert!(d < Duration::from_secs(3));
+    assert_eq!(d, Duration::from_secs(2));
 }
 
 #[test]
 fn predicted_time_has_changed_after_paused_a_second_time() {
+    let clock = FakeClock::install();
     let mut timer = create_timer(&["A"]);
     run_with_splits(&mut timer, &[60.0]);
 
@@ -137,18 +171,39 @@ fn predicted_time_has_changed_after_paused_a_second_time() {
     let (predicted_time0, _) = analysis::current_pace::predict_wall_clock_time(&snap0, COMPARISON);
 
     timer.pause().unwrap();
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
     timer.resume().unwrap();
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
     timer.pause().unwrap();
-    thread::sleep(Duration::from_secs(1));
+    clock.advance(TimeSpan::from_seconds(1.0));
     timer.resume().unwrap();
 
     let snap1 = timer.snapshot();
     let (predicted_time1, _) = analysis::current_pace::predict_wall_clock_time(&snap1, COMPARISON);
 
     let d = predicted_time1.unwrap().time - predicted_time0.unwrap().time;
-    println!("{:?}", d);
-    assert!(d >= Duration::from_secs(2));
-    assert!(d < Duration::from_secs(3));
+    assert_eq!(d, Duration::from_secs(2));
+}
+
+#[test]
+fn semantic_color_follows_delta_to_final_time() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[30.0, 60.0]);
+    start_run(&mut timer);
+
+    let color = |timer: &crate::Timer| {
+        let snap = timer.snapshot();
+        let (current_pace, _) = analysis::current_pace::calculate(&snap, personal_best::NAME);
+        let method = snap.current_timing_method();
+        analysis::current_pace::semantic_color(&snap, personal_best::NAME, method, current_pace)
+    };
+
+    assert_eq!(color(&timer), SemanticColor::Default);
+
+    timer.set_game_time(TimeSpan::from_seconds(20.0)).unwrap();
+    timer.split().unwrap();
+    assert_eq!(color(&timer), SemanticColor::AheadGainingTime);
+
+    timer.set_game_time(TimeSpan::from_seconds(75.0)).unwrap();
+    assert_eq!(color(&timer), SemanticColor::BehindLosingTime);
 }
@@ -1,6 +1,6 @@
 use super::super::sum_of_segments::{calculate_best, calculate_worst};
 use super::super::total_playtime;
-use crate::{Run, TimeSpan, TimingMethod};
+use crate::{Run, TimeSpan, Timer, TimingMethod};
 
 #[test]
 fn sum_of_best() {
@@ -41,3 +41,41 @@ fn total_playtime() {
     let run = Run::new();
     assert_eq!(total_playtime::calculate(&run), TimeSpan::zero());
 }
+
+#[test]
+fn pb_chance() {
+    let run = Run::new();
+    assert_eq!(
+        super::super::pb_chance::for_run(&run, TimingMethod::RealTime),
+        1.0
+    );
+}
+
+#[test]
+fn finish_distribution() {
+    let run = Run::new();
+    assert_eq!(super::super::finish_distribution::calculate(&run).len(), 0);
+}
+
+#[test]
+fn comparison_deltas() {
+    let run = Run::new();
+    let deltas = super::super::compare_comparisons(&run, "a", "b");
+    assert!(deltas.segments.is_empty());
+    assert_eq!(deltas.largest_loss(TimingMethod::RealTime), None);
+}
+
+#[test]
+fn gold_origins() {
+    let run = Run::new();
+    assert!(super::super::gold_origins::calculate(&run, TimingMethod::RealTime).is_empty());
+}
+
+#[test]
+fn timer_requires_segments() {
+    assert!(Timer::new(Run::new()).is_err());
+
+    let mut timer = Timer::new(crate::testing::create_run(&["A"])).unwrap();
+    assert!(timer.replace_run(Run::new(), true).is_err());
+    assert!(timer.set_run(Run::new()).is_err());
+}
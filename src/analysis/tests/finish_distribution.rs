@@ -0,0 +1,44 @@
+use crate::{
+    analysis::finish_distribution,
+    testing::{create_run, span},
+    AtomicDateTime, DateTime, Time,
+};
+
+fn at(seconds: i64) -> AtomicDateTime {
+    AtomicDateTime::new(DateTime::from_unix_timestamp(seconds).unwrap(), false)
+}
+
+#[test]
+fn only_finished_attempts_are_considered() {
+    let mut run = create_run(&["A"]);
+    for (final_time, duration) in [
+        (Some(30.0), 40),
+        (None, 5),
+        (Some(20.0), 25),
+        (Some(50.0), 60),
+    ] {
+        let time = Time::new().with_real_time(final_time.map(span));
+        run.add_attempt(time, Some(at(0)), Some(at(duration)), None);
+    }
+
+    let distribution = finish_distribution::calculate(&run);
+    assert_eq!(
+        distribution.durations(),
+        [span(25.0), span(40.0), span(60.0)]
+    );
+    assert_eq!(distribution.percentile(0.0), Some(span(25.0)));
+    assert_eq!(distribution.percentile(0.5), Some(span(40.0)));
+    assert_eq!(distribution.percentile(0.9), Some(span(60.0)));
+    assert_eq!(
+        distribution.finish_at(at(1000).time, 0.5),
+        Some(at(1040).time),
+    );
+}
+
+#[test]
+fn empty_history() {
+    let distribution = finish_distribution::calculate(&create_run(&["A"]));
+    assert!(distribution.is_empty());
+    assert_eq!(distribution.percentile(0.5), None);
+    assert_eq!(distribution.finish_at(at(0).time, 0.5), None);
+}
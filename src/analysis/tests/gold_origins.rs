@@ -0,0 +1,59 @@
+use crate::{
+    analysis::gold_origins::{calculate, GoldOrigin},
+    testing::{create_timer, run_with_splits, span},
+    TimingMethod,
+};
+
+#[test]
+fn finds_attempts_of_best_segments() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[8.0, 20.0, 31.0]);
+    run_with_splits(&mut timer, &[9.0, 17.0, 28.0]);
+
+    let origins = calculate(timer.run(), TimingMethod::GameTime);
+    let indices: Vec<_> = origins
+        .iter()
+        .map(|origin| origin.map(|o| o.attempt_index))
+        .collect();
+    assert_eq!(indices, [Some(2), Some(3), Some(1)]);
+
+    for (origin, attempt) in origins
+        .iter()
+        .zip([2, 3, 1].map(|index| timer.run().attempt_history()[index - 1].clone()))
+    {
+        assert_eq!(origin.unwrap().achieved, attempt.started());
+    }
+}
+
+#[test]
+fn earliest_attempt_is_used_for_ties() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let origins = calculate(timer.run(), TimingMethod::GameTime);
+    assert_eq!(origins[0].map(|o| o.attempt_index), Some(1));
+    assert_eq!(origins[1].map(|o| o.attempt_index), Some(1));
+}
+
+#[test]
+fn manually_edited_best_segments_have_no_origin() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut run = timer.run().clone();
+    let mut best_segment_time = run.segment(1).best_segment_time();
+    best_segment_time.game_time = Some(span(5.0));
+    run.segment_mut(1).set_best_segment_time(best_segment_time);
+
+    let origins = calculate(&run, TimingMethod::GameTime);
+    assert!(matches!(
+        origins[0],
+        Some(GoldOrigin {
+            attempt_index: 1,
+            ..
+        })
+    ));
+    assert_eq!(origins[1], None);
+}
@@ -0,0 +1,62 @@
+use crate::{
+    analysis::history_series::{history_series, smooth},
+    testing::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt,
+        span, start_run,
+    },
+    TimingMethod,
+};
+
+#[test]
+fn lists_segment_times_in_attempt_order() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[8.0, 21.0]);
+    run_with_splits(&mut timer, &[9.0, 17.0]);
+
+    let series = history_series(timer.run(), 1, TimingMethod::GameTime);
+    let points: Vec<_> = series.iter().map(|p| (p.attempt_index, p.time)).collect();
+    assert_eq!(points, [(1, span(10.0)), (2, span(13.0)), (3, span(8.0))]);
+
+    for point in &series {
+        let attempt = &timer.run().attempt_history()[point.attempt_index as usize - 1];
+        assert_eq!(point.date, attempt.started());
+    }
+}
+
+#[test]
+fn excludes_skipped_and_unreached_segments() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits_opt(&mut timer, &[Some(9.0), None, Some(29.0)]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+    timer.reset(true).unwrap();
+
+    let indices = |segment| {
+        history_series(timer.run(), segment, TimingMethod::GameTime)
+            .iter()
+            .map(|p| p.attempt_index)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(indices(0), [1, 2, 3]);
+    assert_eq!(indices(1), [1]);
+    // The segment after a skipped one stores the combined segment time.
+    assert_eq!(indices(2), [1]);
+}
+
+#[test]
+fn smooths_with_trailing_moving_average() {
+    let mut timer = create_timer(&["A"]);
+    for time in [10.0, 20.0, 30.0, 40.0] {
+        run_with_splits(&mut timer, &[time]);
+    }
+
+    let series = history_series(timer.run(), 0, TimingMethod::GameTime);
+    let smoothed = smooth(&series, 2);
+    let times: Vec<_> = smoothed.iter().map(|p| p.time).collect();
+    assert_eq!(times, [span(10.0), span(15.0), span(25.0), span(35.0)]);
+    assert_eq!(smoothed[3].attempt_index, 4);
+
+    assert_eq!(smooth(&series, 0), series);
+}
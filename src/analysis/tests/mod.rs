@@ -1,3 +1,18 @@
 mod empty_run;
 mod semantic_colors;
 mod current_pace;
+mod targets;
+mod clock_drift;
+mod remaining_time;
+mod completion;
+mod reset_report;
+mod finish_distribution;
+mod skipped_splits;
+mod cues;
+mod best_possible_time;
+mod adaptive_pace;
+mod comparison_deltas;
+mod gold_origins;
+mod choke_rate;
+mod barriers;
+mod history_series;
@@ -0,0 +1,124 @@
+use crate::{
+    analysis::remaining_time::{self, RemainingTime},
+    comparison::personal_best,
+    testing::{create_run, create_timer, run_with_splits, span, start_run},
+    Time, Timer, TimingMethod,
+};
+
+#[test]
+fn full_run_is_left_without_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 25.0, 40.0]);
+
+    let remaining = remaining_time::calculate(&timer.snapshot(), personal_best::NAME);
+    assert_eq!(remaining.expected, Some(span(30.0)));
+    assert_eq!(remaining.best_possible, Some(span(30.0)));
+    assert_eq!(remaining.worst_observed, Some(span(40.0)));
+}
+
+#[test]
+fn counts_down_while_ahead_and_stays_while_behind() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 25.0, 40.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(span(8.0)).unwrap();
+    let (remaining, is_live) = remaining_time::remaining(&timer.snapshot(), personal_best::NAME);
+    assert_eq!(remaining, Some(span(22.0)));
+    assert!(is_live);
+
+    timer.set_game_time(span(11.0)).unwrap();
+    let (remaining, is_live) = remaining_time::remaining(&timer.snapshot(), personal_best::NAME);
+    assert_eq!(remaining, Some(span(20.0)));
+    assert!(!is_live);
+
+    timer.split().unwrap();
+    let remaining = remaining_time::calculate(&timer.snapshot(), personal_best::NAME);
+    assert_eq!(remaining.expected, Some(span(20.0)));
+    assert_eq!(remaining.best_possible, Some(span(20.0)));
+    assert_eq!(remaining.worst_observed, Some(span(28.0)));
+}
+
+#[test]
+fn nothing_is_left_once_ended() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    start_run(&mut timer);
+    timer.split().unwrap();
+    assert_eq!(
+        remaining_time::calculate(&timer.snapshot(), personal_best::NAME),
+        RemainingTime {
+            expected: Some(span(0.0)),
+            best_possible: Some(span(0.0)),
+            worst_observed: Some(span(0.0)),
+            updates_frequently: false,
+        },
+    );
+}
+
+#[test]
+fn counts_down_until_the_estimate() {
+    let mut timer = create_timer(&["A", "B"]);
+    assert_eq!(
+        remaining_time::remaining_until_estimate(&timer.snapshot()),
+        (None, false),
+    );
+
+    let mut run = timer.run().clone();
+    run.metadata_mut().set_estimate(Some(span(60.0)));
+    timer.set_run(run).unwrap();
+    assert_eq!(
+        remaining_time::remaining_until_estimate(&timer.snapshot()),
+        (Some(span(60.0)), false),
+    );
+
+    start_run(&mut timer);
+    timer.set_game_time(span(25.0)).unwrap();
+    assert_eq!(
+        remaining_time::remaining_until_estimate(&timer.snapshot()),
+        (Some(span(35.0)), true),
+    );
+
+    timer.set_game_time(span(70.0)).unwrap();
+    assert_eq!(
+        remaining_time::remaining_until_estimate(&timer.snapshot()),
+        (Some(span(0.0)), false),
+    );
+}
+
+#[test]
+fn uses_the_timing_method_provided() {
+    let mut run = create_run(&["A", "B"]);
+    for (segment, (real_time, game_time)) in run
+        .segments_mut()
+        .iter_mut()
+        .zip([(20.0, 10.0), (40.0, 30.0)])
+    {
+        segment.set_personal_best_split_time(
+            Time::new()
+                .with_real_time(Some(span(real_time)))
+                .with_game_time(Some(span(game_time))),
+        );
+    }
+    let timer = Timer::new(run).unwrap();
+
+    assert_eq!(
+        remaining_time::remaining_for_timing_method(
+            &timer.snapshot(),
+            personal_best::NAME,
+            TimingMethod::RealTime,
+        ),
+        (Some(span(40.0)), false),
+    );
+    assert_eq!(
+        remaining_time::remaining_for_timing_method(
+            &timer.snapshot(),
+            personal_best::NAME,
+            TimingMethod::GameTime,
+        ),
+        (Some(span(30.0)), false),
+    );
+}
@@ -0,0 +1,46 @@
+use crate::testing::{create_timer, run_with_splits, span, start_run};
+
+#[test]
+fn no_report_without_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    assert_eq!(timer.reset_report(), None);
+}
+
+#[test]
+fn reports_new_best_segments_and_pace() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(span(8.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(span(19.0)).unwrap();
+    timer.split().unwrap();
+
+    let report = timer.reset_report().unwrap();
+    assert_eq!(report.new_best_segments, [0]);
+    assert_eq!(report.predicted_final_time, Some(span(29.0)));
+    assert_eq!(report.personal_best_time, Some(span(30.0)));
+    assert!(report.would_beat_personal_best);
+
+    timer.set_game_time(span(45.0)).unwrap();
+    let report = timer.reset_report().unwrap();
+    assert_eq!(report.predicted_final_time, Some(span(45.0)));
+    assert!(!report.would_beat_personal_best);
+}
+
+#[test]
+fn beats_missing_personal_best() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+    timer.set_game_time(span(5.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(span(7.0)).unwrap();
+    timer.split().unwrap();
+
+    let report = timer.reset_report().unwrap();
+    assert_eq!(report.new_best_segments, [0, 1]);
+    assert_eq!(report.personal_best_time, None);
+    assert!(report.would_beat_personal_best);
+}
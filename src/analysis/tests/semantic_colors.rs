@@ -1,10 +1,8 @@
 use crate::{
-    analysis::split_color,
+    analysis::split_color::{self, split_color},
     comparison,
     settings::SemanticColor,
-    util::tests_helper::{
-        create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run,
-    },
+    testing::{create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run},
     Timer, TimingMethod,
 };
 
@@ -33,6 +31,53 @@ fn segment_colors_are_correct() {
     assert_eq!(color(&timer, 0.0), SemanticColor::Default);
 }
 
+#[test]
+fn delta_colors_are_based_on_the_sign() {
+    assert_eq!(
+        split_color::delta_color(Some(span(-3.0))),
+        SemanticColor::AheadGainingTime,
+    );
+    assert_eq!(
+        split_color::delta_color(Some(span(3.0))),
+        SemanticColor::BehindLosingTime,
+    );
+    assert_eq!(
+        split_color::delta_color(Some(span(0.0))),
+        SemanticColor::Default
+    );
+    assert_eq!(split_color::delta_color(None), SemanticColor::Default);
+}
+
+#[test]
+fn timer_colors_follow_the_phase() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    assert_eq!(timer_color(&timer, 0.0), SemanticColor::NotRunning);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(7.0)]);
+    assert_eq!(timer_color(&timer, 12.0), SemanticColor::AheadGainingTime);
+    assert_eq!(timer_color(&timer, 25.0), SemanticColor::BehindLosingTime);
+
+    timer.toggle_pause().unwrap();
+    assert_eq!(timer_color(&timer, 12.0), SemanticColor::Paused);
+    timer.toggle_pause().unwrap();
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(18.0)]);
+    assert_eq!(timer_color(&timer, 18.0), SemanticColor::PersonalBest);
+    assert_eq!(timer_color(&timer, 22.0), SemanticColor::BehindLosingTime);
+}
+
+fn timer_color(timer: &Timer, time: f64) -> SemanticColor {
+    split_color::timer_color(
+        timer,
+        span(time),
+        comparison::personal_best::NAME,
+        TimingMethod::GameTime,
+    )
+}
+
 fn color(timer: &Timer, delta: f64) -> SemanticColor {
     split_color(
         timer,
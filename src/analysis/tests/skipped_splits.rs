@@ -0,0 +1,111 @@
+use crate::{
+    analysis::skipped_splits::{self, SkipCounts, SkipFilter},
+    run::{parser::livesplit, saver, SkipReason},
+    testing::{create_timer, split_at, start_run},
+    Timer,
+};
+
+fn timer_with_skips() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    start_run(&mut timer);
+    timer.skip_split_with_reason(SkipReason::RouteSkip).unwrap();
+    split_at(&mut timer, 10.0);
+    split_at(&mut timer, 15.0);
+    timer.reset(true).unwrap();
+
+    start_run(&mut timer);
+    timer
+        .skip_split_with_reason(SkipReason::MissedInput)
+        .unwrap();
+    timer.undo_split().unwrap();
+    split_at(&mut timer, 4.0);
+    timer.skip_split().unwrap();
+    split_at(&mut timer, 12.0);
+    timer.reset(true).unwrap();
+
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+    split_at(&mut timer, 9.0);
+    split_at(&mut timer, 14.0);
+    timer.reset(true).unwrap();
+
+    timer
+}
+
+#[test]
+fn counts_skips_by_reason() {
+    let timer = timer_with_skips();
+    let run = timer.run();
+
+    assert_eq!(
+        skipped_splits::count(run, 0),
+        SkipCounts {
+            route_skip: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        skipped_splits::count(run, 1),
+        SkipCounts {
+            unspecified: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(skipped_splits::count(run, 2).total(), 0);
+}
+
+#[test]
+fn filters_attempts_by_skip_reason() {
+    let timer = timer_with_skips();
+    let run = timer.run();
+    let [first, second, third] = [0, 1, 2].map(|i| run.attempt_history()[i].index());
+
+    let only_route_skips = SkipFilter {
+        route_skip: true,
+        ..SkipFilter::NONE
+    };
+    assert!(skipped_splits::includes_attempt(
+        run,
+        first,
+        only_route_skips
+    ));
+    assert!(!skipped_splits::includes_attempt(
+        run,
+        second,
+        only_route_skips
+    ));
+    assert!(skipped_splits::includes_attempt(
+        run,
+        third,
+        SkipFilter::NONE
+    ));
+    assert!(skipped_splits::includes_attempt(
+        run,
+        second,
+        SkipFilter::ALL
+    ));
+
+    let history = skipped_splits::filtered_segment_history(run, 2, SkipFilter::NONE)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    assert_eq!(history, [third]);
+}
+
+#[test]
+fn skip_reasons_survive_saving_and_parsing() {
+    let timer = timer_with_skips();
+    let run = timer.run();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    let parsed = livesplit::parse(&buf).unwrap();
+
+    let first = run.attempt_history()[0].index();
+    assert_eq!(
+        parsed.segment(0).skip_reason_for_attempt(first),
+        Some(SkipReason::RouteSkip)
+    );
+    assert_eq!(parsed.segment(0).skip_reason_history().len(), 1);
+    assert_eq!(parsed.segment(1).skip_reason_history().len(), 0);
+}
@@ -0,0 +1,48 @@
+use crate::{
+    analysis::targets,
+    testing::{create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run},
+    TimingMethod,
+};
+
+#[test]
+fn whole_attempt_when_not_running() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    let prediction = targets::calculate(&timer.snapshot(), span(36.0), TimingMethod::GameTime);
+    assert!(prediction.achievable);
+    assert_eq!(prediction.time_left, Some(span(36.0)));
+    assert_eq!(prediction.required_pace, Some(span(12.0)));
+
+    let prediction = targets::calculate(&timer.snapshot(), span(29.0), TimingMethod::GameTime);
+    assert!(!prediction.achievable);
+}
+
+#[test]
+fn remaining_segments_during_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0)]);
+
+    let prediction = targets::calculate(&timer.snapshot(), span(35.0), TimingMethod::GameTime);
+    assert!(prediction.achievable);
+    assert_eq!(prediction.time_left, Some(span(23.0)));
+    assert_eq!(prediction.required_pace, Some(span(11.5)));
+
+    let prediction = targets::calculate(&timer.snapshot(), span(30.0), TimingMethod::GameTime);
+    assert!(!prediction.achievable);
+    assert_eq!(prediction.required_pace, Some(span(9.0)));
+}
+
+#[test]
+fn finished_attempt() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), Some(25.0)]);
+
+    let snapshot = timer.snapshot();
+    assert!(targets::calculate(&snapshot, span(25.0), TimingMethod::GameTime).achievable);
+    assert!(!targets::calculate(&snapshot, span(24.0), TimingMethod::GameTime).achievable);
+}
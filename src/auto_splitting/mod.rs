@@ -541,11 +541,7 @@
 //! - There is no networking.
 //! - There is no threading.
 
-use crate::{
-    event::{self, TimerQuery},
-    platform::Arc,
-    timing::TimerPhase,
-};
+use crate::{event::TimerControl, platform::Arc, timing::TimerPhase};
 pub use livesplit_auto_splitting::{settings, wasi_path};
 use livesplit_auto_splitting::{
     AutoSplitter, Config, CreationError, InterruptHandle, Timer as AutoSplitTimer, TimerState,
@@ -597,13 +593,13 @@ impl<T> Drop for Runtime<T> {
     }
 }
 
-impl<T: event::CommandSink + TimerQuery + Send + 'static> Default for Runtime<T> {
+impl<T: TimerControl + Send + 'static> Default for Runtime<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: event::CommandSink + TimerQuery + Send + 'static> Runtime<T> {
+impl<T: TimerControl + Send + 'static> Runtime<T> {
     /// Starts the runtime. Doesn't actually load an auto splitter until
     /// [`load`][Runtime::load] is called.
     pub fn new() -> Self {
@@ -719,9 +715,9 @@ impl<T: event::CommandSink + TimerQuery + Send + 'static> Runtime<T> {
 // is an Arc<RwLock<T>>, so we can't implement the trait directly on it.
 struct Timer<E>(E);
 
-impl<E: event::CommandSink + TimerQuery> AutoSplitTimer for Timer<E> {
+impl<E: TimerControl> AutoSplitTimer for Timer<E> {
     fn state(&self) -> TimerState {
-        match self.0.get_timer().current_phase() {
+        match self.0.current_phase() {
             TimerPhase::NotRunning => TimerState::NotRunning,
             TimerPhase::Running => TimerState::Running,
             TimerPhase::Paused => TimerState::Paused,
@@ -770,7 +766,7 @@ impl<E: event::CommandSink + TimerQuery> AutoSplitTimer for Timer<E> {
     }
 }
 
-async fn run<T: event::CommandSink + TimerQuery>(
+async fn run<T: TimerControl>(
     mut auto_splitter: watch::Receiver<Option<AutoSplitter<Timer<T>>>>,
     timeout_sender: watch::Sender<Option<Instant>>,
     interrupt_sender: watch::Sender<Option<InterruptHandle>>,
@@ -5,15 +5,20 @@
 //! the current performance of a runner.
 
 use super::ComparisonGenerator;
-use crate::{Attempt, Segment, TimeSpan, TimingMethod};
+use crate::{Attempt, Segment, Time, TimeSpan, TimingMethod};
 
 /// The Comparison Generator for calculating the Average Segments of a
 /// [`Run`](crate::Run). The Average Segments are calculated through a weighted
 /// arithmetic mean that gives more recent segments a larger weight so that
 /// the Average Segments are more suited to represent the current performance
 /// of a runner.
-#[derive(Copy, Clone, Debug)]
-pub struct AverageSegments;
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AverageSegments {
+    /// Specifies whether only the segment times of attempts that finished the
+    /// run are taken into account. This excludes the segments of attempts that
+    /// got reset, which tend to skew the averages of the early segments.
+    pub finished_runs_only: bool,
+}
 
 /// The short name of this comparison. Suitable for situations where not a lot
 /// of space for text is available.
@@ -23,35 +28,58 @@ pub const NAME: &str = "Average Segments";
 
 const WEIGHT: f64 = 0.75;
 
-fn generate(segments: &mut [Segment], method: TimingMethod) {
+fn average<'a>(
+    history: impl Iterator<Item = &'a (i32, Time)>,
+    previous_segment: Option<&Segment>,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let (mut total_weights, mut total_time) = (0.0, 0.0);
+    let mut current_weight = 1.0;
+
+    for &(id, time) in history {
+        if let Some(time) = time[method] {
+            // Skip all the combined segments
+            let skip = catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+
+            if !skip {
+                total_weights += current_weight;
+                total_time += current_weight * time.total_seconds();
+                current_weight *= WEIGHT;
+            }
+        }
+    }
+
+    if total_weights == 0.0 {
+        None
+    } else {
+        Some(TimeSpan::saturating_from_seconds(
+            total_time / total_weights,
+        ))
+    }
+}
+
+fn generate(segments: &mut [Segment], attempts: Option<&[Attempt]>, method: TimingMethod) {
     let mut accumulated = Some(TimeSpan::zero());
 
     let mut previous_segment: Option<&Segment> = None;
     for segment in segments {
         if let Some(accumulated_val) = &mut accumulated {
-            let (mut total_weights, mut total_time) = (0.0, 0.0);
-            let mut current_weight = 1.0;
-
-            for &(id, time) in segment.segment_history().iter_actual_runs().rev() {
-                if let Some(time) = time[method] {
-                    // Skip all the combined segments
-                    let skip = catch! {
-                        previous_segment?.segment_history().get(id)?[method].is_none()
-                    }
-                    .unwrap_or(false);
-
-                    if !skip {
-                        total_weights += current_weight;
-                        total_time += current_weight * time.total_seconds();
-                        current_weight *= WEIGHT;
-                    }
-                }
-            }
+            let history = segment.segment_history();
+            let average = match attempts {
+                Some(attempts) => average(
+                    history.iter_completed(attempts).rev(),
+                    previous_segment,
+                    method,
+                ),
+                None => average(history.iter_actual_runs().rev(), previous_segment, method),
+            };
 
-            if total_weights == 0.0 {
-                accumulated = None;
-            } else {
-                *accumulated_val += TimeSpan::from_seconds(total_time / total_weights);
+            match average {
+                Some(average) => *accumulated_val += average,
+                None => accumulated = None,
             }
         }
         segment.comparison_mut(NAME)[method] = accumulated;
@@ -64,8 +92,9 @@ impl ComparisonGenerator for AverageSegments {
         NAME
     }
 
-    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
-        generate(segments, TimingMethod::RealTime);
-        generate(segments, TimingMethod::GameTime);
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let attempts = self.finished_runs_only.then_some(attempts);
+        generate(segments, attempts, TimingMethod::RealTime);
+        generate(segments, attempts, TimingMethod::GameTime);
     }
 }
@@ -0,0 +1,132 @@
+//! Defines the Comparison Generator for calculating the Best Segments of a
+//! [`Run`](crate::Run) without taking outliers into account. Very old or
+//! extraordinarily lucky segment times can make the Best Segments unrealistic,
+//! so they can be excluded by their age or by how far they are away from the
+//! average segment time.
+
+use super::ComparisonGenerator;
+use crate::{
+    analysis::sum_of_segments::best::calculate,
+    platform::{math::f64::sqrt, prelude::*, DateTime},
+    Attempt, Segment, Time, TimingMethod,
+};
+
+/// Defines the Comparison Generator for calculating the Best Segments of a
+/// [`Run`](crate::Run) without taking outliers into account. The segment times
+/// of the [`SegmentHistory`](crate::run::SegmentHistory) are filtered before
+/// the Best Segments are calculated from them, so the Best Segment Times stored
+/// in the segments are not used directly. The Personal Best is not taken into
+/// account either, unless its segment times remain after filtering. Without any
+/// filter configured, this matches the [`BestSegments`](super::BestSegments).
+///
+/// The generator needs to be added to the Comparison Generators of the Run in
+/// order to generate its comparison.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BestSegmentsWithoutOutliers {
+    /// Segment times of attempts that got started before this point in time
+    /// are excluded. This also excludes all the segment times where it is not
+    /// known when they were achieved.
+    pub oldest_allowed: Option<DateTime>,
+    /// Segment times that are more than this amount of standard deviations
+    /// away from the average time of their segment are excluded.
+    pub max_standard_deviations: Option<f64>,
+}
+
+/// The short name of this comparison. Suitable for situations where not a lot
+/// of space for text is available.
+pub const SHORT_NAME: &str = "Best (No Outliers)";
+/// The name of this comparison.
+pub const NAME: &str = "Best Segments (Without Outliers)";
+
+impl BestSegmentsWithoutOutliers {
+    fn filter_segments(
+        &self,
+        segments: &[Segment],
+        attempts: &[Attempt],
+        method: TimingMethod,
+    ) -> Vec<Segment> {
+        segments
+            .iter()
+            .map(|segment| {
+                let mut segment = segment.clone();
+                segment.set_personal_best_split_time(Time::new());
+                let history = segment.segment_history_mut();
+
+                if let Some(oldest_allowed) = self.oldest_allowed {
+                    history.retain(|&(index, _)| {
+                        attempts
+                            .iter()
+                            .find(|a| a.index() == index)
+                            .and_then(Attempt::started)
+                            .is_some_and(|started| started.time >= oldest_allowed)
+                    });
+                }
+
+                if let Some(max_deviations) = self.max_standard_deviations {
+                    if let Some((mean, deviation)) = mean_and_deviation(history.iter(), method) {
+                        let max_distance = max_deviations * deviation;
+                        let allowed = mean - max_distance..=mean + max_distance;
+                        history.retain(|(_, time)| {
+                            time[method].map_or(true, |t| allowed.contains(&t.total_seconds()))
+                        });
+                    }
+                }
+
+                let best_segment_time = history.iter().filter_map(|(_, t)| t[method]).min();
+                let mut time = Time::new();
+                time[method] = best_segment_time;
+                segment.set_best_segment_time(time);
+
+                segment
+            })
+            .collect()
+    }
+}
+
+fn mean_and_deviation<'a>(
+    history: impl Iterator<Item = &'a (i32, Time)> + Clone,
+    method: TimingMethod,
+) -> Option<(f64, f64)> {
+    let times = history.filter_map(|(_, t)| Some(t[method]?.total_seconds()));
+    let count = times.clone().count();
+    if count == 0 {
+        return None;
+    }
+    let mean = times.clone().sum::<f64>() / count as f64;
+    let variance = times.map(|t| (t - mean) * (t - mean)).sum::<f64>() / count as f64;
+    Some((mean, sqrt(variance)))
+}
+
+impl ComparisonGenerator for BestSegmentsWithoutOutliers {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let mut predictions = Vec::with_capacity(segments.len() + 1);
+
+        segments
+            .iter_mut()
+            .for_each(|s| *s.comparison_mut(NAME) = Time::new());
+
+        for method in TimingMethod::all() {
+            let filtered = self.filter_segments(segments, attempts, method);
+
+            predictions.clear();
+            predictions.resize(segments.len() + 1, None);
+
+            calculate(&filtered, &mut predictions, false, false, method);
+
+            let mut index = predictions
+                .iter()
+                .rposition(Option::is_some)
+                .expect("There must always be a first sentinel prediction that is not None");
+            while let Some(segment_index) = index.checked_sub(1) {
+                let prediction =
+                    predictions[index].expect("A predecessor prediction always needs to exist");
+                segments[segment_index].comparison_mut(NAME)[method] = Some(prediction.time);
+                index = prediction.predecessor;
+            }
+        }
+    }
+}
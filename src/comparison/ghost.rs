@@ -0,0 +1,138 @@
+//! Defines the Comparison Generator for racing a Ghost. A Ghost replays a
+//! specific attempt from the history of the [`Run`](crate::Run) alongside the
+//! active attempt. Using the [`SegmentHistory`](crate::run::SegmentHistory),
+//! the splits of the attempt are reconstructed into a comparison, so all the
+//! components showing deltas can show the delta to the Ghost. Additionally the
+//! Ghost can be played back in real time, to determine which segment it would
+//! be in at the current time of the active attempt.
+
+use super::ComparisonGenerator;
+use crate::{
+    analysis, platform::prelude::*, timing::Snapshot, Attempt, Segment, TimeSpan, TimerPhase,
+    TimingMethod,
+};
+
+/// The prefix of the names of the comparisons generated by Ghosts.
+pub const NAME_PREFIX: &str = "Ghost";
+
+/// Defines the Comparison Generator for racing a Ghost. A Ghost replays a
+/// specific attempt from the history of the [`Run`](crate::Run) alongside the
+/// active attempt. Using the [`SegmentHistory`](crate::run::SegmentHistory),
+/// the splits of the attempt are reconstructed into a comparison, so all the
+/// components showing deltas can show the delta to the Ghost. Additionally the
+/// Ghost can be played back in real time, to determine which segment it would
+/// be in at the current time of the active attempt.
+///
+/// A Ghost needs to be added to the Comparison Generators of the Run in order
+/// to generate its comparison.
+#[derive(Clone, Debug)]
+pub struct Ghost {
+    attempt_index: i32,
+    name: String,
+}
+
+impl Ghost {
+    /// Creates a new Ghost replaying the attempt with the index provided. The
+    /// comparison is named after the attempt.
+    pub fn new(attempt_index: i32) -> Self {
+        Self {
+            attempt_index,
+            name: format!("{NAME_PREFIX} #{attempt_index}"),
+        }
+    }
+
+    /// Creates a new Ghost replaying the attempt with the index provided, using
+    /// a custom name for the comparison.
+    pub const fn with_name(attempt_index: i32, name: String) -> Self {
+        Self {
+            attempt_index,
+            name,
+        }
+    }
+
+    /// Accesses the index of the attempt the Ghost replays.
+    pub const fn attempt_index(&self) -> i32 {
+        self.attempt_index
+    }
+
+    /// Determines the index of the segment the Ghost is in at the current time
+    /// of the active attempt. Just like
+    /// [`Timer::current_split_index`](crate::Timer::current_split_index), the
+    /// index equals the amount of segments if the Ghost finished already. If
+    /// the Ghost's attempt got reset, the Ghost stays in the segment it got
+    /// reset in. `None` is returned if there is no active attempt.
+    pub fn current_split_index(&self, timer: &Snapshot<'_>) -> Option<usize> {
+        if timer.current_phase() == TimerPhase::NotRunning {
+            return None;
+        }
+        let method = timer.current_timing_method();
+        let current_time = timer.current_time()[method]?;
+
+        Some(
+            timer
+                .run()
+                .segments()
+                .iter()
+                .rposition(|s| s.comparison(&self.name)[method].is_some_and(|t| t <= current_time))
+                .map_or(0, |i| i + 1),
+        )
+    }
+
+    /// Calculates the delta of the active attempt to the Ghost. Additionally a
+    /// value is returned that indicates whether the delta is a live delta that
+    /// is actively changing at the moment.
+    pub fn delta(&self, timer: &Snapshot<'_>) -> (Option<TimeSpan>, bool) {
+        analysis::delta::calculate(timer, &self.name)
+    }
+}
+
+fn generate(segments: &mut [Segment], attempt_index: i32, name: &str, method: TimingMethod) {
+    let mut remaining_segments = segments.iter_mut();
+
+    let mut total_time = TimeSpan::zero();
+    for segment in remaining_segments.by_ref() {
+        let segment_time = segment
+            .segment_history()
+            .get(attempt_index)
+            .map(|t| t[method]);
+
+        let split_time = match segment_time {
+            Some(Some(segment_time)) => {
+                total_time += segment_time;
+                Some(total_time)
+            }
+            Some(None) => None,
+            None => {
+                segment.comparison_mut(name)[method] = None;
+                break;
+            }
+        };
+
+        segment.comparison_mut(name)[method] = split_time;
+    }
+
+    for segment in remaining_segments {
+        segment.comparison_mut(name)[method] = None;
+    }
+}
+
+impl ComparisonGenerator for Ghost {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        generate(
+            segments,
+            self.attempt_index,
+            &self.name,
+            TimingMethod::RealTime,
+        );
+        generate(
+            segments,
+            self.attempt_index,
+            &self.name,
+            TimingMethod::GameTime,
+        );
+    }
+}
@@ -4,14 +4,19 @@
 //! Median Segments are more suited to represent the current performance of a runner.
 
 use super::ComparisonGenerator;
-use crate::{platform::prelude::*, Attempt, Segment, TimeSpan, TimingMethod};
+use crate::{platform::prelude::*, Attempt, Segment, Time, TimeSpan, TimingMethod};
 
 /// Defines the Comparison Generator for calculating the Median Segments of a
 /// [`Run`](crate::Run). The Median Segments are calculated through a weighted
 /// median that gives more recent segments a larger weight so that the
 /// Median Segments are more suited to represent the current performance of a runner.
-#[derive(Copy, Clone, Debug)]
-pub struct MedianSegments;
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MedianSegments {
+    /// Specifies whether only the segment times of attempts that finished the
+    /// run are taken into account. This excludes the segments of attempts that
+    /// got reset, which tend to be the runner's worst segments.
+    pub finished_runs_only: bool,
+}
 
 /// The short name of this comparison. Suitable for situations where not a lot
 /// of space for text is available.
@@ -21,7 +26,36 @@ pub const NAME: &str = "Median Segments";
 
 const WEIGHT: f64 = 0.75;
 
-fn generate(segments: &mut [Segment], medians: &mut Vec<(f64, TimeSpan)>, method: TimingMethod) {
+fn collect_weighted_times<'a>(
+    medians: &mut Vec<(f64, TimeSpan)>,
+    history: impl Iterator<Item = &'a (i32, Time)>,
+    previous_segment: Option<&Segment>,
+    method: TimingMethod,
+) {
+    let mut current_weight = 1.0;
+
+    for &(id, time) in history {
+        if let Some(time) = time[method] {
+            // Skip all the combined segments
+            let skip = catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+
+            if !skip {
+                medians.push((current_weight, time));
+                current_weight *= WEIGHT;
+            }
+        }
+    }
+}
+
+fn generate(
+    segments: &mut [Segment],
+    attempts: Option<&[Attempt]>,
+    medians: &mut Vec<(f64, TimeSpan)>,
+    method: TimingMethod,
+) {
     let mut accumulated = Some(TimeSpan::zero());
 
     let mut previous_segment: Option<&Segment> = None;
@@ -29,21 +63,20 @@ fn generate(segments: &mut [Segment], medians: &mut Vec<(f64, TimeSpan)>, method
         if let Some(accumulated_val) = &mut accumulated {
             medians.clear();
 
-            let mut current_weight = 1.0;
-
-            for &(id, time) in segment.segment_history().iter_actual_runs().rev() {
-                if let Some(time) = time[method] {
-                    // Skip all the combined segments
-                    let skip = catch! {
-                        previous_segment?.segment_history().get(id)?[method].is_none()
-                    }
-                    .unwrap_or(false);
-
-                    if !skip {
-                        medians.push((current_weight, time));
-                        current_weight *= WEIGHT;
-                    }
-                }
+            let history = segment.segment_history();
+            match attempts {
+                Some(attempts) => collect_weighted_times(
+                    medians,
+                    history.iter_completed(attempts).rev(),
+                    previous_segment,
+                    method,
+                ),
+                None => collect_weighted_times(
+                    medians,
+                    history.iter_actual_runs().rev(),
+                    previous_segment,
+                    method,
+                ),
             }
 
             if medians.is_empty() {
@@ -70,9 +103,10 @@ impl ComparisonGenerator for MedianSegments {
         NAME
     }
 
-    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let attempts = self.finished_runs_only.then_some(attempts);
         let medians = &mut Vec::new();
-        generate(segments, medians, TimingMethod::RealTime);
-        generate(segments, medians, TimingMethod::GameTime);
+        generate(segments, attempts, medians, TimingMethod::RealTime);
+        generate(segments, attempts, medians, TimingMethod::GameTime);
     }
 }
@@ -9,17 +9,21 @@ mod tests;
 pub mod average_segments;
 pub mod balanced_pb;
 pub mod best_segments;
+pub mod best_segments_without_outliers;
 pub mod best_split_times;
+pub mod ghost;
 pub mod goal;
 pub mod latest_run;
 pub mod median_segments;
 pub mod none;
+pub mod session_median;
 pub mod worst_segments;
 
 pub use self::{
     average_segments::AverageSegments, balanced_pb::BalancedPB, best_segments::BestSegments,
-    best_split_times::BestSplitTimes, latest_run::LatestRun, median_segments::MedianSegments,
-    none::None, worst_segments::WorstSegments,
+    best_segments_without_outliers::BestSegmentsWithoutOutliers, best_split_times::BestSplitTimes,
+    ghost::Ghost, latest_run::LatestRun, median_segments::MedianSegments, none::None,
+    session_median::SessionMedian, worst_segments::WorstSegments,
 };
 
 use crate::{platform::prelude::*, Attempt, Segment, Timer};
@@ -91,8 +95,8 @@ pub fn default_generators() -> Vec<Box<dyn ComparisonGenerator>> {
     vec![
         Box::new(BestSegments),
         Box::new(BestSplitTimes),
-        Box::new(AverageSegments),
-        Box::new(MedianSegments),
+        Box::new(AverageSegments::default()),
+        Box::new(MedianSegments::default()),
         Box::new(WorstSegments),
         Box::new(BalancedPB),
         Box::new(LatestRun),
@@ -111,14 +115,36 @@ pub fn try_shorten(comparison: &str) -> Option<&'static str> {
         median_segments::NAME => median_segments::SHORT_NAME,
         balanced_pb::NAME => balanced_pb::SHORT_NAME,
         best_segments::NAME => best_segments::SHORT_NAME,
+        best_segments_without_outliers::NAME => best_segments_without_outliers::SHORT_NAME,
         best_split_times::NAME => best_split_times::SHORT_NAME,
         latest_run::NAME => latest_run::SHORT_NAME,
         none::NAME => none::SHORT_NAME,
+        session_median::NAME => session_median::SHORT_NAME,
         worst_segments::NAME => worst_segments::SHORT_NAME,
         _ => return Option::None,
     })
 }
 
+/// Checks whether the name provided is the name of one of the built-in
+/// Comparison Generators. Custom comparisons can't use these names, as they
+/// would shadow the generated comparison once the Comparison Generator is
+/// active.
+pub fn is_generator_name(comparison: &str) -> bool {
+    matches!(
+        comparison,
+        average_segments::NAME
+            | balanced_pb::NAME
+            | best_segments::NAME
+            | best_segments_without_outliers::NAME
+            | best_split_times::NAME
+            | latest_run::NAME
+            | median_segments::NAME
+            | none::NAME
+            | session_median::NAME
+            | worst_segments::NAME
+    )
+}
+
 /// Shortens a comparison name. If the name of the comparison matches one of the
 /// comparison generators, the short name of that comparison generator is
 /// returned. Otherwise, the comparison name is returned without being
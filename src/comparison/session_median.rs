@@ -0,0 +1,103 @@
+//! Defines the Comparison Generator for calculating the median segment times of
+//! the current session. A session starts when it gets marked through
+//! [`Timer::mark_session_start`](crate::Timer::mark_session_start), so the
+//! comparison reflects how the runner performs right now, rather than over the
+//! whole history of the run.
+
+use super::ComparisonGenerator;
+use crate::{
+    platform::{prelude::*, DateTime},
+    Attempt, Segment, TimeSpan, TimingMethod,
+};
+
+/// Defines the Comparison Generator for calculating the median segment times of
+/// the current session. Only the segment times of attempts that got started at
+/// or after the [`session_start`](Self::session_start) are taken into
+/// account. The comparison is regenerated between all the attempts, so it
+/// follows along as the session accumulates more attempts.
+///
+/// The [`Timer`](crate::Timer) adds this generator to the Comparison
+/// Generators of its Run when a session gets marked, so it doesn't need to be
+/// added manually.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SessionMedian {
+    /// The point in time at which the session started.
+    pub session_start: DateTime,
+}
+
+/// The short name of this comparison. Suitable for situations where not a lot
+/// of space for text is available.
+pub const SHORT_NAME: &str = "Session";
+/// The name of this comparison.
+pub const NAME: &str = "Session Median";
+
+fn generate(
+    segments: &mut [Segment],
+    session_attempts: &[i32],
+    times: &mut Vec<TimeSpan>,
+    method: TimingMethod,
+) {
+    let mut accumulated = Some(TimeSpan::zero());
+
+    let mut previous_segment: Option<&Segment> = None;
+    for segment in segments {
+        if let Some(accumulated_val) = &mut accumulated {
+            times.clear();
+
+            for &(id, time) in segment.segment_history().iter() {
+                if !session_attempts.contains(&id) {
+                    continue;
+                }
+                if let Some(time) = time[method] {
+                    // Skip all the combined segments
+                    let skip = catch! {
+                        previous_segment?.segment_history().get(id)?[method].is_none()
+                    }
+                    .unwrap_or(false);
+
+                    if !skip {
+                        times.push(time);
+                    }
+                }
+            }
+
+            if times.is_empty() {
+                accumulated = None;
+            } else {
+                times.sort_unstable();
+                let middle = times.len() / 2;
+                *accumulated_val += if times.len() % 2 == 0 {
+                    TimeSpan::from_seconds(
+                        0.5 * (times[middle - 1].total_seconds() + times[middle].total_seconds()),
+                    )
+                } else {
+                    times[middle]
+                };
+            }
+        }
+        segment.comparison_mut(NAME)[method] = accumulated;
+        previous_segment = Some(&*segment);
+    }
+}
+
+impl ComparisonGenerator for SessionMedian {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let session_attempts: Vec<i32> = attempts
+            .iter()
+            .filter(|attempt| {
+                attempt
+                    .started()
+                    .is_some_and(|started| started.time >= self.session_start)
+            })
+            .map(Attempt::index)
+            .collect();
+
+        let times = &mut Vec::new();
+        generate(segments, &session_attempts, times, TimingMethod::RealTime);
+        generate(segments, &session_attempts, times, TimingMethod::GameTime);
+    }
+}
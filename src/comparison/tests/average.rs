@@ -1,5 +1,5 @@
 use crate::comparison::average_segments::{AverageSegments, NAME};
-use crate::util::tests_helper::run_with_splits;
+use crate::testing::run_with_splits;
 use crate::{Run, Segment, TimeSpan, Timer};
 
 #[test]
@@ -12,7 +12,7 @@ fn test() {
 
     run.comparison_generators_mut().clear();
     run.comparison_generators_mut()
-        .push(Box::new(AverageSegments));
+        .push(Box::new(AverageSegments::default()));
 
     let mut timer = Timer::new(run).unwrap();
 
@@ -47,3 +47,31 @@ fn test() {
         assert!(current_average > last_average);
     }
 }
+
+#[test]
+fn finished_runs_only() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut()
+        .push(Box::new(AverageSegments {
+            finished_runs_only: true,
+        }));
+
+    let mut timer = Timer::new(run).unwrap();
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[50.0]);
+
+    let run = timer.run();
+    assert_eq!(
+        run.segment(0).comparison(NAME).game_time,
+        Some(TimeSpan::from_seconds(10.0)),
+    );
+    assert_eq!(
+        run.segment(1).comparison(NAME).game_time,
+        Some(TimeSpan::from_seconds(20.0)),
+    );
+}
@@ -0,0 +1,79 @@
+use crate::{
+    comparison::{
+        best_segments,
+        best_segments_without_outliers::{BestSegmentsWithoutOutliers, NAME},
+    },
+    platform::Duration,
+    testing::{create_run, run_with_splits, span},
+    Timer,
+};
+
+fn timer(generator: BestSegmentsWithoutOutliers) -> Timer {
+    let mut run = create_run(&["A", "B"]);
+    run.comparison_generators_mut().push(Box::new(generator));
+    let mut timer = Timer::new(run).unwrap();
+    for splits in [
+        [10.0, 20.0],
+        [2.0, 25.0],
+        [10.5, 20.5],
+        [9.5, 19.5],
+        [10.0, 20.0],
+    ] {
+        run_with_splits(&mut timer, &splits);
+    }
+    timer
+}
+
+fn game_times(timer: &Timer, comparison: &str) -> Vec<Option<f64>> {
+    timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| {
+            s.comparison(comparison)
+                .game_time
+                .map(|t| t.total_seconds())
+        })
+        .collect()
+}
+
+#[test]
+fn matches_the_best_segments_without_filters() {
+    let timer = timer(BestSegmentsWithoutOutliers::default());
+    assert_eq!(
+        game_times(&timer, NAME),
+        game_times(&timer, best_segments::NAME)
+    );
+    assert_eq!(game_times(&timer, NAME), [Some(2.0), Some(12.0)]);
+}
+
+#[test]
+fn excludes_segment_times_far_away_from_the_average() {
+    let timer = timer(BestSegmentsWithoutOutliers {
+        max_standard_deviations: Some(1.5),
+        ..Default::default()
+    });
+    assert_eq!(game_times(&timer, NAME), [Some(9.5), Some(19.5)]);
+}
+
+#[test]
+fn excludes_segment_times_of_old_attempts() {
+    let timer = timer(BestSegmentsWithoutOutliers::default());
+    let second_attempt = timer.run().attempt_history()[1].started().unwrap().time;
+
+    let mut run = timer.run().clone();
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut()
+        .push(Box::new(BestSegmentsWithoutOutliers {
+            oldest_allowed: Some(second_attempt + Duration::nanoseconds(1)),
+            ..Default::default()
+        }));
+    run.regenerate_comparisons();
+
+    let segment_times: Vec<_> = run
+        .segments()
+        .iter()
+        .map(|s| s.comparison(NAME).game_time)
+        .collect();
+    assert_eq!(segment_times, [Some(span(9.5)), Some(span(19.5))]);
+}
@@ -6,7 +6,7 @@ fn test<T: ComparisonGenerator>(mut generator: T) {
 
 #[test]
 fn average_segments() {
-    test(comparison::AverageSegments);
+    test(comparison::AverageSegments::default());
 }
 
 #[test]
@@ -31,7 +31,7 @@ fn latest_run() {
 
 #[test]
 fn median_segments() {
-    test(comparison::MedianSegments);
+    test(comparison::MedianSegments::default());
 }
 
 #[test]
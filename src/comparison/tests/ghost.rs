@@ -0,0 +1,69 @@
+use crate::comparison::{ComparisonGenerator, Ghost};
+use crate::testing::{
+    create_run, make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, span,
+    start_run,
+};
+use crate::Timer;
+
+fn timer_with_ghosts(ghosts: &[Ghost]) -> Timer {
+    let mut run = create_run(&["A", "B", "C"]);
+    for ghost in ghosts {
+        run.comparison_generators_mut()
+            .push(Box::new(ghost.clone()));
+    }
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn reconstructs_split_times_of_the_attempt() {
+    let first = Ghost::new(1);
+    let second = Ghost::new(2);
+    let mut timer = timer_with_ghosts(&[first.clone(), second.clone()]);
+
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits_opt(&mut timer, &[None, Some(4.0)]);
+
+    let run = timer.run();
+    let times = |name| {
+        run.segments()
+            .iter()
+            .map(|s| s.comparison(name).game_time)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        times(first.name()),
+        [Some(span(1.0)), Some(span(3.0)), Some(span(6.0))],
+    );
+    assert_eq!(times(second.name()), [None, Some(span(4.0)), None]);
+}
+
+#[test]
+fn plays_back_in_real_time() {
+    let ghost = Ghost::new(1);
+    let mut timer = timer_with_ghosts(core::slice::from_ref(&ghost));
+
+    assert_eq!(ghost.current_split_index(&timer.snapshot()), None);
+
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    timer.set_current_comparison(ghost.name()).unwrap();
+
+    start_run(&mut timer);
+    assert_eq!(ghost.current_split_index(&timer.snapshot()), Some(0));
+
+    timer.set_game_time(span(3.5)).unwrap();
+    assert_eq!(ghost.current_split_index(&timer.snapshot()), Some(2));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(2.0)]);
+    assert_eq!(ghost.delta(&timer.snapshot()), (Some(span(1.0)), false));
+
+    timer.set_game_time(span(6.0)).unwrap();
+    assert_eq!(ghost.current_split_index(&timer.snapshot()), Some(3));
+}
+
+#[test]
+fn uses_custom_name() {
+    let ghost = Ghost::with_name(4, "Rival".into());
+    assert_eq!(ghost.name(), "Rival");
+    assert_eq!(ghost.attempt_index(), 4);
+    assert_eq!(Ghost::new(4).name(), "Ghost #4");
+}
@@ -1,5 +1,5 @@
 use crate::comparison::median_segments::{MedianSegments, NAME};
-use crate::util::tests_helper::run_with_splits;
+use crate::testing::run_with_splits;
 use crate::{Run, Segment, TimeSpan, Timer};
 
 #[test]
@@ -12,7 +12,7 @@ fn test() {
 
     run.comparison_generators_mut().clear();
     run.comparison_generators_mut()
-        .push(Box::new(MedianSegments));
+        .push(Box::new(MedianSegments::default()));
 
     let mut timer = Timer::new(run).unwrap();
 
@@ -59,3 +59,27 @@ fn test() {
         assert!(run.segment(0).comparison(NAME).game_time > Some(s(0.59)));
     }
 }
+
+#[test]
+fn finished_runs_only() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut()
+        .push(Box::new(MedianSegments {
+            finished_runs_only: true,
+        }));
+
+    let mut timer = Timer::new(run).unwrap();
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[50.0]);
+    run_with_splits(&mut timer, &[60.0]);
+
+    assert_eq!(
+        timer.run().segment(0).comparison(NAME).game_time,
+        Some(TimeSpan::from_seconds(10.0)),
+    );
+}
@@ -1,4 +1,7 @@
 mod average;
 mod balanced_pb;
+mod best_segments_without_outliers;
 mod empty;
+mod ghost;
 mod median;
+mod session_median;
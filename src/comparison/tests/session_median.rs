@@ -0,0 +1,54 @@
+use crate::{
+    comparison::session_median::NAME,
+    testing::{create_timer, run_with_splits, split_at, start_run},
+    Timer,
+};
+
+fn game_times(timer: &Timer) -> Vec<Option<f64>> {
+    timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.comparison(NAME).game_time.map(|t| t.total_seconds()))
+        .collect()
+}
+
+#[test]
+fn only_exists_once_a_session_is_marked() {
+    let mut timer = create_timer(&["A", "B"]);
+    assert!(!timer.run().comparisons().any(|c| c == NAME));
+    assert!(timer.set_current_comparison(NAME).is_err());
+
+    timer.mark_session_start();
+    timer.mark_session_start();
+    assert_eq!(timer.run().comparisons().filter(|&c| c == NAME).count(), 1);
+    assert!(timer.set_current_comparison(NAME).is_ok());
+}
+
+#[test]
+fn uses_the_median_of_the_attempts_of_the_session() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 24.0]);
+
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+    timer.mark_session_start();
+    assert_eq!(game_times(&timer), [None, None]);
+
+    // The attempt in progress started before the session.
+    split_at(&mut timer, 10.0);
+    timer.reset(true).unwrap();
+    assert_eq!(game_times(&timer), [None, None]);
+
+    run_with_splits(&mut timer, &[20.0, 40.0]);
+    assert_eq!(game_times(&timer), [Some(20.0), Some(40.0)]);
+
+    run_with_splits(&mut timer, &[30.0, 50.0]);
+    run_with_splits(&mut timer, &[22.0, 46.0]);
+    assert_eq!(game_times(&timer), [Some(22.0), Some(42.0)]);
+
+    // Resets count towards the median of the segments they reached.
+    run_with_splits(&mut timer, &[18.0]);
+    assert_eq!(game_times(&timer), [Some(21.0), Some(41.0)]);
+}
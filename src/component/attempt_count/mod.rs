@@ -0,0 +1,244 @@
+//! Provides the Attempt Count Component and relevant types for using it. The
+//! Attempt Count Component shows how many attempts of the category have been
+//! started and how many of them have been finished.
+
+use super::key_value;
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    Timer, TimerPhase,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Attempt Count Component shows how many attempts of the category have
+/// been started and how many of them have been finished. Optionally only the
+/// attempts that got past a certain segment are counted, so that attempts that
+/// were reset early don't inflate the attempt count.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// Specifies whether to show the amount of attempts.
+    pub show_attempt_count: bool,
+    /// Specifies whether to show the amount of finished runs.
+    pub show_finished_runs_count: bool,
+    /// Only the attempts that completed at least this amount of segments are
+    /// counted. If this is 0, all the attempts are counted. Finished runs
+    /// completed all the segments, so they are always counted.
+    pub min_completed_segments: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            show_attempt_count: true,
+            show_finished_runs_count: false,
+            min_completed_segments: 0,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Attempt Count Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Attempt Count Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Attempt Count"
+    }
+
+    fn attempt_count(&self, timer: &Timer) -> u32 {
+        let run = timer.run();
+        let min_completed_segments = (self.settings.min_completed_segments as usize).min(run.len());
+        if min_completed_segments == 0 {
+            return run.attempt_count();
+        }
+
+        let segment = run.segment(min_completed_segments - 1);
+        let mut count = run
+            .attempt_history()
+            .iter()
+            .filter(|a| segment.segment_history().get(a.index()).is_some())
+            .count() as u32;
+
+        let completed_segments = match timer.current_phase() {
+            TimerPhase::NotRunning => 0,
+            TimerPhase::Ended => run.len(),
+            TimerPhase::Running | TimerPhase::Paused => timer.current_split_index().unwrap_or(0),
+        };
+        if completed_segments >= min_completed_segments {
+            count += 1;
+        }
+
+        count
+    }
+
+    fn finished_runs_count(&self, timer: &Timer) -> u32 {
+        let mut count = timer
+            .run()
+            .attempt_history()
+            .iter()
+            .filter(|a| a.time().real_time.is_some())
+            .count() as u32;
+
+        if timer.current_phase() == TimerPhase::Ended {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.value.clear();
+        state.key_abbreviations.clear();
+
+        match (
+            self.settings.show_finished_runs_count,
+            self.settings.show_attempt_count,
+        ) {
+            (true, true) => {
+                state.key.push_str("Finished Runs / Attempts");
+                let _ = write!(
+                    state.value,
+                    "{}/{}",
+                    self.finished_runs_count(timer),
+                    self.attempt_count(timer),
+                );
+                state.key_abbreviations.push("Finished / Attempts".into());
+                state.key_abbreviations.push("Attempts".into());
+            }
+            (true, false) => {
+                state.key.push_str("Finished Runs");
+                let _ = write!(state.value, "{}", self.finished_runs_count(timer));
+                state.key_abbreviations.push("Finished".into());
+            }
+            (false, _) => {
+                state.key.push_str("Attempts");
+                if self.settings.show_attempt_count {
+                    let _ = write!(state.value, "{}", self.attempt_count(timer));
+                }
+            }
+        }
+
+        state.secondary_value = None;
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = false;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the counts in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the counts. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Show Attempt Count".into(),
+                "Specifies whether to show how many attempts have been started.".into(),
+                self.settings.show_attempt_count.into(),
+            ),
+            Field::new(
+                "Show Finished Runs Count".into(),
+                "Specifies whether to show how many attempts have been finished.".into(),
+                self.settings.show_finished_runs_count.into(),
+            ),
+            Field::new(
+                "Minimum Completed Segments".into(),
+                "Only the attempts that completed at least this amount of segments are counted as attempts. If this is 0, all the attempts are counted.".into(),
+                u64::from(self.settings.min_completed_segments).into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.show_attempt_count = value.into(),
+            5 => self.settings.show_finished_runs_count = value.into(),
+            6 => self.settings.min_completed_segments = value.into_uint().unwrap() as _,
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
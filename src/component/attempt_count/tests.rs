@@ -0,0 +1,60 @@
+use super::{Component, Settings};
+use crate::testing::{create_timer, run_with_splits, split_at, start_run};
+
+#[test]
+fn shows_attempts_and_finished_runs() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut component = Component::new();
+    let state = component.state(&timer);
+    assert_eq!(state.key, "Attempts");
+    assert_eq!(state.value, "3");
+
+    component.settings_mut().show_finished_runs_count = true;
+    let state = component.state(&timer);
+    assert_eq!(state.key, "Finished Runs / Attempts");
+    assert_eq!(state.value, "1/3");
+
+    component.settings_mut().show_attempt_count = false;
+    let state = component.state(&timer);
+    assert_eq!(state.key, "Finished Runs");
+    assert_eq!(state.value, "1");
+
+    start_run(&mut timer);
+    split_at(&mut timer, 10.0);
+    split_at(&mut timer, 20.0);
+    split_at(&mut timer, 30.0);
+    assert_eq!(component.state(&timer).value, "2");
+}
+
+#[test]
+fn only_counts_attempts_past_a_segment() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut component = Component::with_settings(Settings {
+        min_completed_segments: 2,
+        ..Default::default()
+    });
+    assert_eq!(component.state(&timer).value, "2");
+
+    start_run(&mut timer);
+    split_at(&mut timer, 10.0);
+    assert_eq!(component.state(&timer).value, "2");
+    split_at(&mut timer, 20.0);
+    assert_eq!(component.state(&timer).value, "3");
+    timer.reset(true).unwrap();
+    assert_eq!(component.state(&timer).value, "3");
+
+    component.settings_mut().min_completed_segments = 1;
+    assert_eq!(component.state(&timer).value, "4");
+
+    component.settings_mut().min_completed_segments = 10;
+    assert_eq!(component.state(&timer).value, "1");
+}
@@ -0,0 +1,243 @@
+//! Provides the Barrier Watch Component and relevant types for using it. The
+//! Barrier Watch Component shows the nearest round-number barrier below the
+//! predicted final time of the active attempt, such as sub 1:50:00, and how
+//! much time needs to be saved in order to break it. The barrier is switched
+//! automatically as the pace of the attempt changes.
+
+use super::key_value;
+use crate::{
+    analysis::{barriers, current_pace, split_color},
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Delta, Regular, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, TimeSpan,
+};
+use alloc::borrow::Cow;
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Barrier Watch Component shows the nearest round-number barrier below
+/// the predicted final time of the active attempt and the margin to it, which
+/// is the amount of time that needs to be saved in order to break the barrier.
+/// The final time is predicted based on the chosen comparison.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen for predicting the final time. Uses the Timer's
+    /// current comparison if set to `None`.
+    pub comparison_override: Option<String>,
+    /// The interval between the barriers. If `None` is specified, the interval
+    /// is chosen based on the magnitude of the predicted final time.
+    pub interval: Option<TimeSpan>,
+    /// Specifies whether to display the barrier and the margin to it in two
+    /// separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// Specifies if the decimals should not be shown anymore when the
+    /// visualized margin is above one minute.
+    pub drop_decimals: bool,
+    /// The accuracy of the margin shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            comparison_override: None,
+            interval: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            drop_decimals: true,
+            accuracy: Accuracy::Tenths,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Barrier Watch Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Barrier Watch Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if let Some(comparison) = &self.settings.comparison_override {
+            format!("Barrier Watch ({})", comparison::shorten(comparison)).into()
+        } else {
+            "Barrier Watch".into()
+        }
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let resolved_comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(resolved_comparison, timer);
+        let (final_time, is_live) = current_pace::calculate(timer, comparison);
+        let barrier = final_time.and_then(|time| barriers::below(time, self.settings.interval));
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        let semantic_color = split_color::delta_color(barrier.map(|b| b.margin));
+        state.value_color = Some(
+            self.settings
+                .value_color
+                .unwrap_or_else(|| semantic_color.visualize(layout_settings)),
+        );
+        state.semantic_color = semantic_color;
+
+        state.key.clear();
+        state.value.clear();
+        state.key_abbreviations.clear();
+
+        match barrier {
+            Some(barrier) => {
+                let _ = write!(state.key, "Sub {}", Regular::new().format(barrier.time));
+                let _ = write!(
+                    state.value,
+                    "{}",
+                    Delta::custom(self.settings.drop_decimals, self.settings.accuracy)
+                        .format(barrier.margin),
+                );
+                state.key_abbreviations.push(state.key.clone().into());
+            }
+            None => {
+                state.key.push_str(&self.name());
+                state.value.push_str(&layout_settings.placeholder);
+            }
+        }
+        state.key_abbreviations.push("Barrier".into());
+
+        state.secondary_value = None;
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = is_live && barrier.is_some();
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Comparison".into(),
+                "The comparison to predict the final time from. If not specified, the current comparison is used.".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Interval".into(),
+                "The interval between the barriers, such as 5:00 for barriers every 5 minutes. If not specified, the interval is chosen based on the predicted final time.".into(),
+                self.settings
+                    .interval
+                    .map(|time| Regular::with_accuracy(Accuracy::Hundredths).format(time).to_string())
+                    .into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the barrier and the margin to it in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the barrier. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the margin. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Drop Decimals".into(),
+                "Specifies if the decimals should not be shown anymore when the visualized margin is over a minute.".into(),
+                self.settings.drop_decimals.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the margin shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => {
+                let interval: Option<String> = value.into();
+                self.settings.interval = interval.and_then(|interval| interval.parse().ok());
+            }
+            3 => self.settings.display_two_rows = value.into(),
+            4 => self.settings.label_color = value.into(),
+            5 => self.settings.value_color = value.into(),
+            6 => self.settings.drop_decimals = value.into(),
+            7 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
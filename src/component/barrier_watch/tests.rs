@@ -0,0 +1,49 @@
+use super::Component;
+use crate::{
+    settings::{SemanticColor, Value},
+    testing::{create_timer, run_with_splits, span, split_at, start_run},
+    timing::formatter::DASH,
+    GeneralLayoutSettings,
+};
+
+#[test]
+fn needs_a_prediction() {
+    let timer = create_timer(&["A", "B"]);
+    let state = Component::new().state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.key, "Barrier Watch");
+    assert_eq!(state.value, DASH);
+    assert_eq!(state.semantic_color, SemanticColor::Default);
+}
+
+#[test]
+fn switches_barriers_as_the_pace_changes() {
+    let layout_settings = GeneralLayoutSettings::default();
+    let component = Component::new();
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[60.0 * 60.0, 111.5 * 60.0]);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.key, "Sub 1:50:00");
+    assert_eq!(state.value, "+1:30");
+    assert_eq!(state.semantic_color, SemanticColor::BehindLosingTime);
+
+    start_run(&mut timer);
+    split_at(&mut timer, 58.0 * 60.0);
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.key, "Sub 1:45:00");
+    assert_eq!(state.value, "+4:30");
+}
+
+#[test]
+fn the_interval_can_be_chosen() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[111.5 * 60.0]);
+
+    let mut component = Component::new();
+    component.set_value(2, Value::OptionalString(Some("1:00".into())));
+    assert_eq!(component.settings().interval, Some(span(60.0)));
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.key, "Sub 1:51:00");
+    assert_eq!(state.value, "+30.0");
+}
@@ -54,6 +54,17 @@ impl State {
     {
         serde_json::to_writer(writer, self)
     }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
 }
 
 impl Component {
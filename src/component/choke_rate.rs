@@ -0,0 +1,177 @@
+//! Provides the Choke Rate Component and relevant types for using it. The
+//! Choke Rate Component is a component that shows how often attempts ended in
+//! the current segment, based on the attempts in the Attempt History.
+
+use super::key_value;
+use crate::{
+    analysis::choke_rate,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings, TimerPhase,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+/// The Choke Rate Component is a component that shows how often attempts
+/// ended in the current segment, based on the attempts in the Attempt History.
+/// If there is no active attempt, the choke rate of the first segment is
+/// shown. Once the attempt is finished, the choke rate of the last segment is
+/// shown.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Choke Rate Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Choke Rate Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Choke Rate"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let run = timer.run();
+        let segment_index = match timer.current_phase() {
+            TimerPhase::NotRunning => 0,
+            TimerPhase::Ended => run.len() - 1,
+            TimerPhase::Running | TimerPhase::Paused => timer.current_split_index().unwrap(),
+        };
+        let rate = choke_rate::calculate(run)[segment_index].rate();
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str(self.name());
+
+        state.value.clear();
+        match rate {
+            Some(rate) => {
+                let _ = write!(state.value, "{:.1}%", 100.0 * rate);
+            }
+            None => state.value.push_str(&layout_settings.placeholder),
+        }
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push("Chokes".into());
+        state.secondary_value = None;
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = false;
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the choke rate in two separate rows."
+                    .into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout."
+                    .into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the choke rate. If not specified, the color is taken from the layout."
+                    .into(),
+                self.settings.value_color.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
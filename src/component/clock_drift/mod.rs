@@ -0,0 +1,221 @@
+//! Provides the Clock Drift Component and relevant types for using it. The
+//! Clock Drift Component warns about the system clock drifting away from the
+//! clock the timer is based on during the active attempt. This is mostly
+//! useful for marathons, as predictions of the wall clock time of the finish
+//! are meaningless when the system clock jumps.
+
+use super::key_value;
+use crate::{
+    analysis::clock_drift,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Delta, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, TimeSpan,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Clock Drift Component warns about the system clock drifting away from
+/// the clock the timer is based on during the active attempt. This is mostly
+/// useful for marathons, as predictions of the wall clock time of the finish
+/// are meaningless when the system clock jumps.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout, based on whether the drift exceeds the threshold.
+    pub value_color: Option<Color>,
+    /// The amount of drift, in either direction, that is tolerated before a
+    /// warning is shown.
+    pub threshold: TimeSpan,
+    /// The accuracy of the drift shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            threshold: TimeSpan::from_seconds(1.0),
+            accuracy: Accuracy::Tenths,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Clock Drift Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Clock Drift Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Clock Drift"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let drift = clock_drift::calculate(timer);
+        let is_drifting = clock_drift::exceeds_threshold(timer, self.settings.threshold);
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        let semantic_color = if is_drifting {
+            SemanticColor::BehindLosingTime
+        } else {
+            SemanticColor::Default
+        };
+        state.value_color = Some(
+            self.settings
+                .value_color
+                .unwrap_or_else(|| semantic_color.visualize(layout_settings)),
+        );
+        state.semantic_color = semantic_color;
+
+        state.key.clear();
+        state.key.push_str(if is_drifting {
+            "Clock Drift Warning"
+        } else {
+            "Clock Drift"
+        });
+
+        state.value.clear();
+        match drift {
+            Some(drift) => {
+                let _ = write!(
+                    state.value,
+                    "{}",
+                    Delta::custom(false, self.settings.accuracy).format(drift),
+                );
+            }
+            None => state.value.push_str(&layout_settings.placeholder),
+        }
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push("Drift".into());
+
+        state.secondary_value = None;
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = drift.is_some();
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the clock drift in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the clock drift. If not specified, the color is taken from the layout, based on whether the drift exceeds the threshold.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Threshold".into(),
+                "The amount of drift between the system clock and the timer that is tolerated before a warning is shown.".into(),
+                Delta::custom(false, Accuracy::Hundredths)
+                    .format(self.settings.threshold)
+                    .to_string()
+                    .into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the clock drift shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => {
+                let threshold: String = value.into();
+                if let Ok(threshold) = threshold.trim_start_matches('+').parse() {
+                    self.settings.threshold = threshold;
+                }
+            }
+            5 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::Component;
+use crate::{
+    settings::SemanticColor,
+    testing::{create_timer, FakeClock},
+    GeneralLayoutSettings, TimeSpan,
+};
+
+#[test]
+fn warns_once_the_drift_exceeds_the_threshold() {
+    let clock = FakeClock::install();
+    let mut timer = create_timer(&["A"]);
+    let component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.key, "Clock Drift");
+    assert_eq!(state.value, layout_settings.placeholder);
+    assert_eq!(state.semantic_color, SemanticColor::Default);
+
+    timer.start().unwrap();
+    clock.advance(TimeSpan::from_seconds(10.0));
+    clock.adjust_wall_clock(TimeSpan::from_seconds(0.5));
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.key, "Clock Drift");
+    assert_eq!(state.value, "+0.5");
+    assert_eq!(state.semantic_color, SemanticColor::Default);
+
+    clock.adjust_wall_clock(TimeSpan::from_seconds(-5.5));
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.key, "Clock Drift Warning");
+    assert_eq!(state.value, "−5.0");
+    assert_eq!(state.semantic_color, SemanticColor::BehindLosingTime);
+    assert_eq!(
+        state.value_color,
+        Some(SemanticColor::BehindLosingTime.visualize(&layout_settings)),
+    );
+}
@@ -0,0 +1,226 @@
+//! Provides the Completion Component and relevant types for using it. The
+//! Completion Component shows how much of the run the active attempt has
+//! completed as a percentage. The percentage is either based on the amount of
+//! segments that are done or on how much of the comparison's final time has
+//! elapsed, in which case it keeps progressing within the current segment.
+
+use super::key_value;
+use crate::{
+    analysis::completion,
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings, TimingMethod,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+/// The Completion Component shows how much of the run the active attempt has
+/// completed as a percentage. The percentage is either based on the amount of
+/// segments that are done or on how much of the comparison's final time has
+/// elapsed, in which case it keeps progressing within the current segment.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// Describes what the completion percentage is based on.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompletionBasis {
+    /// The percentage is based on the amount of segments that are done.
+    #[default]
+    SegmentCount,
+    /// The percentage is based on how much of the comparison's final time has
+    /// elapsed.
+    ComparisonTime,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen for the time based percentage. Uses the Timer's
+    /// current comparison if set to `None`.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// What the completion percentage is based on.
+    pub basis: CompletionBasis,
+    /// Specifies the timing method to use for the time based percentage. If
+    /// set to `None` the current timing method is used.
+    pub timing_method: Option<TimingMethod>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            basis: CompletionBasis::SegmentCount,
+            timing_method: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Completion Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Completion Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Completion"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let (completed, updates_frequently) = match self.settings.basis {
+            CompletionBasis::SegmentCount => (Some(completion::by_segments(timer)), false),
+            CompletionBasis::ComparisonTime => {
+                let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+                let comparison = comparison::or_current(comparison, timer);
+                let method = super::resolve_timing_method(self.settings.timing_method, timer);
+                completion::by_comparison_time_for_timing_method(timer, comparison, method)
+            }
+        };
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str("Completion");
+
+        state.value.clear();
+        match completed {
+            Some(completed) => {
+                let _ = match self.settings.basis {
+                    CompletionBasis::SegmentCount => {
+                        write!(state.value, "{:.0}%", 100.0 * completed)
+                    }
+                    CompletionBasis::ComparisonTime => {
+                        write!(state.value, "{:.1}%", 100.0 * completed)
+                    }
+                };
+            }
+            None => state.value.push_str(&layout_settings.placeholder),
+        }
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push("Done".into());
+
+        state.secondary_value = None;
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = updates_frequently;
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Comparison".into(),
+                "The comparison to use when the percentage is based on the comparison's time. If not specified, the current comparison is used.".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the percentage in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the percentage. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Based On".into(),
+                "Specifies whether the percentage is based on the amount of segments that are done or on how much of the comparison's final time has elapsed.".into(),
+                self.settings.basis.into(),
+            ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use when the percentage is based on the comparison's time. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.basis = value.into(),
+            6 => self.settings.timing_method = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
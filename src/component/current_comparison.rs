@@ -87,6 +87,7 @@ impl Component {
         state.key_abbreviations.clear();
         state.key_abbreviations.push("Comparison".into());
 
+        state.secondary_value = None;
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = false;
     }
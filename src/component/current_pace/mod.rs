@@ -0,0 +1,586 @@
+//! Provides the Current Pace Component and relevant types for using it. The
+//! Current Pace Component is a component that shows a prediction of the current
+//! attempt's final time, if the current attempt's pace matches the chosen
+//! comparison for the remainder of the run.
+
+use super::key_value;
+use crate::{
+    analysis::{adaptive_pace, best_possible_time, current_pace},
+    comparison,
+    platform::prelude::*,
+    platform::{to_local, DateTime, Duration},
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, TimerPhase, TimingMethod,
+};
+use alloc::borrow::Cow;
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+use time::{
+    macros::format_description,
+    format_description::BorrowedFormatItem,
+};
+
+/// The name of the pseudo comparison that can be chosen as the comparison of
+/// the Current Pace Component to adapt the Average Segments to the performance
+/// of the current attempt, as described by [`adaptive_pace`].
+pub const ADAPTIVE_COMPARISON: &str = "Adaptive";
+
+/// The Current Pace Component is a component that shows a prediction of the
+/// current attempt's final time, if the current attempt's pace matches the
+/// chosen comparison for the remainder of the run.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// Describes what the Current Pace Component shows while there's no active
+/// attempt, such as before the attempt is started or after it got reset.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotRunningDisplay {
+    /// The final time of the comparison is shown, unless the comparison is the
+    /// Personal Best or a custom comparison, in which case a dash is shown.
+    /// When showing the predicted wall clock time, the wall clock time at which
+    /// an attempt started right now would finish is shown.
+    #[default]
+    Automatic,
+    /// The final time of the comparison is always shown. When showing the
+    /// predicted wall clock time, the wall clock time at which an attempt
+    /// started right now would finish is shown.
+    FinalTime,
+    /// A dash is shown.
+    Dash,
+    /// Nothing is shown.
+    Blank,
+}
+
+/// Describes how the predicted wall clock time is rounded. A prediction that
+/// is accurate to the second implies a precision that the prediction of a
+/// long attempt doesn't have. Rounded times are shown without seconds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WallClockRounding {
+    /// The predicted wall clock time is shown to the second.
+    #[default]
+    Off,
+    /// The predicted wall clock time is rounded to the nearest minute.
+    Minute,
+    /// The predicted wall clock time is rounded to the nearest 5 minutes.
+    FiveMinutes,
+}
+
+impl WallClockRounding {
+    const fn minutes(self) -> Option<u8> {
+        match self {
+            WallClockRounding::Off => None,
+            WallClockRounding::Minute => Some(1),
+            WallClockRounding::FiveMinutes => Some(5),
+        }
+    }
+
+    /// Rounds the wall clock time provided. The rounding happens in the
+    /// offset of the time provided, so it needs to be converted to the local
+    /// time zone first, as not all time zones are offset by whole hours.
+    pub fn round(self, date_time: DateTime) -> DateTime {
+        let Some(minutes) = self.minutes() else {
+            return date_time;
+        };
+        let step = Duration::minutes(minutes as _);
+        let past_step = Duration::minutes((date_time.minute() % minutes) as _)
+            + Duration::seconds(date_time.second() as _)
+            + Duration::nanoseconds(date_time.nanosecond() as _);
+        let rounded_down = date_time - past_step;
+        if past_step * 2 >= step {
+            rounded_down + step
+        } else {
+            rounded_down
+        }
+    }
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen. Uses the Timer's current comparison if set to
+    /// `None`. The [`ADAPTIVE_COMPARISON`] can be chosen as well.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+    /// Specifies whether to show the predicted wall clock time at which the
+    /// attempt finishes instead of the predicted final time. The state is
+    /// marked as frequently updating whenever this prediction keeps moving,
+    /// such as before the attempt is started or while it is paused.
+    pub wall_clock: bool,
+    /// Specifies how the predicted wall clock time is rounded.
+    pub wall_clock_rounding: WallClockRounding,
+    /// Specifies whether the value is colored based on whether the predicted
+    /// final time is ahead or behind the final time of the comparison.
+    pub color_by_delta: bool,
+    /// Specifies the Timing Method to use. If set to `None` the Timing Method
+    /// of the Timer is used for showing the time. Otherwise the Timing Method
+    /// provided is used.
+    pub timing_method: Option<TimingMethod>,
+    /// Specifies whether the Best Possible Time is rebalanced with the segment
+    /// times the active attempt already achieved, instead of being based on
+    /// the Best Segments comparison. This is only used if the comparison is
+    /// the Best Segments comparison.
+    pub rebalance_best_possible_time: bool,
+    /// Specifies what is shown while there's no active attempt.
+    pub not_running_display: NotRunningDisplay,
+    /// Specifies what is shown while there's no active attempt when showing
+    /// the predicted wall clock time.
+    pub wall_clock_not_running_display: NotRunningDisplay,
+    /// The name of the layout's time format to use for the predicted time. If
+    /// set to `None` or not available, the default format is used.
+    pub time_format: Option<String>,
+    /// Additional comparisons to predict the final time from. The prediction
+    /// of each of them is shown as an additional row below the main
+    /// prediction, so the predictions for the Personal Best, the Best Segments
+    /// and the Average Segments can be shown at once, for example. The
+    /// additional rows always show the predicted final time, even when showing
+    /// the predicted wall clock time.
+    pub additional_comparisons: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Seconds,
+            wall_clock: false,
+            wall_clock_rounding: WallClockRounding::Off,
+            color_by_delta: false,
+            timing_method: None,
+            rebalance_best_possible_time: false,
+            not_running_display: NotRunningDisplay::Automatic,
+            wall_clock_not_running_display: NotRunningDisplay::Automatic,
+            time_format: None,
+            additional_comparisons: Vec::new(),
+        }
+    }
+}
+
+const SETTINGS_BEFORE_ADDITIONAL_COMPARISONS: usize = 15;
+
+const DEFAULT_WALL_CLOCK_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[hour]:[minute]:[second]");
+const ROUNDED_WALL_CLOCK_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[hour]:[minute]");
+
+impl Component {
+    /// Creates a new Current Pace Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Current Pace Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        self.text(self.settings.comparison_override.as_deref())
+    }
+
+    fn text(&self, comparison: Option<&str>) -> Cow<'static, str> {
+        if let Some(comparison) = comparison {
+            match comparison {
+                comparison::personal_best::NAME => "Current Pace".into(),
+                comparison::best_segments::NAME => "Best Possible Time".into(),
+                comparison::best_segments_without_outliers::NAME => {
+                    "Best Possible Time (No Outliers)".into()
+                }
+                comparison::worst_segments::NAME => "Worst Possible Time".into(),
+                comparison::average_segments::NAME => "Predicted Time".into(),
+                ADAPTIVE_COMPARISON => "Adaptive Pace".into(),
+                comparison => format!("Current Pace ({})", comparison::shorten(comparison)).into(),
+            }
+        } else {
+            "Current Pace".into()
+        }
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let is_adaptive = self.settings.comparison_override.as_deref() == Some(ADAPTIVE_COMPARISON);
+        let (comparison, key) = if is_adaptive {
+            (
+                comparison::average_segments::NAME,
+                self.text(Some(ADAPTIVE_COMPARISON)),
+            )
+        } else {
+            let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+            let comparison = comparison::or_current(comparison, timer);
+            (comparison, self.text(Some(comparison)))
+        };
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str(&key); // FIXME: Uncow this
+
+        state.value.clear();
+
+        let method = super::resolve_timing_method(self.settings.timing_method, timer);
+
+        let not_running_display = if timer.current_phase() == TimerPhase::NotRunning {
+            Some(if self.settings.wall_clock {
+                self.settings.wall_clock_not_running_display
+            } else {
+                self.settings.not_running_display
+            })
+        } else {
+            None
+        };
+
+        let hide_value = match not_running_display {
+            Some(NotRunningDisplay::Automatic) => {
+                !self.settings.wall_clock && key.starts_with("Current Pace")
+            }
+            Some(NotRunningDisplay::Dash | NotRunningDisplay::Blank) => true,
+            Some(NotRunningDisplay::FinalTime) | None => false,
+        };
+
+        let rows_update_frequently = self.update_additional_comparisons(
+            &mut state.rows,
+            timer,
+            layout_settings,
+            method,
+            not_running_display,
+        );
+
+        let (current_pace, uf) = if hide_value {
+            (None, false)
+        } else if is_adaptive {
+            adaptive_pace::calculate(timer, method)
+        } else if self.settings.rebalance_best_possible_time
+            && comparison == comparison::best_segments::NAME
+        {
+            best_possible_time::calculate(timer, method)
+        } else {
+            current_pace::calculate_for_timing_method(timer, comparison, method)
+        };
+
+        if self.settings.color_by_delta {
+            state.semantic_color =
+                current_pace::semantic_color(timer, comparison, method, current_pace);
+        }
+
+        if not_running_display == Some(NotRunningDisplay::Blank) {
+            state.updates_frequently = false;
+        } else if !self.settings.wall_clock {
+            state.updates_frequently = uf;
+
+            super::write_time(
+                &mut state.value,
+                self.settings.time_format.as_deref(),
+                layout_settings,
+                current_pace,
+                self.settings.accuracy,
+                Regular::with_accuracy(self.settings.accuracy).format(current_pace),
+            );
+        } else {
+            let (predicted_time, uf) = if hide_value {
+                (None, false)
+            } else if is_adaptive {
+                current_pace::predict_wall_clock_time_for_pace(timer, current_pace, uf)
+            } else {
+                current_pace::predict_wall_clock_time(timer, comparison)
+            };
+
+            state.updates_frequently = uf;
+
+            if let Some(pt) = predicted_time {
+                let rounding = self.settings.wall_clock_rounding;
+                let format = if rounding == WallClockRounding::Off {
+                    DEFAULT_WALL_CLOCK_FORMAT
+                } else {
+                    ROUNDED_WALL_CLOCK_FORMAT
+                };
+                let value = rounding.round(to_local(pt.time)).format(format).unwrap();
+                let _ = write!(state.value, "{}", value);
+            } else {
+                state.value.push_str(&layout_settings.placeholder);
+            }
+        }
+
+        state.key_abbreviations.clear();
+        // FIXME: This &* probably is different when key is uncowed
+        match &*key {
+            "Best Possible Time" => {
+                state.key_abbreviations.push("Best Poss. Time".into());
+                state.key_abbreviations.push("Best Time".into());
+                state.key_abbreviations.push("BPT".into());
+            }
+            "Best Possible Time (No Outliers)" => {
+                state.key_abbreviations.push("Best Possible Time".into());
+                state.key_abbreviations.push("Best Poss. Time".into());
+                state.key_abbreviations.push("BPT".into());
+            }
+            "Worst Possible Time" => {
+                state.key_abbreviations.push("Worst Poss. Time".into());
+                state.key_abbreviations.push("Worst Time".into());
+            }
+            "Predicted Time" => {
+                state.key_abbreviations.push("Pred. Time".into());
+            }
+            "Adaptive Pace" => {
+                state.key_abbreviations.push("Adapt. Pace".into());
+                state.key_abbreviations.push("Pace".into());
+            }
+            "Current Pace" => {
+                state.key_abbreviations.push("Cur. Pace".into());
+                state.key_abbreviations.push("Pace".into());
+            }
+            _ => {
+                state.key_abbreviations.push("Current Pace".into());
+                state.key_abbreviations.push("Cur. Pace".into());
+                state.key_abbreviations.push("Pace".into());
+            }
+        }
+
+        state.updates_frequently |= rows_update_frequently;
+        state.secondary_value = None;
+        state.display_two_rows = self.settings.display_two_rows;
+    }
+
+    fn update_additional_comparisons(
+        &self,
+        rows: &mut Vec<key_value::Row>,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+        method: TimingMethod,
+        not_running_display: Option<NotRunningDisplay>,
+    ) -> bool {
+        let comparisons = &self.settings.additional_comparisons;
+        rows.truncate(comparisons.len());
+        rows.resize_with(comparisons.len(), Default::default);
+
+        let mut updates_frequently = false;
+
+        for (comparison, row) in comparisons.iter().zip(rows) {
+            let key = self.text(Some(comparison));
+
+            let hide_value = match not_running_display {
+                Some(NotRunningDisplay::Automatic) => key.starts_with("Current Pace"),
+                Some(NotRunningDisplay::Dash | NotRunningDisplay::Blank) => true,
+                Some(NotRunningDisplay::FinalTime) | None => false,
+            };
+
+            let (current_pace, uf) = if hide_value {
+                (None, false)
+            } else {
+                current_pace::calculate_for_timing_method(timer, comparison, method)
+            };
+            updates_frequently |= uf;
+
+            row.key.clear();
+            row.key.push_str(&key);
+
+            row.value.clear();
+            if not_running_display != Some(NotRunningDisplay::Blank) {
+                super::write_time(
+                    &mut row.value,
+                    self.settings.time_format.as_deref(),
+                    layout_settings,
+                    current_pace,
+                    self.settings.accuracy,
+                    Regular::with_accuracy(self.settings.accuracy).format(current_pace),
+                );
+            }
+
+            row.semantic_color = if self.settings.color_by_delta {
+                current_pace::semantic_color(timer, comparison, method, current_pace)
+            } else {
+                Default::default()
+            };
+            row.value_color = self.settings.value_color;
+        }
+
+        updates_frequently
+    }
+
+    /// Calculates the component's state based on the timer and layout
+    /// settings provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut settings = SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Comparison".into(),
+                "The comparison to predict the final time from. If not specified, the current comparison is used. Specify \"Adaptive\" to scale the Average Segments by how fast the current attempt is compared to them.".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the predicted time in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the predicted time. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the predicted time shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+            Field::new(
+                "Display relative wall clock".into(),
+                "Display the predicted wall clock time".into(),
+                self.settings.wall_clock.into(),
+            ),
+            Field::new(
+                "Color by Delta".into(),
+                "Specifies whether to color the predicted time based on whether it is ahead or behind the final time of the comparison.".into(),
+                self.settings.color_by_delta.into(),
+            ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+            Field::new(
+                "Rebalance Best Possible Time".into(),
+                "Specifies whether the Best Possible Time takes the segment times into account that the current attempt already achieved, instead of being based on the Best Segments comparison. This is only used if the comparison is Best Segments.".into(),
+                self.settings.rebalance_best_possible_time.into(),
+            ),
+            Field::new(
+                "Before Start".into(),
+                "Specifies what to show while there's no active attempt, such as before the attempt is started or after it got reset.".into(),
+                self.settings.not_running_display.into(),
+            ),
+            Field::new(
+                "Wall Clock Before Start".into(),
+                "Specifies what to show while there's no active attempt when displaying the predicted wall clock time. Layouts that are shown before the attempt starts may not want a prediction that moves along with the clock.".into(),
+                self.settings.wall_clock_not_running_display.into(),
+            ),
+            Field::new(
+                "Wall Clock Rounding".into(),
+                "Specifies how to round the predicted wall clock time. Predictions of long attempts aren't accurate to the second, so rounding them avoids implying a false precision.".into(),
+                self.settings.wall_clock_rounding.into(),
+            ),
+            Field::new(
+                "Time Format".into(),
+                "The name of the time format to use for the predicted time. This is not used for the predicted wall clock time. If not specified or not available, the default time format is used.".into(),
+                self.settings.time_format.clone().into(),
+            ),
+            Field::new(
+                "Additional Comparisons".into(),
+                "The number of additional comparisons to predict the final time from. The predicted final time for each of them is shown in an additional row.".into(),
+                Value::UInt(self.settings.additional_comparisons.len() as _),
+            ),
+        ]);
+
+        settings
+            .fields
+            .reserve_exact(self.settings.additional_comparisons.len());
+
+        for comparison in &self.settings.additional_comparisons {
+            settings.fields.push(Field::new(
+                "Additional Comparison".into(),
+                "The comparison to predict the final time from in an additional row.".into(),
+                comparison.clone().into(),
+            ));
+        }
+
+        settings
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.accuracy = value.into(),
+            6 => self.settings.wall_clock = value.into(),
+            7 => self.settings.color_by_delta = value.into(),
+            8 => self.settings.timing_method = value.into(),
+            9 => self.settings.rebalance_best_possible_time = value.into(),
+            10 => self.settings.not_running_display = value.into(),
+            11 => self.settings.wall_clock_not_running_display = value.into(),
+            12 => self.settings.wall_clock_rounding = value.into(),
+            13 => self.settings.time_format = value.into(),
+            14 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings
+                    .additional_comparisons
+                    .resize(new_len, Default::default());
+            }
+            index => {
+                self.settings.additional_comparisons
+                    [index - SETTINGS_BEFORE_ADDITIONAL_COMPARISONS] = value.into();
+            }
+        }
+    }
+}
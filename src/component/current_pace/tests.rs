@@ -0,0 +1,173 @@
+use super::{Component, NotRunningDisplay, WallClockRounding, ADAPTIVE_COMPARISON};
+use crate::{
+    comparison::{best_segments, personal_best},
+    settings::Value,
+    testing::{create_timer, run_with_splits, split_at, start_run},
+    timing::formatter::DASH,
+    GeneralLayoutSettings, Timer,
+};
+use time::macros::datetime;
+
+fn value(component: &Component, timer: &Timer) -> String {
+    component
+        .state(&timer.snapshot(), &GeneralLayoutSettings::default())
+        .value
+}
+
+#[test]
+fn shows_what_is_configured_before_the_attempt_starts() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[60.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some(personal_best::NAME.into());
+    assert_eq!(value(&component, &timer), DASH);
+
+    component.settings_mut().not_running_display = NotRunningDisplay::FinalTime;
+    assert_eq!(value(&component, &timer), "1:00");
+
+    component.settings_mut().comparison_override = Some(best_segments::NAME.into());
+    component.settings_mut().not_running_display = NotRunningDisplay::Automatic;
+    assert_eq!(value(&component, &timer), "1:00");
+
+    component.settings_mut().not_running_display = NotRunningDisplay::Dash;
+    assert_eq!(value(&component, &timer), DASH);
+
+    component.settings_mut().not_running_display = NotRunningDisplay::Blank;
+    assert_eq!(value(&component, &timer), "");
+
+    timer.start().unwrap();
+    assert_eq!(value(&component, &timer), "1:00");
+}
+
+#[test]
+fn wall_clock_mode_is_configured_separately() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[60.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().wall_clock = true;
+    component.settings_mut().not_running_display = NotRunningDisplay::Blank;
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_ne!(state.value, DASH);
+    assert!(state.updates_frequently);
+
+    component.settings_mut().wall_clock_not_running_display = NotRunningDisplay::Dash;
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.value, DASH);
+    assert!(!state.updates_frequently);
+
+    component.settings_mut().wall_clock_not_running_display = NotRunningDisplay::Blank;
+    assert_eq!(value(&component, &timer), "");
+}
+
+#[test]
+fn adaptive_comparison_scales_the_average_segments() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[30.0, 60.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some(ADAPTIVE_COMPARISON.into());
+    assert_eq!(component.name(), "Adaptive Pace");
+    assert_eq!(value(&component, &timer), "1:00");
+
+    start_run(&mut timer);
+    split_at(&mut timer, 33.0);
+    assert_eq!(value(&component, &timer), "1:06");
+}
+
+#[test]
+fn wall_clock_rounding_happens_in_the_local_offset() {
+    let date_time = datetime!(2024-01-01 12:32:30.5 +5:45);
+    assert_eq!(WallClockRounding::Off.round(date_time), date_time);
+    assert_eq!(
+        WallClockRounding::Minute.round(date_time),
+        datetime!(2024-01-01 12:33 +5:45),
+    );
+    assert_eq!(
+        WallClockRounding::FiveMinutes.round(date_time),
+        datetime!(2024-01-01 12:35 +5:45),
+    );
+    assert_eq!(
+        WallClockRounding::FiveMinutes.round(datetime!(2024-01-01 23:57:30 +5:45)),
+        datetime!(2024-01-02 0:00 +5:45),
+    );
+    assert_eq!(
+        WallClockRounding::FiveMinutes.round(datetime!(2024-01-01 12:32:29 +5:45)),
+        datetime!(2024-01-01 12:30 +5:45),
+    );
+}
+
+#[test]
+fn rounded_wall_clock_times_are_shown_without_seconds() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[60.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().wall_clock = true;
+    assert_eq!(value(&component, &timer).len(), "00:00:00".len());
+
+    component.settings_mut().wall_clock_rounding = WallClockRounding::FiveMinutes;
+    let value = value(&component, &timer);
+    assert_eq!(value.len(), "00:00".len());
+    assert!(value.ends_with('0') || value.ends_with('5'));
+}
+
+#[test]
+fn uses_the_time_format_selected_in_the_settings() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[83.5]);
+
+    let mut layout_settings = GeneralLayoutSettings::default();
+    layout_settings.time_formatters.register(
+        "Seconds",
+        |time: Option<crate::TimeSpan>, _, f: &mut core::fmt::Formatter<'_>| {
+            write!(f, "{}s", time.unwrap().total_seconds())
+        },
+    );
+
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some(best_segments::NAME.into());
+    let state = |component: &Component| component.state(&timer.snapshot(), &layout_settings).value;
+    assert_eq!(state(&component), "1:23");
+
+    component.settings_mut().time_format = Some("Seconds".into());
+    assert_eq!(state(&component), "83.5s");
+
+    component.settings_mut().time_format = Some("Complete".into());
+    assert_eq!(state(&component), "00:01:23.500000000");
+
+    component.settings_mut().time_format = Some("Unknown".into());
+    assert_eq!(state(&component), "1:23");
+}
+
+#[test]
+fn predicts_for_additional_comparisons() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[60.0, 120.0]);
+    run_with_splits(&mut timer, &[50.0, 130.0]);
+    start_run(&mut timer);
+    split_at(&mut timer, 70.0);
+
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some(personal_best::NAME.into());
+    component.set_value(14, Value::UInt(2));
+    component.set_value(15, Value::String(best_segments::NAME.into()));
+    component.set_value(16, Value::String(personal_best::NAME.into()));
+    assert_eq!(component.settings_description().fields.len(), 17);
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.key, "Current Pace");
+    assert_eq!(state.value, "2:10");
+    assert_eq!(state.rows.len(), 2);
+    assert_eq!(state.rows[0].key, "Best Possible Time");
+    assert_eq!(state.rows[0].value, "2:10");
+    assert_eq!(state.rows[1].key, "Current Pace");
+    assert_eq!(state.rows[1].value, "2:10");
+
+    timer.reset(false).unwrap();
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.rows[0].value, "1:50");
+    assert_eq!(state.rows[1].value, DASH);
+}
@@ -4,18 +4,17 @@
 
 use super::key_value;
 use crate::{
-    analysis::{delta, state_helper},
-    comparison,
+    analysis::{delta, split_color},
+    comparison, component,
     platform::prelude::*,
     settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
         formatter::{Accuracy, Delta, TimeFormatter},
         Snapshot,
     },
-    GeneralLayoutSettings,
+    GeneralLayoutSettings, TimingMethod,
 };
 use alloc::borrow::Cow;
-use core::fmt::Write;
 use serde_derive::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -48,6 +47,13 @@ pub struct Settings {
     pub drop_decimals: bool,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// Specifies the Timing Method to use. If set to `None` the Timing Method
+    /// of the Timer is used for showing the time. Otherwise the Timing Method
+    /// provided is used.
+    pub timing_method: Option<TimingMethod>,
+    /// The name of the layout's time format to use for the delta. If set to
+    /// `None` or not available, the default format is used.
+    pub time_format: Option<String>,
 }
 
 impl Default for Settings {
@@ -59,6 +65,8 @@ impl Default for Settings {
             label_color: None,
             drop_decimals: true,
             accuracy: Accuracy::Tenths,
+            timing_method: None,
+            time_format: None,
         }
     }
 }
@@ -105,7 +113,8 @@ impl Component {
         let text = comparison.unwrap_or_else(|| timer.current_comparison());
         let comparison = comparison::or_current(comparison, timer);
 
-        let (delta, use_live_delta) = delta::calculate(timer, comparison);
+        let method = component::resolve_timing_method(self.settings.timing_method, timer);
+        let (delta, use_live_delta) = delta::calculate_for_timing_method(timer, comparison, method);
 
         let mut index = timer.current_split_index();
         if !use_live_delta {
@@ -113,15 +122,7 @@ impl Component {
         }
 
         let semantic_color = if let Some(index) = index {
-            state_helper::split_color(
-                timer,
-                delta,
-                index,
-                true,
-                false,
-                comparison,
-                timer.current_timing_method(),
-            )
+            split_color(timer, delta, index, true, false, comparison, method)
         } else {
             SemanticColor::Default
         };
@@ -136,9 +137,12 @@ impl Component {
         state.key.push_str(text);
 
         state.value.clear();
-        let _ = write!(
-            state.value,
-            "{}",
+        component::write_time(
+            &mut state.value,
+            self.settings.time_format.as_deref(),
+            layout_settings,
+            delta,
+            self.settings.accuracy,
             Delta::custom(self.settings.drop_decimals, self.settings.accuracy).format(delta),
         );
 
@@ -147,6 +151,7 @@ impl Component {
             state.key_abbreviations.push(abbreviation.into());
         }
 
+        state.secondary_value = None;
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = use_live_delta;
     }
@@ -197,6 +202,16 @@ impl Component {
                 "The accuracy of the delta shown.".into(),
                 self.settings.accuracy.into()
             ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+            Field::new(
+                "Time Format".into(),
+                "The name of the time format to use for the delta. If not specified or not available, the default time format is used.".into(),
+                self.settings.time_format.clone().into(),
+            ),
         ])
     }
 
@@ -215,6 +230,8 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.drop_decimals = value.into(),
             5 => self.settings.accuracy = value.into(),
+            6 => self.settings.timing_method = value.into(),
+            7 => self.settings.time_format = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -8,10 +8,13 @@ use super::timer;
 use crate::{
     analysis::comparison_single_segment_time,
     comparison::{self, best_segments, none},
+    component,
     platform::prelude::*,
     settings::{Color, Field, Gradient, Image, ImageCache, ImageId, SettingsDescription, Value},
     timing::{
-        formatter::{Accuracy, DigitsFormat, SegmentTime, TimeFormatter},
+        formatter::{
+            none_wrapper::NoneWrapper, Accuracy, DigitsFormat, SegmentTime, TimeFormatter,
+        },
         Snapshot,
     },
     GeneralLayoutSettings, TimeSpan, TimerPhase,
@@ -113,6 +116,7 @@ fn update_comparison(
     state: &mut Option<ComparisonState>,
     new_state: Option<(&str, Option<TimeSpan>)>,
     accuracy: Accuracy,
+    placeholder: &str,
 ) {
     if let Some((name, time)) = new_state {
         let state = state.get_or_insert_with(|| ComparisonState {
@@ -127,7 +131,7 @@ fn update_comparison(
         let _ = write!(
             state.time,
             "{}",
-            SegmentTime::with_accuracy(accuracy).format(time)
+            NoneWrapper::new(SegmentTime::with_accuracy(accuracy), placeholder).format(time)
         );
     } else {
         *state = None;
@@ -172,6 +176,17 @@ impl State {
     {
         serde_json::to_writer(writer, self)
     }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
 }
 
 impl Component {
@@ -221,11 +236,8 @@ impl Component {
         layout_settings: &GeneralLayoutSettings,
     ) {
         let current_phase = timer.current_phase();
-        let timing_method = self
-            .settings
-            .timer
-            .timing_method
-            .unwrap_or_else(|| timer.current_timing_method());
+        let timing_method =
+            component::resolve_timing_method(self.settings.timer.timing_method, timer);
 
         let run = timer.run();
 
@@ -313,11 +325,13 @@ impl Component {
             &mut state.comparison1,
             comparison1,
             self.settings.comparison_times_accuracy,
+            &layout_settings.placeholder,
         );
         update_comparison(
             &mut state.comparison2,
             comparison2,
             self.settings.comparison_times_accuracy,
+            &layout_settings.placeholder,
         );
 
         match current_split.filter(|_| self.settings.show_segment_name) {
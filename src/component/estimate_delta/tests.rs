@@ -0,0 +1,46 @@
+use super::Component;
+use crate::{
+    settings::SemanticColor,
+    testing::{create_timer, run_with_splits, span, split_at, start_run},
+    timing::formatter::DASH,
+    GeneralLayoutSettings, Timer,
+};
+
+fn timer_with_estimate(estimate: Option<f64>) -> Timer {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[20.0, 50.0]);
+    let mut run = timer.run().clone();
+    run.metadata_mut().set_estimate(estimate.map(span));
+    timer.set_run(run).unwrap();
+    timer
+}
+
+#[test]
+fn needs_an_estimate() {
+    let timer = timer_with_estimate(None);
+    let state = Component::new().state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.key, "Estimate Delta");
+    assert_eq!(state.value, DASH);
+    assert_eq!(state.semantic_color, SemanticColor::Default);
+}
+
+#[test]
+fn compares_the_predicted_final_time_to_the_estimate() {
+    let layout_settings = GeneralLayoutSettings::default();
+    let component = Component::new();
+    let mut timer = timer_with_estimate(Some(60.0));
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.value, "−10");
+    assert_eq!(state.semantic_color, SemanticColor::AheadGainingTime);
+
+    start_run(&mut timer);
+    split_at(&mut timer, 45.0);
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.value, "+15");
+    assert_eq!(state.semantic_color, SemanticColor::BehindLosingTime);
+    assert_eq!(
+        state.value_color,
+        Some(SemanticColor::BehindLosingTime.visualize(&layout_settings)),
+    );
+}
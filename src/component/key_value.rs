@@ -28,6 +28,15 @@ pub struct State {
     pub key: String,
     /// The value to visualize.
     pub value: String,
+    /// An optional secondary value to visualize next to the value, separated
+    /// by a slash, such as the possible time save shown by the Previous
+    /// Segment component.
+    pub secondary_value: Option<String>,
+    /// The color of the secondary value. If `None` is specified, the color is
+    /// taken from the layout.
+    pub secondary_value_color: Option<Color>,
+    /// The semantic coloring information the secondary value carries.
+    pub secondary_semantic_color: SemanticColor,
     /// Specifies additional abbreviations for the key that can be used instead
     /// of the key, if there is not enough space to show the whole key.
     pub key_abbreviations: Vec<Cow<'static, str>>,
@@ -36,6 +45,24 @@ pub struct State {
     /// This value indicates whether the value is currently frequently being
     /// updated. This can be used for rendering optimizations.
     pub updates_frequently: bool,
+    /// Additional rows to visualize below the key and the value, such as the
+    /// deltas of the most recent segments shown by the Previous Segment
+    /// component. Most components don't provide any.
+    pub rows: Vec<Row>,
+}
+
+/// An additional row of a key value based component.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct Row {
+    /// The key to visualize.
+    pub key: String,
+    /// The value to visualize.
+    pub value: String,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The semantic coloring information the value carries.
+    pub semantic_color: SemanticColor,
 }
 
 #[cfg(feature = "std")]
@@ -47,9 +74,20 @@ impl State {
     {
         serde_json::to_writer(writer, self)
     }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
 }
 
-pub(super) const DEFAULT_GRADIENT: Gradient = Gradient::Vertical(
+pub(crate) const DEFAULT_GRADIENT: Gradient = Gradient::Vertical(
     Color {
         red: 1.0,
         green: 1.0,
@@ -3,40 +3,113 @@
 //! information is provided as state objects in a way that can easily be
 //! visualized by any kind of User Interface.
 
+pub mod attempt_count;
+pub mod barrier_watch;
 pub mod blank_space;
+pub mod choke_rate;
+pub mod clock_drift;
+pub mod completion;
 pub mod current_comparison;
 pub mod current_pace;
 pub mod delta;
 pub mod detailed_timer;
+pub mod estimate_delta;
 pub mod graph;
+pub mod pace_graph;
+pub mod page_break;
 pub mod pb_chance;
+pub mod pb_progression;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod run_prediction;
+pub mod segment_sparkline;
 pub mod segment_time;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
 pub mod text;
+pub mod time_remaining;
 pub mod timer;
 pub mod title;
 pub mod total_playtime;
+pub mod world_record;
 
 pub mod key_value;
+mod registry;
 
+pub use attempt_count::Component as AttemptCount;
+pub use barrier_watch::Component as BarrierWatch;
 pub use blank_space::Component as BlankSpace;
+pub use choke_rate::Component as ChokeRate;
+pub use clock_drift::Component as ClockDrift;
+pub use completion::Component as Completion;
 pub use current_comparison::Component as CurrentComparison;
 pub use current_pace::Component as CurrentPace;
 pub use delta::Component as Delta;
 pub use detailed_timer::Component as DetailedTimer;
+pub use estimate_delta::Component as EstimateDelta;
 pub use graph::Component as Graph;
+pub use pace_graph::Component as PaceGraph;
+pub use page_break::Component as PageBreak;
 pub use pb_chance::Component as PbChance;
+pub use pb_progression::Component as PbProgression;
 pub use possible_time_save::Component as PossibleTimeSave;
 pub use previous_segment::Component as PreviousSegment;
+pub use run_prediction::Component as RunPrediction;
+pub use segment_sparkline::Component as SegmentSparkline;
 pub use segment_time::Component as SegmentTime;
 pub use separator::Component as Separator;
 pub use splits::Component as Splits;
 pub use sum_of_best::Component as SumOfBest;
 pub use text::Component as Text;
+pub use time_remaining::Component as TimeRemaining;
 pub use timer::Component as Timer;
 pub use title::Component as Title;
 pub use total_playtime::Component as TotalPlaytime;
+pub use world_record::Component as WorldRecord;
+
+pub(crate) use registry::unregistered;
+pub use registry::{Component, ComponentRegistry};
+
+use crate::{platform::prelude::*, timing::formatter::Accuracy, GeneralLayoutSettings, TimeSpan};
+use core::fmt::{self, Write};
+
+/// Resolves the timing method that a component is supposed to use. If the Run
+/// is locked to a timing method, that timing method is always used, as the
+/// other one isn't meaningful for the Run. Otherwise, if the component
+/// overrides the timing method, the override is used. If it doesn't, the
+/// timer's current timing method is used.
+pub fn resolve_timing_method(
+    timing_method: Option<crate::TimingMethod>,
+    timer: &crate::Timer,
+) -> crate::TimingMethod {
+    match timing_method {
+        Some(method) => timer.run().resolve_timing_method(method),
+        None => timer.current_timing_method(),
+    }
+}
+
+/// Writes the time provided to the value of a component. If the time is
+/// missing, the placeholder of the layout is written instead. If the component
+/// selects a Time Formatter by its name and the layout has a formatter with
+/// that name, it is used. Otherwise the default formatter of the component is
+/// used.
+fn write_time(
+    value: &mut String,
+    time_format: Option<&str>,
+    layout_settings: &GeneralLayoutSettings,
+    time: Option<TimeSpan>,
+    accuracy: Accuracy,
+    default: impl fmt::Display,
+) {
+    if time.is_none() {
+        value.push_str(&layout_settings.placeholder);
+        return;
+    }
+
+    let formatter = time_format.and_then(|name| layout_settings.time_formatters.get(name));
+    let _ = match formatter {
+        Some(formatter) => write!(value, "{}", formatter.format(time, accuracy)),
+        None => write!(value, "{default}"),
+    };
+}
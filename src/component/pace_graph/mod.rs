@@ -0,0 +1,244 @@
+//! Provides the Pace Graph Component and relevant types for using it. The Pace
+//! Graph Component visualizes how the predicted wall clock time of the finish
+//! of the active attempt drifted over the course of the attempt. A prediction
+//! is made when the attempt gets started and at every split. This is mostly
+//! useful for streamers that want to show how the ETA of the run changed.
+
+use crate::{
+    platform::{prelude::*, to_local},
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+use time::{format_description::BorrowedFormatItem, macros::format_description};
+
+#[cfg(test)]
+mod tests;
+
+const WALL_CLOCK_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second]");
+
+/// The Pace Graph Component visualizes how the predicted wall clock time of
+/// the finish of the active attempt drifted over the course of the attempt. A
+/// prediction is made when the attempt gets started and at every split. This is
+/// mostly useful for streamers that want to show how the ETA of the run
+/// changed.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the most recent prediction. If `None` is specified, the
+    /// color is taken from the layout.
+    pub value_color: Option<Color>,
+    /// The color of the line connecting the predictions. If `None` is
+    /// specified, the text color of the layout is used.
+    pub line_color: Option<Color>,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The label to show.
+    pub label: String,
+    /// The most recent prediction of the finish, formatted in local time.
+    pub value: String,
+    /// The predicted wall clock times of the finish, from oldest to newest,
+    /// specified as milliseconds since the Unix epoch.
+    pub finish_times: Vec<i64>,
+    /// The predictions, normalized to the range `0..=1`. The earliest finish is
+    /// 0 and the latest finish is 1. If all the predictions are equal, they are
+    /// all 0.5.
+    pub points: Vec<f32>,
+    /// The color of the line connecting the points.
+    pub line_color: Color,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            label_color: None,
+            value_color: None,
+            line_color: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Pace Graph Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Pace Graph Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Pace Graph"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let predictions = timer.finish_predictions();
+
+        state.background = self.settings.background;
+        state.label_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.line_color = self
+            .settings
+            .line_color
+            .unwrap_or(layout_settings.text_color);
+
+        state.label.clear();
+        state.label.push_str("Predicted Finish");
+
+        state.value.clear();
+        match predictions.last() {
+            Some(prediction) => {
+                let time = to_local(prediction.finish.time);
+                let _ = write!(
+                    state.value,
+                    "{}",
+                    time.format(WALL_CLOCK_FORMAT).unwrap_or_default(),
+                );
+            }
+            None => state.value.push_str(&layout_settings.placeholder),
+        }
+
+        state.finish_times.clear();
+        state.finish_times.extend(
+            predictions
+                .iter()
+                .map(|p| (p.finish.time.unix_timestamp_nanos() / 1_000_000) as i64),
+        );
+
+        let (min, max) = state
+            .finish_times
+            .iter()
+            .fold((i64::MAX, i64::MIN), |(min, max), &t| {
+                (min.min(t), max.max(t))
+            });
+
+        state.points.clear();
+        state.points.extend(state.finish_times.iter().map(|&t| {
+            if max > min {
+                ((t - min) as f64 / (max - min) as f64) as f32
+            } else {
+                0.5
+            }
+        }));
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the most recent prediction of the finish. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Line Color".into(),
+                "The color of the line connecting the predictions. If not specified, the text color of the layout is used.".into(),
+                self.settings.line_color.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.line_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
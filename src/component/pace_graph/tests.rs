@@ -0,0 +1,43 @@
+use super::Component;
+use crate::{
+    testing::{create_timer, run_with_splits, span, start_run},
+    GeneralLayoutSettings,
+};
+
+#[test]
+fn shows_dash_without_predictions() {
+    let timer = create_timer(&["A", "B"]);
+    let state = Component::new().state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.value, "—");
+    assert!(state.finish_times.is_empty());
+    assert!(state.points.is_empty());
+}
+
+#[test]
+fn samples_prediction_at_start_and_each_split() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    start_run(&mut timer);
+    let component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.finish_times.len(), 1);
+    assert_eq!(state.points, [0.5]);
+    assert_ne!(state.value, "—");
+
+    timer.set_game_time(span(15.0)).unwrap();
+    timer.split().unwrap();
+    timer.skip_split().unwrap();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.finish_times.len(), 2);
+    assert_eq!(state.points, [0.0, 1.0]);
+    assert_eq!(state.line_color, layout_settings.text_color);
+
+    timer.undo_split().unwrap();
+    timer.undo_split().unwrap();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.finish_times.len(), 1);
+}
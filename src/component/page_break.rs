@@ -0,0 +1,41 @@
+//! Provides the Page Break Component. The Page Break Component splits the
+//! components of a [`Layout`](crate::layout::Layout) into multiple pages, of
+//! which only one is visible at a time. This is useful for small layouts that
+//! rotate between different views.
+
+use crate::settings::{SettingsDescription, Value};
+
+/// The Page Break Component splits the components of a
+/// [`Layout`](crate::layout::Layout) into multiple pages, of which only one is
+/// visible at a time. The page break itself is never visible in the layout's
+/// state.
+#[derive(Default, Clone)]
+pub struct Component;
+
+impl Component {
+    /// Creates a new Page Break Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Page Break"
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::default()
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn set_value(&mut self, _index: usize, _value: Value) {}
+}
@@ -93,6 +93,7 @@ impl Component {
         let _ = write!(state.value, "{:.1}%", 100.0 * chance);
 
         state.key_abbreviations.clear();
+        state.secondary_value = None;
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = is_live;
     }
@@ -0,0 +1,290 @@
+//! Provides the PB Progression Component and relevant types for using it. The
+//! PB Progression Component visualizes how the personal best of the run
+//! improved over time. Every attempt that finished faster than all the attempts
+//! before it is an improvement and is placed on a chart based on when it was
+//! started. This is mostly useful to show during intermissions.
+
+use crate::{
+    component,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Regular, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, TimeSpan, TimingMethod,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The PB Progression Component visualizes how the personal best of the run
+/// improved over time. Every attempt that finished faster than all the attempts
+/// before it is an improvement and is placed on a chart based on when it was
+/// started. This is mostly useful to show during intermissions.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the current personal best. If `None` is specified, the
+    /// color is taken from the layout.
+    pub value_color: Option<Color>,
+    /// The color of the line connecting the improvements. If `None` is
+    /// specified, the personal best color of the layout is used.
+    pub line_color: Option<Color>,
+    /// The timing method to use for the personal bests. If `None` is
+    /// specified, the current timing method is used.
+    pub timing_method: Option<TimingMethod>,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The label to show.
+    pub label: String,
+    /// The current personal best, which is the time of the most recent
+    /// improvement.
+    pub value: String,
+    /// The improvements of the personal best, from oldest to newest.
+    pub improvements: Vec<Improvement>,
+    /// The positions of the improvements on the chart, normalized to the range
+    /// `0..=1` on both axes. The horizontal position is based on when the
+    /// attempts were started. If that is not known for all of the attempts, the
+    /// improvements are spaced evenly instead. Vertically the fastest time is 0
+    /// and the slowest time is 1. If there is only a single improvement, it is
+    /// placed at 0.5 on both axes.
+    pub points: Vec<[f32; 2]>,
+    /// The color of the line connecting the points.
+    pub line_color: Color,
+}
+
+/// A single improvement of the personal best.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Improvement {
+    /// The index of the [`Attempt`](crate::run::Attempt) that improved the
+    /// personal best.
+    pub attempt_index: i32,
+    /// When the attempt was started, specified as milliseconds since the Unix
+    /// epoch. This is `None` if it is not known when the attempt was started.
+    pub date: Option<i64>,
+    /// The final time of the attempt.
+    pub time: String,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            label_color: None,
+            value_color: None,
+            line_color: None,
+            timing_method: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new PB Progression Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new PB Progression Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "PB Progression"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let method = component::resolve_timing_method(self.settings.timing_method, timer);
+
+        state.background = self.settings.background;
+        state.label_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.line_color = self
+            .settings
+            .line_color
+            .unwrap_or(layout_settings.personal_best_color);
+
+        state.label.clear();
+        state.label.push_str("PB Progression");
+
+        let mut best = None::<TimeSpan>;
+        let mut times = Vec::new();
+        state.improvements.clear();
+
+        for attempt in timer.run().attempt_history() {
+            let Some(time) = attempt.time()[method] else {
+                continue;
+            };
+            if best.is_some_and(|best| time >= best) {
+                continue;
+            }
+            best = Some(time);
+            times.push(time.total_seconds());
+
+            let mut improvement = Improvement {
+                attempt_index: attempt.index(),
+                date: attempt
+                    .started()
+                    .map(|date| (date.time.unix_timestamp_nanos() / 1_000_000) as i64),
+                time: String::new(),
+            };
+            let _ = write!(improvement.time, "{}", Regular::new().format(time));
+            state.improvements.push(improvement);
+        }
+
+        state.value.clear();
+        match state.improvements.last() {
+            Some(improvement) => state.value.push_str(&improvement.time),
+            None => state.value.push_str(&layout_settings.placeholder),
+        }
+
+        let dates: Option<Vec<i64>> = state.improvements.iter().map(|i| i.date).collect();
+        let xs: Vec<f64> = match dates {
+            Some(dates) => dates.into_iter().map(|d| d as f64).collect(),
+            None => (0..times.len()).map(|i| i as f64).collect(),
+        };
+
+        state.points.clear();
+        state
+            .points
+            .extend(normalize(&xs).zip(normalize(&times)).map(|(x, y)| [x, y]));
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the current personal best. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Line Color".into(),
+                "The color of the line connecting the improvements of the personal best. If not specified, the personal best color of the layout is used.".into(),
+                self.settings.line_color.into(),
+            ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use for the personal bests. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.line_color = value.into(),
+            4 => self.settings.timing_method = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn normalize(values: &[f64]) -> impl Iterator<Item = f32> + '_ {
+    let (min, max) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    values.iter().map(move |&v| {
+        if max > min {
+            ((v - min) / (max - min)) as f32
+        } else {
+            0.5
+        }
+    })
+}
@@ -0,0 +1,66 @@
+use super::Component;
+use crate::{
+    testing::{create_timer, run_with_splits, span, split_at, start_run, FakeClock},
+    GeneralLayoutSettings,
+};
+
+#[test]
+fn shows_dash_without_finished_attempts() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+    split_at(&mut timer, 10.0);
+    timer.reset(true).unwrap();
+
+    let state = Component::new().state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.value, "—");
+    assert!(state.improvements.is_empty());
+    assert!(state.points.is_empty());
+}
+
+#[test]
+fn lists_improvements_of_the_personal_best() {
+    let clock = FakeClock::install();
+    let mut timer = create_timer(&["A", "B"]);
+    for (gap, splits) in [
+        (0.0, [10.0, 40.0]),
+        (60.0, [10.0, 45.0]),
+        (60.0, [10.0, 30.0]),
+        (60.0, [10.0, 30.0]),
+        (240.0, [10.0, 20.0]),
+    ] {
+        clock.advance(span(gap));
+        run_with_splits(&mut timer, &splits);
+    }
+    let layout_settings = GeneralLayoutSettings::default();
+
+    let state = Component::new().state(&timer.snapshot(), &layout_settings);
+    let indices: Vec<_> = state.improvements.iter().map(|i| i.attempt_index).collect();
+    assert_eq!(indices, [1, 3, 5]);
+    assert_eq!(state.value, state.improvements[2].time);
+    assert_eq!(state.line_color, layout_settings.personal_best_color);
+
+    for (improvement, index) in state.improvements.iter().zip(indices) {
+        let attempt = &timer.run().attempt_history()[index as usize - 1];
+        assert_eq!(
+            improvement.date,
+            attempt
+                .started()
+                .map(|date| (date.time.unix_timestamp_nanos() / 1_000_000) as i64),
+        );
+    }
+
+    // The improvements were started 0, 120 and 420 seconds into the history.
+    assert_eq!(
+        state.points,
+        [[0.0, 1.0], [(120.0 / 420.0_f64) as f32, 0.5], [1.0, 0.0]],
+    );
+}
+
+#[test]
+fn single_improvement_is_centered() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    let state = Component::new().state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.points, [[0.5, 0.5]]);
+}
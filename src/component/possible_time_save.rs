@@ -11,10 +11,10 @@ use crate::{
     platform::prelude::*,
     settings::{Color, Field, Gradient, SettingsDescription, Value},
     timing::{
-        formatter::{Accuracy, SegmentTime, TimeFormatter},
+        formatter::{none_wrapper::NoneWrapper, Accuracy, SegmentTime, TimeFormatter},
         Snapshot,
     },
-    TimerPhase,
+    GeneralLayoutSettings, TimerPhase, TimingMethod,
 };
 use alloc::borrow::Cow;
 use core::fmt::Write as FmtWrite;
@@ -53,6 +53,10 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// Specifies the Timing Method to use. If set to `None` the Timing Method
+    /// of the Timer is used for showing the time. Otherwise the Timing Method
+    /// provided is used.
+    pub timing_method: Option<TimingMethod>,
 }
 
 impl Default for Settings {
@@ -65,6 +69,7 @@ impl Default for Settings {
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Hundredths,
+            timing_method: None,
         }
     }
 }
@@ -113,20 +118,38 @@ impl Component {
         text
     }
 
-    /// Updates the component's state based on the timer provided.
-    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
         let segment_index = timer.current_split_index();
         let current_phase = timer.current_phase();
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let text = self.text(comparison);
         let comparison = comparison::or_current(comparison, timer);
 
+        let method = super::resolve_timing_method(self.settings.timing_method, timer);
+
         let (time, updates_frequently) = if self.settings.total_possible_time_save {
-            let (time, updates_frequently) =
-                possible_time_save::calculate_total(timer, segment_index.unwrap_or(0), comparison);
+            let (time, updates_frequently) = possible_time_save::calculate_total_for_timing_method(
+                timer,
+                segment_index.unwrap_or(0),
+                comparison,
+                method,
+            );
             (Some(time), updates_frequently)
         } else if current_phase == TimerPhase::Running || current_phase == TimerPhase::Paused {
-            possible_time_save::calculate(timer, segment_index.unwrap(), comparison, false)
+            possible_time_save::calculate_for_timing_method(
+                timer,
+                segment_index.unwrap(),
+                comparison,
+                method,
+                false,
+            )
         } else {
             (None, false)
         };
@@ -143,7 +166,11 @@ impl Component {
         let _ = write!(
             state.value,
             "{}",
-            SegmentTime::with_accuracy(self.settings.accuracy).format(time)
+            NoneWrapper::new(
+                SegmentTime::with_accuracy(self.settings.accuracy),
+                &*layout_settings.placeholder,
+            )
+            .format(time)
         );
 
         state.key_abbreviations.clear();
@@ -156,14 +183,20 @@ impl Component {
         state.key_abbreviations.push("Poss. Time Save".into());
         state.key_abbreviations.push("Time Save".into());
 
+        state.secondary_value = None;
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = updates_frequently;
     }
 
-    /// Calculates the component's state based on the timer provided.
-    pub fn state(&self, timer: &Snapshot<'_>) -> key_value::State {
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
         let mut state = Default::default();
-        self.update_state(&mut state, timer);
+        self.update_state(&mut state, timer, layout_settings);
         state
     }
 
@@ -206,6 +239,11 @@ impl Component {
                 "The accuracy of the possible time save shown.".into(),
                 self.settings.accuracy.into(),
             ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
         ])
     }
 
@@ -225,6 +263,7 @@ impl Component {
             4 => self.settings.label_color = value.into(),
             5 => self.settings.value_color = value.into(),
             6 => self.settings.accuracy = value.into(),
+            7 => self.settings.timing_method = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -12,15 +12,18 @@ use crate::{
     platform::prelude::*,
     settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
-        formatter::{Accuracy, Delta, SegmentTime, TimeFormatter},
+        formatter::{none_wrapper::NoneWrapper, Accuracy, Delta, SegmentTime, TimeFormatter},
         Snapshot,
     },
-    GeneralLayoutSettings, TimerPhase,
+    GeneralLayoutSettings, TimerPhase, TimingMethod,
 };
 use alloc::borrow::Cow;
 use core::fmt::Write as FmtWrite;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// The Previous Segment Component is a component that shows how much time was
 /// saved or lost during the previous [`Segment`](crate::run::Segment) based on
 /// the chosen comparison. Additionally, the potential time save for the previous
@@ -55,8 +58,16 @@ pub struct Settings {
     /// Determines if the time save that could've been saved is shown in
     /// addition to the previous segment.
     pub show_possible_time_save: bool,
-
+    /// Specifies whether the live segment time is always shown, instead of
+    /// only once time is being lost on the current segment.
     pub always_show_live_segment: bool,
+    /// Specifies the Timing Method to use. If set to `None` the Timing Method
+    /// of the Timer is used for showing the time. Otherwise the Timing Method
+    /// provided is used.
+    pub timing_method: Option<TimingMethod>,
+    /// The amount of most recently completed segments to show the deltas of
+    /// as additional rows. No additional rows are shown if this is 0.
+    pub recent_segments: u32,
 }
 
 impl Default for Settings {
@@ -70,6 +81,8 @@ impl Default for Settings {
             accuracy: Accuracy::Tenths,
             show_possible_time_save: false,
             always_show_live_segment: false,
+            timing_method: None,
+            recent_segments: 0,
         }
     }
 }
@@ -131,24 +144,25 @@ impl Component {
         let mut previous_possible = None;
         let resolved_comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(resolved_comparison, timer);
+        let method = super::resolve_timing_method(self.settings.timing_method, timer);
 
         let live_segment = if self.settings.always_show_live_segment {
             true
         } else {
-            analysis::check_live_delta(timer, false, comparison, timer.current_timing_method()).is_some()
+            analysis::check_live_delta(timer, false, comparison, method).is_some()
         };
 
         let phase = timer.current_phase();
-        let method = timer.current_timing_method();
         let semantic_color = if phase != TimerPhase::NotRunning {
             let split_index = timer.current_split_index().unwrap();
             if live_segment {
                 time_change = analysis::live_segment_delta(timer, split_index, comparison, method);
                 if self.settings.show_possible_time_save {
-                    previous_possible = analysis::possible_time_save::calculate(
+                    previous_possible = analysis::possible_time_save::calculate_for_timing_method(
                         timer,
                         split_index,
                         comparison,
+                        method,
                         false,
                     )
                     .0;
@@ -157,10 +171,11 @@ impl Component {
                 time_change =
                     analysis::previous_segment_delta(timer, prev_split_index, comparison, method);
                 if self.settings.show_possible_time_save {
-                    previous_possible = analysis::possible_time_save::calculate(
+                    previous_possible = analysis::possible_time_save::calculate_for_timing_method(
                         timer,
                         prev_split_index,
                         comparison,
+                        method,
                         false,
                     )
                     .0;
@@ -224,16 +239,30 @@ impl Component {
         let _ = write!(
             state.value,
             "{}",
-            Delta::custom(self.settings.drop_decimals, self.settings.accuracy).format(time_change),
+            NoneWrapper::new(
+                Delta::custom(self.settings.drop_decimals, self.settings.accuracy),
+                &*layout_settings.placeholder,
+            )
+            .format(time_change),
         );
 
         if self.settings.show_possible_time_save {
+            let secondary_value = state.secondary_value.get_or_insert_with(String::new);
+            secondary_value.clear();
             let _ = write!(
-                state.value,
-                " / {}",
-                SegmentTime::with_accuracy(self.settings.accuracy).format(previous_possible),
+                secondary_value,
+                "{}",
+                NoneWrapper::new(
+                    SegmentTime::with_accuracy(self.settings.accuracy),
+                    &*layout_settings.placeholder,
+                )
+                .format(previous_possible),
             );
+        } else {
+            state.secondary_value = None;
         }
+        state.secondary_value_color = None;
+        state.secondary_semantic_color = SemanticColor::Default;
 
         state.key_abbreviations.clear();
         if live_segment {
@@ -247,6 +276,57 @@ impl Component {
 
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = live_segment && phase.updates_frequently(method);
+
+        self.update_recent_segments(&mut state.rows, timer, layout_settings, comparison, method);
+    }
+
+    fn update_recent_segments(
+        &self,
+        rows: &mut Vec<key_value::Row>,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+        comparison: &str,
+        method: TimingMethod,
+    ) {
+        let Some(split_index) = timer.current_split_index() else {
+            rows.clear();
+            return;
+        };
+        let start = split_index.saturating_sub(self.settings.recent_segments as usize);
+        let segments = &timer.run().segments()[start..split_index];
+
+        rows.truncate(segments.len());
+        rows.resize_with(segments.len(), Default::default);
+
+        for ((segment_index, segment), row) in (start..).zip(segments).zip(rows) {
+            let delta = analysis::previous_segment_delta(timer, segment_index, comparison, method);
+            let semantic_color = analysis::split_color(
+                timer,
+                delta,
+                segment_index,
+                delta.is_none(),
+                true,
+                comparison,
+                method,
+            );
+
+            row.key.clear();
+            row.key.push_str(segment.name());
+
+            row.value.clear();
+            let _ = write!(
+                row.value,
+                "{}",
+                NoneWrapper::new(
+                    Delta::custom(self.settings.drop_decimals, self.settings.accuracy),
+                    &*layout_settings.placeholder,
+                )
+                .format(delta),
+            );
+
+            row.value_color = Some(semantic_color.visualize(layout_settings));
+            row.semantic_color = semantic_color;
+        }
     }
 
     /// Calculates the component's state based on the timer and the layout
@@ -305,6 +385,16 @@ impl Component {
                 "Always show the live segment time.".into(),
                 self.settings.always_show_live_segment.into(),
             ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+            Field::new(
+                "Recent Segments".into(),
+                "The amount of most recently completed segments to show how much time was saved or lost on in additional rows. No additional rows are shown if this is 0.".into(),
+                Value::UInt(self.settings.recent_segments as _),
+            ),
         ])
     }
 
@@ -325,6 +415,8 @@ impl Component {
             5 => self.settings.accuracy = value.into(),
             6 => self.settings.show_possible_time_save = value.into(),
             7 => self.settings.always_show_live_segment = value.into(),
+            8 => self.settings.timing_method = value.into(),
+            9 => self.settings.recent_segments = value.into_uint().unwrap() as _,
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -0,0 +1,74 @@
+use super::Component;
+use crate::{
+    settings::SemanticColor,
+    testing::{create_timer, run_with_splits, split_at, start_run},
+    GeneralLayoutSettings,
+};
+
+#[test]
+fn shows_the_deltas_of_the_most_recent_segments() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    let mut component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+    assert!(component
+        .state(&timer.snapshot(), &layout_settings)
+        .rows
+        .is_empty());
+
+    component.settings_mut().recent_segments = 2;
+    start_run(&mut timer);
+    assert!(component
+        .state(&timer.snapshot(), &layout_settings)
+        .rows
+        .is_empty());
+
+    split_at(&mut timer, 12.0);
+    split_at(&mut timer, 21.0);
+    split_at(&mut timer, 33.0);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let rows: Vec<_> = state
+        .rows
+        .iter()
+        .map(|row| (&*row.key, &*row.value, row.semantic_color))
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            ("B", "−1.0", SemanticColor::BestSegment),
+            ("C", "+2.0", SemanticColor::BehindLosingTime),
+        ],
+    );
+    assert_eq!(
+        state.rows[0].value_color,
+        Some(layout_settings.best_segment_color),
+    );
+
+    timer.reset(true).unwrap();
+    assert!(component
+        .state(&timer.snapshot(), &layout_settings)
+        .rows
+        .is_empty());
+}
+
+#[test]
+fn shows_the_possible_time_save_as_the_secondary_value() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+    start_run(&mut timer);
+    split_at(&mut timer, 12.0);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.value, "+2.0");
+    assert_eq!(state.secondary_value, None);
+
+    component.settings_mut().show_possible_time_save = true;
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.value, "+2.0");
+    assert_eq!(state.secondary_value.as_deref(), Some("0.0"));
+}
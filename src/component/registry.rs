@@ -0,0 +1,256 @@
+use super::{
+    attempt_count, barrier_watch, blank_space, choke_rate, clock_drift, completion,
+    current_comparison, current_pace, delta, detailed_timer, estimate_delta, graph, pace_graph,
+    page_break, pb_chance, pb_progression, possible_time_save, previous_segment, run_prediction,
+    segment_sparkline, segment_time, separator, splits, sum_of_best, text, time_remaining, timer,
+    title, total_playtime, world_record,
+};
+use crate::{
+    layout::{self, ComponentSettings, ComponentState, CustomComponentSettings, GeneralSettings},
+    platform::prelude::*,
+    settings::{ImageCache, SettingsDescription, Value},
+    timing::Snapshot,
+};
+use alloc::borrow::Cow;
+
+/// A `Component` that is not provided by this crate. Custom components can be
+/// stored in a [`Layout`](crate::Layout) like any of the components provided
+/// by this crate by wrapping them in [`layout::Component::Custom`]. Their state
+/// is one of the existing state objects, so they are visualized the same way
+/// the components provided by this crate are.
+pub trait Component: Send + Sync {
+    /// The ID of the component. The ID is stored alongside the settings of the
+    /// component, so that the component can be created again by a
+    /// [`ComponentRegistry`] it is registered with under that same ID.
+    fn id(&self) -> &str;
+
+    /// Accesses the name of the component.
+    fn name(&self) -> Cow<'_, str>;
+
+    /// Calculates the component's state based on the timer and settings
+    /// provided.
+    fn state(
+        &mut self,
+        image_cache: &mut ImageCache,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralSettings,
+    ) -> ComponentState;
+
+    /// Updates the component's state based on the timer and settings provided.
+    /// By default, the state is calculated from scratch.
+    fn update_state(
+        &mut self,
+        state: &mut ComponentState,
+        image_cache: &mut ImageCache,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralSettings,
+    ) {
+        *state = self.state(image_cache, timer, layout_settings);
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    fn settings_description(&self) -> SettingsDescription;
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the index doesn't match any setting provided by the
+    /// Settings Description of this component. Additionally, the value needs
+    /// to have a compatible type.
+    fn set_value(&mut self, index: usize, value: Value);
+
+    /// Serializes the settings of the component, so that they can be stored as
+    /// part of the [`LayoutSettings`](crate::layout::LayoutSettings).
+    fn settings(&self) -> serde_json::Value;
+
+    /// Replaces the settings of the component with settings that were
+    /// previously serialized by [`settings`](Self::settings).
+    fn set_settings(&mut self, settings: serde_json::Value) -> serde_json::Result<()>;
+
+    /// Tells the component to scroll up. This is ignored by default.
+    fn scroll_up(&mut self) {}
+
+    /// Tells the component to scroll down. This is ignored by default.
+    fn scroll_down(&mut self) {}
+
+    /// Clones the component into a new box.
+    fn boxed_clone(&self) -> Box<dyn Component>;
+}
+
+impl Clone for Box<dyn Component> {
+    fn clone(&self) -> Self {
+        self.boxed_clone()
+    }
+}
+
+/// Keeps the settings of a custom component that isn't registered, so that
+/// they don't get lost when the layout is saved again. It is visualized as a
+/// blank space.
+#[derive(Clone)]
+struct Unregistered {
+    settings: CustomComponentSettings,
+}
+
+impl Component for Unregistered {
+    fn id(&self) -> &str {
+        &self.settings.id
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        self.settings.id.as_str().into()
+    }
+
+    fn state(
+        &mut self,
+        _: &mut ImageCache,
+        _: &Snapshot<'_>,
+        _: &GeneralSettings,
+    ) -> ComponentState {
+        ComponentState::BlankSpace(blank_space::Component::new().state())
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::default()
+    }
+
+    fn set_value(&mut self, _: usize, _: Value) {
+        panic!("Unsupported Setting Index")
+    }
+
+    fn settings(&self) -> serde_json::Value {
+        self.settings.settings.clone()
+    }
+
+    fn set_settings(&mut self, settings: serde_json::Value) -> serde_json::Result<()> {
+        self.settings.settings = settings;
+        Ok(())
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Component> {
+        Box::new(self.clone())
+    }
+}
+
+pub(crate) fn unregistered(settings: CustomComponentSettings) -> Box<dyn Component> {
+    Box::new(Unregistered { settings })
+}
+
+type Create = fn() -> layout::Component;
+
+const BUILTIN_COMPONENTS: [(&str, Create); 30] = [
+    ("AttemptCount", || attempt_count::Component::new().into()),
+    ("BarrierWatch", || barrier_watch::Component::new().into()),
+    ("BlankSpace", || blank_space::Component::new().into()),
+    ("ChokeRate", || choke_rate::Component::new().into()),
+    ("ClockDrift", || clock_drift::Component::new().into()),
+    ("Completion", || completion::Component::new().into()),
+    ("CurrentComparison", || {
+        current_comparison::Component::new().into()
+    }),
+    ("CurrentPace", || current_pace::Component::new().into()),
+    ("Delta", || delta::Component::new().into()),
+    ("DetailedTimer", || {
+        Box::new(detailed_timer::Component::new()).into()
+    }),
+    ("EstimateDelta", || estimate_delta::Component::new().into()),
+    ("Graph", || graph::Component::new().into()),
+    ("PaceGraph", || pace_graph::Component::new().into()),
+    ("PageBreak", || page_break::Component::new().into()),
+    ("PbChance", || pb_chance::Component::new().into()),
+    ("PbProgression", || pb_progression::Component::new().into()),
+    ("PossibleTimeSave", || {
+        possible_time_save::Component::new().into()
+    }),
+    ("PreviousSegment", || {
+        previous_segment::Component::new().into()
+    }),
+    ("RunPrediction", || run_prediction::Component::new().into()),
+    ("SegmentSparkline", || {
+        segment_sparkline::Component::new().into()
+    }),
+    ("SegmentTime", || segment_time::Component::new().into()),
+    ("Separator", || separator::Component::new().into()),
+    ("Splits", || splits::Component::new().into()),
+    ("SumOfBest", || sum_of_best::Component::new().into()),
+    ("Text", || text::Component::new().into()),
+    ("TimeRemaining", || time_remaining::Component::new().into()),
+    ("Timer", || timer::Component::new().into()),
+    ("Title", || title::Component::new().into()),
+    ("TotalPlaytime", || total_playtime::Component::new().into()),
+    ("WorldRecord", || world_record::Component::new().into()),
+];
+
+/// A `ComponentRegistry` creates components based on their ID. It starts out
+/// with all the components provided by this crate, which use the names of the
+/// variants of [`ComponentSettings`] as their IDs. Custom components can be
+/// registered as well, so that layouts containing them can be loaded.
+#[derive(Clone)]
+pub struct ComponentRegistry {
+    components: Vec<(Cow<'static, str>, Create)>,
+}
+
+impl Default for ComponentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComponentRegistry {
+    /// Creates a new registry that contains all the components provided by
+    /// this crate.
+    pub fn new() -> Self {
+        Self {
+            components: BUILTIN_COMPONENTS
+                .iter()
+                .map(|&(id, create)| (id.into(), create))
+                .collect(),
+        }
+    }
+
+    /// Registers a component under the ID provided. The function provided
+    /// creates the component with its default settings. If there already is a
+    /// component with the same ID, it gets replaced. Original LiveSplit layout
+    /// files identify their components by the path of their library, so
+    /// registering a component under such a path allows the
+    /// [`parser`](crate::layout::parser) to load it.
+    pub fn register(&mut self, id: impl Into<Cow<'static, str>>, create: Create) {
+        let id = id.into();
+        if let Some((_, existing)) = self.components.iter_mut().find(|(i, _)| *i == id) {
+            *existing = create;
+        } else {
+            self.components.push((id, create));
+        }
+    }
+
+    /// Returns the IDs of all the registered components.
+    pub fn ids(&self) -> impl Iterator<Item = &str> + '_ {
+        self.components.iter().map(|(id, _)| &**id)
+    }
+
+    /// Creates the component with the ID provided with its default settings.
+    /// `None` is returned if there is no such component.
+    pub fn create(&self, id: &str) -> Option<layout::Component> {
+        let (_, create) = self.components.iter().find(|(i, _)| i == id)?;
+        Some(create())
+    }
+
+    /// Creates a component from its settings. Custom components are created
+    /// through the registry. If a custom component isn't registered or its
+    /// settings can't be applied, the component keeps its settings but is
+    /// visualized as a blank space.
+    pub fn component_from_settings(&self, settings: ComponentSettings) -> layout::Component {
+        let ComponentSettings::Custom(settings) = settings else {
+            return settings.into();
+        };
+
+        if let Some(layout::Component::Custom(mut component)) = self.create(&settings.id) {
+            if component.set_settings(settings.settings.clone()).is_ok() {
+                return layout::Component::Custom(component);
+            }
+        }
+
+        layout::Component::Custom(unregistered(settings))
+    }
+}
@@ -0,0 +1,339 @@
+//! Provides the Run Prediction Component and relevant types for using it. The
+//! Run Prediction Component shows a list of target times, such as a Personal
+//! Best or a certain sub time the runner is going for. For each target it
+//! shows whether it's still achievable and which average segment time is
+//! needed on the remaining segments in order to reach it.
+
+use super::key_value;
+use crate::{
+    analysis::targets,
+    component,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, TimeSpan, TimerPhase, TimingMethod,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+const SETTINGS_BEFORE_TARGETS: usize = 7;
+
+/// The Run Prediction Component shows a list of target times, such as a
+/// Personal Best or a certain sub time the runner is going for. For each
+/// target it shows whether it's still achievable and which average segment
+/// time is needed on the remaining segments in order to reach it.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of each target and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the labels. If `None` is specified, the color is taken
+    /// from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken from
+    /// the layout, depending on whether the target is still achievable.
+    pub value_color: Option<Color>,
+    /// The timing method to use for the predictions. If `None` is specified,
+    /// the current timing method is used.
+    pub timing_method: Option<TimingMethod>,
+    /// The accuracy of the times shown.
+    pub accuracy: Accuracy,
+    /// The targets to show.
+    pub targets: Vec<Target>,
+}
+
+/// A target time shown by the component.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Target {
+    /// The name of the target. If it is empty, a name is derived from the
+    /// target time.
+    pub name: String,
+    /// The time to reach. If `None` is specified, the final time of the
+    /// Personal Best is used.
+    pub time: Option<TimeSpan>,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken
+    /// from the layout.
+    pub label_color: Option<Color>,
+    /// Specifies whether to display the name of each target and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The state of each of the targets.
+    pub targets: Vec<TargetState>,
+}
+
+/// The state of a single target shown by the component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct TargetState {
+    /// The name of the target.
+    pub name: String,
+    /// The average segment time needed on the remaining segments in order to
+    /// reach the target. This is a dash if the target can't be reached
+    /// anymore.
+    pub value: String,
+    /// Specifies whether the target can still be reached.
+    pub achievable: bool,
+    /// The semantic coloring information the value carries.
+    pub semantic_color: SemanticColor,
+    /// The color of the value.
+    pub value_color: Color,
+    /// This value indicates whether the value is currently frequently being
+    /// updated. This can be used for rendering optimizations.
+    pub updates_frequently: bool,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            timing_method: None,
+            accuracy: Accuracy::Tenths,
+            targets: vec![Target::default()],
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Run Prediction Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Run Prediction Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Run Prediction"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let method = component::resolve_timing_method(self.settings.timing_method, timer);
+        let personal_best = timer
+            .run()
+            .segments()
+            .last()
+            .and_then(|s| s.personal_best_split_time()[method]);
+        let is_ended = timer.current_phase() == TimerPhase::Ended;
+
+        state.background = self.settings.background;
+        state.label_color = self.settings.label_color;
+        state.display_two_rows = self.settings.display_two_rows;
+
+        state
+            .targets
+            .resize_with(self.settings.targets.len(), Default::default);
+
+        for (target, target_state) in self.settings.targets.iter().zip(&mut state.targets) {
+            target_state.name.clear();
+            if !target.name.is_empty() {
+                target_state.name.push_str(&target.name);
+            } else if let Some(time) = target.time {
+                let _ = write!(target_state.name, "Sub {}", Regular::new().format(time));
+            } else {
+                target_state.name.push_str("Personal Best");
+            }
+
+            let prediction = target
+                .time
+                .or(personal_best)
+                .map(|target| targets::calculate(timer, target, method))
+                .unwrap_or_default();
+
+            target_state.value.clear();
+            match prediction.required_pace {
+                Some(pace) if prediction.achievable => {
+                    let _ = write!(
+                        target_state.value,
+                        "{}",
+                        Regular::with_accuracy(self.settings.accuracy).format(pace),
+                    );
+                }
+                _ => target_state.value.push_str(&layout_settings.placeholder),
+            }
+
+            target_state.achievable = prediction.achievable;
+            target_state.semantic_color = match (prediction.achievable, is_ended) {
+                (true, true) => SemanticColor::PersonalBest,
+                (true, false) => SemanticColor::Default,
+                (false, _) => SemanticColor::BehindLosingTime,
+            };
+            target_state.value_color = self
+                .settings
+                .value_color
+                .unwrap_or_else(|| target_state.semantic_color.visualize(layout_settings));
+            target_state.updates_frequently = prediction.updates_frequently;
+        }
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut settings = SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of each target and the time needed in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the names of the targets. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the times needed. If not specified, the color is taken from the layout, based on whether the target is still achievable.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use for the predictions. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the times shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+            Field::new(
+                "Targets".into(),
+                "The number of targets to show. For each target the average segment time needed on the remaining segments is shown.".into(),
+                Value::UInt(self.settings.targets.len() as _),
+            ),
+        ]);
+
+        settings
+            .fields
+            .reserve_exact(2 * self.settings.targets.len());
+
+        for target in &self.settings.targets {
+            settings.fields.push(Field::new(
+                "Target Name".into(),
+                "The name of the target. If not specified, a name is derived from the target time."
+                    .into(),
+                target.name.clone().into(),
+            ));
+            settings.fields.push(Field::new(
+                "Target Time".into(),
+                "The final time to reach. If not specified, the final time of the Personal Best is used.".into(),
+                target
+                    .time
+                    .map(|time| Regular::with_accuracy(Accuracy::Hundredths).format(time).to_string())
+                    .into(),
+            ));
+        }
+
+        settings
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.timing_method = value.into(),
+            5 => self.settings.accuracy = value.into(),
+            6 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings.targets.resize(new_len, Default::default());
+            }
+            index => {
+                let index = index - SETTINGS_BEFORE_TARGETS;
+                let target = &mut self.settings.targets[index / 2];
+                if index % 2 == 0 {
+                    target.name = value.into();
+                } else {
+                    let time: Option<String> = value.into();
+                    target.time = time.and_then(|time| time.parse().ok());
+                }
+            }
+        }
+    }
+}
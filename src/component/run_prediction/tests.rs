@@ -0,0 +1,55 @@
+use super::{Component, Target};
+use crate::{
+    settings::{SemanticColor, Value},
+    testing::{create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run},
+    GeneralLayoutSettings, TimingMethod,
+};
+
+#[test]
+fn shows_required_pace_per_target() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0)]);
+
+    let mut component = Component::new();
+    component.settings_mut().timing_method = Some(TimingMethod::GameTime);
+    component.settings_mut().targets = vec![
+        Target {
+            name: String::new(),
+            time: Some(span(35.0)),
+        },
+        Target::default(),
+    ];
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.targets.len(), 2);
+
+    assert_eq!(state.targets[0].name, "Sub 0:35");
+    assert_eq!(state.targets[0].value, "0:11.5");
+    assert!(state.targets[0].achievable);
+
+    assert_eq!(state.targets[1].name, "Personal Best");
+    assert_eq!(state.targets[1].value, "—");
+    assert!(!state.targets[1].achievable);
+    assert_eq!(
+        state.targets[1].semantic_color,
+        SemanticColor::BehindLosingTime
+    );
+}
+
+#[test]
+fn targets_can_be_edited_through_the_settings_description() {
+    let mut component = Component::new();
+    component.set_value(6, Value::UInt(2));
+    component.set_value(8, Value::OptionalString(Some("59:00".into())));
+    component.set_value(9, Value::String("Sub Hour".into()));
+    component.set_value(10, Value::OptionalString(Some("1:00:00".into())));
+
+    let targets = &component.settings().targets;
+    assert_eq!(targets.len(), 2);
+    assert_eq!(targets[0].time, Some(span(3540.0)));
+    assert_eq!(targets[1].name, "Sub Hour");
+    assert_eq!(targets[1].time, Some(span(3600.0)));
+    assert_eq!(component.settings_description().fields.len(), 11);
+}
@@ -0,0 +1,251 @@
+//! Provides the Segment Sparkline Component and relevant types for using it.
+//! The Segment Sparkline Component visualizes the most recent segment times of
+//! the current segment as a small line chart, which allows the runner to see at
+//! a glance whether they have been improving that segment recently.
+
+use crate::{
+    component,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings, TimingMethod,
+};
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Segment Sparkline Component visualizes the most recent segment times of
+/// the current segment as a small line chart, which allows the runner to see at
+/// a glance whether they have been improving that segment recently.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the line connecting the segment times. If `None` is
+    /// specified, the text color of the layout is used.
+    pub line_color: Option<Color>,
+    /// The color used to highlight the fastest of the segment times shown. If
+    /// `None` is specified, the best segment color of the layout is used.
+    pub best_time_color: Option<Color>,
+    /// The amount of most recent segment times to show.
+    pub segment_times: u32,
+    /// The timing method to use for the segment times. If `None` is
+    /// specified, the current timing method is used.
+    pub timing_method: Option<TimingMethod>,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The label to show, which is the name of the segment.
+    pub label: String,
+    /// The most recent segment times of the segment, from oldest to newest,
+    /// normalized to the range `0..=1`. The fastest time shown is 0 and the
+    /// slowest time shown is 1. If all the times are equal, they are all 0.5.
+    pub points: Vec<f32>,
+    /// The index of the fastest of the segment times within the points.
+    pub best_index: Option<usize>,
+    /// The color of the line connecting the points.
+    pub line_color: Color,
+    /// The color used to highlight the fastest of the segment times.
+    pub best_time_color: Color,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            label_color: None,
+            line_color: None,
+            best_time_color: None,
+            segment_times: 10,
+            timing_method: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Segment Sparkline Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Segment Sparkline Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Segment Sparkline"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let method = component::resolve_timing_method(self.settings.timing_method, timer);
+        let segments = timer.run().segments();
+        let segment_index = timer
+            .current_split_index()
+            .unwrap_or(0)
+            .min(segments.len() - 1);
+        let segment = &segments[segment_index];
+
+        state.background = self.settings.background;
+        state.label_color = self.settings.label_color;
+        state.line_color = self
+            .settings
+            .line_color
+            .unwrap_or(layout_settings.text_color);
+        state.best_time_color = self
+            .settings
+            .best_time_color
+            .unwrap_or(layout_settings.best_segment_color);
+
+        state.label.clear();
+        state.label.push_str(segment.name());
+
+        let times: Vec<f64> = segment
+            .segment_history()
+            .iter_actual_runs()
+            .filter_map(|(_, time)| Some(time[method]?.total_seconds()))
+            .collect();
+        let times = &times[times
+            .len()
+            .saturating_sub(self.settings.segment_times as usize)..];
+
+        let (min, max) = times
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &t| {
+                (min.min(t), max.max(t))
+            });
+
+        state.points.clear();
+        state.points.extend(times.iter().map(|&t| {
+            if max > min {
+                ((t - min) / (max - min)) as f32
+            } else {
+                0.5
+            }
+        }));
+
+        state.best_index = times.iter().rposition(|&t| t == min);
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the segment's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Line Color".into(),
+                "The color of the line connecting the segment times. If not specified, the text color of the layout is used.".into(),
+                self.settings.line_color.into(),
+            ),
+            Field::new(
+                "Best Time Color".into(),
+                "The color used to highlight the fastest of the segment times shown. If not specified, the best segment color of the layout is used.".into(),
+                self.settings.best_time_color.into(),
+            ),
+            Field::new(
+                "Segment Times".into(),
+                "The amount of most recent segment times of the current segment to show.".into(),
+                Value::UInt(self.settings.segment_times as _),
+            ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use for the segment times. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.line_color = value.into(),
+            3 => self.settings.best_time_color = value.into(),
+            4 => self.settings.segment_times = value.into_uint().unwrap() as _,
+            5 => self.settings.timing_method = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
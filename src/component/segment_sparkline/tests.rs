@@ -0,0 +1,42 @@
+use super::Component;
+use crate::{
+    testing::{create_timer, run_with_splits, start_run},
+    GeneralLayoutSettings,
+};
+
+#[test]
+fn normalizes_most_recent_segment_times() {
+    let mut timer = create_timer(&["A", "B"]);
+    for splits in [[10.0, 20.0], [14.0, 25.0], [12.0, 24.0], [11.0, 20.0]] {
+        run_with_splits(&mut timer, &splits);
+    }
+
+    let mut component = Component::new();
+    component.settings_mut().segment_times = 3;
+    let layout_settings = GeneralLayoutSettings::default();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.label, "A");
+    assert_eq!(state.points, [1.0, 1.0 / 3.0, 0.0]);
+    assert_eq!(state.best_index, Some(2));
+    assert_eq!(state.best_time_color, layout_settings.best_segment_color);
+
+    start_run(&mut timer);
+    timer.split().unwrap();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.label, "B");
+    assert_eq!(state.points, [2.0 / 3.0, 1.0, 0.0]);
+    assert_eq!(state.best_index, Some(2));
+}
+
+#[test]
+fn equal_times_are_centered() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    let state = Component::new().state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.points, [0.5, 0.5]);
+    assert_eq!(state.best_index, Some(1));
+}
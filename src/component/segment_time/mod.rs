@@ -6,11 +6,11 @@
 use super::key_value;
 use crate::{
     analysis::state_helper::comparison_single_segment_time,
-    comparison,
+    comparison, component,
     platform::prelude::*,
     settings::{Color, Field, Gradient, SettingsDescription, Value},
-    timing::formatter::{Accuracy, SegmentTime, TimeFormatter},
-    Timer, TimerPhase,
+    timing::formatter::{none_wrapper::NoneWrapper, Accuracy, SegmentTime, TimeFormatter},
+    GeneralLayoutSettings, Timer, TimerPhase, TimingMethod,
 };
 use alloc::borrow::Cow;
 use core::fmt::Write;
@@ -47,6 +47,10 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// Specifies the Timing Method to use. If set to `None` the Timing Method
+    /// of the Timer is used for showing the time. Otherwise the Timing Method
+    /// provided is used.
+    pub timing_method: Option<TimingMethod>,
 }
 
 impl Default for Settings {
@@ -58,6 +62,7 @@ impl Default for Settings {
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Hundredths,
+            timing_method: None,
         }
     }
 }
@@ -103,8 +108,14 @@ impl Component {
         }
     }
 
-    /// Updates the component's state based on the timer provided.
-    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Timer,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
         let resolved_comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(resolved_comparison, timer);
         let key = self.text(resolved_comparison); // FIXME: Uncow
@@ -119,7 +130,7 @@ impl Component {
                 timer.run(),
                 timer.current_split_index()?,
                 comparison,
-                timer.current_timing_method(),
+                component::resolve_timing_method(self.settings.timing_method, timer),
             )?
         };
 
@@ -135,7 +146,11 @@ impl Component {
         let _ = write!(
             state.value,
             "{}",
-            SegmentTime::with_accuracy(self.settings.accuracy).format(time),
+            NoneWrapper::new(
+                SegmentTime::with_accuracy(self.settings.accuracy),
+                &*layout_settings.placeholder,
+            )
+            .format(time),
         );
 
         state.key_abbreviations.clear();
@@ -167,14 +182,20 @@ impl Component {
             }
         };
 
+        state.secondary_value = None;
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = false;
     }
 
-    /// Calculates the component's state based on the timer provided.
-    pub fn state(&self, timer: &Timer) -> key_value::State {
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &self,
+        timer: &Timer,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
         let mut state = Default::default();
-        self.update_state(&mut state, timer);
+        self.update_state(&mut state, timer, layout_settings);
         state
     }
 
@@ -214,6 +235,11 @@ impl Component {
                 "The accuracy of the segment time shown.".into(),
                 self.settings.accuracy.into(),
             ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
         ])
     }
 
@@ -232,6 +258,7 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.value_color = value.into(),
             5 => self.settings.accuracy = value.into(),
+            6 => self.settings.timing_method = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
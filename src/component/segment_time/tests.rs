@@ -1,11 +1,11 @@
-use super::Component;
+use super::{Component, Settings};
 use crate::{
-    util::tests_helper::{self, make_progress_run_with_splits_opt, run_with_splits, start_run},
-    Timer,
+    testing::{self, make_progress_run_with_splits_opt, run_with_splits, start_run},
+    GeneralLayoutSettings, Timer, TimingMethod,
 };
 
 fn create_timer() -> Timer {
-    let mut timer = tests_helper::create_timer(&["A", "B"]);
+    let mut timer = testing::create_timer(&["A", "B"]);
     run_with_splits(&mut timer, &[12.3, 45.6]);
     timer
 }
@@ -14,7 +14,7 @@ fn create_timer() -> Timer {
 fn is_empty_when_no_attempt_is_started() {
     let component = Component::new();
     let timer = create_timer();
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
     assert_eq!(&*state.value, "—");
 }
 
@@ -24,7 +24,7 @@ fn is_not_empty_when_attempt_is_started() {
     let mut timer = create_timer();
     start_run(&mut timer);
     make_progress_run_with_splits_opt(&mut timer, &[Some(467.23)]);
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
     assert_eq!(&*state.value, "33.30");
 }
 
@@ -35,7 +35,7 @@ fn is_not_empty_when_attempt_is_paused() {
     start_run(&mut timer);
     make_progress_run_with_splits_opt(&mut timer, &[Some(467.23)]);
     timer.pause().unwrap();
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
     assert_eq!(&*state.value, "33.30");
 }
 
@@ -45,6 +45,20 @@ fn is_empty_when_attempt_is_finished() {
     let mut timer = create_timer();
     start_run(&mut timer);
     make_progress_run_with_splits_opt(&mut timer, &[Some(467.23), Some(742.65)]);
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
     assert_eq!(&*state.value, "—");
 }
+
+#[test]
+fn uses_the_timing_method_override() {
+    let component = Component::with_settings(Settings {
+        timing_method: Some(TimingMethod::GameTime),
+        ..Default::default()
+    });
+    let mut timer = create_timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(467.23)]);
+    timer.set_current_timing_method(TimingMethod::RealTime);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+    assert_eq!(&*state.value, "33.30");
+}
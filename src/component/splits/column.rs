@@ -1,7 +1,7 @@
 use crate::{
     analysis::{self, possible_time_save, split_color},
     comparison,
-    component::splits::Settings as SplitsSettings,
+    component::{self, splits::Settings as SplitsSettings},
     platform::prelude::*,
     settings::{Color, SemanticColor},
     timing::{
@@ -37,7 +37,17 @@ pub enum ColumnKind {
     Time(TimeColumn),
 }
 
-/// A column that shows a time.
+/// A column that shows a time. What the column shows for a segment is
+/// determined by the segment's progress: Segments that haven't been reached yet
+/// show the value the column [starts with](Self::start_with). Once the
+/// [update trigger](Self::update_trigger) fires, the value gets replaced by the
+/// value the column [updates with](Self::update_with). For example starting
+/// with the [`ComparisonTime`](ColumnStartWith::ComparisonTime), updating with
+/// the [`Delta`](ColumnUpdateWith::Delta) and using the
+/// [`Contextual`](ColumnUpdateTrigger::Contextual) trigger shows the
+/// comparison's split time until the current attempt starts losing time on the
+/// segment, at which point the live delta is shown, which then becomes the
+/// final delta once the segment is split.
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TimeColumn {
@@ -228,6 +238,20 @@ pub fn update_state(
     }
 }
 
+pub(super) fn write_label_details(details: &mut String, kind: &ColumnKind, timer: &Snapshot<'_>) {
+    if let ColumnKind::Time(column) = kind {
+        let comparison = comparison::or_current(
+            comparison::resolve(&column.comparison_override, timer),
+            timer,
+        );
+        let method = match component::resolve_timing_method(column.timing_method, timer) {
+            TimingMethod::RealTime => "Real Time",
+            TimingMethod::GameTime => "Game Time",
+        };
+        let _ = write!(details, "{} ({method})", comparison::shorten(comparison));
+    }
+}
+
 fn update_time_column(
     state: &mut ColumnState,
     column_settings: &TimeColumn,
@@ -272,7 +296,14 @@ fn update_time_column(
                     ColumnFormatter::SegmentTime,
                 ),
                 ColumnStartWith::PossibleTimeSave => (
-                    possible_time_save::calculate(timer, segment_index, comparison, false).0,
+                    possible_time_save::calculate_for_timing_method(
+                        timer,
+                        segment_index,
+                        comparison,
+                        method,
+                        false,
+                    )
+                    .0,
                     SemanticColor::Default,
                     ColumnFormatter::SegmentTime,
                 ),
@@ -289,30 +320,35 @@ fn update_time_column(
     state.value.clear();
 
     if !is_empty {
-        let _ = match formatter {
-            ColumnFormatter::Time => write!(
-                state.value,
-                "{}",
-                Regular::with_accuracy(splits_settings.split_time_accuracy).format(column_value)
-            ),
-            ColumnFormatter::Delta => write!(
-                state.value,
-                "{}",
-                Delta::custom(
-                    splits_settings.delta_drop_decimals,
-                    splits_settings.delta_time_accuracy,
-                )
-                .format(column_value)
-            ),
-            ColumnFormatter::SegmentTime => {
-                write!(
+        if column_value.is_none() {
+            state.value.push_str(&layout_settings.placeholder);
+        } else {
+            let _ = match formatter {
+                ColumnFormatter::Time => write!(
                     state.value,
                     "{}",
-                    SegmentTime::with_accuracy(splits_settings.segment_time_accuracy)
+                    Regular::with_accuracy(splits_settings.split_time_accuracy)
                         .format(column_value)
-                )
-            }
-        };
+                ),
+                ColumnFormatter::Delta => write!(
+                    state.value,
+                    "{}",
+                    Delta::custom(
+                        splits_settings.delta_drop_decimals,
+                        splits_settings.delta_time_accuracy,
+                    )
+                    .format(column_value)
+                ),
+                ColumnFormatter::SegmentTime => {
+                    write!(
+                        state.value,
+                        "{}",
+                        SegmentTime::with_accuracy(splits_settings.segment_time_accuracy)
+                            .format(column_value)
+                    )
+                }
+            };
+        }
     }
     state.semantic_color = semantic_color;
     state.visual_color = semantic_color.visualize(layout_settings);
@@ -12,7 +12,7 @@ use crate::{
     },
     timing::{formatter::Accuracy, Snapshot},
     util::{Clear, ClearVec},
-    GeneralLayoutSettings,
+    GeneralLayoutSettings, SegmentId,
 };
 use core::cmp::{max, min};
 use serde_derive::{Deserialize, Serialize};
@@ -27,7 +27,7 @@ pub use column::{
     ColumnUpdateWith, TimeColumn, VariableColumn,
 };
 
-const SETTINGS_BEFORE_COLUMNS: usize = 15;
+const SETTINGS_BEFORE_COLUMNS: usize = 18;
 const SETTINGS_PER_TIME_COLUMN: usize = 6;
 const SETTINGS_PER_VARIABLE_COLUMN: usize = 2;
 
@@ -96,6 +96,17 @@ pub struct Settings {
     pub delta_drop_decimals: bool,
     /// Specifies whether to show the names of the columns above the splits.
     pub show_column_labels: bool,
+    /// Specifies whether to show the comparison and timing method that each
+    /// time column is resolved to below the column labels. This only has an
+    /// effect if the column labels are shown.
+    pub show_column_label_details: bool,
+    /// Specifies whether to show the icons of the segments. If the layout
+    /// doesn't show icons anyway, disabling this avoids storing the images of
+    /// the icons in the image cache.
+    pub show_icons: bool,
+    /// The size of the icons as a percentage of the space available for them
+    /// in each row. Values above 100 are treated as 100.
+    pub icon_size: u32,
     /// The columns to show on the splits. These can be configured in various
     /// way to show split times, segment times, deltas and so on. The columns
     /// are defined from right to left.
@@ -107,7 +118,9 @@ pub struct Settings {
 pub struct SplitState {
     /// The icon of the segment. The associated image can be looked up in the
     /// image cache. The image may be the empty image. This indicates that there
-    /// is no icon.
+    /// is no icon or that icons are not supposed to be shown. The ID changes
+    /// whenever the icon changes, so it can be used to determine whether the
+    /// icon of a row needs to be updated.
     pub icon: ImageId,
     /// The name of the segment.
     pub name: String,
@@ -122,6 +135,11 @@ pub struct SplitState {
     /// there can be a scrolling window, showing only a subset of segments. Each
     /// index is guaranteed to be unique.
     pub index: usize,
+    /// The stable identifier of the segment. Unlike the index, it stays the
+    /// same when segments are inserted, removed or reordered, so it can be
+    /// used to track rows across such changes. This is `None` for the blank
+    /// rows that are used to fill up the component.
+    pub id: Option<SegmentId>,
 }
 
 impl Clear for SplitState {
@@ -141,13 +159,22 @@ pub struct State {
     /// `None`, no labels are supposed to be visualized. The list is specified
     /// from right to left.
     pub column_labels: Option<ClearVec<String>>,
+    /// The comparison and timing method that each column is resolved to, such
+    /// as `PB (Game Time)`. The details are empty for columns that don't show
+    /// times. If this is `None`, no details are supposed to be visualized. The
+    /// list is specified from right to left.
+    pub column_label_details: Option<ClearVec<String>>,
     /// The list of all the segments to visualize.
     pub splits: ClearVec<SplitState>,
     /// Specifies whether the current run has any icons, even those that are not
     /// currently visible by the splits component. This allows for properly
     /// indenting the icon column, even when the icons are scrolled outside the
-    /// splits component.
+    /// splits component. This is always `false` if icons are not supposed to be
+    /// shown.
     pub has_icons: bool,
+    /// The size of the icons relative to the space available for them in each
+    /// row, ranging from 0 to 1.
+    pub icon_size: f32,
     /// Specifies whether thin separators should be shown between the individual
     /// segments shown by the component.
     pub show_thin_separators: bool,
@@ -185,6 +212,9 @@ impl Default for Settings {
             delta_time_accuracy: Accuracy::Tenths,
             delta_drop_decimals: true,
             show_column_labels: false,
+            show_column_label_details: false,
+            show_icons: true,
+            icon_size: 100,
             columns: vec![
                 ColumnSettings {
                     name: String::from("Time"),
@@ -220,6 +250,17 @@ impl State {
     {
         serde_json::to_writer(writer, self)
     }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
 }
 
 impl Component {
@@ -321,6 +362,7 @@ impl Component {
             show_thin_separators,
             fill_with_blank_space,
             display_two_rows,
+            show_icons,
             ref columns,
             ..
         } = self.settings;
@@ -337,6 +379,18 @@ impl Component {
             state.column_labels = None;
         }
 
+        if self.settings.show_column_labels && self.settings.show_column_label_details {
+            let details = state
+                .column_label_details
+                .get_or_insert_with(Default::default);
+            details.clear();
+            for c in &self.settings.columns {
+                column::write_label_details(details.push(), &c.kind, timer);
+            }
+        } else {
+            state.column_label_details = None;
+        }
+
         state.splits.clear();
         for (i, segment) in run
             .segments()
@@ -353,10 +407,15 @@ impl Component {
                 columns: ClearVec::new(),
                 is_current_split: false,
                 index: 0,
+                id: None,
             });
 
             let icon = segment.icon();
-            state.icon = *image_cache.cache(icon.id(), || icon.clone()).id();
+            state.icon = if show_icons {
+                *image_cache.cache(icon.id(), || icon.clone()).id()
+            } else {
+                *ImageId::EMPTY
+            };
 
             state.name.push_str(segment.name());
 
@@ -381,6 +440,7 @@ impl Component {
 
             state.is_current_split = Some(i) == current_split;
             state.index = i;
+            state.id = Some(segment.id());
         }
 
         if fill_with_blank_space && state.splits.len() < visual_split_count {
@@ -392,13 +452,16 @@ impl Component {
                     columns: ClearVec::new(),
                     is_current_split: false,
                     index: 0,
+                    id: None,
                 });
                 state.is_current_split = false;
+                state.id = None;
                 state.index = (usize::MAX ^ 1) - 2 * i;
             }
         }
 
-        state.has_icons = run.segments().iter().any(|s| !s.icon().is_empty());
+        state.has_icons = show_icons && run.segments().iter().any(|s| !s.icon().is_empty());
+        state.icon_size = self.settings.icon_size.min(100) as f32 / 100.0;
         state.show_thin_separators = show_thin_separators;
         state.show_final_separator = show_final_separator;
         state.display_two_rows = display_two_rows;
@@ -495,6 +558,21 @@ impl Component {
                 "Specifies whether to show the names of the columns at the top of the list.".into(),
                 self.settings.show_column_labels.into(),
             ),
+            Field::new(
+                "Show Column Comparisons".into(),
+                "Specifies whether to show the comparison and timing method that each column uses below the column labels.".into(),
+                self.settings.show_column_label_details.into(),
+            ),
+            Field::new(
+                "Show Icons".into(),
+                "Specifies whether to show the icons of the segments.".into(),
+                self.settings.show_icons.into(),
+            ),
+            Field::new(
+                "Icon Size".into(),
+                "The size of the icons as a percentage of the space available for them in each row.".into(),
+                Value::UInt(self.settings.icon_size as _),
+            ),
             Field::new(
                 "Columns".into(),
                 "The number of columns to show in each row. Each column can be configured to show different information. The columns are defined from right to left.".into(),
@@ -600,7 +678,10 @@ impl Component {
             11 => self.settings.delta_time_accuracy = value.into(),
             12 => self.settings.delta_drop_decimals = value.into(),
             13 => self.settings.show_column_labels = value.into(),
-            14 => {
+            14 => self.settings.show_column_label_details = value.into(),
+            15 => self.settings.show_icons = value.into(),
+            16 => self.settings.icon_size = value.into_uint().unwrap() as _,
+            17 => {
                 let new_len = value.into_uint().unwrap() as usize;
                 self.settings.columns.resize(new_len, Default::default());
             }
@@ -3,6 +3,7 @@ use super::{
     State,
 };
 use crate::{
+    comparison::{best_segments, personal_best},
     component::splits::{ColumnKind, TimeColumn},
     settings::{
         ImageCache,
@@ -11,8 +12,8 @@ use crate::{
             BestSegment as Best, Default as Text,
         },
     },
-    util::tests_helper::{make_progress_run_with_splits_opt, run_with_splits_opt, start_run},
-    Run, Segment, TimeSpan, Timer,
+    testing::{make_progress_run_with_splits_opt, run_with_splits_opt, start_run},
+    Run, Segment, TimeSpan, Timer, TimingMethod,
 };
 
 type Values = &'static [([&'static str; 6], [SemanticColor; 6])];
@@ -1354,3 +1355,69 @@ fn check_column_color(state: &State, split_index: usize, expected_color: Semanti
         expected_color
     );
 }
+
+#[test]
+fn columns_resolve_their_own_comparison_and_timing_method() {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(10.0),
+            Some(20.0),
+            Some(30.0),
+            Some(40.0),
+            Some(50.0),
+            Some(60.0),
+        ],
+    );
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(8.0),
+            Some(20.0),
+            Some(35.0),
+            Some(45.0),
+            Some(55.0),
+            Some(65.0),
+        ],
+    );
+    timer.set_current_timing_method(TimingMethod::RealTime);
+
+    let column = |start_with, comparison: &str| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with,
+            update_with: ColumnUpdateWith::DontUpdate,
+            comparison_override: Some(comparison.into()),
+            timing_method: Some(TimingMethod::GameTime),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            column(ColumnStartWith::ComparisonTime, personal_best::NAME),
+            column(ColumnStartWith::ComparisonTime, best_segments::NAME),
+            column(ColumnStartWith::PossibleTimeSave, personal_best::NAME),
+        ],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    let state = component.state(
+        &mut ImageCache::new(),
+        &timer.snapshot(),
+        &Default::default(),
+    );
+    let values = |column: usize| {
+        state
+            .splits
+            .iter()
+            .map(|split| split.columns[column].value.as_str())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(values(0), ["0:10", "0:20", "0:30", "0:40", "0:50", "1:00"]);
+    assert_eq!(values(1), ["0:08", "0:18", "0:28", "0:38", "0:48", "0:58"]);
+    assert_eq!(values(2), ["2.00", "0.00", "0.00", "0.00", "0.00", "0.00"]);
+}
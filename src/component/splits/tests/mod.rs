@@ -3,9 +3,10 @@ use super::{
     State,
 };
 use crate::{
+    comparison::best_segments,
     component::splits::{ColumnKind, TimeColumn},
-    settings::ImageCache,
-    Run, Segment, TimeSpan, Timer, TimingMethod,
+    settings::{Image, ImageCache, ImageId},
+    Run, RunEditor, Segment, TimeSpan, Timer, TimingMethod,
 };
 
 pub mod column;
@@ -146,3 +147,116 @@ fn unique_split_indices() {
 
     assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
 }
+
+#[test]
+fn column_label_details() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new(""));
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        show_column_labels: true,
+        show_column_label_details: true,
+        columns: vec![
+            ColumnSettings {
+                name: "Best".into(),
+                kind: ColumnKind::Time(TimeColumn {
+                    comparison_override: Some(best_segments::NAME.into()),
+                    timing_method: Some(TimingMethod::GameTime),
+                    ..Default::default()
+                }),
+            },
+            ColumnSettings {
+                name: "Notes".into(),
+                kind: ColumnKind::Variable(Default::default()),
+            },
+            ColumnSettings::default(),
+        ],
+        ..Default::default()
+    });
+
+    let mut image_cache = ImageCache::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert_eq!(
+        state.column_labels.as_deref(),
+        Some(&["Best", "Notes", "Column"].map(String::from)[..]),
+    );
+    assert_eq!(
+        state.column_label_details.as_deref(),
+        Some(&["Best (Game Time)", "", "PB (Real Time)"].map(String::from)[..]),
+    );
+
+    component.settings_mut().show_column_labels = false;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert!(state.column_labels.is_none());
+    assert!(state.column_label_details.is_none());
+}
+
+#[test]
+fn segment_ids_follow_reordered_segments() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    let (a, c) = (run.segment_id(0).unwrap(), run.segment_id(2).unwrap());
+    assert_ne!(a, c);
+    assert_eq!(run.segment_id(3), None);
+
+    let mut editor = RunEditor::new(run).unwrap();
+    editor.select_only(2);
+    editor.move_segments_up();
+    editor.select_only(0);
+    editor.insert_segment_above();
+    let timer = Timer::new(editor.close()).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 5,
+        fill_with_blank_space: true,
+        ..Default::default()
+    });
+    let state = component.state(
+        &mut ImageCache::new(),
+        &timer.snapshot(),
+        &Default::default(),
+    );
+
+    let ids = state.splits.iter().map(|s| s.id).collect::<Vec<_>>();
+    assert_eq!(ids.len(), 5);
+    assert_eq!(ids[1], Some(a));
+    assert_eq!(ids[2], Some(c));
+    assert!(ids[0].is_some() && ids[0] != Some(a) && ids[0] != Some(c));
+    assert_eq!(ids[4], None);
+}
+
+#[test]
+fn icons_can_be_hidden() {
+    let mut run = Run::new();
+    let mut segment = Segment::new("A");
+    let icon = Image::new([0x00, 0x12, 0x34].into(), Image::ICON);
+    segment.set_icon(icon.clone());
+    run.push_segment(segment);
+    run.push_segment(Segment::new("B"));
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        icon_size: 150,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert!(state.has_icons);
+    assert_eq!(state.icon_size, 1.0);
+    assert_eq!(state.splits[0].icon, *icon.id());
+    assert_eq!(state.splits[1].icon, *ImageId::EMPTY);
+    assert!(image_cache.lookup(icon.id()).is_some());
+
+    component.settings_mut().show_icons = false;
+    component.settings_mut().icon_size = 50;
+    let mut image_cache = ImageCache::new();
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert!(!state.has_icons);
+    assert_eq!(state.icon_size, 0.5);
+    assert_eq!(state.splits[0].icon, *ImageId::EMPTY);
+    assert!(image_cache.lookup(icon.id()).is_none());
+}
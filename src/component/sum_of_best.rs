@@ -13,9 +13,8 @@ use crate::{
     platform::prelude::*,
     settings::{Color, Field, Gradient, SettingsDescription, Value},
     timing::formatter::{Accuracy, Regular, TimeFormatter},
-    Timer,
+    GeneralLayoutSettings, Timer, TimingMethod,
 };
-use core::fmt::Write;
 use serde_derive::{Deserialize, Serialize};
 
 /// The Sum of Best Segments Component shows the fastest possible time to
@@ -47,6 +46,13 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// Specifies the Timing Method to use. If set to `None` the Timing Method
+    /// of the Timer is used for showing the time. Otherwise the Timing Method
+    /// provided is used.
+    pub timing_method: Option<TimingMethod>,
+    /// The name of the layout's time format to use for the sum of best
+    /// segments. If set to `None` or not available, the default format is used.
+    pub time_format: Option<String>,
 }
 
 impl Default for Settings {
@@ -57,6 +63,8 @@ impl Default for Settings {
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Seconds,
+            timing_method: None,
+            time_format: None,
         }
     }
 }
@@ -87,13 +95,19 @@ impl Component {
         "Sum of Best Segments"
     }
 
-    /// Updates the component's state based on the timer provided.
-    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Timer,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
         let time = calculate_best(
             timer.run().segments(),
             false,
             true,
-            timer.current_timing_method(),
+            super::resolve_timing_method(self.settings.timing_method, timer),
         );
 
         state.background = self.settings.background;
@@ -105,9 +119,12 @@ impl Component {
         state.key.push_str("Sum of Best Segments");
 
         state.value.clear();
-        let _ = write!(
-            state.value,
-            "{}",
+        super::write_time(
+            &mut state.value,
+            self.settings.time_format.as_deref(),
+            layout_settings,
+            time,
+            self.settings.accuracy,
             Regular::with_accuracy(self.settings.accuracy).format(time),
         );
 
@@ -115,14 +132,20 @@ impl Component {
         state.key_abbreviations.push("Sum of Best".into());
         state.key_abbreviations.push("SoB".into());
 
+        state.secondary_value = None;
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = false;
     }
 
-    /// Calculates the component's state based on the timer provided.
-    pub fn state(&self, timer: &Timer) -> key_value::State {
+    /// Calculates the component's state based on the timer and layout
+    /// settings provided.
+    pub fn state(
+        &self,
+        timer: &Timer,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
         let mut state = Default::default();
-        self.update_state(&mut state, timer);
+        self.update_state(&mut state, timer, layout_settings);
         state
     }
 
@@ -155,6 +178,16 @@ impl Component {
                 "The accuracy of the sum of best segments shown.".into(),
                 self.settings.accuracy.into(),
             ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+            Field::new(
+                "Time Format".into(),
+                "The name of the time format to use for the sum of best segments. If not specified or not available, the default time format is used.".into(),
+                self.settings.time_format.clone().into(),
+            ),
         ])
     }
 
@@ -172,6 +205,8 @@ impl Component {
             2 => self.settings.label_color = value.into(),
             3 => self.settings.value_color = value.into(),
             4 => self.settings.accuracy = value.into(),
+            5 => self.settings.timing_method = value.into(),
+            6 => self.settings.time_format = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
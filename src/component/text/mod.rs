@@ -7,9 +7,8 @@ use super::key_value;
 use crate::{
     platform::prelude::*,
     settings::{Color, Field, Gradient, SettingsDescription, Value},
-    timing::formatter,
     util::PopulateString,
-    Timer,
+    GeneralLayoutSettings, Timer,
 };
 use alloc::borrow::Cow;
 use core::mem;
@@ -159,6 +158,17 @@ impl State {
     {
         serde_json::to_writer(writer, self)
     }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
 }
 
 impl Component {
@@ -205,8 +215,14 @@ impl Component {
         }
     }
 
-    /// Updates the component's state based on the timer provided.
-    pub fn update_state(&self, state: &mut State, timer: &Timer) {
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Timer,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
         state.background = self.settings.background;
         state.display_two_rows = self.settings.text.is_split() && self.settings.display_two_rows;
         state.left_center_color = self.settings.left_center_color;
@@ -222,7 +238,7 @@ impl Component {
                     .custom_variable(var_name)
                     .map(|var| var.value.as_str())
                     .filter(|value| !value.trim_start().is_empty())
-                    .unwrap_or(formatter::DASH);
+                    .unwrap_or(&layout_settings.placeholder);
 
                 if *is_split {
                     (var_name.as_str(), Some(value))
@@ -260,10 +276,11 @@ impl Component {
         }
     }
 
-    /// Calculates the component's state.
-    pub fn state(&self, timer: &Timer) -> State {
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
         let mut state = Default::default();
-        self.update_state(&mut state, timer);
+        self.update_state(&mut state, timer, layout_settings);
         state
     }
 
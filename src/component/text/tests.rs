@@ -1,5 +1,5 @@
 use super::{Component, Text, TextState};
-use crate::{timing::formatter, util::tests_helper::create_run, Timer};
+use crate::{testing::create_run, timing::formatter, GeneralLayoutSettings, Timer};
 
 #[test]
 fn resolves_variables() {
@@ -10,7 +10,7 @@ fn resolves_variables() {
     let mut component = Component::new();
     component.settings_mut().text = Text::Variable(String::from("Goal Time"), true);
 
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
 
     assert_eq!(
         state.text,
@@ -19,7 +19,7 @@ fn resolves_variables() {
 
     component.settings_mut().text = Text::Variable(String::from("Goal Time"), false);
 
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
 
     assert_eq!(state.text, TextState::Center(String::from("4:20:00")));
 }
@@ -32,7 +32,7 @@ fn uses_dash_for_non_existing_variables() {
     let mut component = Component::new();
     component.settings_mut().text = Text::Variable(String::from("Goal Time"), true);
 
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
 
     assert_eq!(
         state.text,
@@ -49,7 +49,7 @@ fn uses_dash_for_empty_string_variable() {
     let mut component = Component::new();
     component.settings_mut().text = Text::Variable(String::from("Goal Time"), true);
 
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
 
     assert_eq!(
         state.text,
@@ -66,10 +66,27 @@ fn uses_dash_for_whitespace_variable() {
     let mut component = Component::new();
     component.settings_mut().text = Text::Variable(String::from("Goal Time"), true);
 
-    let state = component.state(&timer);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
 
     assert_eq!(
         state.text,
         TextState::Split(String::from("Goal Time"), String::from(formatter::DASH))
     );
 }
+
+#[test]
+fn uses_the_placeholder_of_the_layout_for_non_existing_variables() {
+    let run = create_run(&["A"]);
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().text = Text::Variable(String::from("Goal Time"), false);
+
+    let layout_settings = GeneralLayoutSettings {
+        placeholder: String::from("N/A"),
+        ..Default::default()
+    };
+    let state = component.state(&timer, &layout_settings);
+
+    assert_eq!(state.text, TextState::Center(String::from("N/A")));
+}
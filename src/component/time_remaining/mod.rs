@@ -0,0 +1,264 @@
+//! Provides the Time Remaining Component and relevant types for using it. The
+//! Time Remaining Component shows how much time is expected to be left in the
+//! active attempt, if the remainder of the attempt matches the chosen
+//! comparison. This is especially useful for marathons, where the hosts want to
+//! know how much time is left on the current run.
+
+use super::key_value;
+use crate::{
+    analysis::remaining_time,
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, TimingMethod,
+};
+use alloc::borrow::Cow;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Time Remaining Component shows how much time is expected to be left in
+/// the active attempt, if the remainder of the attempt matches the chosen
+/// comparison. This is especially useful for marathons, where the hosts want to
+/// know how much time is left on the current run. Optionally the time left until
+/// the estimated duration of the run is used up can be shown as well.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen. Uses the Timer's current comparison if set to
+    /// `None`.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+    /// Specifies whether to additionally show how much time is left until the
+    /// estimated duration of the run, as stored in its metadata, is used up.
+    /// Nothing additional is shown if the run has no estimate.
+    pub show_estimate: bool,
+    /// Specifies the timing method to use. If set to `None` the current timing
+    /// method is used.
+    pub timing_method: Option<TimingMethod>,
+    /// The name of the layout's time format to use for the remaining time. If
+    /// set to `None` or not available, the default format is used.
+    pub time_format: Option<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Seconds,
+            show_estimate: false,
+            timing_method: None,
+            time_format: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Time Remaining Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Time Remaining Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        self.text(self.settings.comparison_override.as_deref())
+    }
+
+    fn text(&self, comparison: Option<&str>) -> Cow<'static, str> {
+        if let Some(comparison) = comparison {
+            format!("Time Remaining ({})", comparison::shorten(comparison)).into()
+        } else {
+            "Time Remaining".into()
+        }
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let resolved_comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(resolved_comparison, timer);
+        let method = super::resolve_timing_method(self.settings.timing_method, timer);
+        let (remaining, updates_frequently) =
+            remaining_time::remaining_for_timing_method(timer, comparison, method);
+        let (estimate, estimate_updates_frequently) = if self.settings.show_estimate {
+            remaining_time::remaining_until_estimate_for_timing_method(timer, method)
+        } else {
+            (None, false)
+        };
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str(&self.text(resolved_comparison));
+
+        state.value.clear();
+        super::write_time(
+            &mut state.value,
+            self.settings.time_format.as_deref(),
+            layout_settings,
+            remaining,
+            self.settings.accuracy,
+            Regular::with_accuracy(self.settings.accuracy).format(remaining),
+        );
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push("Time Remaining".into());
+        state.key_abbreviations.push("Remaining".into());
+        state.key_abbreviations.push("Left".into());
+
+        if let Some(estimate) = estimate {
+            let secondary_value = state.secondary_value.get_or_insert_with(String::new);
+            secondary_value.clear();
+            super::write_time(
+                secondary_value,
+                self.settings.time_format.as_deref(),
+                layout_settings,
+                Some(estimate),
+                self.settings.accuracy,
+                Regular::with_accuracy(self.settings.accuracy).format(estimate),
+            );
+        } else {
+            state.secondary_value = None;
+        }
+        state.secondary_value_color = self.settings.value_color;
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = updates_frequently || estimate_updates_frequently;
+    }
+
+    /// Calculates the component's state based on the timer and layout
+    /// settings provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Comparison".into(),
+                "The comparison to predict the remaining time from. If not specified, the current comparison is used.".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the remaining time in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the remaining time. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the remaining time shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+            Field::new(
+                "Show Estimate".into(),
+                "Specifies whether to additionally show how much time is left until the estimated duration of the run is used up. This is only shown if an estimate is stored in the run's metadata, such as the one a marathon schedules for the run.".into(),
+                self.settings.show_estimate.into(),
+            ),
+            Field::new(
+                "Timing Method".into(),
+                "Specifies the timing method to use. If not specified, the current timing method is used.".into(),
+                self.settings.timing_method.into(),
+            ),
+            Field::new(
+                "Time Format".into(),
+                "The name of the time format to use for the remaining time. If not specified or not available, the default time format is used.".into(),
+                self.settings.time_format.clone().into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.accuracy = value.into(),
+            6 => self.settings.show_estimate = value.into(),
+            7 => self.settings.timing_method = value.into(),
+            8 => self.settings.time_format = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
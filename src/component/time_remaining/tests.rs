@@ -0,0 +1,72 @@
+use super::Component;
+use crate::{
+    comparison::best_segments,
+    testing::{create_timer, run_with_splits, span, split_at, start_run},
+    GeneralLayoutSettings, TimingMethod,
+};
+
+#[test]
+fn shows_the_time_left_in_the_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 25.0, 40.0]);
+
+    let component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.key, "Time Remaining");
+    assert_eq!(state.value, "0:30");
+    assert_eq!(state.secondary_value, None);
+    assert!(!state.updates_frequently);
+
+    start_run(&mut timer);
+    timer.set_game_time(span(8.0)).unwrap();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.value, "0:22");
+    assert!(state.updates_frequently);
+
+    split_at(&mut timer, 11.0);
+    split_at(&mut timer, 21.0);
+    split_at(&mut timer, 31.0);
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.value, "0:00");
+    assert!(!state.updates_frequently);
+}
+
+#[test]
+fn shows_the_comparison_and_the_estimate() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[8.0, 25.0]);
+    let mut run = timer.run().clone();
+    run.metadata_mut().set_estimate(Some(span(60.0)));
+    timer.set_run(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some(best_segments::NAME.into());
+    component.settings_mut().show_estimate = true;
+    let layout_settings = GeneralLayoutSettings::default();
+
+    start_run(&mut timer);
+    timer.set_game_time(span(5.0)).unwrap();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.key, "Time Remaining (Best)");
+    assert_eq!(state.value, "0:13");
+    assert_eq!(state.secondary_value.as_deref(), Some("0:55"));
+}
+
+#[test]
+fn uses_the_timing_method_override() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[30.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().timing_method = Some(TimingMethod::RealTime);
+    let layout_settings = GeneralLayoutSettings::default();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_ne!(state.value, "0:30");
+
+    component.settings_mut().timing_method = Some(TimingMethod::GameTime);
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.value, "0:30");
+}
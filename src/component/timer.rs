@@ -8,10 +8,12 @@ use crate::{
     platform::prelude::*,
     settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
-        formatter::{timer as formatter, Accuracy, DigitsFormat, TimeFormatter},
+        formatter::{
+            none_wrapper::NoneWrapper, timer as formatter, Accuracy, DigitsFormat, TimeFormatter,
+        },
         Snapshot,
     },
-    GeneralLayoutSettings, TimeSpan, TimerPhase, TimingMethod,
+    GeneralLayoutSettings, TimerPhase, TimingMethod,
 };
 use core::fmt::Write;
 use serde_derive::{Deserialize, Serialize};
@@ -155,6 +157,17 @@ impl State {
     {
         serde_json::to_writer(writer, self)
     }
+
+    /// Encodes the state object's information as MessagePack. See
+    /// [`LayoutState::write_msgpack`](crate::layout::LayoutState::write_msgpack)
+    /// for how the information is encoded.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
 }
 
 impl Component {
@@ -195,71 +208,27 @@ impl Component {
         timer: &Snapshot<'_>,
         layout_settings: &GeneralLayoutSettings,
     ) {
-        let method = self
-            .settings
-            .timing_method
-            .unwrap_or_else(|| timer.current_timing_method());
+        let method = super::resolve_timing_method(self.settings.timing_method, timer);
 
         let phase = timer.current_phase();
 
         let (time, semantic_color) = if self.settings.is_segment_timer {
-            let last_split_index = if phase == TimerPhase::Ended {
-                timer.run().len() - 1
-            } else {
-                timer.current_split_index().unwrap_or_default()
-            };
-            let mut segment_time = calculate_live_segment_time(timer, method, last_split_index);
-
-            if segment_time.is_none() && method == TimingMethod::GameTime {
-                segment_time =
-                    calculate_live_segment_time(timer, TimingMethod::RealTime, last_split_index);
-            }
+            let segment_time = timer.current_segment_time();
+            let segment_time = segment_time[method].or(segment_time.real_time);
 
             (segment_time, SemanticColor::Default)
         } else {
             let time = timer.current_time();
-            let time = time[method].or(time.real_time).unwrap_or_default();
-            let current_comparison = timer.current_comparison();
-
-            let semantic_color = match phase {
-                TimerPhase::Running if time >= TimeSpan::zero() => {
-                    let pb_split_time = timer
-                        .current_split()
-                        .unwrap()
-                        .comparison(current_comparison)[method];
-
-                    if let Some(pb_split_time) = pb_split_time {
-                        split_color(
-                            timer,
-                            Some(time - pb_split_time),
-                            timer.current_split_index().unwrap(),
-                            true,
-                            false,
-                            current_comparison,
-                            method,
-                        )
-                        .or(SemanticColor::AheadGainingTime)
-                    } else {
-                        SemanticColor::AheadGainingTime
-                    }
-                }
-                TimerPhase::Paused => SemanticColor::Paused,
-                TimerPhase::Ended => {
-                    let pb_time = timer
-                        .run()
-                        .segments()
-                        .last()
-                        .unwrap()
-                        .comparison(current_comparison)[method];
-
-                    if pb_time.map_or(true, |t| time < t) {
-                        SemanticColor::PersonalBest
-                    } else {
-                        SemanticColor::BehindLosingTime
-                    }
-                }
-                _ => SemanticColor::NotRunning,
-            };
+            let mut time = time[method].or(time.real_time).unwrap_or_default();
+            // Count down towards the scheduled start, such that the timer
+            // continues seamlessly from the offset once it starts.
+            if let (TimerPhase::NotRunning, Some(countdown)) =
+                (phase, timer.time_until_scheduled_start())
+            {
+                time -= countdown;
+            }
+            let semantic_color =
+                split_color::timer_color(timer, time, timer.current_comparison(), method);
 
             (Some(time), semantic_color)
         };
@@ -286,7 +255,11 @@ impl Component {
         let _ = write!(
             state.time,
             "{}",
-            formatter::Time::with_digits_format(self.settings.digits_format).format(time),
+            NoneWrapper::new(
+                formatter::Time::with_digits_format(self.settings.digits_format),
+                &*layout_settings.placeholder,
+            )
+            .format(time),
         );
 
         state.fraction.clear();
@@ -296,7 +269,9 @@ impl Component {
             formatter::Fraction::with_accuracy(self.settings.accuracy).format(time),
         );
 
-        state.updates_frequently = phase.updates_frequently(method) && time.is_some();
+        state.updates_frequently = (phase.updates_frequently(method)
+            || timer.time_until_scheduled_start().is_some())
+            && time.is_some();
         state.semantic_color = semantic_color;
         state.height = self.settings.height;
     }
@@ -389,24 +364,6 @@ pub fn top_and_bottom_color(color: Color) -> (Color, Color) {
     (top_color, bottom_color)
 }
 
-fn calculate_live_segment_time(
-    timer: &Snapshot<'_>,
-    timing_method: TimingMethod,
-    last_split_index: usize,
-) -> Option<TimeSpan> {
-    let last_split = if last_split_index > 0 {
-        timer.run().segment(last_split_index - 1).split_time()[timing_method]
-    } else {
-        Some(TimeSpan::zero())
-    };
-
-    if timer.current_phase() == TimerPhase::NotRunning {
-        Some(timer.run().offset())
-    } else {
-        Some(timer.current_time()[timing_method]? - last_split?)
-    }
-}
-
 // FIXME: Workaround for #[serde(flatten)] not being a thing on enums.
 mod serialize {
     #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
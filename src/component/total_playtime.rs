@@ -100,6 +100,7 @@ impl Component {
         state.key_abbreviations.clear();
         state.key_abbreviations.push("Playtime".into());
 
+        state.secondary_value = None;
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = timer
             .current_phase()
@@ -0,0 +1,324 @@
+//! Provides the World Record Component and relevant types for using it. The
+//! World Record Component shows the world record, or the time of any other
+//! configured leaderboard rank, for the game and category of the run.
+
+use super::key_value;
+use crate::{
+    platform::prelude::*,
+    run::RunMetadata,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, Run, TimeSpan,
+};
+use alloc::borrow::Cow;
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The name of the custom variable in the run's metadata that the World
+/// Record Component falls back to if it doesn't have a fetched record for the
+/// run. It is expected to store the time of the record.
+pub const TIME_VARIABLE: &str = "World Record";
+/// The name of the custom variable in the run's metadata that stores the
+/// runner holding the record that the World Record Component falls back to.
+pub const RUNNER_VARIABLE: &str = "World Record Holder";
+
+/// The World Record Component shows the world record, or the time of any other
+/// configured leaderboard rank, for the game and category of the run. The
+/// component doesn't query any leaderboards by itself. Instead the record is
+/// fetched from the leaderboards by the frontend and cached in the component
+/// via [`set_record`](Component::set_record). A manual refresh can be
+/// requested via [`request_refresh`](Component::request_refresh). If there's no
+/// record cached for the run's game and category, the component falls back to
+/// the record stored in the run's metadata.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+    record: Option<Record>,
+    refresh_requested: bool,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+    /// The leaderboard rank to show. A rank of 1 shows the world record.
+    pub rank: u32,
+    /// Specifies whether to show the name of the runner holding the record.
+    pub show_runner: bool,
+}
+
+/// A record on the leaderboards of a game's category, as cached by the World
+/// Record Component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// The name of the game the record belongs to.
+    pub game_name: String,
+    /// The name of the category the record belongs to.
+    pub category_name: String,
+    /// The leaderboard rank of the record.
+    pub rank: u32,
+    /// The time of the record.
+    pub time: TimeSpan,
+    /// The name of the runner holding the record, if known.
+    pub runner: Option<String>,
+}
+
+impl Record {
+    /// Stores the record in the metadata of a run, so that the World Record
+    /// Component can fall back to it when the leaderboards can't be reached.
+    pub fn store_in(&self, metadata: &mut RunMetadata) {
+        metadata
+            .custom_variable_mut(TIME_VARIABLE)
+            .permanent()
+            .set_value(
+                Regular::with_accuracy(Accuracy::Hundredths)
+                    .format(self.time)
+                    .to_string(),
+            );
+        match &self.runner {
+            Some(runner) => metadata
+                .custom_variable_mut(RUNNER_VARIABLE)
+                .permanent()
+                .set_value(runner.as_str()),
+            None => metadata.remove_custom_variable(RUNNER_VARIABLE),
+        }
+    }
+
+    fn matches(&self, run: &Run, rank: u32) -> bool {
+        self.rank == rank
+            && self.game_name == run.game_name()
+            && self.category_name == run.category_name()
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Hundredths,
+            rank: 1,
+            show_runner: true,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new World Record Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new World Record Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the record that is currently cached by the component.
+    pub const fn record(&self) -> Option<&Record> {
+        self.record.as_ref()
+    }
+
+    /// Caches a record that got fetched from the leaderboards. This also
+    /// clears any pending refresh request.
+    pub fn set_record(&mut self, record: Option<Record>) {
+        self.record = record;
+        self.refresh_requested = false;
+    }
+
+    /// Requests the record to be fetched from the leaderboards again.
+    pub fn request_refresh(&mut self) {
+        self.refresh_requested = true;
+    }
+
+    /// Checks whether the record needs to be fetched from the leaderboards for
+    /// the run provided. This is the case if a refresh got requested or if the
+    /// cached record doesn't belong to the run's game and category or to the
+    /// rank configured.
+    pub fn needs_refresh(&self, run: &Run) -> bool {
+        self.refresh_requested
+            || !self
+                .record
+                .as_ref()
+                .is_some_and(|r| r.matches(run, self.settings.rank))
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if self.settings.rank > 1 {
+            format!("Rank {}", self.settings.rank).into()
+        } else {
+            "World Record".into()
+        }
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let run = timer.run();
+        let rank = self.settings.rank;
+
+        let (time, runner) = match self.record.as_ref().filter(|r| r.matches(run, rank)) {
+            Some(record) => (Some(record.time), record.runner.as_deref()),
+            None if rank <= 1 => {
+                let metadata = run.metadata();
+                (
+                    metadata
+                        .custom_variable_value(TIME_VARIABLE)
+                        .and_then(|t| t.trim().parse().ok()),
+                    metadata
+                        .custom_variable_value(RUNNER_VARIABLE)
+                        .filter(|r| !r.is_empty()),
+                )
+            }
+            None => (None, None),
+        };
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key_abbreviations.clear();
+        if rank > 1 {
+            let _ = write!(state.key, "Rank {rank}");
+            state.key_abbreviations.push(format!("#{rank}").into());
+        } else {
+            state.key.push_str("World Record");
+            state.key_abbreviations.push("WR".into());
+        }
+
+        state.value.clear();
+        match time {
+            Some(time) => {
+                let _ = write!(
+                    state.value,
+                    "{}",
+                    Regular::with_accuracy(self.settings.accuracy).format(time),
+                );
+                if let Some(runner) = runner.filter(|_| self.settings.show_runner) {
+                    let _ = write!(state.value, " by {runner}");
+                }
+            }
+            None => state.value.push_str(&layout_settings.placeholder),
+        }
+
+        state.secondary_value = None;
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = false;
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the record in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the record. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the time shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+            Field::new(
+                "Rank".into(),
+                "The leaderboard rank to show. A rank of 1 shows the world record.".into(),
+                Value::UInt(self.settings.rank as _),
+            ),
+            Field::new(
+                "Show Runner".into(),
+                "Specifies whether to show the name of the runner holding the record.".into(),
+                self.settings.show_runner.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.accuracy = value.into(),
+            5 => self.settings.rank = (value.into_uint().unwrap() as u32).max(1),
+            6 => self.settings.show_runner = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::{Component, Record};
+use crate::{testing::create_run, GeneralLayoutSettings, TimeSpan, Timer};
+
+fn timer() -> Timer {
+    let mut run = create_run(&["A", "B"]);
+    run.set_game_name("Game");
+    run.set_category_name("Any%");
+    Timer::new(run).unwrap()
+}
+
+fn record(rank: u32) -> Record {
+    Record {
+        game_name: "Game".into(),
+        category_name: "Any%".into(),
+        rank,
+        time: TimeSpan::from_seconds(3599.5),
+        runner: Some("Runner".into()),
+    }
+}
+
+#[test]
+fn shows_cached_record() {
+    let timer = timer();
+    let mut component = Component::new();
+    assert!(component.needs_refresh(timer.run()));
+
+    component.set_record(Some(record(1)));
+    assert!(!component.needs_refresh(timer.run()));
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.key, "World Record");
+    assert_eq!(state.value, "59:59.50 by Runner");
+}
+
+#[test]
+fn ignores_records_of_other_ranks() {
+    let timer = timer();
+    let mut component = Component::new();
+    component.settings_mut().rank = 2;
+    component.set_record(Some(record(1)));
+    assert!(component.needs_refresh(timer.run()));
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.key, "Rank 2");
+    assert_eq!(state.value, "—");
+}
+
+#[test]
+fn manual_refresh() {
+    let timer = timer();
+    let mut component = Component::new();
+    component.set_record(Some(record(1)));
+    component.request_refresh();
+    assert!(component.needs_refresh(timer.run()));
+    component.set_record(Some(record(1)));
+    assert!(!component.needs_refresh(timer.run()));
+}
+
+#[test]
+fn falls_back_to_run_metadata() {
+    let mut run = create_run(&["A"]);
+    record(1).store_in(run.metadata_mut());
+    run.set_game_name("Other Game");
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.set_record(Some(record(1)));
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.value, "59:59.50 by Runner");
+
+    component.settings_mut().show_runner = false;
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(state.value, "59:59.50");
+}
@@ -0,0 +1,200 @@
+//! The config module provides the application configuration that frontends
+//! persist between sessions, such as the hotkey bindings and the recently
+//! opened files. Sharing the same schema allows the configuration to be
+//! exchanged between different frontends.
+//!
+//! The configuration is stored as JSON along with the version of its schema.
+//! Configurations stored by older versions are migrated when they are loaded.
+//! Before the configuration existed, frontends only stored the
+//! [`HotkeyConfig`] on its own, so such files are migrated as well.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use livesplit_core::config::Config;
+//!
+//! let mut config = Config::load("config.json").unwrap();
+//! config.recent_files.add_splits("splits.lss");
+//! config.save("config.json").unwrap();
+//! ```
+
+use crate::HotkeyConfig;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use snafu::{OptionExt, ResultExt};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The version of the schema of the configuration that is stored by this
+/// version of livesplit-core.
+pub const VERSION: u64 = 1;
+
+/// The Error type for loading and saving a [`Config`].
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+#[non_exhaustive]
+pub enum Error {
+    /// Failed to read the configuration file.
+    ReadFile {
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// Failed to write the configuration file.
+    WriteFile {
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// The configuration is not valid JSON or doesn't match the schema.
+    Json {
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// The version of the schema is not a number.
+    InvalidVersion,
+    /// The configuration was stored by a newer version of livesplit-core that
+    /// uses a schema that is not known yet.
+    UnsupportedVersion {
+        /// The version of the schema of the configuration.
+        version: u64,
+    },
+}
+
+/// The Result type for loading and saving a [`Config`].
+pub type Result<T = (), E = Error> = core::result::Result<T, E>;
+
+/// The configuration of an application using livesplit-core.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// The hotkey bindings.
+    pub hotkeys: HotkeyConfig,
+    /// The options for automatically saving the splits.
+    pub autosave: Autosave,
+    /// The port of the server that allows controlling the timer remotely.
+    /// `None` if the server is disabled.
+    pub server_port: Option<u16>,
+    /// The recently opened files.
+    pub recent_files: RecentFiles,
+}
+
+/// The options for automatically saving the splits.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Autosave {
+    /// Specifies whether the splits are saved whenever an attempt is reset.
+    pub on_reset: bool,
+    /// The interval in seconds in which splits with unsaved changes are saved.
+    /// `None` if the splits are not saved periodically.
+    pub interval_seconds: Option<u64>,
+}
+
+/// The recently opened files, ordered from the most recently opened file to
+/// the least recently opened file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecentFiles {
+    /// The recently opened splits files.
+    pub splits: Vec<PathBuf>,
+    /// The recently opened layout files.
+    pub layouts: Vec<PathBuf>,
+}
+
+impl RecentFiles {
+    /// The maximum amount of files remembered for each kind of file.
+    pub const MAX_LEN: usize = 10;
+
+    /// Remembers the splits file at the path provided as the most recently
+    /// opened one.
+    pub fn add_splits(&mut self, path: impl Into<PathBuf>) {
+        push_recent(&mut self.splits, path.into());
+    }
+
+    /// Remembers the layout file at the path provided as the most recently
+    /// opened one.
+    pub fn add_layout(&mut self, path: impl Into<PathBuf>) {
+        push_recent(&mut self.layouts, path.into());
+    }
+}
+
+fn push_recent(files: &mut Vec<PathBuf>, path: PathBuf) {
+    files.retain(|p| *p != path);
+    files.insert(0, path);
+    files.truncate(RecentFiles::MAX_LEN);
+}
+
+#[derive(Serialize)]
+struct Versioned<'a> {
+    version: u64,
+    #[serde(flatten)]
+    config: &'a Config,
+}
+
+impl Config {
+    /// Loads the configuration from the file at the path provided. If there's
+    /// no such file, the default configuration is returned.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read(path) {
+            Ok(source) => Self::from_json(&*source),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).context(ReadFile),
+        }
+    }
+
+    /// Saves the configuration to the file at the path provided.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut buf = Vec::new();
+        self.write_json(&mut buf)?;
+        fs::write(path, buf).context(WriteFile)
+    }
+
+    /// Decodes the configuration from JSON, migrating it to the current
+    /// version of the schema if necessary.
+    pub fn from_json<R>(reader: R) -> Result<Self>
+    where
+        R: io::Read,
+    {
+        let mut value: Value = serde_json::from_reader(reader).context(Json)?;
+        migrate(&mut value)?;
+        serde_json::from_value(value).context(Json)
+    }
+
+    /// Encodes the configuration as JSON, along with the current version of
+    /// the schema.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: io::Write,
+    {
+        serde_json::to_writer_pretty(
+            writer,
+            &Versioned {
+                version: VERSION,
+                config: self,
+            },
+        )
+        .context(Json)
+    }
+}
+
+fn migrate(value: &mut Value) -> Result<()> {
+    let version = match value.get("version") {
+        Some(version) => version.as_u64().context(InvalidVersion)?,
+        None => 0,
+    };
+
+    if version > VERSION {
+        return UnsupportedVersion { version }.fail();
+    }
+
+    if version == 0 {
+        // Only the hotkey configuration used to be stored.
+        let hotkeys = value.take();
+        *value = serde_json::json!({ "hotkeys": hotkeys });
+    }
+
+    Ok(())
+}
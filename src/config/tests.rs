@@ -0,0 +1,67 @@
+use super::{Config, Error, RecentFiles};
+use crate::{hotkey::KeyCode, HotkeyConfig};
+use std::path::PathBuf;
+
+#[test]
+fn roundtrips_through_json() {
+    let mut config = Config::default();
+    config.hotkeys.split = Some(KeyCode::Space.into());
+    config.autosave.on_reset = true;
+    config.autosave.interval_seconds = Some(300);
+    config.server_port = Some(16834);
+    config.recent_files.add_splits("a.lss");
+    config.recent_files.add_layout("a.ls1l");
+
+    let mut buf = Vec::new();
+    config.write_json(&mut buf).unwrap();
+    assert!(String::from_utf8_lossy(&buf).contains(r#""version": 1"#));
+
+    assert_eq!(Config::from_json(&*buf).unwrap(), config);
+}
+
+#[test]
+fn migrates_a_standalone_hotkey_config() {
+    let hotkeys = HotkeyConfig {
+        reset: Some(KeyCode::KeyR.into()),
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    hotkeys.write_json(&mut buf).unwrap();
+
+    let config = Config::from_json(&*buf).unwrap();
+    assert_eq!(config.hotkeys, hotkeys);
+    assert_eq!(config.server_port, None);
+}
+
+#[test]
+fn rejects_newer_versions() {
+    let result = Config::from_json(&br#"{ "version": 2 }"#[..]);
+    assert!(matches!(
+        result,
+        Err(Error::UnsupportedVersion { version: 2 })
+    ));
+
+    let result = Config::from_json(&br#"{ "version": "1" }"#[..]);
+    assert!(matches!(result, Err(Error::InvalidVersion)));
+}
+
+#[test]
+fn recent_files_are_deduplicated_and_limited() {
+    let mut recent_files = RecentFiles::default();
+    for i in 0..12 {
+        recent_files.add_splits(format!("{i}.lss"));
+    }
+    recent_files.add_splits("5.lss");
+
+    assert_eq!(recent_files.splits.len(), RecentFiles::MAX_LEN);
+    assert_eq!(recent_files.splits[0], PathBuf::from("5.lss"));
+    assert_eq!(recent_files.splits[1], PathBuf::from("11.lss"));
+    assert_eq!(
+        recent_files
+            .splits
+            .iter()
+            .filter(|p| p.as_os_str() == "5.lss")
+            .count(),
+        1,
+    );
+}
@@ -13,7 +13,7 @@ use core::{future::Future, ops::Deref};
 
 use alloc::sync::Arc;
 
-use crate::{TimeSpan, Timer, TimingMethod};
+use crate::{TimeSpan, Timer, TimerPhase, TimingMethod};
 
 /// An event informs you about a change in the timer.
 #[derive(
@@ -59,6 +59,22 @@ pub enum Event {
     LoadingTimesSet = 16,
     /// A custom variable has been set.
     CustomVariableSet = 17,
+    /// The visible page of the layout has been changed.
+    LayoutPageChanged = 18,
+    /// A checkpoint within the current segment has been reached.
+    CheckpointReached = 19,
+    /// The offset of the run has been changed.
+    OffsetChanged = 20,
+    /// The split time of the most recent split has been corrected.
+    SplitTimeSet = 21,
+    /// The last of the practiced segments has been split, so a new practice
+    /// attempt has been started.
+    PracticeLoopCompleted = 22,
+    /// The Current Pace components of the layout have been toggled between
+    /// showing the predicted final time and the predicted wall clock time.
+    WallClockToggled = 23,
+    /// The timer has been scheduled to start automatically.
+    StartScheduled = 24,
 }
 
 impl TryFrom<u32> for Event {
@@ -84,6 +100,13 @@ impl TryFrom<u32> for Event {
             15 => Event::GameTimeResumed,
             16 => Event::LoadingTimesSet,
             17 => Event::CustomVariableSet,
+            18 => Event::LayoutPageChanged,
+            19 => Event::CheckpointReached,
+            20 => Event::OffsetChanged,
+            21 => Event::SplitTimeSet,
+            22 => Event::PracticeLoopCompleted,
+            23 => Event::WallClockToggled,
+            24 => Event::StartScheduled,
             _ => return Err(()),
         })
     }
@@ -138,6 +161,8 @@ pub enum Error {
     TimerPaused = 15,
     /// The runner decided to not reset the run.
     RunnerDecidedAgainstReset = 16,
+    /// There is no split whose time could be corrected.
+    NoSplitToCorrect = 17,
     /// An unknown error occurred.
     #[serde(other)]
     Unknown,
@@ -163,6 +188,7 @@ impl From<u32> for Error {
             14 => Error::CouldNotParseTime,
             15 => Error::TimerPaused,
             16 => Error::RunnerDecidedAgainstReset,
+            17 => Error::NoSplitToCorrect,
             _ => Error::Unknown,
         }
     }
@@ -272,6 +298,20 @@ pub trait CommandSink {
         name: &str,
         value: &str,
     ) -> impl Future<Output = Result> + 'static;
+    /// Switches the layout to its next page of components. The timer itself
+    /// doesn't have a layout, so this is only supported by command sinks that
+    /// also manage the layout, such as the one provided by the UI.
+    fn next_layout_page(&self) -> impl Future<Output = Result> + 'static {
+        async { Err(Error::Unsupported) }
+    }
+    /// Toggles all the Current Pace components of the layout between showing
+    /// the predicted final time and the predicted wall clock time at which the
+    /// attempt finishes. The timer itself doesn't have a layout, so this is
+    /// only supported by command sinks that also manage the layout, such as
+    /// the one provided by the UI.
+    fn toggle_wall_clock(&self) -> impl Future<Output = Result> + 'static {
+        async { Err(Error::Unsupported) }
+    }
 }
 
 /// This trait provides functionality for querying information from the timer.
@@ -285,6 +325,21 @@ pub trait TimerQuery {
     fn get_timer(&self) -> Self::Guard<'_>;
 }
 
+/// The common surface for driving a timer, which is what the auto splitting
+/// runtime and the server protocol build on. It combines the [`CommandSink`]
+/// for sending the commands with the [`TimerQuery`] for reading the state of
+/// the timer. It is implemented for everything that implements both of them,
+/// such as the [`SharedTimer`](crate::SharedTimer), so there is no need to
+/// implement it manually.
+pub trait TimerControl: CommandSink + TimerQuery {
+    /// Returns the current phase of the timer.
+    fn current_phase(&self) -> TimerPhase {
+        self.get_timer().current_phase()
+    }
+}
+
+impl<T: CommandSink + TimerQuery + ?Sized> TimerControl for T {}
+
 #[cfg(feature = "std")]
 impl CommandSink for crate::SharedTimer {
     fn start(&self) -> impl Future<Output = Result> + 'static {
@@ -499,6 +554,14 @@ impl<T: CommandSink + ?Sized> CommandSink for Arc<T> {
     ) -> impl Future<Output = Result> + 'static {
         CommandSink::set_custom_variable(&**self, name, value)
     }
+
+    fn next_layout_page(&self) -> impl Future<Output = Result> + 'static {
+        CommandSink::next_layout_page(&**self)
+    }
+
+    fn toggle_wall_clock(&self) -> impl Future<Output = Result> + 'static {
+        CommandSink::toggle_wall_clock(&**self)
+    }
 }
 
 impl<T: TimerQuery + ?Sized> TimerQuery for Arc<T> {
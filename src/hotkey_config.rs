@@ -31,6 +31,11 @@ pub struct HotkeyConfig {
     /// The key to use for toggling between the `Real Time` and `Game Time`
     /// timing methods.
     pub toggle_timing_method: Option<Hotkey>,
+    /// The key to use for switching the layout to its next page of components.
+    pub next_layout_page: Option<Hotkey>,
+    /// The key to use for toggling the Current Pace components between showing
+    /// the predicted final time and the predicted wall clock time.
+    pub toggle_wall_clock: Option<Hotkey>,
 }
 
 impl Default for HotkeyConfig {
@@ -46,6 +51,8 @@ impl Default for HotkeyConfig {
             previous_comparison: Some(Numpad4.into()),
             next_comparison: Some(Numpad6.into()),
             toggle_timing_method: None,
+            next_layout_page: None,
+            toggle_wall_clock: None,
         }
     }
 }
@@ -100,6 +107,16 @@ impl HotkeyConfig {
                 r#"The hotkey to use for toggling between the "Real Time" and "Game Time" timing methods."#.into(),
                 self.toggle_timing_method.into(),
             ),
+            Field::new(
+                "Next Layout Page".into(),
+                "The hotkey to use for switching the layout to its next page of components.".into(),
+                self.next_layout_page.into(),
+            ),
+            Field::new(
+                "Toggle Wall Clock".into(),
+                "The hotkey to use for toggling the Current Pace components between showing the predicted final time and the predicted wall clock time at which the attempt finishes.".into(),
+                self.toggle_wall_clock.into(),
+            ),
         ])
     }
 
@@ -129,6 +146,8 @@ impl HotkeyConfig {
                 self.previous_comparison,
                 self.next_comparison,
                 self.toggle_timing_method,
+                self.next_layout_page,
+                self.toggle_wall_clock,
             ]
             .into_iter()
             .enumerate()
@@ -150,6 +169,8 @@ impl HotkeyConfig {
             6 => self.previous_comparison = value,
             7 => self.next_comparison = value,
             8 => self.toggle_timing_method = value,
+            9 => self.next_layout_page = value,
+            10 => self.toggle_wall_clock = value,
             _ => panic!("Unsupported Setting Index"),
         }
 
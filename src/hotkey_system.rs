@@ -30,6 +30,12 @@ enum Action {
     /// The key to use for toggling between the `Real Time` and `Game Time`
     /// timing methods.
     ToggleTimingMethod,
+    /// The key to use for switching the layout to its next page of
+    /// components.
+    NextLayoutPage,
+    /// The key to use for toggling the Current Pace components between showing
+    /// the predicted final time and the predicted wall clock time.
+    ToggleWallClock,
 }
 
 impl Action {
@@ -44,6 +50,8 @@ impl Action {
             Action::PreviousComparison => config.previous_comparison = hotkey,
             Action::NextComparison => config.next_comparison = hotkey,
             Action::ToggleTimingMethod => config.toggle_timing_method = hotkey,
+            Action::NextLayoutPage => config.next_layout_page = hotkey,
+            Action::ToggleWallClock => config.toggle_wall_clock = hotkey,
         }
     }
 
@@ -58,6 +66,8 @@ impl Action {
             Action::PreviousComparison => config.previous_comparison,
             Action::NextComparison => config.next_comparison,
             Action::ToggleTimingMethod => config.toggle_timing_method,
+            Action::NextLayoutPage => config.next_layout_page,
+            Action::ToggleWallClock => config.toggle_wall_clock,
         }
     }
 
@@ -93,6 +103,12 @@ impl Action {
             Action::ToggleTimingMethod => Box::new(move || {
                 drop(command_sink.toggle_timing_method());
             }),
+            Action::NextLayoutPage => Box::new(move || {
+                drop(command_sink.next_layout_page());
+            }),
+            Action::ToggleWallClock => Box::new(move || {
+                drop(command_sink.toggle_wall_clock());
+            }),
         }
     }
 }
@@ -220,6 +236,18 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
         self.set_hotkey(Action::ToggleTimingMethod, hotkey)
     }
 
+    /// Sets the key to use for switching the layout to its next page of
+    /// components.
+    pub fn set_next_layout_page(&mut self, hotkey: Option<Hotkey>) -> Result<()> {
+        self.set_hotkey(Action::NextLayoutPage, hotkey)
+    }
+
+    /// Sets the key to use for toggling the Current Pace components between
+    /// showing the predicted final time and the predicted wall clock time.
+    pub fn set_toggle_wall_clock(&mut self, hotkey: Option<Hotkey>) -> Result<()> {
+        self.set_hotkey(Action::ToggleWallClock, hotkey)
+    }
+
     /// Deactivates the Hotkey System. No hotkeys will go through until it gets
     /// activated again. If it's already deactivated, nothing happens.
     pub fn deactivate(&mut self) -> Result<()> {
@@ -233,6 +261,8 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
             self.unregister_inner(Action::PreviousComparison)?;
             self.unregister_inner(Action::NextComparison)?;
             self.unregister_inner(Action::ToggleTimingMethod)?;
+            self.unregister_inner(Action::NextLayoutPage)?;
+            self.unregister_inner(Action::ToggleWallClock)?;
         }
         self.is_active = false;
         Ok(())
@@ -251,6 +281,8 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
             self.register_inner(Action::PreviousComparison)?;
             self.register_inner(Action::NextComparison)?;
             self.register_inner(Action::ToggleTimingMethod)?;
+            self.register_inner(Action::NextLayoutPage)?;
+            self.register_inner(Action::ToggleWallClock)?;
         }
         self.is_active = true;
         Ok(())
@@ -280,6 +312,8 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
         self.set_next_comparison(config.next_comparison)?;
         self.set_undo_all_pauses(config.undo_all_pauses)?;
         self.set_toggle_timing_method(config.toggle_timing_method)?;
+        self.set_next_layout_page(config.next_layout_page)?;
+        self.set_toggle_wall_clock(config.toggle_wall_clock)?;
 
         Ok(())
     }
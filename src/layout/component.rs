@@ -1,9 +1,11 @@
-use super::{ComponentSettings, ComponentState, GeneralSettings};
+use super::{ComponentSettings, ComponentState, CustomComponentSettings, GeneralSettings};
 use crate::{
     component::{
-        blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        attempt_count, barrier_watch, blank_space, choke_rate, clock_drift, completion,
+        current_comparison, current_pace, delta, detailed_timer, estimate_delta, graph, pace_graph,
+        page_break, pb_chance, pb_progression, possible_time_save, previous_segment,
+        run_prediction, segment_sparkline, segment_time, separator, splits, sum_of_best, text,
+        time_remaining, timer, title, total_playtime, world_record, Component as CustomComponent,
     },
     platform::prelude::*,
     settings::{ImageCache, SettingsDescription, Value},
@@ -15,24 +17,48 @@ use alloc::borrow::Cow;
 /// visualize. This type can store any of the components provided by this crate.
 #[derive(Clone)]
 pub enum Component {
+    /// The Attempt Count Component.
+    AttemptCount(attempt_count::Component),
+    /// The Barrier Watch Component.
+    BarrierWatch(barrier_watch::Component),
     /// The Blank Space Component.
     BlankSpace(blank_space::Component),
+    /// The Choke Rate Component.
+    ChokeRate(choke_rate::Component),
+    /// The Clock Drift Component.
+    ClockDrift(clock_drift::Component),
+    /// The Completion Component.
+    Completion(completion::Component),
     /// The Current Comparison Component.
     CurrentComparison(current_comparison::Component),
     /// The Current Pace Component.
     CurrentPace(current_pace::Component),
+    /// A custom component that is not provided by this crate.
+    Custom(Box<dyn CustomComponent>),
     /// The Delta Component.
     Delta(delta::Component),
     /// The Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::Component>),
+    /// The Estimate Delta Component.
+    EstimateDelta(estimate_delta::Component),
     /// The Graph Component.
     Graph(graph::Component),
+    /// The Pace Graph Component.
+    PaceGraph(pace_graph::Component),
+    /// The Page Break Component.
+    PageBreak(page_break::Component),
     /// The PB Chance Component.
     PbChance(pb_chance::Component),
+    /// The PB Progression Component.
+    PbProgression(pb_progression::Component),
     /// The Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Component),
     /// The Previous Segment Component.
     PreviousSegment(previous_segment::Component),
+    /// The Run Prediction Component.
+    RunPrediction(run_prediction::Component),
+    /// The Segment Sparkline Component.
+    SegmentSparkline(segment_sparkline::Component),
     /// The Segment Time Component.
     SegmentTime(segment_time::Component),
     /// The Separator Component.
@@ -43,12 +69,28 @@ pub enum Component {
     SumOfBest(sum_of_best::Component),
     /// The Text Component.
     Text(text::Component),
+    /// The Time Remaining Component.
+    TimeRemaining(time_remaining::Component),
     /// The Timer Component.
     Timer(timer::Component),
     /// The Title Component.
     Title(title::Component),
     /// The Total Playtime Component.
     TotalPlaytime(total_playtime::Component),
+    /// The World Record Component.
+    WorldRecord(world_record::Component),
+}
+
+impl From<attempt_count::Component> for Component {
+    fn from(component: attempt_count::Component) -> Self {
+        Self::AttemptCount(component)
+    }
+}
+
+impl From<barrier_watch::Component> for Component {
+    fn from(component: barrier_watch::Component) -> Self {
+        Self::BarrierWatch(component)
+    }
 }
 
 impl From<blank_space::Component> for Component {
@@ -57,6 +99,24 @@ impl From<blank_space::Component> for Component {
     }
 }
 
+impl From<choke_rate::Component> for Component {
+    fn from(component: choke_rate::Component) -> Self {
+        Self::ChokeRate(component)
+    }
+}
+
+impl From<clock_drift::Component> for Component {
+    fn from(component: clock_drift::Component) -> Self {
+        Self::ClockDrift(component)
+    }
+}
+
+impl From<completion::Component> for Component {
+    fn from(component: completion::Component) -> Self {
+        Self::Completion(component)
+    }
+}
+
 impl From<current_comparison::Component> for Component {
     fn from(component: current_comparison::Component) -> Self {
         Self::CurrentComparison(component)
@@ -69,6 +129,12 @@ impl From<current_pace::Component> for Component {
     }
 }
 
+impl From<Box<dyn CustomComponent>> for Component {
+    fn from(component: Box<dyn CustomComponent>) -> Self {
+        Self::Custom(component)
+    }
+}
+
 impl From<delta::Component> for Component {
     fn from(component: delta::Component) -> Self {
         Self::Delta(component)
@@ -81,18 +147,42 @@ impl From<Box<detailed_timer::Component>> for Component {
     }
 }
 
+impl From<estimate_delta::Component> for Component {
+    fn from(component: estimate_delta::Component) -> Self {
+        Self::EstimateDelta(component)
+    }
+}
+
 impl From<graph::Component> for Component {
     fn from(component: graph::Component) -> Self {
         Self::Graph(component)
     }
 }
 
+impl From<pace_graph::Component> for Component {
+    fn from(component: pace_graph::Component) -> Self {
+        Self::PaceGraph(component)
+    }
+}
+
+impl From<page_break::Component> for Component {
+    fn from(component: page_break::Component) -> Self {
+        Self::PageBreak(component)
+    }
+}
+
 impl From<pb_chance::Component> for Component {
     fn from(component: pb_chance::Component) -> Self {
         Self::PbChance(component)
     }
 }
 
+impl From<pb_progression::Component> for Component {
+    fn from(component: pb_progression::Component) -> Self {
+        Self::PbProgression(component)
+    }
+}
+
 impl From<possible_time_save::Component> for Component {
     fn from(component: possible_time_save::Component) -> Self {
         Self::PossibleTimeSave(component)
@@ -105,6 +195,18 @@ impl From<previous_segment::Component> for Component {
     }
 }
 
+impl From<run_prediction::Component> for Component {
+    fn from(component: run_prediction::Component) -> Self {
+        Self::RunPrediction(component)
+    }
+}
+
+impl From<segment_sparkline::Component> for Component {
+    fn from(component: segment_sparkline::Component) -> Self {
+        Self::SegmentSparkline(component)
+    }
+}
+
 impl From<segment_time::Component> for Component {
     fn from(component: segment_time::Component) -> Self {
         Self::SegmentTime(component)
@@ -135,6 +237,12 @@ impl From<text::Component> for Component {
     }
 }
 
+impl From<time_remaining::Component> for Component {
+    fn from(component: time_remaining::Component) -> Self {
+        Self::TimeRemaining(component)
+    }
+}
+
 impl From<timer::Component> for Component {
     fn from(component: timer::Component) -> Self {
         Self::Timer(component)
@@ -153,6 +261,12 @@ impl From<total_playtime::Component> for Component {
     }
 }
 
+impl From<world_record::Component> for Component {
+    fn from(component: world_record::Component) -> Self {
+        Self::WorldRecord(component)
+    }
+}
+
 impl Component {
     /// Updates the component's state based on the timer and settings provided.
     /// The timer provides the information to visualize and the layout settings
@@ -169,14 +283,32 @@ impl Component {
         layout_settings: &GeneralSettings,
     ) {
         match (state, self) {
+            (ComponentState::KeyValue(state), Component::AttemptCount(component)) => {
+                component.update_state(state, timer)
+            }
+            (ComponentState::KeyValue(state), Component::BarrierWatch(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::BlankSpace(state), Component::BlankSpace(component)) => {
                 component.update_state(state)
             }
+            (ComponentState::KeyValue(state), Component::ChokeRate(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
+            (ComponentState::KeyValue(state), Component::ClockDrift(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
+            (ComponentState::KeyValue(state), Component::Completion(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::KeyValue(state), Component::CurrentComparison(component)) => {
                 component.update_state(state, timer)
             }
             (ComponentState::KeyValue(state), Component::CurrentPace(component)) => {
-                component.update_state(state, timer)
+                component.update_state(state, timer, layout_settings)
+            }
+            (state, Component::Custom(component)) => {
+                component.update_state(state, image_cache, timer, layout_settings)
             }
             (ComponentState::KeyValue(state), Component::Delta(component)) => {
                 component.update_state(state, timer, layout_settings)
@@ -184,20 +316,36 @@ impl Component {
             (ComponentState::DetailedTimer(state), Component::DetailedTimer(component)) => {
                 component.update_state(state, image_cache, timer, layout_settings)
             }
+            (ComponentState::KeyValue(state), Component::EstimateDelta(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::Graph(state), Component::Graph(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
+            (ComponentState::PaceGraph(state), Component::PaceGraph(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
+            (ComponentState::Separator(_), Component::PageBreak(_)) => {}
             (ComponentState::KeyValue(state), Component::PbChance(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::PbProgression(state), Component::PbProgression(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::KeyValue(state), Component::PossibleTimeSave(component)) => {
-                component.update_state(state, timer)
+                component.update_state(state, timer, layout_settings)
             }
             (ComponentState::KeyValue(state), Component::PreviousSegment(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
+            (ComponentState::RunPrediction(state), Component::RunPrediction(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
+            (ComponentState::SegmentSparkline(state), Component::SegmentSparkline(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::KeyValue(state), Component::SegmentTime(component)) => {
-                component.update_state(state, timer)
+                component.update_state(state, timer, layout_settings)
             }
             (ComponentState::Separator(state), Component::Separator(component)) => {
                 component.update_state(state)
@@ -206,10 +354,13 @@ impl Component {
                 component.update_state(state, image_cache, timer, layout_settings)
             }
             (ComponentState::KeyValue(state), Component::SumOfBest(component)) => {
-                component.update_state(state, timer)
+                component.update_state(state, timer, layout_settings)
             }
             (ComponentState::Text(state), Component::Text(component)) => {
-                component.update_state(state, timer)
+                component.update_state(state, timer, layout_settings)
+            }
+            (ComponentState::KeyValue(state), Component::TimeRemaining(component)) => {
+                component.update_state(state, timer, layout_settings)
             }
             (ComponentState::Timer(state), Component::Timer(component)) => {
                 component.update_state(state, timer, layout_settings)
@@ -220,6 +371,9 @@ impl Component {
             (ComponentState::KeyValue(state), Component::TotalPlaytime(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::WorldRecord(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (state, component) => *state = component.state(image_cache, timer, layout_settings),
         }
     }
@@ -231,7 +385,8 @@ impl Component {
     /// images that are part of the state. The images are marked as visited in
     /// the [`ImageCache`]. You still need to manually run
     /// [`ImageCache::collect`] to ensure unused images are removed from the
-    /// cache.
+    /// cache. A Page Break Component is never part of a layout's state, so it
+    /// is visualized as a separator if its state is queried directly.
     pub fn state(
         &mut self,
         image_cache: &mut ImageCache,
@@ -239,34 +394,75 @@ impl Component {
         layout_settings: &GeneralSettings,
     ) -> ComponentState {
         match self {
+            Component::AttemptCount(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::BarrierWatch(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
             Component::BlankSpace(component) => ComponentState::BlankSpace(component.state()),
+            Component::ChokeRate(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
+            Component::ClockDrift(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
+            Component::Completion(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
             Component::CurrentComparison(component) => {
                 ComponentState::KeyValue(component.state(timer))
             }
-            Component::CurrentPace(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::CurrentPace(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
+            Component::Custom(component) => component.state(image_cache, timer, layout_settings),
             Component::Delta(component) => {
                 ComponentState::KeyValue(component.state(timer, layout_settings))
             }
             Component::DetailedTimer(component) => ComponentState::DetailedTimer(Box::new(
                 component.state(image_cache, timer, layout_settings),
             )),
+            Component::EstimateDelta(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
             Component::Graph(component) => {
                 ComponentState::Graph(component.state(timer, layout_settings))
             }
+            Component::PaceGraph(component) => {
+                ComponentState::PaceGraph(component.state(timer, layout_settings))
+            }
+            Component::PageBreak(_) => ComponentState::Separator(separator::State),
             Component::PbChance(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::PbProgression(component) => {
+                ComponentState::PbProgression(component.state(timer, layout_settings))
+            }
             Component::PossibleTimeSave(component) => {
-                ComponentState::KeyValue(component.state(timer))
+                ComponentState::KeyValue(component.state(timer, layout_settings))
             }
             Component::PreviousSegment(component) => {
                 ComponentState::KeyValue(component.state(timer, layout_settings))
             }
-            Component::SegmentTime(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::RunPrediction(component) => {
+                ComponentState::RunPrediction(component.state(timer, layout_settings))
+            }
+            Component::SegmentSparkline(component) => {
+                ComponentState::SegmentSparkline(component.state(timer, layout_settings))
+            }
+            Component::SegmentTime(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
             Component::Separator(component) => ComponentState::Separator(component.state()),
             Component::Splits(component) => {
                 ComponentState::Splits(component.state(image_cache, timer, layout_settings))
             }
-            Component::SumOfBest(component) => ComponentState::KeyValue(component.state(timer)),
-            Component::Text(component) => ComponentState::Text(component.state(timer)),
+            Component::SumOfBest(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
+            Component::Text(component) => {
+                ComponentState::Text(component.state(timer, layout_settings))
+            }
+            Component::TimeRemaining(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
             Component::Timer(component) => {
                 ComponentState::Timer(component.state(timer, layout_settings))
             }
@@ -274,6 +470,9 @@ impl Component {
                 ComponentState::Title(component.state(image_cache, timer))
             }
             Component::TotalPlaytime(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::WorldRecord(component) => {
+                ComponentState::KeyValue(component.state(timer, layout_settings))
+            }
         }
     }
 
@@ -283,29 +482,64 @@ impl Component {
     /// Settings Description instead.
     pub fn settings(&self) -> ComponentSettings {
         match self {
+            Component::AttemptCount(component) => {
+                ComponentSettings::AttemptCount(component.settings().clone())
+            }
+            Component::BarrierWatch(component) => {
+                ComponentSettings::BarrierWatch(component.settings().clone())
+            }
             Component::BlankSpace(component) => {
                 ComponentSettings::BlankSpace(component.settings().clone())
             }
+            Component::ChokeRate(component) => {
+                ComponentSettings::ChokeRate(component.settings().clone())
+            }
+            Component::ClockDrift(component) => {
+                ComponentSettings::ClockDrift(component.settings().clone())
+            }
+            Component::Completion(component) => {
+                ComponentSettings::Completion(component.settings().clone())
+            }
             Component::CurrentComparison(component) => {
                 ComponentSettings::CurrentComparison(component.settings().clone())
             }
             Component::CurrentPace(component) => {
                 ComponentSettings::CurrentPace(component.settings().clone())
             }
+            Component::Custom(component) => ComponentSettings::Custom(CustomComponentSettings {
+                id: component.id().into(),
+                settings: component.settings(),
+            }),
             Component::Delta(component) => ComponentSettings::Delta(component.settings().clone()),
             Component::DetailedTimer(component) => {
                 ComponentSettings::DetailedTimer(Box::new(component.settings().clone()))
             }
+            Component::EstimateDelta(component) => {
+                ComponentSettings::EstimateDelta(component.settings().clone())
+            }
             Component::Graph(component) => ComponentSettings::Graph(component.settings().clone()),
+            Component::PaceGraph(component) => {
+                ComponentSettings::PaceGraph(component.settings().clone())
+            }
+            Component::PageBreak(_) => ComponentSettings::PageBreak,
             Component::PbChance(component) => {
                 ComponentSettings::PbChance(component.settings().clone())
             }
+            Component::PbProgression(component) => {
+                ComponentSettings::PbProgression(component.settings().clone())
+            }
             Component::PossibleTimeSave(component) => {
                 ComponentSettings::PossibleTimeSave(component.settings().clone())
             }
             Component::PreviousSegment(component) => {
                 ComponentSettings::PreviousSegment(component.settings().clone())
             }
+            Component::RunPrediction(component) => {
+                ComponentSettings::RunPrediction(component.settings().clone())
+            }
+            Component::SegmentSparkline(component) => {
+                ComponentSettings::SegmentSparkline(component.settings().clone())
+            }
             Component::SegmentTime(component) => {
                 ComponentSettings::SegmentTime(component.settings().clone())
             }
@@ -315,50 +549,112 @@ impl Component {
                 ComponentSettings::SumOfBest(component.settings().clone())
             }
             Component::Text(component) => ComponentSettings::Text(component.settings().clone()),
+            Component::TimeRemaining(component) => {
+                ComponentSettings::TimeRemaining(component.settings().clone())
+            }
             Component::Timer(component) => ComponentSettings::Timer(component.settings().clone()),
             Component::Title(component) => ComponentSettings::Title(component.settings().clone()),
             Component::TotalPlaytime(component) => {
                 ComponentSettings::TotalPlaytime(component.settings().clone())
             }
+            Component::WorldRecord(component) => {
+                ComponentSettings::WorldRecord(component.settings().clone())
+            }
+        }
+    }
+
+    /// Accesses the ID of the component. The components provided by this crate
+    /// use the names of the variants of [`ComponentSettings`] as their IDs.
+    pub fn id(&self) -> &str {
+        match self {
+            Component::AttemptCount(_) => "AttemptCount",
+            Component::BarrierWatch(_) => "BarrierWatch",
+            Component::BlankSpace(_) => "BlankSpace",
+            Component::ChokeRate(_) => "ChokeRate",
+            Component::ClockDrift(_) => "ClockDrift",
+            Component::Completion(_) => "Completion",
+            Component::CurrentComparison(_) => "CurrentComparison",
+            Component::CurrentPace(_) => "CurrentPace",
+            Component::Custom(component) => component.id(),
+            Component::Delta(_) => "Delta",
+            Component::DetailedTimer(_) => "DetailedTimer",
+            Component::EstimateDelta(_) => "EstimateDelta",
+            Component::Graph(_) => "Graph",
+            Component::PaceGraph(_) => "PaceGraph",
+            Component::PageBreak(_) => "PageBreak",
+            Component::PbChance(_) => "PbChance",
+            Component::PbProgression(_) => "PbProgression",
+            Component::PossibleTimeSave(_) => "PossibleTimeSave",
+            Component::PreviousSegment(_) => "PreviousSegment",
+            Component::RunPrediction(_) => "RunPrediction",
+            Component::SegmentSparkline(_) => "SegmentSparkline",
+            Component::SegmentTime(_) => "SegmentTime",
+            Component::Separator(_) => "Separator",
+            Component::Splits(_) => "Splits",
+            Component::SumOfBest(_) => "SumOfBest",
+            Component::Text(_) => "Text",
+            Component::TimeRemaining(_) => "TimeRemaining",
+            Component::Timer(_) => "Timer",
+            Component::Title(_) => "Title",
+            Component::TotalPlaytime(_) => "TotalPlaytime",
+            Component::WorldRecord(_) => "WorldRecord",
         }
     }
 
     /// Accesses the name of the component.
     pub fn name(&self) -> Cow<'_, str> {
         match self {
+            Component::AttemptCount(component) => component.name().into(),
+            Component::BarrierWatch(component) => component.name(),
             Component::BlankSpace(component) => component.name().into(),
+            Component::ChokeRate(component) => component.name().into(),
+            Component::ClockDrift(component) => component.name().into(),
+            Component::Completion(component) => component.name().into(),
             Component::CurrentComparison(component) => component.name().into(),
             Component::CurrentPace(component) => component.name(),
+            Component::Custom(component) => component.name(),
             Component::Delta(component) => component.name(),
             Component::DetailedTimer(component) => component.name().into(),
+            Component::EstimateDelta(component) => component.name(),
             Component::Graph(component) => component.name(),
+            Component::PaceGraph(component) => component.name().into(),
+            Component::PageBreak(component) => component.name().into(),
             Component::PbChance(component) => component.name().into(),
+            Component::PbProgression(component) => component.name().into(),
             Component::PossibleTimeSave(component) => component.name(),
             Component::PreviousSegment(component) => component.name(),
+            Component::RunPrediction(component) => component.name().into(),
+            Component::SegmentSparkline(component) => component.name().into(),
             Component::SegmentTime(component) => component.name(),
             Component::Separator(component) => component.name().into(),
             Component::Splits(component) => component.name().into(),
             Component::SumOfBest(component) => component.name().into(),
             Component::Text(component) => component.name(),
+            Component::TimeRemaining(component) => component.name(),
             Component::Timer(component) => component.name().into(),
             Component::Title(component) => component.name().into(),
             Component::TotalPlaytime(component) => component.name().into(),
+            Component::WorldRecord(component) => component.name(),
         }
     }
 
     /// Tells the component to scroll up. This may be interpreted differently
     /// based on the kind of component. Most components will ignore this.
     pub fn scroll_up(&mut self) {
-        if let Component::Splits(component) = self {
-            component.scroll_up();
+        match self {
+            Component::Splits(component) => component.scroll_up(),
+            Component::Custom(component) => component.scroll_up(),
+            _ => {}
         }
     }
 
     /// Tells the component to scroll down. This may be interpreted differently
     /// based on the kind of component. Most components will ignore this.
     pub fn scroll_down(&mut self) {
-        if let Component::Splits(component) = self {
-            component.scroll_down();
+        match self {
+            Component::Splits(component) => component.scroll_down(),
+            Component::Custom(component) => component.scroll_down(),
+            _ => {}
         }
     }
 
@@ -368,23 +664,37 @@ impl Component {
     /// interface independent way of changing the settings.
     pub fn settings_description(&self) -> SettingsDescription {
         match self {
+            Component::AttemptCount(component) => component.settings_description(),
+            Component::BarrierWatch(component) => component.settings_description(),
             Component::BlankSpace(component) => component.settings_description(),
+            Component::ChokeRate(component) => component.settings_description(),
+            Component::ClockDrift(component) => component.settings_description(),
+            Component::Completion(component) => component.settings_description(),
             Component::CurrentComparison(component) => component.settings_description(),
             Component::CurrentPace(component) => component.settings_description(),
+            Component::Custom(component) => component.settings_description(),
             Component::Delta(component) => component.settings_description(),
             Component::DetailedTimer(component) => component.settings_description(),
+            Component::EstimateDelta(component) => component.settings_description(),
             Component::Graph(component) => component.settings_description(),
+            Component::PaceGraph(component) => component.settings_description(),
+            Component::PageBreak(component) => component.settings_description(),
             Component::PbChance(component) => component.settings_description(),
+            Component::PbProgression(component) => component.settings_description(),
             Component::PossibleTimeSave(component) => component.settings_description(),
             Component::PreviousSegment(component) => component.settings_description(),
+            Component::RunPrediction(component) => component.settings_description(),
+            Component::SegmentSparkline(component) => component.settings_description(),
             Component::SegmentTime(component) => component.settings_description(),
             Component::Separator(component) => component.settings_description(),
             Component::Splits(component) => component.settings_description(),
             Component::SumOfBest(component) => component.settings_description(),
             Component::Text(component) => component.settings_description(),
+            Component::TimeRemaining(component) => component.settings_description(),
             Component::Timer(component) => component.settings_description(),
             Component::Title(component) => component.settings_description(),
             Component::TotalPlaytime(component) => component.settings_description(),
+            Component::WorldRecord(component) => component.settings_description(),
         }
     }
 
@@ -398,23 +708,37 @@ impl Component {
     /// have a compatible type.
     pub fn set_value(&mut self, index: usize, value: Value) {
         match self {
+            Component::AttemptCount(component) => component.set_value(index, value),
+            Component::BarrierWatch(component) => component.set_value(index, value),
             Component::BlankSpace(component) => component.set_value(index, value),
+            Component::ChokeRate(component) => component.set_value(index, value),
+            Component::ClockDrift(component) => component.set_value(index, value),
+            Component::Completion(component) => component.set_value(index, value),
             Component::CurrentComparison(component) => component.set_value(index, value),
             Component::CurrentPace(component) => component.set_value(index, value),
+            Component::Custom(component) => component.set_value(index, value),
             Component::Delta(component) => component.set_value(index, value),
             Component::DetailedTimer(component) => component.set_value(index, value),
+            Component::EstimateDelta(component) => component.set_value(index, value),
             Component::Graph(component) => component.set_value(index, value),
+            Component::PaceGraph(component) => component.set_value(index, value),
+            Component::PageBreak(component) => component.set_value(index, value),
             Component::PbChance(component) => component.set_value(index, value),
+            Component::PbProgression(component) => component.set_value(index, value),
             Component::PossibleTimeSave(component) => component.set_value(index, value),
             Component::PreviousSegment(component) => component.set_value(index, value),
+            Component::RunPrediction(component) => component.set_value(index, value),
+            Component::SegmentSparkline(component) => component.set_value(index, value),
             Component::SegmentTime(component) => component.set_value(index, value),
             Component::Separator(component) => component.set_value(index, value),
             Component::Splits(component) => component.set_value(index, value),
             Component::SumOfBest(component) => component.set_value(index, value),
             Component::Text(component) => component.set_value(index, value),
+            Component::TimeRemaining(component) => component.set_value(index, value),
             Component::Timer(component) => component.set_value(index, value),
             Component::Title(component) => component.set_value(index, value),
             Component::TotalPlaytime(component) => component.set_value(index, value),
+            Component::WorldRecord(component) => component.set_value(index, value),
         }
     }
 }
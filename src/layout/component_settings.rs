@@ -1,9 +1,11 @@
 use super::Component;
 use crate::{
     component::{
-        blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        attempt_count, barrier_watch, blank_space, choke_rate, clock_drift, completion,
+        current_comparison, current_pace, delta, detailed_timer, estimate_delta, graph, pace_graph,
+        page_break, pb_chance, pb_progression, possible_time_save, previous_segment,
+        run_prediction, segment_sparkline, segment_time, separator, splits, sum_of_best, text,
+        time_remaining, timer, title, total_playtime, world_record,
     },
     platform::prelude::*,
 };
@@ -12,24 +14,48 @@ use serde_derive::{Deserialize, Serialize};
 /// The settings for one of the components available.
 #[derive(Clone, Serialize, Deserialize)]
 pub enum ComponentSettings {
+    /// The Settings for the Attempt Count Component.
+    AttemptCount(attempt_count::Settings),
+    /// The Settings for the Barrier Watch Component.
+    BarrierWatch(barrier_watch::Settings),
     /// The Settings for the Blank Space Component.
     BlankSpace(blank_space::Settings),
+    /// The Settings for the Choke Rate Component.
+    ChokeRate(choke_rate::Settings),
+    /// The Settings for the Clock Drift Component.
+    ClockDrift(clock_drift::Settings),
+    /// The Settings for the Completion Component.
+    Completion(completion::Settings),
     /// The Settings for the Current Comparison Component.
     CurrentComparison(current_comparison::Settings),
     /// The Settings for the Current Pace Component.
     CurrentPace(current_pace::Settings),
+    /// The Settings for a custom component.
+    Custom(CustomComponentSettings),
     /// The Settings for the Delta Component.
     Delta(delta::Settings),
     /// The Settings for the Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::Settings>),
+    /// The Settings for the Estimate Delta Component.
+    EstimateDelta(estimate_delta::Settings),
     /// The Settings for the Graph Component.
     Graph(graph::Settings),
+    /// The Settings for the Pace Graph Component.
+    PaceGraph(pace_graph::Settings),
+    /// The Settings for the Page Break Component.
+    PageBreak,
     /// The Settings for the PB Chance Component.
     PbChance(pb_chance::Settings),
+    /// The Settings for the PB Progression Component.
+    PbProgression(pb_progression::Settings),
     /// The Settings for the Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Settings),
     /// The Settings for the Previous Segment Component.
     PreviousSegment(previous_segment::Settings),
+    /// The Settings for the Run Prediction Component.
+    RunPrediction(run_prediction::Settings),
+    /// The Settings for the Segment Sparkline Component.
+    SegmentSparkline(segment_sparkline::Settings),
     /// The Settings for the Segment Time Component.
     SegmentTime(segment_time::Settings),
     /// The Settings for the Separator Component.
@@ -40,44 +66,91 @@ pub enum ComponentSettings {
     SumOfBest(sum_of_best::Settings),
     /// The Settings for the Text Component.
     Text(text::Settings),
+    /// The Settings for the Time Remaining Component.
+    TimeRemaining(time_remaining::Settings),
     /// The Settings for the Timer Component.
     Timer(timer::Settings),
     /// The Settings for the Title Component.
     Title(title::Settings),
     /// The Settings for the Total Playtime Component.
     TotalPlaytime(total_playtime::Settings),
+    /// The Settings for the World Record Component.
+    WorldRecord(world_record::Settings),
+}
+
+/// The settings of a custom [`Component`](crate::component::Component).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CustomComponentSettings {
+    /// The ID of the custom component.
+    pub id: String,
+    /// The settings of the custom component in their serialized form.
+    pub settings: serde_json::Value,
 }
 
 impl From<ComponentSettings> for Component {
     fn from(settings: ComponentSettings) -> Self {
         match settings {
+            ComponentSettings::AttemptCount(settings) => {
+                Component::AttemptCount(attempt_count::Component::with_settings(settings))
+            }
+            ComponentSettings::BarrierWatch(settings) => {
+                Component::BarrierWatch(barrier_watch::Component::with_settings(settings))
+            }
             ComponentSettings::BlankSpace(settings) => {
                 Component::BlankSpace(blank_space::Component::with_settings(settings))
             }
+            ComponentSettings::ChokeRate(settings) => {
+                Component::ChokeRate(choke_rate::Component::with_settings(settings))
+            }
+            ComponentSettings::ClockDrift(settings) => {
+                Component::ClockDrift(clock_drift::Component::with_settings(settings))
+            }
+            ComponentSettings::Completion(settings) => {
+                Component::Completion(completion::Component::with_settings(settings))
+            }
             ComponentSettings::CurrentComparison(settings) => {
                 Component::CurrentComparison(current_comparison::Component::with_settings(settings))
             }
             ComponentSettings::CurrentPace(settings) => {
                 Component::CurrentPace(current_pace::Component::with_settings(settings))
             }
+            ComponentSettings::Custom(settings) => {
+                Component::Custom(crate::component::unregistered(settings))
+            }
             ComponentSettings::Delta(settings) => {
                 Component::Delta(delta::Component::with_settings(settings))
             }
             ComponentSettings::DetailedTimer(settings) => Component::DetailedTimer(Box::new(
                 detailed_timer::Component::with_settings(*settings),
             )),
+            ComponentSettings::EstimateDelta(settings) => {
+                Component::EstimateDelta(estimate_delta::Component::with_settings(settings))
+            }
             ComponentSettings::Graph(settings) => {
                 Component::Graph(graph::Component::with_settings(settings))
             }
+            ComponentSettings::PaceGraph(settings) => {
+                Component::PaceGraph(pace_graph::Component::with_settings(settings))
+            }
+            ComponentSettings::PageBreak => Component::PageBreak(page_break::Component::new()),
             ComponentSettings::PbChance(settings) => {
                 Component::PbChance(pb_chance::Component::with_settings(settings))
             }
+            ComponentSettings::PbProgression(settings) => {
+                Component::PbProgression(pb_progression::Component::with_settings(settings))
+            }
             ComponentSettings::PossibleTimeSave(settings) => {
                 Component::PossibleTimeSave(possible_time_save::Component::with_settings(settings))
             }
             ComponentSettings::PreviousSegment(settings) => {
                 Component::PreviousSegment(previous_segment::Component::with_settings(settings))
             }
+            ComponentSettings::RunPrediction(settings) => {
+                Component::RunPrediction(run_prediction::Component::with_settings(settings))
+            }
+            ComponentSettings::SegmentSparkline(settings) => {
+                Component::SegmentSparkline(segment_sparkline::Component::with_settings(settings))
+            }
             ComponentSettings::SegmentTime(settings) => {
                 Component::SegmentTime(segment_time::Component::with_settings(settings))
             }
@@ -91,6 +164,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::Text(settings) => {
                 Component::Text(text::Component::with_settings(settings))
             }
+            ComponentSettings::TimeRemaining(settings) => {
+                Component::TimeRemaining(time_remaining::Component::with_settings(settings))
+            }
             ComponentSettings::Timer(settings) => {
                 Component::Timer(timer::Component::with_settings(settings))
             }
@@ -100,6 +176,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::TotalPlaytime(settings) => {
                 Component::TotalPlaytime(total_playtime::Component::with_settings(settings))
             }
+            ComponentSettings::WorldRecord(settings) => {
+                Component::WorldRecord(world_record::Component::with_settings(settings))
+            }
         }
     }
 }
@@ -1,8 +1,10 @@
 use serde_derive::{Deserialize, Serialize};
 
+use super::UpdateHint;
 use crate::{
     component::{
-        blank_space, detailed_timer, graph, key_value, separator, splits, text, timer, title,
+        blank_space, detailed_timer, graph, key_value, pace_graph, pb_progression, run_prediction,
+        segment_sparkline, separator, splits, text, timer, title,
     },
     platform::prelude::*,
 };
@@ -18,6 +20,14 @@ pub enum ComponentState {
     Graph(graph::State),
     /// The state object for a key value based component.
     KeyValue(key_value::State),
+    /// The state object for the Pace Graph Component.
+    PaceGraph(pace_graph::State),
+    /// The state object for the PB Progression Component.
+    PbProgression(pb_progression::State),
+    /// The state object for the Run Prediction Component.
+    RunPrediction(run_prediction::State),
+    /// The state object for the Segment Sparkline Component.
+    SegmentSparkline(segment_sparkline::State),
     /// The state object for the Separator Component.
     Separator(separator::State),
     /// The state object for the Splits Component.
@@ -29,3 +39,48 @@ pub enum ComponentState {
     /// The state object for the Title Component.
     Title(title::State),
 }
+
+impl ComponentState {
+    /// Determines how often the state is expected to change, based on the
+    /// parts of the state that are frequently being updated.
+    pub fn update_hint(&self) -> UpdateHint {
+        match self {
+            ComponentState::BlankSpace(_) | ComponentState::Separator(_) => UpdateHint::Static,
+            ComponentState::DetailedTimer(state) => {
+                timer_update_hint(&state.timer).max(timer_update_hint(&state.segment_timer))
+            }
+            ComponentState::Graph(state) => {
+                UpdateHint::from_updates_frequently(state.updates_frequently)
+            }
+            ComponentState::KeyValue(state) => {
+                UpdateHint::from_updates_frequently(state.updates_frequently)
+            }
+            ComponentState::RunPrediction(state) => UpdateHint::from_updates_frequently(
+                state.targets.iter().any(|target| target.updates_frequently),
+            ),
+            ComponentState::Splits(state) => UpdateHint::from_updates_frequently(
+                state
+                    .splits
+                    .iter()
+                    .flat_map(|split| split.columns.iter())
+                    .any(|column| column.updates_frequently),
+            ),
+            ComponentState::Timer(state) => timer_update_hint(state),
+            ComponentState::PaceGraph(_)
+            | ComponentState::PbProgression(_)
+            | ComponentState::SegmentSparkline(_)
+            | ComponentState::Text(_)
+            | ComponentState::Title(_) => UpdateHint::OnSplit,
+        }
+    }
+}
+
+fn timer_update_hint(state: &timer::State) -> UpdateHint {
+    if !state.updates_frequently {
+        UpdateHint::OnSplit
+    } else if state.fraction.is_empty() {
+        UpdateHint::EverySecond
+    } else {
+        UpdateHint::EveryFrame
+    }
+}
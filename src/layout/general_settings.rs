@@ -1,9 +1,12 @@
-use super::LayoutDirection;
+use super::{ComponentState, LayoutDirection};
 use crate::{
+    component::key_value,
     platform::prelude::*,
     settings::{
-        Color, Field, Font, Gradient, ImageCache, LayoutBackground, SettingsDescription, Value,
+        Color, Field, Font, Gradient, ImageCache, LayoutBackground, ListGradient,
+        SettingsDescription, Value,
     },
+    timing::formatter::{TimeFormatterRegistry, DASH},
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -50,6 +53,36 @@ pub struct GeneralSettings {
     pub separators_color: Color,
     /// The text color to use for text that doesn't specify its own color.
     pub text_color: Color,
+    /// The text that components show in place of times and other values that
+    /// are missing. This is a dash by default, but some fonts don't render it
+    /// well, so it can be changed to something like `-` or `N/A`.
+    pub placeholder: String,
+    /// Specifies whether the backgrounds of all the Splits components are
+    /// replaced by the [`splits_background`](Self::splits_background).
+    pub override_splits_background: bool,
+    /// The background shown behind the rows of all the Splits components if
+    /// [`override_splits_background`](Self::override_splits_background) is
+    /// enabled. It can alternate between two colors for each row.
+    pub splits_background: ListGradient,
+    /// Specifies whether all the components that show a key and a value are
+    /// styled uniformly, which replaces their own background and label color
+    /// with the ones configured here. The colors of their values are kept, as
+    /// they often convey information, such as whether the runner is ahead.
+    pub uniform_key_value_style: bool,
+    /// The background shown behind the components that show a key and a value
+    /// if [`uniform_key_value_style`](Self::uniform_key_value_style) is
+    /// enabled.
+    pub key_value_background: Gradient,
+    /// The color of the labels of the components that show a key and a value
+    /// if [`uniform_key_value_style`](Self::uniform_key_value_style) is
+    /// enabled. If `None` is specified, the [`text_color`](Self::text_color)
+    /// is used.
+    pub key_value_label_color: Option<Color>,
+    /// The Time Formatters that components can select by their name. The
+    /// registry is not serialized, so formatters provided by the application
+    /// need to be registered again after the layout is loaded.
+    #[serde(skip)]
+    pub time_formatters: TimeFormatterRegistry,
 }
 
 impl Default for GeneralSettings {
@@ -73,6 +106,16 @@ impl Default for GeneralSettings {
             thin_separators_color: Color::hsla(0.0, 0.0, 1.0, 0.09),
             separators_color: Color::hsla(0.0, 0.0, 1.0, 0.35),
             text_color: Color::hsla(0.0, 0.0, 1.0, 1.0),
+            placeholder: DASH.into(),
+            override_splits_background: false,
+            splits_background: ListGradient::Alternating(
+                Color::transparent(),
+                Color::rgba(1.0, 1.0, 1.0, 0.04),
+            ),
+            uniform_key_value_style: false,
+            key_value_background: key_value::DEFAULT_GRADIENT,
+            key_value_label_color: None,
+            time_formatters: TimeFormatterRegistry::new(),
         }
     }
 }
@@ -173,6 +216,36 @@ impl GeneralSettings {
                 "The color to use for text that doesn't specify its own color.".into(),
                 self.text_color.into(),
             ),
+            Field::new(
+                "Placeholder".into(),
+                "The text shown in place of times and other values that are missing.".into(),
+                self.placeholder.clone().into(),
+            ),
+            Field::new(
+                "Override Splits Background".into(),
+                "Specifies whether the backgrounds of all the Splits components are replaced by the background configured below.".into(),
+                self.override_splits_background.into(),
+            ),
+            Field::new(
+                "Splits Background".into(),
+                "The background shown behind the rows of all the Splits components if they are overridden. You can choose for the colors to be alternating. In that case each row alternates between the two colors chosen.".into(),
+                self.splits_background.into(),
+            ),
+            Field::new(
+                "Uniform Key Value Style".into(),
+                "Specifies whether all the components that show a name and a value use the background and label color configured below instead of their own. The colors of the values are kept.".into(),
+                self.uniform_key_value_style.into(),
+            ),
+            Field::new(
+                "Key Value Background".into(),
+                "The background shown behind the components that show a name and a value if they are styled uniformly.".into(),
+                self.key_value_background.into(),
+            ),
+            Field::new(
+                "Key Value Label Color".into(),
+                "The color of the names of the components that show a name and a value if they are styled uniformly. If not specified, the text color is used.".into(),
+                self.key_value_label_color.into(),
+            ),
         ])
     }
 
@@ -201,7 +274,28 @@ impl GeneralSettings {
             13 => self.thin_separators_color = value.into(),
             14 => self.separators_color = value.into(),
             15 => self.text_color = value.into(),
+            16 => self.placeholder = value.into(),
+            17 => self.override_splits_background = value.into(),
+            18 => self.splits_background = value.into(),
+            19 => self.uniform_key_value_style = value.into(),
+            20 => self.key_value_background = value.into(),
+            21 => self.key_value_label_color = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
+
+    /// Applies the styling that the layout enforces on its components to the
+    /// state of a component.
+    pub(super) fn apply_overrides(&self, state: &mut ComponentState) {
+        match state {
+            ComponentState::Splits(state) if self.override_splits_background => {
+                state.background = self.splits_background;
+            }
+            ComponentState::KeyValue(state) if self.uniform_key_value_style => {
+                state.background = self.key_value_background;
+                state.key_color = self.key_value_label_color;
+            }
+            _ => {}
+        }
+    }
 }
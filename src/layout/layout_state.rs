@@ -1,10 +1,12 @@
 use serde_derive::{Deserialize, Serialize};
 
-use super::{ComponentState, LayoutDirection};
+use super::{ComponentState, LayoutDirection, UpdateHint};
 use crate::{
+    component::{text::TextState, timer},
     platform::prelude::*,
     settings::{Color, Font, ImageId, LayoutBackground},
 };
+use alloc::collections::BTreeMap;
 
 /// The state object describes the information to visualize for the layout.
 #[derive(Default, Serialize, Deserialize)]
@@ -32,6 +34,202 @@ pub struct LayoutState {
     pub text_color: Color,
 }
 
+impl LayoutState {
+    /// Determines how often the state of the whole layout is expected to
+    /// change, which is the most frequent [`UpdateHint`] of its components.
+    pub fn update_hint(&self) -> UpdateHint {
+        self.components
+            .iter()
+            .map(ComponentState::update_hint)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Calls the function provided for the ID of every image referenced by the
+    /// state, such as the background image and the icons of the components.
+    /// Empty images are skipped. The same image may be visited multiple times.
+    pub fn for_each_image_id(&self, mut f: impl FnMut(&ImageId)) {
+        let mut visit = |id: &ImageId| {
+            if !id.is_empty() {
+                f(id);
+            }
+        };
+
+        if let LayoutBackground::Image(image) = &self.background {
+            visit(&image.image);
+        }
+
+        for component in &self.components {
+            match component {
+                ComponentState::DetailedTimer(state) => visit(&state.icon),
+                ComponentState::Splits(state) => {
+                    for split in state.splits.iter() {
+                        visit(&split.icon);
+                    }
+                }
+                ComponentState::Title(state) => visit(&state.icon),
+                _ => {}
+            }
+        }
+    }
+
+    /// Flattens the texts shown by the components into a map of string keys
+    /// to string values. The IDs of the components the states belong to are
+    /// used to name them. See [`Layout::text_map`](super::Layout::text_map)
+    /// for more information.
+    pub(super) fn text_map<'a>(
+        &self,
+        ids: impl IntoIterator<Item = &'a str>,
+    ) -> BTreeMap<String, String> {
+        let mut map = BTreeMap::new();
+        let mut names = BTreeMap::<String, usize>::new();
+
+        for (component, id) in self.components.iter().zip(ids) {
+            if let ComponentState::BlankSpace(_)
+            | ComponentState::Graph(_)
+            | ComponentState::PaceGraph(_)
+            | ComponentState::PbProgression(_)
+            | ComponentState::SegmentSparkline(_)
+            | ComponentState::Separator(_) = component
+            {
+                continue;
+            }
+
+            let mut name = component_name(id);
+            if name.is_empty() {
+                name.push_str("component");
+            }
+
+            let count = names.entry(name.clone()).or_default();
+            *count += 1;
+            let prefix = if *count == 1 {
+                name
+            } else {
+                format!("{name}_{count}")
+            };
+            let mut insert = |key: &str, value: String| {
+                map.insert(format!("{prefix}.{key}"), value);
+            };
+
+            match component {
+                ComponentState::DetailedTimer(state) => {
+                    insert("time", timer_text(&state.timer));
+                    insert("segment_time", timer_text(&state.segment_timer));
+                    if let Some(name) = &state.segment_name {
+                        insert("segment_name", name.clone());
+                    }
+                    for (index, comparison) in [&state.comparison1, &state.comparison2]
+                        .into_iter()
+                        .enumerate()
+                    {
+                        if let Some(comparison) = comparison {
+                            let index = index + 1;
+                            insert(&format!("comparison_{index}.name"), comparison.name.clone());
+                            insert(&format!("comparison_{index}.time"), comparison.time.clone());
+                        }
+                    }
+                }
+                ComponentState::KeyValue(state) => {
+                    insert("key", state.key.clone());
+                    insert("value", state.value.clone());
+                    if let Some(value) = &state.secondary_value {
+                        insert("secondary_value", value.clone());
+                    }
+                    for (index, row) in state.rows.iter().enumerate() {
+                        insert(&format!("rows.{index}.key"), row.key.clone());
+                        insert(&format!("rows.{index}.value"), row.value.clone());
+                    }
+                }
+                ComponentState::RunPrediction(state) => {
+                    for (index, target) in state.targets.iter().enumerate() {
+                        insert(&format!("{index}.name"), target.name.clone());
+                        insert(&format!("{index}.value"), target.value.clone());
+                    }
+                }
+                ComponentState::Splits(state) => {
+                    let splits = state.splits.iter().filter(|split| split.id.is_some());
+                    for (index, split) in splits.enumerate() {
+                        insert(&format!("{index}.name"), split.name.clone());
+                        for (column, value) in split.columns.iter().enumerate() {
+                            let label = state
+                                .column_labels
+                                .as_ref()
+                                .and_then(|labels| labels.get(column))
+                                .map(|label| slug(label))
+                                .filter(|label| !label.is_empty());
+                            let key = match label {
+                                Some(label) => format!("{index}.{label}"),
+                                None => format!("{index}.column_{column}"),
+                            };
+                            insert(&key, value.value.clone());
+                        }
+                    }
+                }
+                ComponentState::Text(state) => match &state.text {
+                    TextState::Center(text) => insert("text", text.clone()),
+                    TextState::Split(left, right) => {
+                        insert("left", left.clone());
+                        insert("right", right.clone());
+                    }
+                },
+                ComponentState::Timer(state) => insert("time", timer_text(state)),
+                ComponentState::Title(state) => {
+                    if let Some(line) = state.line1.first() {
+                        insert("line1", line.to_string());
+                    }
+                    if let Some(line) = state.line2.first() {
+                        insert("line2", line.to_string());
+                    }
+                    if let Some(attempts) = state.attempts {
+                        insert("attempts", attempts.to_string());
+                    }
+                    if let Some(finished_runs) = state.finished_runs {
+                        insert("finished_runs", finished_runs.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        map
+    }
+}
+
+fn timer_text(state: &timer::State) -> String {
+    format!("{}{}", state.time, state.fraction)
+}
+
+/// Turns the ID of a component into a lowercase name, such as `current_pace`
+/// for `CurrentPace`.
+fn component_name(id: &str) -> String {
+    let mut name = String::with_capacity(id.len() + 4);
+    let mut after_lowercase = false;
+    for c in id.chars() {
+        if c.is_ascii_uppercase() && after_lowercase {
+            name.push('_');
+        }
+        after_lowercase = c.is_ascii_lowercase() || c.is_ascii_digit();
+        name.push(c);
+    }
+    slug(&name)
+}
+
+/// Turns a label into a lowercase name that only consists of ASCII letters,
+/// digits and underscores, such as `current_pace` for `Current Pace`.
+fn slug(label: &str) -> String {
+    let mut slug = String::with_capacity(label.len());
+    for c in label.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('_') {
+            slug.push('_');
+        }
+    }
+    let len = slug.trim_end_matches('_').len();
+    slug.truncate(len);
+    slug
+}
+
 #[cfg(feature = "std")]
 impl LayoutState {
     /// Encodes the state object's information as JSON.
@@ -41,4 +239,17 @@ impl LayoutState {
     {
         serde_json::to_writer(writer, self)
     }
+
+    /// Encodes the state object's information as MessagePack. Structs are
+    /// encoded as arrays of their fields in the order they are declared in,
+    /// instead of maps with the names of the fields. This makes the encoded
+    /// state a lot smaller than the JSON encoded state, which is useful when
+    /// the state is sent to another process or device many times a second.
+    #[cfg(feature = "msgpack")]
+    pub fn write_msgpack<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
 }
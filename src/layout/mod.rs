@@ -11,19 +11,30 @@ mod layout_direction;
 mod layout_settings;
 mod layout_state;
 pub mod parser;
+mod update_hint;
+
+#[cfg(test)]
+mod tests;
 
 pub use self::{
-    component::Component, component_settings::ComponentSettings, component_state::ComponentState,
-    editor::Editor, general_settings::GeneralSettings, layout_direction::LayoutDirection,
-    layout_settings::LayoutSettings, layout_state::LayoutState,
+    component::Component,
+    component_settings::{ComponentSettings, CustomComponentSettings},
+    component_state::ComponentState,
+    editor::Editor,
+    general_settings::GeneralSettings,
+    layout_direction::LayoutDirection,
+    layout_settings::LayoutSettings,
+    layout_state::LayoutState,
+    update_hint::UpdateHint,
 };
 
 use crate::{
-    component::{previous_segment, splits, timer, title},
+    component::{previous_segment, splits, timer, title, ComponentRegistry},
     platform::prelude::*,
     settings::ImageCache,
     timing::Snapshot,
 };
+use alloc::collections::BTreeMap;
 
 /// A Layout allows you to combine multiple components together to visualize a
 /// variety of information the runner is interested in.
@@ -32,6 +43,7 @@ pub struct Layout {
     /// All of the layout's components.
     pub components: Vec<Component>,
     settings: GeneralSettings,
+    page: usize,
 }
 
 impl Layout {
@@ -53,6 +65,7 @@ impl Layout {
                 previous_segment::Component::new().into(),
             ],
             settings: GeneralSettings::default(),
+            page: 0,
         }
     }
 
@@ -65,6 +78,24 @@ impl Layout {
                 .map(Into::into)
                 .collect(),
             settings: layout_settings.general,
+            page: 0,
+        }
+    }
+
+    /// Creates a new layout from the layout settings of the whole layout. The
+    /// custom components are created through the registry provided.
+    pub fn from_settings_with_registry(
+        layout_settings: LayoutSettings,
+        registry: &ComponentRegistry,
+    ) -> Self {
+        Self {
+            components: layout_settings
+                .components
+                .into_iter()
+                .map(|settings| registry.component_from_settings(settings))
+                .collect(),
+            settings: layout_settings.general,
+            page: 0,
         }
     }
 
@@ -85,22 +116,78 @@ impl Layout {
         self.components.push(component.into());
     }
 
+    /// Returns the amount of pages the components of the layout are split into
+    /// by [`PageBreak`](crate::component::PageBreak) components. A layout
+    /// without any page breaks consists of a single page.
+    pub fn page_count(&self) -> usize {
+        1 + self
+            .components
+            .iter()
+            .filter(|c| matches!(c, Component::PageBreak(_)))
+            .count()
+    }
+
+    /// Returns the index of the page that is currently visible.
+    pub fn current_page(&self) -> usize {
+        self.page.min(self.page_count() - 1)
+    }
+
+    /// Makes the page with the index provided the visible page. If there's no
+    /// such page, the last page becomes visible.
+    pub fn set_current_page(&mut self, page: usize) {
+        self.page = page.min(self.page_count() - 1);
+    }
+
+    /// Makes the next page the visible page. The first page becomes visible
+    /// again after the last page.
+    pub fn next_page(&mut self) {
+        self.page = (self.current_page() + 1) % self.page_count();
+    }
+
+    /// Makes the previous page the visible page. The last page becomes visible
+    /// again before the first page.
+    pub fn previous_page(&mut self) {
+        self.page = self
+            .current_page()
+            .checked_sub(1)
+            .unwrap_or(self.page_count() - 1);
+    }
+
+    /// Toggles all the [`Current Pace`](crate::component::current_pace)
+    /// components of the layout between showing the predicted final time and
+    /// the predicted wall clock time at which the attempt finishes. This allows
+    /// the runner to glance at the predicted end of the attempt on demand.
+    pub fn toggle_wall_clock(&mut self) {
+        for component in &mut self.components {
+            if let Component::CurrentPace(component) = component {
+                let settings = component.settings_mut();
+                settings.wall_clock = !settings.wall_clock;
+            }
+        }
+    }
+
     /// Updates the layout's state based on the timer provided. You can use this
-    /// to visualize all of the components of a layout. The [`ImageCache`] is
-    /// updated with all the images that are part of the state. The images are
-    /// marked as visited in the [`ImageCache`]. You still need to manually run
-    /// [`ImageCache::collect`] to ensure unused images are removed from the
-    /// cache.
+    /// to visualize all of the components on the current page of a layout. The
+    /// [`ImageCache`] is updated with all the images that are part of the
+    /// state. The images are marked as visited in the [`ImageCache`]. You still
+    /// need to manually run [`ImageCache::collect`] to ensure unused images are
+    /// removed from the cache.
     pub fn update_state(
         &mut self,
         state: &mut LayoutState,
         image_cache: &mut ImageCache,
         timer: &Snapshot<'_>,
     ) {
+        let page = self.current_page();
         let settings = &self.settings;
+        let components = self
+            .components
+            .split_mut(|c| matches!(c, Component::PageBreak(_)))
+            .nth(page)
+            .unwrap_or_default();
 
-        state.components.truncate(self.components.len());
-        let mut components = self.components.iter_mut();
+        state.components.truncate(components.len());
+        let mut components = components.iter_mut();
         // First update all the states that we have.
         for (state, component) in state.components.iter_mut().zip(components.by_ref()) {
             component.update_state(state, image_cache, timer, settings);
@@ -110,6 +197,10 @@ impl Layout {
             .components
             .extend(components.map(|c| c.state(image_cache, timer, settings)));
 
+        for state in &mut state.components {
+            settings.apply_overrides(state);
+        }
+
         state.timer_font.clone_from(&settings.timer_font);
         state.times_font.clone_from(&settings.times_font);
         state.text_font.clone_from(&settings.text_font);
@@ -122,17 +213,39 @@ impl Layout {
     }
 
     /// Calculates the layout's state based on the timer provided. You can use
-    /// this to visualize all of the components of a layout. The [`ImageCache`]
-    /// is updated with all the images that are part of the state. The images
-    /// are marked as visited in the [`ImageCache`]. You still need to manually
-    /// run [`ImageCache::collect`] to ensure unused images are removed from the
-    /// cache.
+    /// this to visualize all of the components on the current page of a
+    /// layout. The [`ImageCache`] is updated with all the images that are part
+    /// of the state. The images are marked as visited in the [`ImageCache`].
+    /// You still need to manually run [`ImageCache::collect`] to ensure unused
+    /// images are removed from the cache.
     pub fn state(&mut self, image_cache: &mut ImageCache, timer: &Snapshot<'_>) -> LayoutState {
         let mut state = Default::default();
         self.update_state(&mut state, image_cache, timer);
         state
     }
 
+    /// Flattens the texts shown by the components into a map of string keys
+    /// to string values, such as `current_pace.value` or `timer.time`. This
+    /// allows text sources of streaming software and simple scripts to use
+    /// the state without having to understand its structure. The state needs
+    /// to be one that got calculated by this layout for its current page.
+    ///
+    /// Each key starts with the name of the component, followed by a dot and
+    /// the name of the text. The components are named after their
+    /// [`ID`](Component::id), so the keys stay the same when the labels of the
+    /// components change. If the same name is used by multiple components, the
+    /// later ones get numbered, such as `text_2.left`. Components that don't
+    /// show any text, like graphs and separators, aren't part of the map.
+    pub fn text_map(&self, state: &LayoutState) -> BTreeMap<String, String> {
+        let components = self
+            .components
+            .split(|c| matches!(c, Component::PageBreak(_)))
+            .nth(self.current_page())
+            .unwrap_or_default();
+
+        state.text_map(components.iter().map(Component::id))
+    }
+
     /// Accesses the settings of the layout.
     pub fn settings(&self) -> LayoutSettings {
         LayoutSettings {
@@ -2,7 +2,7 @@
 
 use super::{Component, Layout, LayoutDirection};
 use crate::{
-    component::{separator, timer::DeltaGradient},
+    component::{separator, timer::DeltaGradient, ComponentRegistry},
     platform::{math::f32::powf, prelude::*},
     settings::{
         Alignment, BackgroundImage, Color, Font, FontStretch, FontStyle, FontWeight, Gradient,
@@ -53,6 +53,7 @@ fn translate_size(v: u32) -> u32 {
 
 /// The Error type for parsing layout files of the original LiveSplit.
 #[derive(Debug, snafu::Snafu)]
+#[non_exhaustive]
 pub enum Error {
     /// The underlying XML format couldn't be parsed.
     Xml {
@@ -625,7 +626,7 @@ where
     })
 }
 
-fn component<F>(reader: &mut Reader<'_>, f: F) -> Result<()>
+fn component<F>(reader: &mut Reader<'_>, registry: Option<&ComponentRegistry>, f: F) -> Result<()>
 where
     F: FnOnce(Component),
 {
@@ -657,7 +658,10 @@ where
                     "LiveSplit.Timer.dll" => timer::Component::new().into(),
                     "LiveSplit.Title.dll" => title::Component::new().into(),
                     "LiveSplit.TotalPlaytime.dll" => total_playtime::Component::new().into(),
-                    _ => return Ok(()),
+                    _ => match registry.and_then(|registry| registry.create(text)) {
+                        Some(component) => component,
+                        None => return Ok(()),
+                    },
                 });
                 Ok(())
             }),
@@ -666,23 +670,37 @@ where
                 // Otherwise we need to cache the settings and load them later.
                 if let Some(component) = &mut component {
                     match component {
+                        Component::AttemptCount(_) => end_tag(reader),
+                        Component::BarrierWatch(_) => end_tag(reader),
                         Component::BlankSpace(c) => blank_space::settings(reader, c),
+                        Component::ChokeRate(_) => end_tag(reader),
+                        Component::ClockDrift(_) => end_tag(reader),
+                        Component::Completion(_) => end_tag(reader),
                         Component::CurrentComparison(c) => current_comparison::settings(reader, c),
                         Component::CurrentPace(c) => current_pace::settings(reader, c),
+                        Component::Custom(_) => end_tag(reader),
                         Component::Delta(c) => delta::settings(reader, c),
                         Component::DetailedTimer(c) => detailed_timer::settings(reader, c),
+                        Component::EstimateDelta(_) => end_tag(reader),
                         Component::Graph(c) => graph::settings(reader, c),
+                        Component::PaceGraph(_) => end_tag(reader),
+                        Component::PageBreak(_) => end_tag(reader),
                         Component::PbChance(c) => pb_chance::settings(reader, c),
+                        Component::PbProgression(_) => end_tag(reader),
                         Component::PossibleTimeSave(c) => possible_time_save::settings(reader, c),
                         Component::PreviousSegment(c) => previous_segment::settings(reader, c),
+                        Component::RunPrediction(_) => end_tag(reader),
+                        Component::SegmentSparkline(_) => end_tag(reader),
                         Component::SegmentTime(_) => end_tag(reader),
                         Component::Separator(_) => end_tag(reader),
                         Component::Splits(c) => splits::settings(reader, c),
                         Component::SumOfBest(c) => sum_of_best::settings(reader, c),
                         Component::Text(c) => text::settings(reader, c),
+                        Component::TimeRemaining(_) => end_tag(reader),
                         Component::Timer(c) => timer::settings(reader, c),
                         Component::Title(c) => title::settings(reader, c),
                         Component::TotalPlaytime(c) => total_playtime::settings(reader, c),
+                        Component::WorldRecord(_) => end_tag(reader),
                     }
                 } else {
                     end_tag(reader)
@@ -805,6 +823,19 @@ fn parse_general_settings(layout: &mut Layout, reader: &mut Reader<'_>) -> Resul
 /// parsed on a best effort basis, so if something isn't supported by
 /// livesplit-core, then it will be parsed without that option.
 pub fn parse(source: &str) -> Result<Layout> {
+    parse_inner(source, None)
+}
+
+/// Attempts to parse a layout file of the original LiveSplit, just like
+/// [`parse`]. Components that are not provided by livesplit-core are looked up
+/// in the registry provided by the path of their library, such as
+/// `LiveSplit.Custom.dll`. Their settings are not parsed, so they use their
+/// default settings.
+pub fn parse_with_registry(source: &str, registry: &ComponentRegistry) -> Result<Layout> {
+    parse_inner(source, Some(registry))
+}
+
+fn parse_inner(source: &str, registry: Option<&ComponentRegistry>) -> Result<Layout> {
     let reader = &mut Reader::new(source);
 
     let mut layout = Layout::new();
@@ -821,7 +852,7 @@ pub fn parse(source: &str) -> Result<Layout> {
             }),
             "Settings" => parse_general_settings(&mut layout, reader),
             "Components" => parse_children(reader, |reader, _, _| {
-                component(reader, |c| {
+                component(reader, registry, |c| {
                     layout.push(c);
                 })
             }),
@@ -42,6 +42,7 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                     }
                     "Display2Rows" => parse_bool(reader, |b| settings.display_two_rows = b),
                     "ShowColumnLabels" => parse_bool(reader, |b| settings.show_column_labels = b),
+                    "DisplayIcons" => parse_bool(reader, |b| settings.show_icons = b),
                     "Columns" => {
                         // Version >= 1.5
                         settings.columns.clear();
@@ -162,7 +163,6 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                     "DropDecimals" => parse_bool(reader, |v| settings.delta_drop_decimals = v),
                     _ => {
                         // FIXME:
-                        // DisplayIcons
                         // SplitWidth
                         // AutomaticAbbreviations
                         // BeforeNamesColor // Version >= 1.3
@@ -0,0 +1,420 @@
+use super::{
+    parser, Component, ComponentSettings, ComponentState, GeneralSettings, Layout, LayoutSettings,
+    UpdateHint,
+};
+use crate::{
+    component::{
+        blank_space, current_pace, delta, key_value, page_break, previous_segment, separator,
+        splits, text, timer, Component as CustomComponent, ComponentRegistry,
+    },
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, ImageCache, ListGradient, SettingsDescription, Value},
+    testing::{create_timer, run_with_splits},
+    timing::{formatter::Accuracy, Snapshot},
+};
+use alloc::borrow::Cow;
+
+fn visible_components(layout: &mut Layout) -> Vec<ComponentState> {
+    let timer = create_timer(&["A"]);
+    layout
+        .state(&mut ImageCache::new(), &timer.snapshot())
+        .components
+}
+
+#[test]
+fn only_the_current_page_is_visible() {
+    let mut layout = Layout::new();
+    layout.push(text::Component::new());
+    layout.push(separator::Component::new());
+    layout.push(page_break::Component::new());
+    layout.push(blank_space::Component::new());
+    assert_eq!(layout.page_count(), 2);
+    assert_eq!(layout.current_page(), 0);
+
+    let components = visible_components(&mut layout);
+    assert!(matches!(
+        &*components,
+        [ComponentState::Text(_), ComponentState::Separator(_)],
+    ));
+
+    layout.next_page();
+    assert_eq!(layout.current_page(), 1);
+    let components = visible_components(&mut layout);
+    assert!(matches!(&*components, [ComponentState::BlankSpace(_)]));
+
+    layout.next_page();
+    assert_eq!(layout.current_page(), 0);
+
+    layout.previous_page();
+    assert_eq!(layout.current_page(), 1);
+}
+
+#[test]
+fn removing_page_breaks_keeps_a_valid_page() {
+    let mut layout = Layout::new();
+    layout.push(text::Component::new());
+    layout.push(page_break::Component::new());
+    layout.push(blank_space::Component::new());
+    layout.set_current_page(5);
+    assert_eq!(layout.current_page(), 1);
+
+    layout.components.remove(1);
+    assert_eq!(layout.page_count(), 1);
+    assert_eq!(layout.current_page(), 0);
+    assert_eq!(visible_components(&mut layout).len(), 2);
+}
+
+#[derive(Clone, Default)]
+struct Greeting {
+    name: String,
+}
+
+impl CustomComponent for Greeting {
+    fn id(&self) -> &str {
+        "Greeting"
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "Greeting".into()
+    }
+
+    fn state(
+        &mut self,
+        _: &mut ImageCache,
+        _: &Snapshot<'_>,
+        _: &GeneralSettings,
+    ) -> ComponentState {
+        ComponentState::KeyValue(key_value::State {
+            key: "Hello".into(),
+            value: self.name.clone(),
+            ..Default::default()
+        })
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new(
+            "Name".into(),
+            "The name to greet.".into(),
+            self.name.clone().into(),
+        )])
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.name = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+
+    fn settings(&self) -> serde_json::Value {
+        self.name.clone().into()
+    }
+
+    fn set_settings(&mut self, settings: serde_json::Value) -> serde_json::Result<()> {
+        self.name = serde_json::from_value(settings)?;
+        Ok(())
+    }
+
+    fn boxed_clone(&self) -> Box<dyn CustomComponent> {
+        Box::new(self.clone())
+    }
+}
+
+fn greeting() -> Component {
+    let component: Box<dyn CustomComponent> = Box::<Greeting>::default();
+    component.into()
+}
+
+fn greeted_name(layout: &mut Layout) -> String {
+    match &*visible_components(layout) {
+        [ComponentState::KeyValue(state)] => state.value.clone(),
+        _ => panic!("Expected a single key value component"),
+    }
+}
+
+#[test]
+fn toggling_the_wall_clock_affects_all_current_pace_components() {
+    let mut layout = Layout::new();
+    layout.push(current_pace::Component::new());
+    layout.push(text::Component::new());
+    let mut wall_clock = current_pace::Component::new();
+    wall_clock.settings_mut().wall_clock = true;
+    layout.push(wall_clock);
+
+    let wall_clock = |layout: &Layout| {
+        layout
+            .components
+            .iter()
+            .filter_map(|c| match c {
+                Component::CurrentPace(c) => Some(c.settings().wall_clock),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    layout.toggle_wall_clock();
+    assert_eq!(wall_clock(&layout), [true, false]);
+
+    layout.toggle_wall_clock();
+    assert_eq!(wall_clock(&layout), [false, true]);
+}
+
+#[test]
+fn the_layout_can_enforce_a_uniform_style() {
+    let mut layout = Layout::new();
+    layout.push(splits::Component::new());
+    let mut pace = current_pace::Component::new();
+    pace.settings_mut().background = Gradient::Plain(Color::black());
+    pace.settings_mut().label_color = Some(Color::black());
+    layout.push(pace);
+
+    let components = visible_components(&mut layout);
+    let [ComponentState::Splits(splits), ComponentState::KeyValue(pace)] = &*components else {
+        panic!("unexpected components");
+    };
+    assert_eq!(splits.background, splits::Settings::default().background);
+    assert_eq!(pace.background, Gradient::Plain(Color::black()));
+    assert_eq!(pace.key_color, Some(Color::black()));
+
+    let background = ListGradient::Alternating(Color::white(), Color::black());
+    let settings = layout.general_settings_mut();
+    settings.override_splits_background = true;
+    settings.splits_background = background;
+    settings.uniform_key_value_style = true;
+    settings.key_value_background = Gradient::Transparent;
+
+    let components = visible_components(&mut layout);
+    let [ComponentState::Splits(splits), ComponentState::KeyValue(pace)] = &*components else {
+        panic!("unexpected components");
+    };
+    assert_eq!(splits.background, background);
+    assert_eq!(pace.background, Gradient::Transparent);
+    assert_eq!(pace.key_color, None);
+}
+
+#[test]
+fn custom_components_are_restored_through_the_registry() {
+    let mut layout = Layout::new();
+    layout.push(greeting());
+    layout.components[0].set_value(0, String::from("Runner").into());
+    assert_eq!(layout.components[0].name(), "Greeting");
+    assert_eq!(greeted_name(&mut layout), "Runner");
+
+    let mut registry = ComponentRegistry::new();
+    registry.register("Greeting", greeting);
+    assert!(registry.ids().any(|id| id == "Timer"));
+
+    let mut restored = Layout::from_settings_with_registry(layout.settings(), &registry);
+    assert_eq!(greeted_name(&mut restored), "Runner");
+    assert!(matches!(
+        registry.create("Splits"),
+        Some(Component::Splits(_))
+    ));
+}
+
+#[test]
+fn selected_time_formats_survive_saving_and_loading() {
+    let mut layout = Layout::new();
+    layout.push(delta::Component::new());
+    let index = layout.components.len() - 1;
+    let description = layout.components[index].settings_description();
+    let field = description
+        .fields
+        .iter()
+        .position(|field| field.text == "Time Format")
+        .unwrap();
+    layout.components[index].set_value(field, Some(String::from("Days")).into());
+
+    let mut json = Vec::new();
+    layout.settings().write_json(&mut json).unwrap();
+    let restored = Layout::from_settings(LayoutSettings::from_json(&*json).unwrap());
+    let Component::Delta(component) = &restored.components[index] else {
+        panic!("Expected a Delta component");
+    };
+    assert_eq!(component.settings().time_format.as_deref(), Some("Days"));
+}
+
+#[test]
+fn components_show_the_placeholder_of_the_layout() {
+    let mut layout = Layout::new();
+    layout.push(delta::Component::new());
+    layout.general_settings_mut().placeholder = String::from("N/A");
+
+    let mut json = Vec::new();
+    layout.settings().write_json(&mut json).unwrap();
+    let mut restored = Layout::from_settings(LayoutSettings::from_json(&*json).unwrap());
+    assert!(matches!(
+        &*visible_components(&mut restored),
+        [ComponentState::KeyValue(state)] if state.value == "N/A",
+    ));
+}
+
+#[test]
+fn unregistered_custom_components_keep_their_settings() {
+    let mut layout = Layout::new();
+    layout.push(greeting());
+    layout.components[0].set_value(0, String::from("Runner").into());
+
+    let mut restored = Layout::from_settings(layout.settings());
+    assert!(matches!(
+        &*visible_components(&mut restored),
+        [ComponentState::BlankSpace(_)],
+    ));
+
+    let [ComponentSettings::Custom(settings)] = &*restored.settings().components else {
+        panic!("Expected a single custom component");
+    };
+    assert_eq!(settings.id, "Greeting");
+    assert_eq!(settings.settings, "Runner");
+}
+
+#[test]
+fn original_layouts_can_contain_registered_components() {
+    let source = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Layout version="1.6.1">
+  <Mode>Vertical</Mode>
+  <Components>
+    <Component>
+      <Path>LiveSplit.Greeting.dll</Path>
+      <Settings />
+    </Component>
+    <Component>
+      <Path>LiveSplit.Text.dll</Path>
+      <Settings />
+    </Component>
+  </Components>
+</Layout>"#;
+
+    let layout = parser::parse(source).unwrap();
+    assert_eq!(layout.components.len(), 1);
+
+    let mut registry = ComponentRegistry::new();
+    registry.register("LiveSplit.Greeting.dll", greeting);
+    let layout = parser::parse_with_registry(source, &registry).unwrap();
+    assert!(matches!(
+        &*layout.components,
+        [Component::Custom(_), Component::Text(_)],
+    ));
+}
+
+#[test]
+fn update_hints_depend_on_what_is_shown() {
+    let mut layout = Layout::new();
+    layout.push(blank_space::Component::new());
+    layout.push(text::Component::new());
+    layout.push(timer::Component::with_settings(timer::Settings {
+        accuracy: Accuracy::Seconds,
+        ..Default::default()
+    }));
+    layout.push(timer::Component::new());
+
+    let mut timer = create_timer(&["A"]);
+    let hints = |layout: &mut Layout, timer: &crate::Timer| {
+        let state = layout.state(&mut ImageCache::new(), &timer.snapshot());
+        let hints: Vec<_> = state
+            .components
+            .iter()
+            .map(ComponentState::update_hint)
+            .collect();
+        (hints, state.update_hint())
+    };
+
+    assert_eq!(
+        hints(&mut layout, &timer),
+        (
+            vec![
+                UpdateHint::Static,
+                UpdateHint::OnSplit,
+                UpdateHint::OnSplit,
+                UpdateHint::OnSplit,
+            ],
+            UpdateHint::OnSplit,
+        ),
+    );
+
+    timer.start().unwrap();
+    assert_eq!(
+        hints(&mut layout, &timer),
+        (
+            vec![
+                UpdateHint::Static,
+                UpdateHint::OnSplit,
+                UpdateHint::EverySecond,
+                UpdateHint::EveryFrame,
+            ],
+            UpdateHint::EveryFrame,
+        ),
+    );
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_is_more_compact_than_json() {
+    let mut layout = Layout::default_layout();
+    let mut timer = create_timer(&["A", "B", "C"]);
+    timer.start().unwrap();
+    let state = layout.state(&mut ImageCache::new(), &timer.snapshot());
+
+    let mut json = Vec::new();
+    state.write_json(&mut json).unwrap();
+    let mut msgpack = Vec::new();
+    state.write_msgpack(&mut msgpack).unwrap();
+    assert!(2 * msgpack.len() < json.len());
+
+    let decoded: super::LayoutState = rmp_serde::from_slice(&msgpack).unwrap();
+    let mut decoded_json = Vec::new();
+    decoded.write_json(&mut decoded_json).unwrap();
+    assert_eq!(decoded_json, json);
+}
+
+#[test]
+fn the_state_can_be_flattened_into_a_text_map() {
+    let mut layout = Layout::default_layout();
+    layout.push(current_pace::Component::new());
+    let mut split_text = text::Component::new();
+    split_text.settings_mut().text = text::Text::Split("Goal".into(), "Sub 30".into());
+    layout.push(split_text);
+    layout.push(text::Component::new());
+    layout.push(separator::Component::new());
+
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+    let state = layout.state(&mut ImageCache::new(), &timer.snapshot());
+    let map = layout.text_map(&state);
+    assert_eq!(map["title.line1"], "Untitled");
+    assert_eq!(map["title.attempts"], "1");
+    assert_eq!(map["splits.1.name"], "B");
+    assert_eq!(map["splits.1.column_0"], "0:25");
+    assert!(!map.contains_key("splits.2.name"));
+    assert_eq!(map["timer.time"], "0.00");
+    assert_eq!(map["previous_segment.key"], "Previous Segment");
+    assert_eq!(map["current_pace.value"], "—");
+    assert_eq!(map["text.left"], "Goal");
+    assert_eq!(map["text.right"], "Sub 30");
+    assert_eq!(map["text_2.text"], "");
+    assert!(map.keys().all(|key| !key.starts_with("separator")));
+}
+
+#[test]
+fn the_keys_of_the_text_map_dont_depend_on_the_labels() {
+    let mut layout = Layout::new();
+    let mut previous_segment = previous_segment::Component::new();
+    previous_segment.settings_mut().always_show_live_segment = true;
+    layout.push(previous_segment);
+    layout.push(delta::Component::new());
+
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+    let mut state = layout.state(&mut ImageCache::new(), &timer.snapshot());
+    let map = layout.text_map(&state);
+    assert_eq!(map["previous_segment.key"], "Live Segment");
+    assert_eq!(map["delta.key"], "Personal Best");
+
+    timer.switch_to_next_comparison();
+    layout.update_state(&mut state, &mut ImageCache::new(), &timer.snapshot());
+    let map = layout.text_map(&state);
+    assert_ne!(map["delta.key"], "Personal Best");
+    assert!(map
+        .keys()
+        .all(|key| key.starts_with("previous_segment.") || key.starts_with("delta.")));
+}
@@ -0,0 +1,40 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Describes how often the state of a component is expected to change. This
+/// allows renderers and other consumers of the state to process each component
+/// only as often as necessary, instead of processing all of them every frame.
+/// The hints are ordered from the least to the most frequently changing, so
+/// the hint for a group of components is the maximum of their hints.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum UpdateHint {
+    /// The state only changes when the settings of the component change.
+    #[default]
+    Static,
+    /// The state only changes when the timer changes, such as when splitting,
+    /// but not because time passes.
+    OnSplit,
+    /// The state changes because time passes, but only once every second.
+    EverySecond,
+    /// The state changes because time passes and needs to be updated every
+    /// frame.
+    EveryFrame,
+}
+
+impl UpdateHint {
+    /// Returns the hint for a component that either frequently changes
+    /// because time passes or only when the timer changes.
+    pub const fn from_updates_frequently(updates_frequently: bool) -> Self {
+        if updates_frequently {
+            Self::EveryFrame
+        } else {
+            Self::OnSplit
+        }
+    }
+
+    /// Returns whether the state changes because time passes.
+    pub const fn updates_frequently(self) -> bool {
+        matches!(self, Self::EverySecond | Self::EveryFrame)
+    }
+}
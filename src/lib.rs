@@ -66,6 +66,8 @@ pub mod analysis;
 pub mod auto_splitting;
 pub mod comparison;
 pub mod component;
+#[cfg(feature = "std")]
+pub mod config;
 pub mod event;
 #[cfg(feature = "std")]
 mod hotkey_config;
@@ -76,14 +78,18 @@ pub mod networking;
 #[cfg(feature = "rendering")]
 pub mod rendering;
 pub mod run;
+#[cfg(feature = "std")]
+pub mod session;
 pub mod settings;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod timing;
 pub mod util;
 
 pub use crate::{
     layout::{Component, Editor as LayoutEditor, GeneralSettings as GeneralLayoutSettings, Layout},
     platform::DateTime,
-    run::{Attempt, Editor as RunEditor, Run, RunMetadata, Segment, SegmentHistory},
+    run::{Attempt, Editor as RunEditor, Run, RunMetadata, Segment, SegmentHistory, SegmentId},
     timing::{
         AtomicDateTime, GameTime, RealTime, Time, TimeSpan, TimeStamp, Timer, TimerPhase,
         TimingMethod,
@@ -0,0 +1,259 @@
+//! The `chat` module answers chat commands, such as the ones viewers type into
+//! the Twitch or YouTube chat of a stream, with information about the run.
+//! The answers are plain text that can be posted to the chat as is, so chat
+//! bots only need to forward the messages to [`respond`] and post the answers.
+//!
+//! The following commands are supported:
+//!
+//! - `!pb`: The Personal Best and Sum of Best Segments of the run, along with
+//!   how many attempts were finished.
+//! - `!splits`: The segment the active attempt is in and its current pace.
+//! - `!eta`: When the active attempt is predicted to finish on the wall clock.
+
+use crate::{
+    analysis::{current_pace, delta},
+    platform::prelude::*,
+    timing::{
+        formatter::{Regular, TimeFormatter},
+        Snapshot,
+    },
+    TimeSpan, TimerPhase,
+};
+use core::fmt::Write;
+use time::{format_description::BorrowedFormatItem, macros::format_description};
+
+const WALL_CLOCK_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[hour]:[minute]");
+
+/// A chat command that can be answered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Command {
+    /// The `!pb` command.
+    PersonalBest,
+    /// The `!splits` command.
+    Splits,
+    /// The `!eta` command.
+    Eta,
+}
+
+impl Command {
+    /// Parses the command at the beginning of the chat message provided. The
+    /// command is matched case insensitively and everything after it is
+    /// ignored. `None` is returned if the message doesn't start with a
+    /// supported command.
+    pub fn parse(message: &str) -> Option<Self> {
+        let command = message.split_whitespace().next()?.strip_prefix('!')?;
+        Some(if command.eq_ignore_ascii_case("pb") {
+            Self::PersonalBest
+        } else if command.eq_ignore_ascii_case("splits") {
+            Self::Splits
+        } else if command.eq_ignore_ascii_case("eta") {
+            Self::Eta
+        } else {
+            return None;
+        })
+    }
+
+    /// Answers the command based on the current state of the timer. The times
+    /// are specified in the timer's current timing method and compared against
+    /// its current comparison.
+    pub fn respond(self, timer: &Snapshot<'_>) -> String {
+        let mut response = String::new();
+        let _ = write!(response, "{}: ", timer.run().extended_name(true));
+        match self {
+            Self::PersonalBest => personal_best(&mut response, timer),
+            Self::Splits => splits(&mut response, timer),
+            Self::Eta => eta(&mut response, timer),
+        }
+        response
+    }
+}
+
+/// Answers the chat message provided, if it is a supported command. `None` is
+/// returned for all the other messages, which the chat bot should ignore.
+pub fn respond(message: &str, timer: &Snapshot<'_>) -> Option<String> {
+    Some(Command::parse(message)?.respond(timer))
+}
+
+fn personal_best(response: &mut String, timer: &Snapshot<'_>) {
+    let summary = timer.run().stats_summary(timer.current_timing_method());
+
+    match summary.personal_best {
+        Some(time) => {
+            let _ = write!(
+                response,
+                "The Personal Best is {}.",
+                Regular::new().format(time)
+            );
+        }
+        None => response.push_str("There is no Personal Best yet."),
+    }
+    if let Some(time) = summary.sum_of_best {
+        let _ = write!(
+            response,
+            " The Sum of Best Segments is {}.",
+            Regular::new().format(time)
+        );
+    }
+    let _ = write!(
+        response,
+        " {} of {} attempts were finished.",
+        summary.finished_count, summary.attempt_count,
+    );
+}
+
+fn splits(response: &mut String, timer: &Snapshot<'_>) {
+    let comparison = timer.current_comparison();
+
+    match (timer.current_phase(), timer.current_split_index()) {
+        (TimerPhase::NotRunning, _) | (_, None) => {
+            response.push_str("There is no attempt in progress.");
+            return;
+        }
+        (TimerPhase::Ended, _) => {
+            let _ = write!(
+                response,
+                "The attempt finished with a time of {}.",
+                Regular::new().format(current_pace::calculate(timer, comparison).0),
+            );
+        }
+        (TimerPhase::Running | TimerPhase::Paused, Some(index)) => {
+            let _ = write!(
+                response,
+                "Segment {} of {} ({}). The current pace is {}.",
+                index + 1,
+                timer.run().len(),
+                timer.run().segment(index).name(),
+                Regular::new().format(current_pace::calculate(timer, comparison).0),
+            );
+        }
+    }
+
+    if let (Some(delta), _) = delta::calculate(timer, comparison) {
+        let (delta, relation) = if delta < TimeSpan::zero() {
+            (-delta, "ahead of")
+        } else {
+            (delta, "behind")
+        };
+        let _ = write!(
+            response,
+            " That is {} {relation} the {comparison}.",
+            Regular::new().format(delta),
+        );
+    }
+}
+
+fn eta(response: &mut String, timer: &Snapshot<'_>) {
+    let comparison = timer.current_comparison();
+    let (finish, _) = current_pace::predict_wall_clock_time(timer, comparison);
+    let Some(finish) = finish else {
+        response.push_str("There is no prediction of when the run finishes.");
+        return;
+    };
+    let Ok(finish) = finish.time.format(WALL_CLOCK_FORMAT) else {
+        return;
+    };
+
+    match timer.current_phase() {
+        TimerPhase::NotRunning => {
+            let _ = write!(
+                response,
+                "An attempt started now is predicted to finish at {finish} UTC."
+            );
+        }
+        TimerPhase::Running | TimerPhase::Paused => {
+            let _ = write!(
+                response,
+                "The attempt is predicted to finish at {finish} UTC."
+            );
+        }
+        TimerPhase::Ended => {
+            let _ = write!(response, "The attempt finished at {finish} UTC.");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{create_run, run_with_splits, split_at, start_run},
+        Timer,
+    };
+
+    fn timer() -> Timer {
+        let mut run = create_run(&["A", "B"]);
+        run.set_game_name("Game");
+        run.set_category_name("Any%");
+        Timer::new(run).unwrap()
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(Command::parse("!PB please"), Some(Command::PersonalBest));
+        assert_eq!(Command::parse("  !splits"), Some(Command::Splits));
+        assert_eq!(Command::parse("!eta"), Some(Command::Eta));
+        assert_eq!(Command::parse("!etas"), None);
+        assert_eq!(Command::parse("pb"), None);
+        assert_eq!(Command::parse(""), None);
+    }
+
+    #[test]
+    fn ignores_other_messages() {
+        let timer = timer();
+        assert_eq!(respond("hello chat", &timer.snapshot()), None);
+    }
+
+    #[test]
+    fn responds_with_the_personal_best() {
+        let mut timer = timer();
+        assert_eq!(
+            respond("!pb", &timer.snapshot()).unwrap(),
+            "Game - Any%: There is no Personal Best yet. 0 of 0 attempts were finished.",
+        );
+
+        run_with_splits(&mut timer, &[10.0, 25.0]);
+        run_with_splits(&mut timer, &[8.0]);
+        let response = respond("!pb", &timer.snapshot()).unwrap();
+        assert!(response.starts_with("Game - Any%: The Personal Best is "));
+        assert!(response.ends_with(" 1 of 2 attempts were finished."));
+    }
+
+    #[test]
+    fn responds_with_the_splits() {
+        let mut timer = timer();
+        assert_eq!(
+            respond("!splits", &timer.snapshot()).unwrap(),
+            "Game - Any%: There is no attempt in progress.",
+        );
+
+        run_with_splits(&mut timer, &[10.0, 25.0]);
+        start_run(&mut timer);
+        split_at(&mut timer, 12.0);
+        let response = respond("!splits", &timer.snapshot()).unwrap();
+        assert!(
+            response.starts_with("Game - Any%: Segment 2 of 2 (B). The current pace is "),
+            "{response}",
+        );
+        assert!(
+            response.ends_with(" behind the Personal Best."),
+            "{response}"
+        );
+    }
+
+    #[test]
+    fn responds_with_the_eta() {
+        let mut timer = timer();
+        assert_eq!(
+            respond("!eta", &timer.snapshot()).unwrap(),
+            "Game - Any%: There is no prediction of when the run finishes.",
+        );
+
+        run_with_splits(&mut timer, &[10.0, 25.0]);
+        let response = respond("!eta", &timer.snapshot()).unwrap();
+        assert!(
+            response.starts_with("Game - Any%: An attempt started now is predicted to finish at "),
+            "{response}",
+        );
+        assert!(response.ends_with(" UTC."), "{response}");
+    }
+}
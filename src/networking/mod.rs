@@ -3,7 +3,15 @@
 //! and Speedrun.com to query and submit to the leaderboards of most games. The
 //! module is optional and is not compiled in by default.
 
+#[cfg(feature = "chat")]
+pub mod chat;
+#[cfg(feature = "ntp")]
+pub mod ntp;
+#[cfg(feature = "obs")]
+pub mod obs;
 #[cfg(feature = "std")]
 pub mod server_protocol;
+#[cfg(feature = "std")]
+pub mod server_state;
 #[cfg(feature = "networking")]
 pub mod splits_io;
@@ -0,0 +1,331 @@
+//! The `ntp` module synchronizes the clock used by
+//! [`AtomicDateTime::now`](crate::AtomicDateTime::now) with a time server via
+//! the Network Time Protocol. A successful synchronization determines the
+//! offset of the system clock, which then gets applied to all the Atomic Date
+//! Times created afterwards. If the offset is known precisely enough, the
+//! Atomic Date Times are also marked as synchronized with an atomic clock.
+
+use crate::{platform::utc_now, TimeSpan};
+use snafu::ResultExt;
+use std::{
+    io,
+    net::UdpSocket,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+/// The time server that is used by default.
+pub const DEFAULT_SERVER: &str = "pool.ntp.org:123";
+
+/// The amount of seconds between the NTP epoch (1900) and the Unix epoch
+/// (1970).
+const NTP_UNIX_EPOCH_OFFSET: i128 = 2_208_988_800;
+const PACKET_LEN: usize = 48;
+
+const NOT_SYNCED: u8 = 0;
+const OFFSET_ONLY: u8 = 1;
+const SYNCED: u8 = 2;
+
+static OFFSET_NANOS: AtomicI64 = AtomicI64::new(0);
+static STATE: AtomicU8 = AtomicU8::new(NOT_SYNCED);
+
+/// Describes an error that happened when synchronizing with a time server.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to communicate with the time server.
+    Io {
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// The response of the time server is too short.
+    ResponseTooShort,
+    /// The response of the time server doesn't belong to the request that was
+    /// sent.
+    UnexpectedResponse,
+    /// The time server isn't synchronized itself.
+    ServerNotSynchronized,
+}
+
+/// The result of querying a time server.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    /// The offset that needs to be applied to the system clock in order to get
+    /// the time of the time server.
+    pub offset: TimeSpan,
+    /// The time it took for the request to reach the time server and for its
+    /// response to come back, not including the time the server took to
+    /// respond.
+    pub round_trip_delay: TimeSpan,
+}
+
+impl Measurement {
+    /// The maximum error of the offset, which is half the round trip delay.
+    pub fn uncertainty(&self) -> TimeSpan {
+        TimeSpan::from_seconds(0.5 * self.round_trip_delay.total_seconds())
+    }
+}
+
+/// Synchronizes the clock used for Atomic Date Times with a time server.
+#[derive(Clone, Debug)]
+pub struct TimeSync {
+    /// The address of the time server, including the port.
+    pub server: String,
+    /// The maximum uncertainty of the offset for the clock to be considered
+    /// synchronized with an atomic clock.
+    pub tolerance: TimeSpan,
+    /// How long to wait for the time server to respond.
+    pub timeout: Duration,
+}
+
+impl Default for TimeSync {
+    fn default() -> Self {
+        Self {
+            server: DEFAULT_SERVER.into(),
+            tolerance: TimeSpan::from_milliseconds(100.0),
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl TimeSync {
+    /// Creates a new time synchronization with the default time server.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Queries the time server for the offset of the system clock without
+    /// applying it.
+    pub fn query(&self) -> Result<Measurement, Error> {
+        let socket = UdpSocket::bind("0.0.0.0:0").context(Io)?;
+        socket.set_read_timeout(Some(self.timeout)).context(Io)?;
+        socket.connect(&*self.server).context(Io)?;
+
+        let mut request = [0; PACKET_LEN];
+        // Leap Indicator 0, Version 4, Mode 3 (Client)
+        request[0] = 0x23;
+        let sent_at = unix_nanos_now();
+        request[40..48].copy_from_slice(&to_ntp_timestamp(sent_at).to_be_bytes());
+
+        socket.send(&request).context(Io)?;
+
+        let mut response = [0; PACKET_LEN];
+        let len = socket.recv(&mut response).context(Io)?;
+        let received_at = unix_nanos_now();
+
+        parse_response(&request, &response[..len], sent_at, received_at)
+    }
+
+    /// Queries the time server and applies the offset to all the Atomic Date
+    /// Times created from now on. They are marked as synchronized with an
+    /// atomic clock if the uncertainty of the offset is within the tolerance.
+    /// If the query fails, the previous offset is kept.
+    pub fn sync(&self) -> Result<Measurement, Error> {
+        let measurement = self.query()?;
+        let is_synced = measurement.uncertainty() <= self.tolerance;
+        apply(measurement.offset, is_synced);
+        Ok(measurement)
+    }
+
+    /// Starts a background thread that synchronizes with the time server
+    /// repeatedly in the interval specified. The thread stops once the
+    /// returned [`SyncService`] is dropped.
+    pub fn spawn(self, interval: Duration) -> SyncService {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = thread::spawn({
+            let stop = stop.clone();
+            move || {
+                while !stop.load(Ordering::Acquire) {
+                    let _ = self.sync();
+                    let next_sync = Instant::now() + interval;
+                    while !stop.load(Ordering::Acquire) {
+                        let Some(remaining) = next_sync.checked_duration_since(Instant::now())
+                        else {
+                            break;
+                        };
+                        thread::park_timeout(remaining);
+                    }
+                }
+            }
+        });
+        SyncService {
+            stop,
+            thread: Some(thread),
+        }
+    }
+}
+
+/// A background thread that repeatedly synchronizes with a time server. The
+/// thread is stopped when this is dropped.
+pub struct SyncService {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for SyncService {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Returns the offset of the system clock determined by the last successful
+/// synchronization and whether it is considered to be synchronized with an
+/// atomic clock.
+pub fn clock_offset() -> Option<(TimeSpan, bool)> {
+    let state = STATE.load(Ordering::Acquire);
+    if state == NOT_SYNCED {
+        return None;
+    }
+    let offset = OFFSET_NANOS.load(Ordering::Relaxed);
+    Some((nanos_to_time_span(offset), state == SYNCED))
+}
+
+/// Forgets the offset of the system clock, so that Atomic Date Times are
+/// based on the system clock again.
+pub fn reset() {
+    STATE.store(NOT_SYNCED, Ordering::Release);
+}
+
+fn apply(offset: TimeSpan, is_synced: bool) {
+    let nanos = offset.to_duration().whole_nanoseconds() as i64;
+    OFFSET_NANOS.store(nanos, Ordering::Relaxed);
+    STATE.store(
+        if is_synced { SYNCED } else { OFFSET_ONLY },
+        Ordering::Release,
+    );
+}
+
+fn nanos_to_time_span(nanos: i64) -> TimeSpan {
+    crate::platform::Duration::nanoseconds(nanos).into()
+}
+
+fn unix_nanos_now() -> i128 {
+    utc_now().unix_timestamp_nanos()
+}
+
+const fn to_ntp_timestamp(unix_nanos: i128) -> u64 {
+    let nanos = unix_nanos + NTP_UNIX_EPOCH_OFFSET * 1_000_000_000;
+    let seconds = nanos.div_euclid(1_000_000_000) as u64;
+    let fraction = ((nanos.rem_euclid(1_000_000_000) as u128) << 32) / 1_000_000_000;
+    (seconds << 32) | fraction as u64
+}
+
+const fn from_ntp_timestamp(timestamp: u64) -> i128 {
+    let mut seconds = (timestamp >> 32) as i128;
+    // Timestamps in the lower half of the range belong to the next era, which
+    // starts in 2036.
+    if seconds < 0x8000_0000 {
+        seconds += 1 << 32;
+    }
+    let fraction = ((timestamp & 0xFFFF_FFFF) as i128 * 1_000_000_000) >> 32;
+    (seconds - NTP_UNIX_EPOCH_OFFSET) * 1_000_000_000 + fraction
+}
+
+fn read_timestamp(packet: &[u8], offset: usize) -> u64 {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(&packet[offset..offset + 8]);
+    u64::from_be_bytes(buf)
+}
+
+fn parse_response(
+    request: &[u8],
+    response: &[u8],
+    sent_at: i128,
+    received_at: i128,
+) -> Result<Measurement, Error> {
+    if response.len() < PACKET_LEN {
+        return Err(Error::ResponseTooShort);
+    }
+    if response[24..32] != request[40..48] || response[0] & 0x7 != 4 {
+        return Err(Error::UnexpectedResponse);
+    }
+    // A Leap Indicator of 3 marks an unsynchronized clock and stratum 0 is used
+    // for Kiss-o'-Death packets.
+    if response[0] >> 6 == 3 || response[1] == 0 {
+        return Err(Error::ServerNotSynchronized);
+    }
+
+    let server_received_at = from_ntp_timestamp(read_timestamp(response, 32));
+    let server_sent_at = from_ntp_timestamp(read_timestamp(response, 40));
+
+    let offset = ((server_received_at - sent_at) + (server_sent_at - received_at)) / 2;
+    let round_trip_delay = (received_at - sent_at) - (server_sent_at - server_received_at);
+
+    Ok(Measurement {
+        offset: nanos_to_time_span(offset as i64),
+        round_trip_delay: nanos_to_time_span(round_trip_delay.max(0) as i64),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: i128 = 1_000_000_000;
+
+    fn response(request: &[u8], received: i128, sent: i128) -> [u8; PACKET_LEN] {
+        let mut response = [0; PACKET_LEN];
+        response[0] = 0x24;
+        response[1] = 1;
+        response[24..32].copy_from_slice(&request[40..48]);
+        response[32..40].copy_from_slice(&to_ntp_timestamp(received).to_be_bytes());
+        response[40..48].copy_from_slice(&to_ntp_timestamp(sent).to_be_bytes());
+        response
+    }
+
+    #[test]
+    fn timestamp_roundtrip() {
+        let now = 1_700_000_000 * SECOND + 123_456_789;
+        let back = from_ntp_timestamp(to_ntp_timestamp(now));
+        assert!((back - now).abs() <= 1);
+    }
+
+    #[test]
+    fn calculates_offset_and_delay() {
+        let sent_at = 1_700_000_000 * SECOND;
+        let mut request = [0; PACKET_LEN];
+        request[40..48].copy_from_slice(&to_ntp_timestamp(sent_at).to_be_bytes());
+
+        // The server is 10 seconds ahead, each way takes 50ms and the server
+        // takes 10ms to respond.
+        let server_received = sent_at + 10 * SECOND + SECOND / 20;
+        let server_sent = server_received + SECOND / 100;
+        let received_at = sent_at + SECOND / 10 + SECOND / 100;
+
+        let measurement = parse_response(
+            &request,
+            &response(&request, server_received, server_sent),
+            sent_at,
+            received_at,
+        )
+        .unwrap();
+
+        assert!((measurement.offset.total_seconds() - 10.0).abs() < 1e-6);
+        assert!((measurement.round_trip_delay.total_seconds() - 0.1).abs() < 1e-6);
+        assert!((measurement.uncertainty().total_seconds() - 0.05).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rejects_unrelated_responses() {
+        let request = [0; PACKET_LEN];
+        let mut other = [0; PACKET_LEN];
+        other[40] = 1;
+        let response = response(&other, 0, 0);
+        assert!(matches!(
+            parse_response(&request, &response, 0, 0),
+            Err(Error::UnexpectedResponse)
+        ));
+        assert!(matches!(
+            parse_response(&request, &response[..10], 0, 0),
+            Err(Error::ResponseTooShort)
+        ));
+    }
+}
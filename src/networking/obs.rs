@@ -0,0 +1,343 @@
+//! The `obs` module helps with controlling [OBS Studio](https://obsproject.com)
+//! through version 5 of the obs-websocket protocol, based on the events of the
+//! timer. This allows switching to a different scene when an attempt gets
+//! started or showing a source when the run is finished, for example. The
+//! reactions to the events are configured through a list of [`Rules`], which
+//! can be stored alongside the rest of the configuration.
+//!
+//! The module doesn't establish the WebSocket connection on its own, so that
+//! any WebSocket client can be used. The connection works like this:
+//!
+//! 1. Connect to obs-websocket, which listens on [`DEFAULT_ADDRESS`] by
+//!    default.
+//! 2. OBS sends a `Hello` message. Pass it to [`identify`] and send the
+//!    `Identify` message returned back to OBS.
+//! 3. For each [`Event`] of the timer, send the message returned by
+//!    [`Rules::message_for`], if there is any.
+//!
+//! The responses OBS sends can be ignored.
+
+use crate::{event::Event, platform::prelude::*};
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use snafu::ResultExt;
+
+/// The address obs-websocket listens on by default.
+pub const DEFAULT_ADDRESS: &str = "ws://localhost:4455";
+
+/// Describes an error that happened when identifying with OBS.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+#[non_exhaustive]
+pub enum Error {
+    /// The message is not a valid `Hello` message.
+    InvalidHello {
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// The message is a different message than the `Hello` message.
+    NotHello,
+    /// OBS requires a password, but none was provided.
+    PasswordRequired,
+}
+
+/// The events of the timer that rules can react to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Trigger {
+    /// An attempt has been started.
+    RunStarted,
+    /// The final split happened.
+    RunFinished,
+    /// The attempt has been reset.
+    Reset,
+}
+
+impl Trigger {
+    /// Determines the trigger that the event provided corresponds to, if any.
+    pub const fn from_event(event: Event) -> Option<Self> {
+        Some(match event {
+            Event::Started => Self::RunStarted,
+            Event::Finished => Self::RunFinished,
+            Event::Reset => Self::Reset,
+            _ => return None,
+        })
+    }
+}
+
+/// An action that OBS performs when a rule gets triggered.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Switches the program scene to the scene provided.
+    SwitchScene {
+        /// The name of the scene.
+        scene: String,
+    },
+    /// Shows or hides a source within a scene.
+    SetSourceVisibility {
+        /// The name of the scene that contains the source.
+        scene: String,
+        /// The name of the source.
+        source: String,
+        /// Specifies whether the source gets shown or hidden.
+        visible: bool,
+    },
+}
+
+/// A rule that performs an action in OBS when the timer triggers it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    /// The trigger that causes the action to be performed.
+    pub trigger: Trigger,
+    /// The action to perform.
+    pub action: Action,
+}
+
+/// The list of rules that determine how OBS reacts to the events of the timer.
+/// The actions of all the rules that get triggered by the same event are
+/// performed in the order of the rules.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Rules(pub Vec<Rule>);
+
+impl Rules {
+    /// Creates the message to send to OBS in reaction to the event provided.
+    /// `None` is returned if none of the rules get triggered by the event.
+    pub fn message_for(&self, event: Event) -> Option<String> {
+        let trigger = Trigger::from_event(event)?;
+
+        let mut requests = Vec::new();
+        for rule in self.0.iter().filter(|rule| rule.trigger == trigger) {
+            match &rule.action {
+                Action::SwitchScene { scene } => requests.push(Request {
+                    data: RequestData::SetCurrentProgramScene { scene_name: scene },
+                    input_variables: None,
+                    output_variables: None,
+                }),
+                Action::SetSourceVisibility {
+                    scene,
+                    source,
+                    visible,
+                } => {
+                    // The source needs to be looked up by its name first. The
+                    // requests of a batch are executed in order, so the ID of
+                    // the source can be passed to the next request through a
+                    // variable.
+                    requests.push(Request {
+                        data: RequestData::GetSceneItemId {
+                            scene_name: scene,
+                            source_name: source,
+                        },
+                        input_variables: None,
+                        output_variables: Some(SCENE_ITEM_ID),
+                    });
+                    requests.push(Request {
+                        data: RequestData::SetSceneItemEnabled {
+                            scene_name: scene,
+                            scene_item_enabled: *visible,
+                        },
+                        input_variables: Some(SCENE_ITEM_ID),
+                        output_variables: None,
+                    });
+                }
+            }
+        }
+
+        if requests.is_empty() {
+            return None;
+        }
+
+        Some(
+            serde_json::to_string(&Message {
+                op: OP_REQUEST_BATCH,
+                d: RequestBatch {
+                    request_id: "livesplit",
+                    halt_on_failure: false,
+                    requests,
+                },
+            })
+            .unwrap(),
+        )
+    }
+}
+
+/// Creates the `Identify` message to send to OBS in reaction to the `Hello`
+/// message it sends after connecting. If OBS requires authentication, the
+/// password needs to be provided.
+pub fn identify(hello: &str, password: Option<&str>) -> Result<String, Error> {
+    let hello: Message<Hello> = serde_json::from_str(hello).context(InvalidHello)?;
+    if hello.op != OP_HELLO {
+        return Err(Error::NotHello);
+    }
+
+    let authentication = match hello.d.authentication {
+        Some(Authentication { challenge, salt }) => {
+            let password = password.ok_or(Error::PasswordRequired)?;
+            let secret = sha256_base64(&[password, &salt]);
+            Some(sha256_base64(&[&secret, &challenge]))
+        }
+        None => None,
+    };
+
+    Ok(serde_json::to_string(&Message {
+        op: OP_IDENTIFY,
+        d: Identify {
+            rpc_version: RPC_VERSION,
+            authentication,
+            event_subscriptions: 0,
+        },
+    })
+    .unwrap())
+}
+
+fn sha256_base64(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    base64_simd::STANDARD.encode_to_string(hasher.finalize())
+}
+
+const RPC_VERSION: u32 = 1;
+const OP_HELLO: u8 = 0;
+const OP_IDENTIFY: u8 = 1;
+const OP_REQUEST_BATCH: u8 = 8;
+
+#[derive(Serialize, Deserialize)]
+struct Message<T> {
+    op: u8,
+    d: T,
+}
+
+#[derive(Deserialize)]
+struct Hello {
+    authentication: Option<Authentication>,
+}
+
+#[derive(Deserialize)]
+struct Authentication {
+    challenge: String,
+    salt: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Identify {
+    rpc_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authentication: Option<String>,
+    event_subscriptions: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestBatch<'a> {
+    request_id: &'a str,
+    halt_on_failure: bool,
+    requests: Vec<Request<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Request<'a> {
+    #[serde(flatten)]
+    data: RequestData<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_variables: Option<SceneItemIdVariable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_variables: Option<SceneItemIdVariable>,
+}
+
+#[derive(Serialize)]
+#[serde(
+    tag = "requestType",
+    content = "requestData",
+    rename_all_fields = "camelCase"
+)]
+enum RequestData<'a> {
+    SetCurrentProgramScene {
+        scene_name: &'a str,
+    },
+    GetSceneItemId {
+        scene_name: &'a str,
+        source_name: &'a str,
+    },
+    SetSceneItemEnabled {
+        scene_name: &'a str,
+        scene_item_enabled: bool,
+    },
+}
+
+/// Maps the `sceneItemId` field of a request or response to a variable of the
+/// same name.
+#[derive(Copy, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SceneItemIdVariable {
+    scene_item_id: &'static str,
+}
+
+const SCENE_ITEM_ID: SceneItemIdVariable = SceneItemIdVariable {
+    scene_item_id: "sceneItemId",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifies_without_authentication() {
+        let hello = r#"{"op":0,"d":{"obsWebSocketVersion":"5.1.0","rpcVersion":1}}"#;
+        assert_eq!(
+            identify(hello, None).unwrap(),
+            r#"{"op":1,"d":{"rpcVersion":1,"eventSubscriptions":0}}"#,
+        );
+    }
+
+    #[test]
+    fn identifies_with_authentication() {
+        // The example from the documentation of the protocol.
+        let hello = r#"{"op":0,"d":{"rpcVersion":1,"authentication":{
+            "challenge":"+IxH4CnCiqpX1rM9scsNynZzbOe4KhDeYcTNS3PDaeY=",
+            "salt":"lM1GncleQOaCu9lT1yeUZhFYnqhsLLP1G5lAGo3ixaI="}}}"#;
+        assert!(matches!(
+            identify(hello, None),
+            Err(Error::PasswordRequired)
+        ));
+        assert_eq!(
+            identify(hello, Some("supersecretpassword")).unwrap(),
+            r#"{"op":1,"d":{"rpcVersion":1,"authentication":"1Ct943GAT+6YQUUX47Ia/ncufilbe6+oD6lY+5kaCu4=","eventSubscriptions":0}}"#,
+        );
+        assert!(matches!(
+            identify(r#"{"op":2,"d":{}}"#, None),
+            Err(Error::NotHello)
+        ));
+    }
+
+    #[test]
+    fn rules_are_turned_into_request_batches() {
+        let rules: Rules = serde_json::from_str(
+            r#"[
+                {"trigger":"RunStarted","action":{"SwitchScene":{"scene":"Game"}}},
+                {"trigger":"RunFinished","action":{"SetSourceVisibility":{
+                    "scene":"Game","source":"Confetti","visible":true}}},
+                {"trigger":"RunFinished","action":{"SwitchScene":{"scene":"Outro"}}}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.message_for(Event::Splitted), None);
+        assert_eq!(rules.message_for(Event::Reset), None);
+        assert_eq!(
+            rules.message_for(Event::Started).unwrap(),
+            r#"{"op":8,"d":{"requestId":"livesplit","haltOnFailure":false,"requests":[{"requestType":"SetCurrentProgramScene","requestData":{"sceneName":"Game"}}]}}"#,
+        );
+        assert_eq!(
+            rules.message_for(Event::Finished).unwrap(),
+            concat!(
+                r#"{"op":8,"d":{"requestId":"livesplit","haltOnFailure":false,"requests":["#,
+                r#"{"requestType":"GetSceneItemId","requestData":{"sceneName":"Game","sourceName":"Confetti"},"outputVariables":{"sceneItemId":"sceneItemId"}},"#,
+                r#"{"requestType":"SetSceneItemEnabled","requestData":{"sceneName":"Game","sceneItemEnabled":true},"inputVariables":{"sceneItemId":"sceneItemId"}},"#,
+                r#"{"requestType":"SetCurrentProgramScene","requestData":{"sceneName":"Outro"}}]}}"#,
+            ),
+        );
+    }
+}
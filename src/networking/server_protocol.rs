@@ -51,15 +51,13 @@
 
 use crate::{
     event::{self, Event},
+    run::StatsSummary,
     timing::formatter::{self, TimeFormatter, ASCII_MINUS},
     TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 
 /// Handles an incoming command and returns the response to be sent.
-pub async fn handle_command<S: event::CommandSink + event::TimerQuery>(
-    command: &str,
-    command_sink: &S,
-) -> String {
+pub async fn handle_command<S: event::TimerControl>(command: &str, command_sink: &S) -> String {
     let response = match serde_json::from_str::<Command>(command) {
         Ok(command) => command.handle(command_sink).await.into(),
         Err(e) => CommandResult::Error(Error::InvalidCommand {
@@ -153,6 +151,10 @@ enum Command {
         timing_method: Option<TimingMethod>,
     },
     GetCurrentState,
+    #[serde(rename_all = "camelCase")]
+    GetStatsSummary {
+        timing_method: Option<TimingMethod>,
+    },
     Ping,
 }
 
@@ -171,6 +173,7 @@ enum Response {
     None,
     String(String),
     State(State),
+    StatsSummary(StatsSummary),
 }
 
 #[derive(serde_derive::Serialize)]
@@ -193,10 +196,7 @@ impl Error {
 }
 
 impl Command {
-    async fn handle<E: event::CommandSink + event::TimerQuery>(
-        &self,
-        command_sink: &E,
-    ) -> Result<Response, Error> {
+    async fn handle<E: event::TimerControl>(&self, command_sink: &E) -> Result<Response, Error> {
         Ok(match self {
             Command::SplitOrStart => {
                 command_sink.split_or_start().await.map_err(Error::timer)?;
@@ -356,6 +356,12 @@ impl Command {
                     TimerPhase::Ended => State::Ended,
                 })
             }
+            Command::GetStatsSummary { timing_method } => {
+                let guard = command_sink.get_timer();
+                let timer = &*guard;
+                let timing_method = timing_method.unwrap_or_else(|| timer.current_timing_method());
+                Response::StatsSummary(timer.run().stats_summary(timing_method))
+            }
             Command::Ping => Response::None,
         })
     }
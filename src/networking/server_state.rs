@@ -0,0 +1,231 @@
+//! The server state is a versioned JSON representation of the state of the
+//! timer and optionally the layout, meant to be sent to external overlays and
+//! other remote frontends. Every document carries a `schema_version` field, so
+//! that consumers can detect when the structure of the state changes in an
+//! incompatible way.
+//!
+//! A document looks like this:
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "timer": {
+//!     "phase": "Running",
+//!     "current_timing_method": "RealTime",
+//!     "current_comparison": "Personal Best",
+//!     "current_split_index": 0,
+//!     "real_time": "00:00:12.345000000",
+//!     "game_time": null,
+//!     "attempt_times": { ... }
+//!   },
+//!   "layout": { ... },
+//!   "images": [{ "id": "e3b0c442...", "data": "iVBORw0KGgo..." }]
+//! }
+//! ```
+//!
+//! The states of the components only reference images by their [`ImageId`],
+//! which is a hash of the image's contents. The data of the images is sent in
+//! the `images` field, but only the first time an image is referenced. The
+//! [`SentImages`] keep track of which images the consumer already received.
+//! Consumers are expected to store the images by their ID, so that they can be
+//! looked up whenever they are referenced. The field is omitted if there are no
+//! new images.
+//!
+//! The version is only increased when fields are removed, renamed or change
+//! their meaning. Adding new fields is not considered a breaking change, so
+//! consumers should ignore fields they don't know about.
+
+use crate::{
+    layout::LayoutState,
+    platform::prelude::*,
+    settings::{Image, ImageCache, ImageId},
+    timing::{AttemptTimes, Snapshot},
+    TimeSpan, TimerPhase, TimingMethod,
+};
+use serde_derive::{Deserialize, Serialize};
+use snafu::ResultExt;
+use std::collections::HashSet;
+
+/// The current version of the schema. Documents with a different version are
+/// rejected when decoding.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Describes an error that happened when decoding a server state document.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// The document is not valid JSON or doesn't match the schema.
+    Json {
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// The document uses a version of the schema that is not supported.
+    UnsupportedVersion {
+        /// The version of the schema used by the document.
+        version: u32,
+    },
+}
+
+/// A server state document, describing the state of the timer and optionally
+/// the layout.
+#[derive(Serialize, Deserialize)]
+pub struct ServerState {
+    /// The version of the schema used by the document.
+    pub schema_version: u32,
+    /// The state of the timer.
+    pub timer: TimerState,
+    /// The state of the layout, if it is part of the document.
+    pub layout: Option<LayoutState>,
+    /// The data of the images referenced by the layout state that the consumer
+    /// hasn't received yet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<ImageData>,
+}
+
+/// The data of an image that is sent along with a [`ServerState`] document.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImageData {
+    /// The ID of the image, as referenced by the states of the components.
+    pub id: ImageId,
+    /// The image itself. This is encoded as base64.
+    pub data: Image,
+}
+
+/// Keeps track of the images that a consumer of [`ServerState`] documents
+/// already received, so that the data of each image is only sent once.
+#[derive(Default, Debug)]
+pub struct SentImages {
+    ids: HashSet<ImageId>,
+}
+
+impl SentImages {
+    /// Creates a new tracker for a consumer that hasn't received any images
+    /// yet.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Looks up the data of all the images referenced by the layout state that
+    /// haven't been sent yet and marks them as sent. Images that are not in the
+    /// image cache are skipped and are attempted to be sent again next time.
+    pub fn collect(&mut self, layout: &LayoutState, image_cache: &ImageCache) -> Vec<ImageData> {
+        let mut images = Vec::new();
+        layout.for_each_image_id(|id| {
+            if self.ids.contains(id) {
+                return;
+            }
+            if let Some(image) = image_cache.lookup(id) {
+                self.ids.insert(*id);
+                images.push(ImageData {
+                    id: *id,
+                    data: image.clone(),
+                });
+            }
+        });
+        images
+    }
+
+    /// Returns whether the data of the image has already been sent.
+    pub fn contains(&self, id: &ImageId) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Forgets about all the images sent, so that they are all sent again. This
+    /// is useful when the consumer reconnects.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+    }
+}
+
+/// The state of the timer as part of a [`ServerState`] document.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimerState {
+    /// The current phase of the timer.
+    pub phase: TimerPhase,
+    /// The timing method that is currently selected.
+    pub current_timing_method: TimingMethod,
+    /// The comparison that is currently selected.
+    pub current_comparison: String,
+    /// The index of the segment that is currently active. This is `None` if
+    /// there is no attempt in progress.
+    pub current_split_index: Option<usize>,
+    /// The current Real Time of the timer.
+    pub real_time: Option<TimeSpan>,
+    /// The current Game Time of the timer. This is `None` if the Game Time is
+    /// not initialized.
+    pub game_time: Option<TimeSpan>,
+    /// The wall clock information about the active attempt. This is `None` if
+    /// there is no attempt in progress.
+    pub attempt_times: Option<AttemptTimes>,
+}
+
+impl TimerState {
+    /// Captures the state of the timer.
+    pub fn new(timer: &Snapshot<'_>) -> Self {
+        let time = timer.current_time();
+        Self {
+            phase: timer.current_phase(),
+            current_timing_method: timer.current_timing_method(),
+            current_comparison: timer.current_comparison().into(),
+            current_split_index: timer.current_split_index(),
+            real_time: time.real_time,
+            game_time: time.game_time,
+            attempt_times: timer.attempt_times(),
+        }
+    }
+}
+
+impl ServerState {
+    /// Captures the state of the timer and optionally the layout, using the
+    /// current version of the schema.
+    pub fn new(timer: &Snapshot<'_>, layout: Option<LayoutState>) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            timer: TimerState::new(timer),
+            layout,
+            images: Vec::new(),
+        }
+    }
+
+    /// Captures the state of the timer and the layout, using the current
+    /// version of the schema. The data of all the images referenced by the
+    /// layout that the consumer hasn't received yet is included as well.
+    pub fn with_images(
+        timer: &Snapshot<'_>,
+        layout: LayoutState,
+        image_cache: &ImageCache,
+        sent_images: &mut SentImages,
+    ) -> Self {
+        let images = sent_images.collect(&layout, image_cache);
+        Self {
+            schema_version: SCHEMA_VERSION,
+            timer: TimerState::new(timer),
+            layout: Some(layout),
+            images,
+        }
+    }
+
+    /// Encodes the document as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Decodes a document from JSON. Documents using a different version of
+    /// the schema are rejected.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        #[derive(Deserialize)]
+        struct Version {
+            schema_version: u32,
+        }
+
+        let Version { schema_version } = serde_json::from_str(json).context(Json)?;
+        if schema_version != SCHEMA_VERSION {
+            return Err(Error::UnsupportedVersion {
+                version: schema_version,
+            });
+        }
+        serde_json::from_str(json).context(Json)
+    }
+}
@@ -5,8 +5,10 @@
 
 use crate::{
     run::{
+        editor::ImportedComparison,
         parser::composite,
         saver::{self, livesplit::IoWrite},
+        AddComparisonError, Editor,
     },
     Run, Timer,
 };
@@ -33,6 +35,25 @@ pub enum DownloadError {
     },
 }
 
+/// Describes an error that happened when importing a run from Splits.io as a
+/// comparison. This may either be because the run couldn't be downloaded or
+/// because the comparison couldn't be added.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+#[non_exhaustive]
+pub enum ImportComparisonError {
+    /// Failed to download the run.
+    DownloadRun {
+        /// The underlying download error.
+        source: DownloadError,
+    },
+    /// Failed to add the comparison.
+    AddComparison {
+        /// The underlying error.
+        source: AddComparisonError,
+    },
+}
+
 /// Describes an error that happened when uploading a run to Splits.io. This may
 /// either be because the upload itself had a problem or because the run itself
 /// couldn't be saved.
@@ -59,6 +80,23 @@ pub async fn download_run(
     Ok(run.into_owned())
 }
 
+/// Asynchronously downloads a run from Splits.io based on its Splits.io ID and
+/// imports its Personal Best as a comparison with the name provided into the
+/// run that is being edited. This allows racing against the public splits of
+/// another runner. The segments are matched up by their names. Segments that
+/// couldn't be matched up are reported back.
+pub async fn import_comparison(
+    client: &Client,
+    id: &str,
+    editor: &mut Editor,
+    comparison: &str,
+) -> Result<ImportedComparison, ImportComparisonError> {
+    let parsed = download_run(client, id).await.context(DownloadRun)?;
+    editor
+        .import_comparison(&parsed.run, comparison)
+        .context(AddComparison)
+}
+
 /// Asynchronously uploads a run to Splits.io. An object representing the ID of
 /// the uploaded run and its claim token gets returned when the run was
 /// successfully uploaded.
@@ -11,9 +11,20 @@ cfg_if::cfg_if! {
                 x.powf(y)
             }
         }
+
+        pub mod f64 {
+            #[inline(always)]
+            pub fn sqrt(x: f64) -> f64 {
+                x.sqrt()
+            }
+        }
     } else {
         pub mod f32 {
             pub use libm::{fabsf as abs, powf};
         }
+
+        pub mod f64 {
+            pub use libm::sqrt;
+        }
     }
 }
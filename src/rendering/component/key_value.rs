@@ -2,15 +2,35 @@ use crate::{
     component::key_value::State,
     layout::{LayoutDirection, LayoutState},
     rendering::{
+        consts::{DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_BOTTOM},
         font::{AbbreviatedLabel, CachedLabel},
         resource::ResourceAllocator,
-        RenderContext,
+        solid, Layer, RenderContext,
     },
 };
 
+const SECONDARY_VALUE_SEPARATOR: &str = " / ";
+
 pub struct Cache<L> {
     key: AbbreviatedLabel<L>,
     value: CachedLabel<L>,
+    separator: CachedLabel<L>,
+    secondary_value: CachedLabel<L>,
+    rows: Vec<RowCache<L>>,
+}
+
+struct RowCache<L> {
+    key: AbbreviatedLabel<L>,
+    value: CachedLabel<L>,
+}
+
+impl<L> RowCache<L> {
+    const fn new() -> Self {
+        Self {
+            key: AbbreviatedLabel::new(),
+            value: CachedLabel::new(),
+        }
+    }
 }
 
 impl<L> Cache<L> {
@@ -18,6 +38,9 @@ impl<L> Cache<L> {
         Self {
             key: AbbreviatedLabel::new(),
             value: CachedLabel::new(),
+            separator: CachedLabel::new(),
+            secondary_value: CachedLabel::new(),
+            rows: Vec::new(),
         }
     }
 }
@@ -25,11 +48,56 @@ impl<L> Cache<L> {
 pub(in crate::rendering) fn render<A: ResourceAllocator>(
     cache: &mut Cache<A::Label>,
     context: &mut RenderContext<'_, A>,
-    dim: [f32; 2],
+    [width, height]: [f32; 2],
     component: &State,
     layout_state: &LayoutState,
 ) {
-    context.render_background(dim, &component.background);
+    context.render_background([width, height], &component.background);
+
+    let is_horizontal = layout_state.direction == LayoutDirection::Horizontal;
+
+    // The additional rows are placed next to the key and the value in
+    // horizontal layouts and below them in vertical layouts.
+    let row_count = component.rows.len() as f32;
+    let (main_dim, row_dim, delta) = if is_horizontal {
+        let row_width = width / (row_count + 1.0);
+        ([row_width, height], [row_width, height], [row_width, 0.0])
+    } else {
+        let main_height = height - row_count * DEFAULT_COMPONENT_HEIGHT;
+        (
+            [width, main_height],
+            [width, DEFAULT_COMPONENT_HEIGHT],
+            [0.0, main_height],
+        )
+    };
+    let [main_width, main_height] = main_dim;
+
+    let mut value_end_x = main_width - PADDING;
+    if let Some(secondary_value) = &component.secondary_value {
+        let layer = Layer::from_updates_frequently(component.updates_frequently);
+        let y = main_height + TEXT_ALIGN_BOTTOM;
+        value_end_x = context.render_numbers(
+            secondary_value,
+            &mut cache.secondary_value,
+            layer,
+            [value_end_x, y],
+            DEFAULT_TEXT_SIZE,
+            solid(
+                &component
+                    .secondary_value_color
+                    .unwrap_or(layout_state.text_color),
+            ),
+        );
+        value_end_x = context.render_numbers(
+            SECONDARY_VALUE_SEPARATOR,
+            &mut cache.separator,
+            layer,
+            [value_end_x, y],
+            DEFAULT_TEXT_SIZE,
+            solid(&layout_state.text_color),
+        );
+    }
+
     context.render_key_value_component(
         &component.key,
         &component.key_abbreviations,
@@ -37,9 +105,44 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         &component.value,
         &mut cache.value,
         component.updates_frequently,
-        dim,
+        main_dim,
+        value_end_x,
         component.key_color.unwrap_or(layout_state.text_color),
         component.value_color.unwrap_or(layout_state.text_color),
-        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal,
+        component.display_two_rows || is_horizontal,
     );
+
+    if component.rows.is_empty() {
+        return;
+    }
+
+    let transform = context.transform;
+
+    cache.rows.resize_with(component.rows.len(), RowCache::new);
+
+    context.translate(delta[0], delta[1]);
+    let delta = if is_horizontal {
+        delta
+    } else {
+        [0.0, DEFAULT_COMPONENT_HEIGHT]
+    };
+
+    for (row, row_cache) in component.rows.iter().zip(&mut cache.rows) {
+        context.render_key_value_component(
+            &row.key,
+            &[],
+            &mut row_cache.key,
+            &row.value,
+            &mut row_cache.value,
+            component.updates_frequently,
+            row_dim,
+            row_dim[0] - PADDING,
+            component.key_color.unwrap_or(layout_state.text_color),
+            row.value_color.unwrap_or(layout_state.text_color),
+            is_horizontal,
+        );
+        context.translate(delta[0], delta[1]);
+    }
+
+    context.transform = transform;
 }
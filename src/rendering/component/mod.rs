@@ -10,6 +10,10 @@ pub mod blank_space;
 pub mod detailed_timer;
 pub mod graph;
 pub mod key_value;
+pub mod pace_graph;
+pub mod pb_progression;
+pub mod run_prediction;
+pub mod segment_sparkline;
 pub mod separator;
 pub mod splits;
 pub mod text;
@@ -20,6 +24,10 @@ pub enum Cache<L> {
     Empty,
     DetailedTimer(detailed_timer::Cache<L>),
     KeyValue(key_value::Cache<L>),
+    PaceGraph(pace_graph::Cache<L>),
+    PbProgression(pb_progression::Cache<L>),
+    RunPrediction(run_prediction::Cache<L>),
+    SegmentSparkline(segment_sparkline::Cache<L>),
     Splits(splits::Cache<L>),
     Text(text::Cache<L>),
     Timer(timer::Cache<L>),
@@ -47,6 +55,12 @@ impl<L> Cache<L> {
         match component {
             ComponentState::DetailedTimer(_) => Self::DetailedTimer(detailed_timer::Cache::new()),
             ComponentState::KeyValue(_) => Self::KeyValue(key_value::Cache::new()),
+            ComponentState::PaceGraph(_) => Self::PaceGraph(pace_graph::Cache::new()),
+            ComponentState::PbProgression(_) => Self::PbProgression(pb_progression::Cache::new()),
+            ComponentState::RunPrediction(_) => Self::RunPrediction(run_prediction::Cache::new()),
+            ComponentState::SegmentSparkline(_) => {
+                Self::SegmentSparkline(segment_sparkline::Cache::new())
+            }
             ComponentState::Splits(_) => Self::Splits(splits::Cache::new()),
             ComponentState::Text(_) => Self::Text(text::Cache::new()),
             ComponentState::Timer(_) => Self::Timer(timer::Cache::new()),
@@ -62,6 +76,10 @@ impl<L> Cache<L> {
     accessors! {
         DetailedTimer detailed_timer,
         KeyValue key_value,
+        PaceGraph pace_graph,
+        PbProgression pb_progression,
+        RunPrediction run_prediction,
+        SegmentSparkline segment_sparkline,
         Splits splits,
         Text text,
         Timer timer,
@@ -82,7 +100,11 @@ pub fn width(component: &ComponentState) -> f32 {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
         ComponentState::DetailedTimer(_) => 7.0,
         ComponentState::Graph(_) => 7.0,
-        ComponentState::KeyValue(_) => 6.0,
+        ComponentState::KeyValue(state) => (state.rows.len() + 1) as f32 * 6.0,
+        ComponentState::PaceGraph(_) => 6.0,
+        ComponentState::PbProgression(_) => 6.0,
+        ComponentState::RunPrediction(state) => state.targets.len() as f32 * 6.0,
+        ComponentState::SegmentSparkline(_) => 6.0,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
             let column_count = 2.0; // FIXME: Not always 2.
@@ -104,12 +126,23 @@ pub fn height(component: &ComponentState) -> f32 {
         }
         ComponentState::Graph(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::KeyValue(state) => {
-            if state.display_two_rows {
+            (if state.display_two_rows {
                 TWO_ROW_HEIGHT
             } else {
                 DEFAULT_COMPONENT_HEIGHT
-            }
+            }) + state.rows.len() as f32 * DEFAULT_COMPONENT_HEIGHT
         }
+        ComponentState::PaceGraph(_) => DEFAULT_COMPONENT_HEIGHT,
+        ComponentState::PbProgression(_) => DEFAULT_COMPONENT_HEIGHT,
+        ComponentState::RunPrediction(state) => {
+            state.targets.len() as f32
+                * if state.display_two_rows {
+                    TWO_ROW_HEIGHT
+                } else {
+                    DEFAULT_COMPONENT_HEIGHT
+                }
+        }
+        ComponentState::SegmentSparkline(_) => DEFAULT_COMPONENT_HEIGHT,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
             state.splits.len() as f32
@@ -158,6 +191,18 @@ pub(super) fn render<A: ResourceAllocator>(
         ComponentState::KeyValue(component) => {
             key_value::render(cache.key_value(), context, dim, component, state)
         }
+        ComponentState::PaceGraph(component) => {
+            pace_graph::render(cache.pace_graph(), context, dim, component, state)
+        }
+        ComponentState::PbProgression(component) => {
+            pb_progression::render(cache.pb_progression(), context, dim, component, state)
+        }
+        ComponentState::RunPrediction(component) => {
+            run_prediction::render(cache.run_prediction(), context, dim, component, state)
+        }
+        ComponentState::SegmentSparkline(component) => {
+            segment_sparkline::render(cache.segment_sparkline(), context, dim, component, state)
+        }
         ComponentState::Separator(component) => {
             cache.make_empty();
             separator::render(context, dim, component, state)
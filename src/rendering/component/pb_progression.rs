@@ -0,0 +1,87 @@
+use crate::{
+    component::pb_progression::State,
+    layout::LayoutState,
+    rendering::{
+        consts::{vertical_padding, DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_CENTER},
+        font::CachedLabel,
+        resource::ResourceAllocator,
+        solid, Layer, PathBuilder, RenderContext,
+    },
+};
+
+const LINE_WIDTH: f32 = 0.05;
+const CIRCLE_RADIUS: f32 = 0.08;
+
+pub struct Cache<L> {
+    label: CachedLabel<L>,
+    value: CachedLabel<L>,
+}
+
+impl<L> Cache<L> {
+    pub const fn new() -> Self {
+        Self {
+            label: CachedLabel::new(),
+            value: CachedLabel::new(),
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    let chart_left = 0.6 * width;
+    let chart_right = width - PADDING;
+    let chart_top = vertical_padding(height) + CIRCLE_RADIUS;
+    let chart_bottom = height - chart_top;
+
+    let value_left = context.render_numbers(
+        &component.value,
+        &mut cache.value,
+        Layer::Bottom,
+        [chart_left - PADDING, 0.5 * height + TEXT_ALIGN_CENTER],
+        DEFAULT_TEXT_SIZE,
+        solid(&component.value_color.unwrap_or(layout_state.text_color)),
+    );
+
+    context.render_text_ellipsis(
+        &component.label,
+        &mut cache.label,
+        [PADDING, 0.5 * height + TEXT_ALIGN_CENTER],
+        DEFAULT_TEXT_SIZE,
+        solid(&component.label_color.unwrap_or(layout_state.text_color)),
+        value_left - PADDING,
+    );
+
+    let point = |[x, y]: [f32; 2]| {
+        [
+            chart_left + x * (chart_right - chart_left),
+            chart_top + y * (chart_bottom - chart_top),
+        ]
+    };
+
+    if component.points.len() > 1 {
+        let mut builder = context.handles.path_builder();
+        for (i, &p) in component.points.iter().enumerate() {
+            let [x, y] = point(p);
+            if i == 0 {
+                builder.move_to(x, y);
+            } else {
+                builder.line_to(x, y);
+            }
+        }
+        let line_path = builder.finish();
+        context.stroke_path(line_path, component.line_color, LINE_WIDTH, Layer::Bottom);
+    }
+
+    if let Some(&p) = component.points.last() {
+        let [x, y] = point(p);
+        let circle_path = context.handles.build_circle(x, y, CIRCLE_RADIUS);
+        context.fill_path(circle_path, component.line_color, Layer::Bottom);
+    }
+}
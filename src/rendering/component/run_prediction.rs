@@ -0,0 +1,83 @@
+use crate::{
+    component::run_prediction::State,
+    layout::{LayoutDirection, LayoutState},
+    rendering::{
+        consts::PADDING,
+        font::{AbbreviatedLabel, CachedLabel},
+        resource::ResourceAllocator,
+        RenderContext,
+    },
+};
+
+pub struct Cache<L> {
+    targets: Vec<TargetCache<L>>,
+}
+
+struct TargetCache<L> {
+    name: AbbreviatedLabel<L>,
+    value: CachedLabel<L>,
+}
+
+impl<L> TargetCache<L> {
+    const fn new() -> Self {
+        Self {
+            name: AbbreviatedLabel::new(),
+            value: CachedLabel::new(),
+        }
+    }
+}
+
+impl<L> Cache<L> {
+    pub const fn new() -> Self {
+        Self {
+            targets: Vec::new(),
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    if component.targets.is_empty() {
+        return;
+    }
+
+    let is_horizontal = layout_state.direction == LayoutDirection::Horizontal;
+    let count = component.targets.len() as f32;
+    let (target_dim, delta) = if is_horizontal {
+        ([width / count, height], [width / count, 0.0])
+    } else {
+        ([width, height / count], [0.0, height / count])
+    };
+
+    let transform = context.transform;
+
+    cache
+        .targets
+        .resize_with(component.targets.len(), TargetCache::new);
+
+    for (target, target_cache) in component.targets.iter().zip(&mut cache.targets) {
+        context.render_key_value_component(
+            &target.name,
+            &[],
+            &mut target_cache.name,
+            &target.value,
+            &mut target_cache.value,
+            target.updates_frequently,
+            target_dim,
+            target_dim[0] - PADDING,
+            component.label_color.unwrap_or(layout_state.text_color),
+            target.value_color,
+            component.display_two_rows || is_horizontal,
+        );
+        context.translate(delta[0], delta[1]);
+    }
+
+    context.transform = transform;
+}
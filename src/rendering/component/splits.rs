@@ -219,7 +219,9 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         }
     }
 
-    let icon_size = split_height - 2.0 * vertical_padding;
+    let icon_slot_size = split_height - 2.0 * vertical_padding;
+    let icon_size = component.icon_size * icon_slot_size;
+    let icon_y = icon_y + 0.5 * (icon_slot_size - icon_size);
     let icon_right = if component.has_icons {
         BOTH_PADDINGS + icon_size
     } else {
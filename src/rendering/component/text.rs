@@ -53,6 +53,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             &mut cache.label2,
             false,
             [width, height],
+            width - PADDING,
             component
                 .left_center_color
                 .unwrap_or(layout_state.text_color),
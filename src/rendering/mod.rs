@@ -559,6 +559,7 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         value_label: &mut CachedLabel<A::Label>,
         updates_frequently: bool,
         [width, height]: [f32; 2],
+        value_end_x: f32,
         key_color: Color,
         value_color: Color,
         display_two_rows: bool,
@@ -567,7 +568,7 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             value,
             value_label,
             Layer::from_updates_frequently(updates_frequently),
-            [width - PADDING, height + TEXT_ALIGN_BOTTOM],
+            [value_end_x, height + TEXT_ALIGN_BOTTOM],
             DEFAULT_TEXT_SIZE,
             solid(&value_color),
         );
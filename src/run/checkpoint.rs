@@ -0,0 +1,25 @@
+use crate::{platform::prelude::*, Time};
+
+/// A `Checkpoint` is a named point in time within a [`Segment`](super::Segment)
+/// that doesn't warrant a split of its own, such as a boss phase within a
+/// long segment. Checkpoints are not part of the segment history and don't
+/// affect any of the split times. Their time is the time of the attempt at
+/// which the checkpoint was reached, just like the split times.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Checkpoint {
+    /// The name of the checkpoint. It identifies the checkpoint within its
+    /// segment.
+    pub name: String,
+    /// The time at which the checkpoint was reached.
+    pub time: Time,
+}
+
+impl Checkpoint {
+    /// Creates a new checkpoint with the name and time provided.
+    pub fn new(name: impl Into<String>, time: Time) -> Self {
+        Self {
+            name: name.into(),
+            time,
+        }
+    }
+}
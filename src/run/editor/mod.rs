@@ -14,7 +14,7 @@ use crate::{
     Run, Segment, Time, TimeSpan, TimingMethod,
 };
 use core::{mem::swap, num::ParseIntError};
-use snafu::{OptionExt, ResultExt};
+use snafu::ResultExt;
 
 pub mod cleaning;
 mod fuzzy_list;
@@ -67,6 +67,62 @@ pub enum RenameError {
     },
 }
 
+/// Describes an Error that occurred while recomputing the times of a
+/// comparison in the Run Editor.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+#[non_exhaustive]
+pub enum ComparisonTimesError {
+    /// The comparison either doesn't exist or is generated by a comparison
+    /// generator, so its times can't be edited.
+    ComparisonNotFound,
+    /// The comparison doesn't have a final time for the selected timing
+    /// method that could be scaled.
+    NoFinalTime,
+    /// Couldn't parse the target time.
+    InvalidTime {
+        /// The underlying error.
+        source: ParseError,
+    },
+}
+
+/// Describes what happens to the times of a [`Run`] when pasting a list of
+/// segment names into the Run Editor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PastedSegmentTimes {
+    /// The times of the segments are kept. Segments that get added don't have
+    /// any times and segments that get removed take their times with them.
+    Keep = 0,
+    /// All the times are cleared, just like [`Editor::clear_times`] does.
+    Clear = 1,
+    /// All the times are cleared, just like [`Editor::clear_times`] does, but
+    /// the final time of the Personal Best is kept and split evenly across all
+    /// the segments.
+    InterpolateFromFinalTime = 2,
+}
+
+/// Describes how the segments of a [`Run`] got matched up with the segments of
+/// the Run Editor's [`Run`] when importing it as a comparison. The segments are
+/// matched up by their names, except for the last segment, which always
+/// receives the final time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportedComparison {
+    /// The names of the segments of the imported [`Run`] that couldn't be
+    /// matched up with any segment. Their split times got dropped.
+    pub unmatched_segments: Vec<String>,
+    /// The indices of the segments that didn't receive a split time from the
+    /// imported [`Run`].
+    pub missing_segments: Vec<usize>,
+}
+
+impl ImportedComparison {
+    /// Returns `true` if all the segments were matched up with each other.
+    pub fn is_exact(&self) -> bool {
+        self.unmatched_segments.is_empty() && self.missing_segments.is_empty()
+    }
+}
+
 /// The Run Editor allows modifying Runs while ensuring that all the different
 /// invariants of the Run objects are upheld no matter what kind of operations
 /// are being applied to the Run. It provides the current state of the editor as
@@ -304,6 +360,15 @@ impl Editor {
         self.raise_run_edited();
     }
 
+    /// Locks the [`Run`] to the timing method provided, for games where only
+    /// one of the timing methods is meaningful. Specify [`None`] if both timing
+    /// methods are meaningful. The comparisons are regenerated accordingly.
+    pub fn set_locked_timing_method(&mut self, method: Option<TimingMethod>) {
+        self.run.set_locked_timing_method(method);
+        self.run.regenerate_comparisons();
+        self.raise_run_edited();
+    }
+
     /// Accesses all the custom comparisons that exist on the Run.
     pub fn custom_comparisons(&self) -> &[String] {
         self.run.custom_comparisons()
@@ -526,6 +591,73 @@ impl Editor {
         self.fix();
     }
 
+    /// Creates, renames and removes segments such that the run has a segment
+    /// for each of the lines of the text provided, named after the line. This
+    /// allows pasting a route that is written down as a list of segment names.
+    /// Empty lines and surrounding whitespace are ignored. The existing
+    /// segments get renamed in order, additional segments are added to the end
+    /// and segments beyond the end of the list are removed. What happens to
+    /// the times is determined by the option provided. Nothing happens if
+    /// there are no segment names. Afterwards the first segment is the only
+    /// selected segment.
+    pub fn paste_segment_names(&mut self, names: &str, times: PastedSegmentTimes) {
+        let names: Vec<&str> = names
+            .lines()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        if names.is_empty() {
+            return;
+        }
+
+        let final_time = self
+            .run
+            .segments()
+            .last()
+            .unwrap()
+            .personal_best_split_time();
+
+        if names.len() < self.run.len() {
+            self.select_only(names.len());
+            self.select_range(self.run.len() - 1);
+            self.remove_segments();
+        }
+
+        for (index, &name) in names.iter().enumerate() {
+            if index < self.run.len() {
+                self.run.segment_mut(index).set_name(name);
+            } else {
+                self.select_only(index - 1);
+                self.insert_segment_below();
+                self.run.segment_mut(index).set_name(name);
+            }
+        }
+
+        self.select_only(0);
+
+        if times != PastedSegmentTimes::Keep {
+            self.run.clear_times();
+        }
+
+        if times == PastedSegmentTimes::InterpolateFromFinalTime {
+            let len = self.run.len();
+            for method in TimingMethod::all() {
+                let Some(final_time) = final_time[method] else {
+                    continue;
+                };
+                for (index, segment) in self.run.segments_mut().iter_mut().enumerate() {
+                    segment.personal_best_split_time_mut()[method] = Some(TimeSpan::from_seconds(
+                        final_time.total_seconds() * (index + 1) as f64 / len as f64,
+                    ));
+                }
+            }
+        }
+
+        self.times_modified();
+        self.fix();
+    }
+
     fn fix_after_deletion(&mut self, index: usize) {
         self.fix_with_timing_method(index, TimingMethod::RealTime);
         self.fix_with_timing_method(index, TimingMethod::GameTime);
@@ -755,24 +887,30 @@ impl Editor {
 
     /// Imports the Personal Best from the provided run as a comparison. The
     /// comparison can't be added if its name starts with `[Race]` or it already
-    /// exists.
+    /// exists. The segments are matched up by their names. Which segments
+    /// couldn't be matched up is reported back.
     pub fn import_comparison(
         &mut self,
         run: &Run,
         comparison: &str,
-    ) -> Result<(), AddComparisonError> {
+    ) -> Result<ImportedComparison, AddComparisonError> {
         self.run.add_custom_comparison(comparison)?;
 
-        let mut remaining_segments = self.run.segments_mut().as_mut_slice();
+        let mut imported = ImportedComparison::default();
+        let mut matched = vec![false; self.run.len()];
+        let mut next_index = 0;
 
         for segment in run.segments().iter().take(run.len().saturating_sub(1)) {
-            if let Some((segment_index, my_segment)) = remaining_segments
+            if let Some((segment_index, my_segment)) = self.run.segments_mut()[next_index..]
                 .iter_mut()
                 .enumerate()
                 .find(|(_, s)| caseless::eq(segment.name(), s.name()))
             {
                 *my_segment.comparison_mut(comparison) = segment.personal_best_split_time();
-                remaining_segments = &mut remaining_segments[segment_index + 1..];
+                matched[next_index + segment_index] = true;
+                next_index += segment_index + 1;
+            } else {
+                imported.unmatched_segments.push(segment.name().to_owned());
             }
         }
 
@@ -780,65 +918,40 @@ impl Editor {
             (self.run.segments_mut().last_mut(), run.segments().last())
         {
             *my_segment.comparison_mut(comparison) = segment.personal_best_split_time();
+            if let Some(last) = matched.last_mut() {
+                *last = true;
+            }
         }
 
+        imported.missing_segments = (0..matched.len()).filter(|&i| !matched[i]).collect();
+
         self.fix();
-        Ok(())
+        Ok(imported)
     }
 
     /// Removes the chosen custom comparison. You can't remove a Comparison
     /// Generator's Comparison or the Personal Best.
     pub fn remove_comparison(&mut self, comparison: &str) {
-        if comparison == comparison::personal_best::NAME {
-            return;
-        }
-
-        self.run
-            .custom_comparisons_mut()
-            .retain(|c| c != comparison);
-
-        if self.run.comparisons().any(|c| c == comparison) {
-            return;
+        if self.run.remove_custom_comparison(comparison) {
+            self.fix();
         }
+    }
 
-        for segment in self.run.segments_mut() {
-            segment.comparisons_mut().remove(comparison);
+    /// Clears all the times of the chosen custom comparison, while keeping the
+    /// comparison itself. You can't clear a Comparison Generator's Comparison
+    /// or the Personal Best.
+    pub fn clear_comparison(&mut self, comparison: &str) {
+        if self.run.clear_custom_comparison(comparison) {
+            self.fix();
         }
-
-        self.fix();
     }
 
     /// Renames a comparison. The comparison can't be renamed if the new name of
-    /// the comparison starts with `[Race]` or it already exists.
+    /// the comparison starts with `[Race]`, it already exists or it is the
+    /// name of a Comparison Generator. You can't rename the Personal Best.
     pub fn rename_comparison(&mut self, old: &str, new: &str) -> Result<(), RenameError> {
-        if old == new {
-            return Ok(());
-        }
-
-        self.run
-            .validate_comparison_name(new)
-            .context(InvalidName)?;
-
-        {
-            let comparison_name = self
-                .run
-                .custom_comparisons_mut()
-                .iter_mut()
-                .find(|c| *c == old)
-                .context(OldNameNotFound)?;
-
-            comparison_name.clear();
-            comparison_name.push_str(new);
-        }
-
-        for segment in self.run.segments_mut() {
-            if let Some(time) = segment.comparisons_mut().remove(old) {
-                *segment.comparison_mut(new) = time;
-            }
-        }
-
+        self.run.rename_custom_comparison(old, new)?;
         self.fix();
-
         Ok(())
     }
 
@@ -915,6 +1028,123 @@ impl Editor {
         Ok(())
     }
 
+    /// Distributes the time of the selected segments evenly across them in the
+    /// comparison provided. Each contiguous block of selected segments keeps
+    /// the split time of its last segment, while the split times of the
+    /// segments before it are spaced out evenly between the previous split
+    /// time and that split time. This is useful for filling in the times of
+    /// segments that have never been run on their own. Blocks whose last
+    /// segment doesn't have a split time are left untouched. Only the times of
+    /// the selected timing method are modified. The comparison needs to be a
+    /// custom comparison, such as the Personal Best.
+    pub fn distribute_time_across_selected_segments(
+        &mut self,
+        comparison: &str,
+    ) -> Result<(), ComparisonTimesError> {
+        self.ensure_custom_comparison(comparison)?;
+
+        let method = self.selected_method;
+        let mut selected = self.selected_segments.clone();
+        selected.sort_unstable();
+
+        let segments = self.run.segments_mut();
+        let mut blocks = selected.iter().copied().peekable();
+        while let Some(first) = blocks.next() {
+            let mut last = first;
+            while blocks.next_if_eq(&(last + 1)).is_some() {
+                last += 1;
+            }
+
+            let start = segments[..first]
+                .iter()
+                .rev()
+                .find_map(|segment| segment.comparison(comparison)[method])
+                .unwrap_or_else(TimeSpan::zero);
+            let Some(end) = segments[last].comparison(comparison)[method] else {
+                continue;
+            };
+            if end < start {
+                continue;
+            }
+
+            let segment_time = (end - start).total_seconds() / (last - first + 1) as f64;
+            for (index, segment) in segments[first..last].iter_mut().enumerate() {
+                segment.comparison_mut(comparison)[method] =
+                    Some(start + TimeSpan::from_seconds(segment_time * (index + 1) as f64));
+            }
+        }
+
+        self.comparison_times_modified(comparison);
+        Ok(())
+    }
+
+    /// Scales all the split times of the comparison provided, such that its
+    /// final time matches the target time provided, while the segments keep
+    /// their relative lengths. This is useful for turning a comparison into a
+    /// goal comparison for a different final time. Only the times of the
+    /// selected timing method are modified. The comparison needs to be a
+    /// custom comparison, such as the Personal Best, and needs to have a final
+    /// time. Best Segments that end up slower than the comparison's segments
+    /// are lowered accordingly, just like when editing the times directly.
+    pub fn scale_comparison(
+        &mut self,
+        comparison: &str,
+        time: TimeSpan,
+    ) -> Result<(), ComparisonTimesError> {
+        self.ensure_custom_comparison(comparison)?;
+
+        let method = self.selected_method;
+        let segments = self.run.segments_mut();
+        let final_time = segments.last().unwrap().comparison(comparison)[method]
+            .filter(|&time| time > TimeSpan::zero())
+            .ok_or(ComparisonTimesError::NoFinalTime)?;
+
+        let factor = time.total_seconds() / final_time.total_seconds();
+        for segment in segments.iter_mut() {
+            if let Some(split_time) = &mut segment.comparison_mut(comparison)[method] {
+                *split_time = TimeSpan::from_seconds(split_time.total_seconds() * factor);
+            }
+        }
+        segments.last_mut().unwrap().comparison_mut(comparison)[method] = Some(time);
+
+        self.comparison_times_modified(comparison);
+        Ok(())
+    }
+
+    /// Parses a target time and scales all the split times of the comparison
+    /// provided, such that its final time matches the parsed value. Check
+    /// [`scale_comparison`](Self::scale_comparison) for more information.
+    pub fn parse_and_scale_comparison(
+        &mut self,
+        comparison: &str,
+        time: &str,
+    ) -> Result<(), ComparisonTimesError> {
+        let time = parse_positive(time)
+            .and_then(|time| time.ok_or(ParseError::EmptyTimeNotAllowed))
+            .context(InvalidTime)?;
+        self.scale_comparison(comparison, time)
+    }
+
+    fn ensure_custom_comparison(&self, comparison: &str) -> Result<(), ComparisonTimesError> {
+        if self
+            .run
+            .custom_comparisons()
+            .iter()
+            .any(|c| c == comparison)
+        {
+            Ok(())
+        } else {
+            Err(ComparisonTimesError::ComparisonNotFound)
+        }
+    }
+
+    fn comparison_times_modified(&mut self, comparison: &str) {
+        if comparison == comparison::personal_best::NAME {
+            self.times_modified();
+        }
+        self.fix();
+    }
+
     /// Copies a comparison with the given name as a new custom comparison with
     /// the new name provided. It can't be added if it starts with `[Race]` or
     /// it already exists. The old comparison needs to exist.
@@ -923,18 +1153,7 @@ impl Editor {
         old_name: &str,
         new_name: &str,
     ) -> Result<(), CopyComparisonError> {
-        if !self.run.comparisons().any(|c| c == old_name) {
-            return Err(CopyComparisonError::NoSuchComparison);
-        }
-
-        self.run
-            .add_custom_comparison(new_name)
-            .map_err(|source| CopyComparisonError::AddComparison { source })?;
-
-        for segment in self.run.segments_mut() {
-            *segment.comparison_mut(new_name) = segment.comparison(old_name);
-        }
-
+        self.run.copy_comparison(old_name, new_name)?;
         self.raise_run_edited();
 
         Ok(())
@@ -947,6 +1166,29 @@ impl Editor {
         self.fix();
     }
 
+    /// Clears out the Attempt History, while keeping the Segment Histories and
+    /// the Attempt Count. The segment times of the attempts that got cleared
+    /// stay in the Segment Histories, but don't belong to any attempt anymore.
+    pub fn clear_attempt_history(&mut self) {
+        self.run.clear_attempt_history();
+        self.fix();
+    }
+
+    /// Clears out the Segment Histories of all the segments, while keeping the
+    /// Attempt History, the Best Segment Times and all the comparisons.
+    pub fn clear_segment_history(&mut self) {
+        self.run.clear_segment_history();
+        self.fix();
+    }
+
+    /// Clears out the Best Segment Times of all the segments and recalculates
+    /// them from the Segment Histories and the Personal Best. This drops all
+    /// the Best Segment Times that are not backed by any of the times.
+    pub fn clear_best_segments(&mut self) {
+        self.run.clear_best_segments();
+        self.fix();
+    }
+
     /// Clears out the Attempt History, the Segment Histories, all the times,
     /// sets the Attempt Count to 0 and clears the speedrun.com run id
     /// association. All Custom Comparisons other than `Personal Best` are
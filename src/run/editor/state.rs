@@ -29,6 +29,9 @@ pub struct State {
     /// The timing method that is currently selected to be visualized and
     /// edited.
     pub timing_method: TimingMethod,
+    /// The timing method the Run is locked to, if only one of the timing
+    /// methods is meaningful for the Run.
+    pub locked_timing_method: Option<TimingMethod>,
     /// The state of all the segments.
     pub segments: Vec<Segment>,
     /// The names of all the custom comparisons that exist for this Run.
@@ -185,6 +188,7 @@ impl Editor {
             offset,
             attempts,
             timing_method,
+            locked_timing_method: self.run.locked_timing_method(),
             segments,
             comparison_names,
             buttons,
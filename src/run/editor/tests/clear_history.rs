@@ -0,0 +1,98 @@
+use super::super::Editor;
+use crate::{
+    testing::{create_timer, run_with_splits, run_with_splits_opt, span},
+    Run, TimeSpan, TimingMethod,
+};
+
+fn editor() -> Editor {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits_opt(&mut timer, &[None, Some(12.0)]);
+    run_with_splits(&mut timer, &[10.0, 25.0, 40.0]);
+    run_with_splits(&mut timer, &[8.0, 30.0]);
+    Editor::new(timer.into_run(true)).unwrap()
+}
+
+fn best_segments(run: &Run) -> Vec<Option<TimeSpan>> {
+    run.segments()
+        .iter()
+        .map(|s| s.best_segment_time().game_time)
+        .collect()
+}
+
+fn history(run: &Run, index: usize) -> Vec<Option<TimeSpan>> {
+    run.segment(index)
+        .segment_history()
+        .iter()
+        .map(|(_, time)| time.game_time)
+        .collect()
+}
+
+#[test]
+fn clearing_the_attempt_history_keeps_the_segment_history() {
+    let mut editor = editor();
+    editor.clear_attempt_history();
+    let run = editor.run();
+
+    assert!(run.attempt_history().is_empty());
+    assert_eq!(run.attempt_count(), 3);
+    assert_eq!(history(run, 0).len(), 3);
+    assert!(run
+        .segment(0)
+        .segment_history()
+        .iter()
+        .all(|&(index, _)| index <= 0));
+    assert_eq!(
+        best_segments(run),
+        [Some(span(8.0)), Some(span(12.0)), Some(span(15.0))]
+    );
+}
+
+#[test]
+fn clearing_the_segment_history_keeps_the_attempts_and_best_segments() {
+    let mut editor = editor();
+    editor.clear_segment_history();
+    let run = editor.run();
+
+    assert_eq!(run.attempt_history().len(), 3);
+    assert!(run
+        .segments()
+        .iter()
+        .all(|s| s.segment_history().iter().next().is_none()));
+    assert_eq!(
+        best_segments(run),
+        [Some(span(8.0)), Some(span(12.0)), Some(span(15.0))]
+    );
+}
+
+#[test]
+fn clearing_the_best_segments_recalculates_them_from_the_history() {
+    let mut editor = editor();
+    editor.select_timing_method(TimingMethod::GameTime);
+    editor.select_only(1);
+    editor
+        .active_segment()
+        .set_best_segment_time(Some(span(5.0)));
+    assert_eq!(best_segments(editor.run())[1], Some(span(5.0)));
+
+    editor.clear_best_segments();
+    let run = editor.run();
+
+    assert_eq!(
+        best_segments(run),
+        [Some(span(8.0)), Some(span(12.0)), Some(span(15.0))]
+    );
+    assert_eq!(run.attempt_history().len(), 3);
+    assert_eq!(history(run, 1).len(), 3);
+}
+
+#[test]
+fn clearing_the_best_segments_without_history_falls_back_to_the_personal_best() {
+    let mut editor = editor();
+    editor.clear_segment_history();
+    editor.clear_best_segments();
+
+    assert_eq!(
+        best_segments(editor.run()),
+        [Some(span(10.0)), Some(span(15.0)), Some(span(15.0))]
+    );
+}
@@ -1,7 +1,7 @@
 use crate::{
     comparison::{best_segments, personal_best},
     run::{AddComparisonError, CopyComparisonError, Editor, RenameError},
-    Run, Segment,
+    Run, Segment, Time, TimeSpan,
 };
 
 #[test]
@@ -119,6 +119,59 @@ fn renaming_to_an_existing_name_fails() {
     );
 }
 
+#[test]
+fn renaming_the_personal_best_fails() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    let mut editor = Editor::new(run).unwrap();
+    let c = editor.rename_comparison(personal_best::NAME, "My Comparison");
+    assert_eq!(c, Err(RenameError::OldNameNotFound));
+}
+
+#[test]
+fn adding_an_inactive_generator_name_fails() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    run.comparison_generators_mut().clear();
+    let c = run.add_custom_comparison(best_segments::NAME);
+    assert_eq!(c, Err(AddComparisonError::GeneratorName));
+}
+
+#[test]
+fn clearing_keeps_the_comparison() {
+    let mut run = Run::new();
+    let mut segment = Segment::new("s");
+    *segment.comparison_mut("Custom") =
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(5.0)));
+    run.push_segment(segment);
+    run.add_custom_comparison("Custom").unwrap();
+    let mut editor = Editor::new(run).unwrap();
+
+    editor.clear_comparison("Custom");
+    let run = editor.close();
+    assert_eq!(run.custom_comparisons()[1..], ["Custom"]);
+    assert_eq!(run.segment(0).comparison("Custom"), Time::default());
+}
+
+#[test]
+fn removing_drops_the_times() {
+    let mut run = Run::new();
+    let mut segment = Segment::new("s");
+    *segment.comparison_mut("Custom") =
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(5.0)));
+    run.push_segment(segment);
+    run.add_custom_comparison("Custom").unwrap();
+
+    assert!(run.remove_custom_comparison("Custom"));
+    assert!(!run.remove_custom_comparison("Custom"));
+    assert!(!run.remove_custom_comparison(personal_best::NAME));
+    assert!(run
+        .segment_mut(0)
+        .comparisons_mut()
+        .remove("Custom")
+        .is_none());
+}
+
 #[test]
 fn reordering_works() {
     let mut run = Run::new();
@@ -156,3 +209,47 @@ fn reordering_works() {
         ["D", "A", "C", "B"]
     );
 }
+
+#[test]
+fn importing_a_comparison_reports_mismatched_segments() {
+    let mut run = Run::new();
+    for name in ["Forest", "Castle", "Lake", "Tower"] {
+        run.push_segment(Segment::new(name));
+    }
+    let mut editor = Editor::new(run).unwrap();
+
+    let mut other = Run::new();
+    for (name, time) in [
+        ("forest", 10.0),
+        ("Cave", 15.0),
+        ("Lake", 30.0),
+        ("Final Boss", 40.0),
+    ] {
+        let mut segment = Segment::new(name);
+        segment.set_personal_best_split_time(
+            Time::new().with_real_time(Some(TimeSpan::from_seconds(time))),
+        );
+        other.push_segment(segment);
+    }
+
+    let imported = editor.import_comparison(&other, "Race").unwrap();
+    assert!(!imported.is_exact());
+    assert_eq!(imported.unmatched_segments, ["Cave"]);
+    assert_eq!(imported.missing_segments, [1]);
+
+    let times: Vec<_> = editor
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.comparison("Race").real_time)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            Some(TimeSpan::from_seconds(10.0)),
+            None,
+            Some(TimeSpan::from_seconds(30.0)),
+            Some(TimeSpan::from_seconds(40.0)),
+        ],
+    );
+}
@@ -0,0 +1,141 @@
+use crate::{
+    comparison::{best_segments, personal_best},
+    run::{editor::ComparisonTimesError, Editor},
+    Run, Segment, TimeSpan, TimingMethod,
+};
+
+fn editor_with_split_times(split_times: &[Option<f64>]) -> Editor {
+    let mut run = Run::new();
+    for (index, &split_time) in split_times.iter().enumerate() {
+        let mut segment = Segment::new(index.to_string());
+        segment.personal_best_split_time_mut().real_time = split_time.map(TimeSpan::from_seconds);
+        run.push_segment(segment);
+    }
+    Editor::new(run).unwrap()
+}
+
+fn split_times(editor: &Editor, comparison: &str) -> Vec<Option<f64>> {
+    editor
+        .run()
+        .segments()
+        .iter()
+        .map(|s| {
+            s.comparison(comparison)
+                .real_time
+                .map(|t| t.total_seconds())
+        })
+        .collect()
+}
+
+#[test]
+fn distributes_the_time_evenly_across_the_selected_segments() {
+    let mut editor = editor_with_split_times(&[Some(10.0), None, None, Some(40.0), Some(50.0)]);
+    editor.select_only(1);
+    editor.select_range(3);
+
+    editor
+        .distribute_time_across_selected_segments(personal_best::NAME)
+        .unwrap();
+
+    assert_eq!(
+        split_times(&editor, personal_best::NAME),
+        [Some(10.0), Some(20.0), Some(30.0), Some(40.0), Some(50.0)],
+    );
+    let best_segments = editor
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.best_segment_time().real_time.map(|t| t.total_seconds()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        best_segments,
+        [Some(10.0), Some(10.0), Some(10.0), Some(10.0), Some(10.0)],
+    );
+}
+
+#[test]
+fn distributes_each_block_of_selected_segments_separately() {
+    let mut editor = editor_with_split_times(&[None, Some(20.0), Some(30.0), None, Some(60.0)]);
+    editor.select_only(0);
+    editor.select_additionally(1);
+    editor.select_additionally(3);
+    editor.select_additionally(4);
+
+    editor
+        .distribute_time_across_selected_segments(personal_best::NAME)
+        .unwrap();
+
+    assert_eq!(
+        split_times(&editor, personal_best::NAME),
+        [Some(10.0), Some(20.0), Some(30.0), Some(45.0), Some(60.0)],
+    );
+}
+
+#[test]
+fn blocks_without_an_end_time_are_left_untouched() {
+    let mut editor = editor_with_split_times(&[Some(10.0), None, None]);
+    editor.select_only(1);
+    editor.select_range(2);
+
+    editor
+        .distribute_time_across_selected_segments(personal_best::NAME)
+        .unwrap();
+
+    assert_eq!(
+        split_times(&editor, personal_best::NAME),
+        [Some(10.0), None, None],
+    );
+}
+
+#[test]
+fn scales_the_comparison_to_the_target_time() {
+    let mut editor = editor_with_split_times(&[Some(30.0), None, Some(60.0), Some(120.0)]);
+    editor.copy_comparison(personal_best::NAME, "Goal").unwrap();
+
+    editor.parse_and_scale_comparison("Goal", "1:30").unwrap();
+
+    assert_eq!(
+        split_times(&editor, "Goal"),
+        [Some(22.5), None, Some(45.0), Some(90.0)],
+    );
+    assert_eq!(
+        split_times(&editor, personal_best::NAME),
+        [Some(30.0), None, Some(60.0), Some(120.0)],
+    );
+}
+
+#[test]
+fn scaling_only_modifies_the_selected_timing_method() {
+    let mut editor = editor_with_split_times(&[Some(30.0), Some(60.0)]);
+    editor.select_timing_method(TimingMethod::GameTime);
+
+    let result = editor.scale_comparison(personal_best::NAME, TimeSpan::from_seconds(30.0));
+    assert!(matches!(result, Err(ComparisonTimesError::NoFinalTime)));
+    assert_eq!(
+        split_times(&editor, personal_best::NAME),
+        [Some(30.0), Some(60.0)],
+    );
+}
+
+#[test]
+fn only_custom_comparisons_can_be_recomputed() {
+    let mut editor = editor_with_split_times(&[Some(30.0), Some(60.0)]);
+
+    let result = editor.scale_comparison(best_segments::NAME, TimeSpan::from_seconds(30.0));
+    assert!(matches!(
+        result,
+        Err(ComparisonTimesError::ComparisonNotFound)
+    ));
+
+    let result = editor.distribute_time_across_selected_segments("Nonexistent");
+    assert!(matches!(
+        result,
+        Err(ComparisonTimesError::ComparisonNotFound)
+    ));
+
+    let result = editor.parse_and_scale_comparison(personal_best::NAME, "");
+    assert!(matches!(
+        result,
+        Err(ComparisonTimesError::InvalidTime { .. })
+    ));
+}
@@ -301,6 +301,27 @@ fn when_clearing_history() {
     assert!(editor.run().has_been_modified());
 }
 
+#[test]
+fn when_clearing_attempt_history() {
+    let mut editor = base();
+    editor.clear_attempt_history();
+    assert!(editor.run().has_been_modified());
+}
+
+#[test]
+fn when_clearing_segment_history() {
+    let mut editor = base();
+    editor.clear_segment_history();
+    assert!(editor.run().has_been_modified());
+}
+
+#[test]
+fn when_clearing_best_segments() {
+    let mut editor = base();
+    editor.clear_best_segments();
+    assert!(editor.run().has_been_modified());
+}
+
 #[test]
 fn when_clearing_times() {
     let mut editor = base();
@@ -1,13 +1,16 @@
 use super::Editor;
 use crate::{
-    util::tests_helper::{create_timer, run_with_splits},
+    testing::{create_timer, run_with_splits},
     Run, Segment,
 };
 
+mod clear_history;
 mod comparison;
+mod comparison_times;
 mod custom_variables;
 mod dissociate_run;
 mod mark_as_modified;
+mod paste_segment_names;
 
 #[test]
 fn new_best_segment() {
@@ -0,0 +1,84 @@
+use super::super::{Editor, PastedSegmentTimes};
+use crate::{
+    testing::{create_timer, run_with_splits, span},
+    Run, TimingMethod,
+};
+
+fn run() -> Run {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    timer.into_run(true)
+}
+
+fn names(editor: &Editor) -> Vec<&str> {
+    editor.run().segments().iter().map(|s| s.name()).collect()
+}
+
+fn personal_best(editor: &Editor) -> Vec<Option<f64>> {
+    editor
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.personal_best_split_time()[TimingMethod::GameTime].map(|t| t.total_seconds()))
+        .collect()
+}
+
+#[test]
+fn renames_and_adds_segments() {
+    let mut editor = Editor::new(run()).unwrap();
+    editor.paste_segment_names(
+        "Forest\n\n  Castle  \r\nLava\nFinal Boss\n",
+        PastedSegmentTimes::Keep,
+    );
+
+    assert_eq!(names(&editor), ["Forest", "Castle", "Lava", "Final Boss"]);
+    assert_eq!(
+        personal_best(&editor),
+        [Some(10.0), Some(20.0), Some(30.0), None]
+    );
+    assert_eq!(editor.run().attempt_history().len(), 1);
+}
+
+#[test]
+fn removes_the_segments_beyond_the_list() {
+    let mut editor = Editor::new(run()).unwrap();
+    editor.select_only(2);
+    editor.paste_segment_names("Forest\nCastle", PastedSegmentTimes::Keep);
+
+    assert_eq!(names(&editor), ["Forest", "Castle"]);
+    assert_eq!(personal_best(&editor), [Some(10.0), Some(20.0)]);
+}
+
+#[test]
+fn clears_the_times() {
+    let mut editor = Editor::new(run()).unwrap();
+    editor.paste_segment_names("Forest\nCastle", PastedSegmentTimes::Clear);
+
+    assert_eq!(personal_best(&editor), [None, None]);
+    assert!(editor.run().attempt_history().is_empty());
+}
+
+#[test]
+fn interpolates_from_the_final_time() {
+    let mut editor = Editor::new(run()).unwrap();
+    editor.paste_segment_names("A\nB\nC\nD", PastedSegmentTimes::InterpolateFromFinalTime);
+
+    assert_eq!(
+        personal_best(&editor),
+        [Some(7.5), Some(15.0), Some(22.5), Some(30.0)]
+    );
+    assert_eq!(
+        editor.run().segment(0).best_segment_time().game_time,
+        Some(span(7.5))
+    );
+    assert!(editor.run().attempt_history().is_empty());
+}
+
+#[test]
+fn ignores_an_empty_list() {
+    let mut editor = Editor::new(run()).unwrap();
+    editor.paste_segment_names(" \n\n", PastedSegmentTimes::Clear);
+
+    assert_eq!(names(&editor), ["A", "B", "C"]);
+    assert_eq!(personal_best(&editor), [Some(10.0), Some(20.0), Some(30.0)]);
+}
@@ -0,0 +1,124 @@
+use crate::{run::parser::composite, Timer};
+use snafu::ResultExt;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl Stamp {
+    fn read(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        })
+    }
+}
+
+/// Describes what happened to the file observed by a [`FileWatcher`] since it
+/// was last polled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileEvent {
+    /// The file didn't change.
+    Unchanged,
+    /// The file got modified or created.
+    Modified,
+    /// The file got removed.
+    Removed,
+}
+
+/// Describes an Error that occurred while reloading a modified splits file.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum ReloadError {
+    /// Failed to read the splits file.
+    ReadFile {
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// Failed to parse the splits file.
+    Parse {
+        /// The underlying error.
+        source: composite::Error,
+    },
+    /// The modified run can't be used by the timer. Either it has no segments
+    /// or the amount of segments changed while an attempt is in progress.
+    IncompatibleRun,
+}
+
+/// A file watcher observes the splits file of a run for modifications by
+/// other applications, such as when the splits are edited in another tool
+/// while the timer is open. The watcher is polling based, so it needs to be
+/// polled regularly, such as whenever the layout is updated.
+///
+/// Saving the splits from within the timer modifies the file as well. Call
+/// [`mark_as_saved`](Self::mark_as_saved) afterwards, so the timer's own
+/// changes are not reported as external modifications.
+#[derive(Debug)]
+pub struct FileWatcher {
+    path: PathBuf,
+    stamp: Option<Stamp>,
+}
+
+impl FileWatcher {
+    /// Creates a new file watcher for the file at the path provided. The
+    /// current state of the file is considered to be known already.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let stamp = Stamp::read(&path);
+        Self { path, stamp }
+    }
+
+    /// Accesses the path of the file that is being watched.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Checks whether the file changed since it was last polled.
+    pub fn poll(&mut self) -> FileEvent {
+        let stamp = Stamp::read(&self.path);
+        if stamp == self.stamp {
+            return FileEvent::Unchanged;
+        }
+        self.stamp = stamp;
+        if stamp.is_some() {
+            FileEvent::Modified
+        } else {
+            FileEvent::Removed
+        }
+    }
+
+    /// Considers the current state of the file to be known, so that the
+    /// changes the timer itself made to it are not reported.
+    pub fn mark_as_saved(&mut self) {
+        self.stamp = Stamp::read(&self.path);
+    }
+
+    /// Reloads the run from the file into the timer if the file got modified.
+    /// The attempt that is in progress is carried over to the new run, as
+    /// described by [`Timer::reload_run`]. Returns whether the run got
+    /// reloaded. If reloading fails, the timer keeps using its current run.
+    pub fn reload_if_modified(&mut self, timer: &mut Timer) -> Result<bool, ReloadError> {
+        if self.poll() != FileEvent::Modified {
+            return Ok(false);
+        }
+
+        let source = fs::read(&self.path).context(ReadFile)?;
+        let run = composite::parse(&source, Some(&self.path))
+            .context(Parse)?
+            .run;
+
+        timer
+            .reload_run(run)
+            .map_err(|_| ReloadError::IncompatibleRun)?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,68 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// `FixRules` select which of the fixes [`Run::fix_splits_with`] applies to a
+/// [`Run`]. The fixes are applied for both timing methods. [`Run::fix_splits`]
+/// applies all of them, which is also what the [`Timer`] and the
+/// [`Editor`](crate::run::Editor) do with the Runs they receive. The default
+/// rules apply all the fixes.
+///
+/// [`Run`]: crate::Run
+/// [`Run::fix_splits_with`]: crate::Run::fix_splits_with
+/// [`Run::fix_splits`]: crate::Run::fix_splits
+/// [`Timer`]: crate::Timer
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FixRules {
+    /// Removes Best Segment Times that are negative.
+    pub remove_negative_best_segments: bool,
+    /// Prevents the split times of the custom comparisons from decreasing
+    /// from one split to the next by raising them to the previous split time.
+    pub monotonic_comparisons: bool,
+    /// Lowers the Best Segment Times to the segment times of the Personal Best
+    /// wherever the Personal Best is faster.
+    pub cap_best_segments: bool,
+    /// Patches the Segment Histories to be consistent with the Best Segment
+    /// Times. Segments without a Best Segment Time only keep their skipped
+    /// segments and segment times faster than the Best Segment Time are raised
+    /// to it.
+    pub patch_segment_history: bool,
+    /// Removes duplicated segment times that don't belong to any attempt, such
+    /// as the ones imported multiple times from the Personal Best.
+    pub remove_duplicates: bool,
+    /// Removes the empty segment times at the end of each attempt in the
+    /// Segment Histories.
+    pub remove_empty_times: bool,
+    /// Reassigns segment times that belong to attempts that are not in the
+    /// Attempt History to indices that don't belong to any attempt.
+    pub reattach_unattached_history: bool,
+}
+
+impl FixRules {
+    /// Applies all the fixes.
+    pub const ALL: Self = Self {
+        remove_negative_best_segments: true,
+        monotonic_comparisons: true,
+        cap_best_segments: true,
+        patch_segment_history: true,
+        remove_duplicates: true,
+        remove_empty_times: true,
+        reattach_unattached_history: true,
+    };
+
+    /// Applies none of the fixes.
+    pub const NONE: Self = Self {
+        remove_negative_best_segments: false,
+        monotonic_comparisons: false,
+        cap_best_segments: false,
+        patch_segment_history: false,
+        remove_duplicates: false,
+        remove_empty_times: false,
+        reattach_unattached_history: false,
+    };
+}
+
+impl Default for FixRules {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{platform::prelude::*, Attempt, DateTime, Run, Segment, SegmentHistory, TimeSpan};
+use core::ops::Range;
+use hashbrown::HashSet;
+
+/// A `KeepPolicy` describes which attempts should survive when pruning the
+/// history of a [`Run`] via [`Run::prune_history`]. An attempt is kept if it
+/// matches any of the criteria. The default policy matches no attempts at
+/// all, so pruning with it clears out the entire history.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeepPolicy {
+    /// Keeps the specified amount of most recent attempts.
+    pub last_attempts: Option<usize>,
+    /// Keeps all the attempts that were a new Personal Best at the point in
+    /// time they were done, for either timing method.
+    pub personal_bests: bool,
+    /// Keeps all the attempts that achieved a new best segment at the point
+    /// in time they were done, for either timing method.
+    pub best_segments: bool,
+    /// Keeps all the attempts that started within the date range provided.
+    /// Attempts where it's not known when they started are not kept by this.
+    pub date_range: Option<Range<DateTime>>,
+}
+
+/// The history that got removed from a [`Run`] by [`Run::prune_history`]. It
+/// can be turned into a [`Run`] of its own via [`PrunedHistory::into_archive`]
+/// in order to archive it to a side file instead of losing it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrunedHistory {
+    attempts: Vec<Attempt>,
+    segment_histories: Vec<SegmentHistory>,
+}
+
+impl PrunedHistory {
+    /// Accesses the attempts that got removed from the Attempt History.
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Accesses the segment times that got removed from the Segment History of
+    /// each segment. There is one Segment History per segment of the run.
+    pub fn segment_histories(&self) -> &[SegmentHistory] {
+        &self.segment_histories
+    }
+
+    /// Returns whether no attempt got pruned.
+    pub fn is_empty(&self) -> bool {
+        self.attempts.is_empty()
+    }
+
+    /// Turns the pruned history into a [`Run`] that can be saved as a side
+    /// file, for example with the [`LiveSplit Saver`](crate::run::saver::livesplit).
+    /// The archive uses the names of the [`Run`] the history got pruned from
+    /// and contains nothing but the pruned attempts and their segment times.
+    pub fn into_archive(self, run: &Run) -> Run {
+        let mut archive = Run::new();
+        archive.set_game_name(run.game_name());
+        archive.set_category_name(run.category_name());
+        archive.set_attempt_count(self.attempts.len() as u32);
+
+        for (segment, history) in run.segments().iter().zip(self.segment_histories) {
+            let mut archived = Segment::new(segment.name());
+            *archived.segment_history_mut() = history;
+            archive.push_segment(archived);
+        }
+
+        for attempt in self.attempts {
+            archive.add_attempt_with_index(
+                attempt.time(),
+                attempt.index(),
+                attempt.started(),
+                attempt.ended(),
+                attempt.pause_time(),
+            );
+        }
+
+        archive
+    }
+}
+
+pub(super) fn attempts_to_keep(run: &Run, policy: &KeepPolicy) -> HashSet<i32> {
+    let mut keep = HashSet::new();
+    let attempts = run.attempt_history();
+
+    if let Some(count) = policy.last_attempts {
+        let mut indices: Vec<i32> = attempts.iter().map(Attempt::index).collect();
+        indices.sort_unstable();
+        keep.extend(indices.iter().rev().take(count));
+    }
+
+    if let Some(range) = &policy.date_range {
+        keep.extend(
+            attempts
+                .iter()
+                .filter(|a| a.started().is_some_and(|s| range.contains(&s.time)))
+                .map(Attempt::index),
+        );
+    }
+
+    if policy.personal_bests {
+        let mut ordered: Vec<&Attempt> = attempts.iter().collect();
+        ordered.sort_unstable_by_key(|a| a.index());
+        let mut best = [None; 2];
+        for attempt in ordered {
+            let time = attempt.time();
+            if improves(&mut best, [time.real_time, time.game_time]) {
+                keep.insert(attempt.index());
+            }
+        }
+    }
+
+    if policy.best_segments {
+        for segment in run.segments() {
+            let mut best = [None; 2];
+            for &(index, time) in segment.segment_history().iter_actual_runs() {
+                if improves(&mut best, [time.real_time, time.game_time]) {
+                    keep.insert(index);
+                }
+            }
+        }
+    }
+
+    keep
+}
+
+fn improves(best: &mut [Option<TimeSpan>; 2], times: [Option<TimeSpan>; 2]) -> bool {
+    let mut improved = false;
+    for (best, time) in best.iter_mut().zip(times) {
+        if let Some(time) = time {
+            if best.map_or(true, |b| time < b) {
+                *best = Some(time);
+                improved = true;
+            }
+        }
+    }
+    improved
+}
+
+pub(super) fn prune(run: &mut Run, keep: &HashSet<i32>) -> PrunedHistory {
+    let mut pruned = PrunedHistory::default();
+
+    let (kept, removed): (Vec<_>, Vec<_>) = run
+        .attempt_history
+        .drain(..)
+        .partition(|a| keep.contains(&a.index()));
+    run.attempt_history = kept;
+    pruned.attempts = removed;
+
+    let removed: HashSet<i32> = pruned.attempts.iter().map(Attempt::index).collect();
+
+    for segment in run.segments_mut() {
+        let mut removed_times = SegmentHistory::default();
+        segment.segment_history_mut().retain(|&(index, time)| {
+            if removed.contains(&index) {
+                removed_times.insert(index, time);
+                false
+            } else {
+                true
+            }
+        });
+        pruned.segment_histories.push(removed_times);
+        segment
+            .skip_reason_history_mut()
+            .retain(|index, _| !removed.contains(index));
+    }
+
+    pruned
+}
@@ -15,28 +15,46 @@
 //! ```
 
 mod attempt;
+mod checkpoint;
 mod comparisons;
 pub mod editor;
+#[cfg(feature = "std")]
+mod file_watcher;
+mod fix_rules;
+mod history_pruning;
 mod linked_layout;
 pub mod parser;
+mod period_stats;
 mod run_metadata;
 pub mod saver;
 mod segment;
 mod segment_history;
+mod skip_reason;
+mod stats_summary;
 
 #[cfg(test)]
 mod tests;
 
 pub use attempt::Attempt;
+pub use checkpoint::Checkpoint;
 pub use comparisons::Comparisons;
 pub use editor::{Editor, RenameError};
+#[cfg(feature = "std")]
+pub use file_watcher::{FileEvent, FileWatcher, ReloadError};
+pub use fix_rules::FixRules;
+pub use history_pruning::{KeepPolicy, PrunedHistory};
 pub use linked_layout::LinkedLayout;
+pub use period_stats::{Period, PeriodStats};
 pub use run_metadata::{CustomVariable, RunMetadata};
-pub use segment::Segment;
-pub use segment_history::SegmentHistory;
+pub use segment::{Segment, SegmentId};
+pub use segment_history::{CompletedIter as SegmentHistoryCompletedIter, SegmentHistory};
+pub use skip_reason::SkipReason;
+pub use stats_summary::StatsSummary;
 
 use crate::{
-    comparison::{default_generators, personal_best, ComparisonGenerator, RACE_COMPARISON_PREFIX},
+    comparison::{
+        self, default_generators, personal_best, ComparisonGenerator, RACE_COMPARISON_PREFIX,
+    },
     platform::prelude::*,
     settings::Image,
     util::{caseless::matches_ascii_key, PopulateString},
@@ -76,6 +94,7 @@ pub struct Run {
     comparison_generators: ComparisonGenerators,
     auto_splitter_settings: String,
     linked_layout: Option<LinkedLayout>,
+    locked_timing_method: Option<TimingMethod>,
 }
 
 #[derive(Clone, Debug)]
@@ -97,6 +116,8 @@ pub enum AddComparisonError {
     NameStartsWithRace,
     /// Comparison name is a duplicate.
     DuplicateName,
+    /// Comparison name is reserved for one of the Comparison Generators.
+    GeneratorName,
 }
 
 /// Error type for copying a comparison.
@@ -129,6 +150,7 @@ impl Run {
             comparison_generators: ComparisonGenerators(default_generators()),
             auto_splitter_settings: String::new(),
             linked_layout: None,
+            locked_timing_method: None,
         }
     }
 
@@ -159,6 +181,48 @@ impl Run {
         self.game_icon = image;
     }
 
+    /// Sets the game's icon to the image stored in the file provided. The
+    /// image is validated and downscaled to [`Image::ICON`] if necessary. The
+    /// icon is left unchanged if the file can't be loaded.
+    #[cfg(feature = "std")]
+    pub fn set_game_icon_from_file<P>(
+        &mut self,
+        path: P,
+    ) -> Result<(), crate::settings::ImageLoadError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        self.game_icon = Image::load(path, Image::ICON)?;
+        Ok(())
+    }
+
+    /// Downscales the game icon and all the segment icons so that neither of
+    /// their dimensions exceed the maximum size provided. Icons that need to
+    /// be downscaled and bitmaps are converted to PNG. Parsed icons are
+    /// already limited to [`Image::ICON`], so this can only be used to shrink
+    /// them further. Returns whether any of the icons changed, in which case
+    /// the Run is marked as modified.
+    #[cfg(feature = "image-shrinking")]
+    pub fn shrink_icons(&mut self, max_icon_size: u32) -> bool {
+        let mut changed = false;
+        let mut shrink = |image: &Image| {
+            let shrunk = image.shrunk(max_icon_size);
+            changed |= shrunk != *image;
+            shrunk
+        };
+
+        self.game_icon = shrink(&self.game_icon);
+        for segment in &mut self.segments {
+            let icon = shrink(segment.icon());
+            segment.set_icon(icon);
+        }
+
+        if changed {
+            self.mark_as_modified();
+        }
+        changed
+    }
+
     /// Accesses the name of the category this Run is for.
     #[inline]
     pub fn category_name(&self) -> &str {
@@ -247,6 +311,14 @@ impl Run {
         &self.segments[index]
     }
 
+    /// Accesses the stable identifier of a certain segment of this Run. Unlike
+    /// the index, the identifier stays the same when segments are inserted,
+    /// removed or reordered. Returns `None` if the index is out of bounds.
+    #[inline]
+    pub fn segment_id(&self, index: usize) -> Option<SegmentId> {
+        self.segments.get(index).map(Segment::id)
+    }
+
     /// Mutably accesses a certain segment of this Run.
     ///
     /// # Panics
@@ -260,7 +332,7 @@ impl Run {
     /// Accesses the history of all the runs that have been attempted. This does
     /// not store the actual segment times, just the overall attempt
     /// information. Information about the individual segments is stored within
-    /// each segment.
+    /// each segment. The attempts are ordered by their indices.
     #[inline]
     pub fn attempt_history(&self) -> &[Attempt] {
         &self.attempt_history
@@ -370,6 +442,31 @@ impl Run {
         self.linked_layout = linked_layout;
     }
 
+    /// Accesses the timing method this `Run` is locked to. Some games only have
+    /// a meaningful timing method, such as games that are only timed by their
+    /// in-game time. If the `Run` is locked to a timing method, the other
+    /// timing method is never shown and its comparisons are not generated.
+    #[inline]
+    pub const fn locked_timing_method(&self) -> Option<TimingMethod> {
+        self.locked_timing_method
+    }
+
+    /// Locks this `Run` to the timing method provided. Specify [`None`] if both
+    /// timing methods are meaningful. You may need to regenerate the
+    /// comparisons afterwards.
+    #[inline]
+    pub fn set_locked_timing_method(&mut self, method: Option<TimingMethod>) {
+        self.locked_timing_method = method;
+    }
+
+    /// Resolves the timing method to use for this `Run`. If the `Run` is locked
+    /// to a timing method, that timing method is used. Otherwise the timing
+    /// method provided is used.
+    #[inline]
+    pub fn resolve_timing_method(&self, method: TimingMethod) -> TimingMethod {
+        self.locked_timing_method.unwrap_or(method)
+    }
+
     /// Returns the amount of segments stored in this Run.
     #[inline]
     pub fn len(&self) -> usize {
@@ -423,7 +520,8 @@ impl Run {
     }
 
     /// Adds a new Attempt to the Run's Attempt History with a predetermined
-    /// History Index.
+    /// History Index. The Attempt is inserted such that the Attempt History
+    /// stays ordered by the indices.
     ///
     /// # Warning
     ///
@@ -438,7 +536,8 @@ impl Run {
         pause_time: Option<TimeSpan>,
     ) {
         let attempt = Attempt::new(index, time, started, ended, pause_time);
-        self.attempt_history.push(attempt);
+        let position = self.attempt_history.partition_point(|a| a.index() < index);
+        self.attempt_history.insert(position, attempt);
     }
 
     /// Clears the speedrun.com Run ID of this Run, as the current Run does not
@@ -461,11 +560,115 @@ impl Run {
         Ok(())
     }
 
+    /// Renames a custom comparison and moves all of its comparison times over to
+    /// the new name. The comparison can't be renamed if the new name is not
+    /// valid for a comparison. You can't rename a Comparison Generator's
+    /// Comparison or the Personal Best.
+    pub fn rename_custom_comparison(&mut self, old: &str, new: &str) -> Result<(), RenameError> {
+        if old == new {
+            return Ok(());
+        }
+
+        if old == personal_best::NAME {
+            return Err(RenameError::OldNameNotFound);
+        }
+
+        self.validate_comparison_name(new)
+            .map_err(|source| RenameError::InvalidName { source })?;
+
+        let comparison_name = self
+            .custom_comparisons
+            .iter_mut()
+            .find(|c| *c == old)
+            .ok_or(RenameError::OldNameNotFound)?;
+
+        comparison_name.clear();
+        comparison_name.push_str(new);
+
+        for segment in &mut self.segments {
+            if let Some(time) = segment.comparisons_mut().remove(old) {
+                *segment.comparison_mut(new) = time;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copies the comparison with the name provided as a new custom comparison
+    /// with the new name provided. This works for any comparison, including
+    /// the Comparison Generators' Comparisons. The new name needs to be valid
+    /// for a comparison.
+    pub fn copy_comparison(&mut self, old: &str, new: &str) -> Result<(), CopyComparisonError> {
+        if !self.comparisons().any(|c| c == old) {
+            return Err(CopyComparisonError::NoSuchComparison);
+        }
+
+        self.add_custom_comparison(new)
+            .map_err(|source| CopyComparisonError::AddComparison { source })?;
+
+        for segment in &mut self.segments {
+            *segment.comparison_mut(new) = segment.comparison(old);
+        }
+
+        Ok(())
+    }
+
+    /// Clears all the times of the custom comparison provided, while keeping
+    /// the comparison itself. Returns whether there is such a custom
+    /// comparison. You can't clear the Personal Best this way.
+    pub fn clear_custom_comparison(&mut self, comparison: &str) -> bool {
+        if comparison == personal_best::NAME
+            || !self.custom_comparisons.iter().any(|c| c == comparison)
+        {
+            return false;
+        }
+
+        for segment in &mut self.segments {
+            *segment.comparison_mut(comparison) = Time::default();
+        }
+
+        true
+    }
+
+    /// Removes the custom comparison provided along with all of its times.
+    /// Returns whether there was such a custom comparison. You can't remove a
+    /// Comparison Generator's Comparison or the Personal Best.
+    pub fn remove_custom_comparison(&mut self, comparison: &str) -> bool {
+        if comparison == personal_best::NAME {
+            return false;
+        }
+
+        let len = self.custom_comparisons.len();
+        self.custom_comparisons.retain(|c| c != comparison);
+        if self.custom_comparisons.len() == len {
+            return false;
+        }
+
+        if !self.comparisons().any(|c| c == comparison) {
+            for segment in &mut self.segments {
+                segment.comparisons_mut().remove(comparison);
+            }
+        }
+
+        true
+    }
+
     /// Recalculates all the comparison times the Comparison Generators provide.
+    /// If the `Run` is locked to a timing method, the comparison times of the
+    /// other timing method are cleared instead.
     #[inline]
     pub fn regenerate_comparisons(&mut self) {
         for generator in &mut self.comparison_generators.0 {
             generator.generate(&mut self.segments, &self.attempt_history);
+            if let Some(locked) = self.locked_timing_method {
+                let unused = match locked {
+                    TimingMethod::RealTime => TimingMethod::GameTime,
+                    TimingMethod::GameTime => TimingMethod::RealTime,
+                };
+                for segment in &mut self.segments {
+                    segment.comparison_mut(generator.name())[unused] = None;
+                }
+            }
         }
     }
 
@@ -540,21 +743,85 @@ impl Run {
 
     /// Applies some fixing algorithms on the Run. This includes fixing the
     /// comparison times and history, removing duplicates in the segment
-    /// histories and removing empty times.
+    /// histories and removing empty times. This applies all the fixes
+    /// described by [`FixRules`].
     pub fn fix_splits(&mut self) {
+        self.fix_splits_with(FixRules::ALL);
+    }
+
+    /// Applies the fixing algorithms selected by the [`FixRules`] provided on
+    /// the Run. This allows editors to apply or skip specific fixes.
+    pub fn fix_splits_with(&mut self, rules: FixRules) {
         for method in TimingMethod::all() {
-            self.fix_comparison_times_and_history(method);
+            self.fix_comparison_times_and_history(method, rules);
+        }
+        if rules.remove_duplicates {
+            self.remove_duplicates();
+        }
+        if rules.remove_empty_times {
+            self.remove_none_values();
+        }
+        if rules.reattach_unattached_history {
+            self.reattach_unattached_segment_history_elements();
         }
-        self.remove_duplicates();
-        self.remove_none_values();
-        self.reattach_unattached_segment_history_elements();
     }
 
-    /// Clears out the Attempt History and the Segment Histories of all the segments.
+    /// Clears out the Attempt History and the Segment Histories and Practice
+    /// Histories of all the segments.
     pub fn clear_history(&mut self) {
         self.attempt_history.clear();
         for segment in &mut self.segments {
             segment.segment_history_mut().clear();
+            segment.skip_reason_history_mut().clear();
+            segment.practice_history_mut().clear();
+        }
+    }
+
+    /// Prunes the Attempt History and the Segment Histories of all the
+    /// segments, keeping only the attempts that match the [`KeepPolicy`]
+    /// provided. Segment times that don't belong to any attempt, such as the
+    /// ones imported from route changes, are left untouched. The removed
+    /// history is returned, so it can be archived instead of being lost. The
+    /// comparisons are not regenerated by this.
+    pub fn prune_history(&mut self, policy: &KeepPolicy) -> PrunedHistory {
+        let keep = history_pruning::attempts_to_keep(self, policy);
+        history_pruning::prune(self, &keep)
+    }
+
+    /// Clears out the Attempt History, while keeping the Segment Histories and
+    /// the Attempt Count. The segment times of the attempts are kept as
+    /// segment times that don't belong to any attempt. As the Skip Reason
+    /// Histories refer to the attempts, they are cleared as well.
+    pub fn clear_attempt_history(&mut self) {
+        self.attempt_history.clear();
+        for segment in &mut self.segments {
+            segment.skip_reason_history_mut().clear();
+        }
+        self.reattach_unattached_segment_history_elements();
+    }
+
+    /// Clears out the Segment Histories and Skip Reason Histories of all the
+    /// segments, while keeping the Attempt History and the Best Segment Times.
+    pub fn clear_segment_history(&mut self) {
+        for segment in &mut self.segments {
+            segment.segment_history_mut().clear();
+            segment.skip_reason_history_mut().clear();
+        }
+    }
+
+    /// Clears out the Best Segment Times of all the segments and recalculates
+    /// them from the Segment Histories. This drops all the Best Segment Times
+    /// that are not backed by any segment time in the history, such as the
+    /// ones that were edited by hand.
+    pub fn clear_best_segments(&mut self) {
+        for segment in &mut self.segments {
+            for method in TimingMethod::all() {
+                segment.best_segment_time_mut()[method] = segment
+                    .segment_history()
+                    .iter()
+                    .filter_map(|(_, time)| time[method])
+                    .min();
+            }
         }
     }
 
@@ -567,22 +834,27 @@ impl Run {
         self.custom_comparisons.retain(|c| c == personal_best::NAME);
         for segment in &mut self.segments {
             segment.comparisons_mut().clear();
+            segment.personal_best_checkpoints_mut().clear();
             segment.set_best_segment_time(Time::default());
         }
         self.attempt_count = 0;
         self.clear_run_id();
     }
 
-    fn fix_comparison_times_and_history(&mut self, method: TimingMethod) {
+    fn fix_comparison_times_and_history(&mut self, method: TimingMethod, rules: FixRules) {
         // Remove negative Best Segment Times
-        for segment in &mut self.segments {
-            if segment.best_segment_time_mut()[method].is_some_and(|t| t < TimeSpan::zero()) {
-                segment.best_segment_time_mut()[method] = None;
+        if rules.remove_negative_best_segments {
+            for segment in &mut self.segments {
+                if segment.best_segment_time_mut()[method].is_some_and(|t| t < TimeSpan::zero()) {
+                    segment.best_segment_time_mut()[method] = None;
+                }
             }
         }
 
-        for segment in &mut self.segments {
-            fix_history_from_none_best_segments(segment, method);
+        if rules.patch_segment_history {
+            for segment in &mut self.segments {
+                fix_history_from_none_best_segments(segment, method);
+            }
         }
 
         for comparison in &self.custom_comparisons {
@@ -590,13 +862,13 @@ impl Run {
             for segment in &mut self.segments {
                 if let Some(mut time) = segment.comparison_mut(comparison)[method] {
                     // Prevent comparison times from decreasing from one split to the next
-                    if time < previous_time {
+                    if rules.monotonic_comparisons && time < previous_time {
                         time = previous_time;
                         segment.comparison_mut(comparison)[method] = Some(time);
                     }
 
                     // Fix Best Segment time if the PB segment is faster
-                    if comparison == personal_best::NAME {
+                    if rules.cap_best_segments && comparison == personal_best::NAME {
                         let current_segment = time - previous_time;
                         if segment.best_segment_time()[method].map_or(true, |t| t > current_segment)
                         {
@@ -609,8 +881,10 @@ impl Run {
             }
         }
 
-        for segment in &mut self.segments {
-            fix_history_from_best_segment_times(segment, method);
+        if rules.patch_segment_history {
+            for segment in &mut self.segments {
+                fix_history_from_best_segment_times(segment, method);
+            }
         }
     }
 
@@ -681,6 +955,7 @@ impl Run {
         let ind = index - cache.len();
         for (index, segment) in cache.drain(..).zip(self.segments_mut()[ind..].iter_mut()) {
             segment.segment_history_mut().remove(index);
+            segment.skip_reason_history_mut().remove(&index);
         }
     }
 
@@ -754,6 +1029,9 @@ impl Run {
             let split_time = segment.split_time();
             let segment_time = split_time - previous_split_time;
             segment.segment_history_mut().insert(index, segment_time);
+            if let Some(reason) = segment.skip_reason() {
+                segment.skip_reason_history_mut().insert(index, reason);
+            }
             if let Some(time) = split_time.real_time {
                 previous_split_time.real_time = Some(time);
             }
@@ -770,6 +1048,8 @@ impl Run {
             Err(AddComparisonError::NameStartsWithRace)
         } else if self.comparisons().any(|c| c == new) {
             Err(AddComparisonError::DuplicateName)
+        } else if comparison::is_generator_name(new) {
+            Err(AddComparisonError::GeneratorName)
         } else {
             Ok(())
         }
@@ -32,13 +32,14 @@ use super::{
     source_live_timer, speedrun_igt, splits_io, splitterino, splitterz, splitty,
     time_split_tracker, urn, wsplit, TimerKind,
 };
-use crate::{platform::path::Path, Run};
+use crate::{platform::path::Path, run::FixRules, Run};
 use core::{result::Result as StdResult, str};
 
 /// The Error type for splits files that couldn't be parsed by the Composite
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// No parser was able to parse the splits file.
     NoParserParsedIt,
@@ -87,6 +88,18 @@ pub fn parse_and_fix<'source>(
     Ok(run)
 }
 
+/// Attempts to parse and fix a splits file, just like [`parse_and_fix`], but
+/// only applies the fixes selected by the [`FixRules`] provided.
+pub fn parse_and_fix_with<'source>(
+    source: &'source [u8],
+    load_files_path: Option<&Path>,
+    rules: FixRules,
+) -> Result<ParsedRun<'source>> {
+    let mut run = parse(source, load_files_path)?;
+    run.run.fix_splits_with(rules);
+    Ok(run)
+}
+
 /// Attempts to parse a splits file by invoking the corresponding parser for the
 /// file format detected. Additionally you can provide the path of the splits
 /// file so additional files, like external images, can be loaded. If you are
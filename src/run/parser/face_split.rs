@@ -9,6 +9,7 @@ use snafu::{OptionExt, ResultExt};
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// Expected the title, but didn't find it.
     ExpectedTitle,
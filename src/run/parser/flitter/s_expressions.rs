@@ -11,6 +11,7 @@ use serde::{
 /// The Error types for splits files that couldn't be parsed by the Flitter
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
+#[non_exhaustive]
 pub enum Error {
     /// Trailing Characters
     TrailingCharacters,
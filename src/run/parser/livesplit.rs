@@ -2,20 +2,20 @@
 
 use crate::{
     platform::prelude::*,
-    run::{AddComparisonError, LinkedLayout},
+    run::{AddComparisonError, Checkpoint, LinkedLayout},
     settings::Image,
     util::{
         ascii_char::AsciiChar,
         xml::{
             helper::{
-                attribute, attribute_escaped_err, end_tag, image, optional_attribute_escaped_err,
-                parse_attributes, parse_base, parse_children, reencode_children, text,
-                text_as_escaped_string_err, text_parsed, Error as XmlError,
+                attribute, attribute_escaped_err, close_unclosed_elements, end_tag, image,
+                optional_attribute_escaped_err, parse_attributes, parse_base, parse_children,
+                reencode_children, text, text_as_escaped_string_err, text_err, Error as XmlError,
             },
             Reader,
         },
     },
-    AtomicDateTime, DateTime, Run, RunMetadata, Segment, Time, TimeSpan,
+    AtomicDateTime, DateTime, Run, RunMetadata, Segment, Time, TimeSpan, TimingMethod,
 };
 use alloc::borrow::Cow;
 use core::{mem::MaybeUninit, str};
@@ -24,6 +24,7 @@ use time::{Date, Duration, PrimitiveDateTime};
 /// The Error type for splits files that couldn't be parsed by the LiveSplit
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
+#[non_exhaustive]
 pub enum Error {
     /// The underlying XML format couldn't be parsed.
     Xml {
@@ -91,6 +92,57 @@ impl From<AddComparisonError> for Error {
 /// The Result type for the LiveSplit Parser.
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// A malformed value that got skipped by [`parse_permissive`].
+#[derive(Debug)]
+pub struct Warning {
+    /// The byte offset into the splits file at which the element containing
+    /// the malformed value ends or, for attributes, at which the element
+    /// starts its content.
+    pub position: usize,
+    /// Describes why the value couldn't be parsed.
+    pub error: Error,
+}
+
+/// A run parsed by [`parse_permissive`], along with the warnings about all the
+/// malformed values that got skipped.
+#[derive(Debug)]
+pub struct PermissiveRun {
+    /// The parsed run.
+    pub run: Run,
+    /// The warnings about the malformed values, in the order they were
+    /// encountered.
+    pub warnings: Vec<Warning>,
+}
+
+/// A run parsed by [`parse_recovering`], along with whether it had to be
+/// recovered from a truncated splits file.
+#[derive(Debug)]
+pub struct RecoveredRun {
+    /// The parsed run.
+    pub run: Run,
+    /// States whether the splits file was truncated. If it was, the run only
+    /// contains the information that was stored before the point where the
+    /// file got cut off.
+    pub recovered: bool,
+}
+
+/// Collects the warnings while parsing permissively. When parsing strictly,
+/// all the errors are passed through instead.
+struct Warnings(Option<Vec<Warning>>);
+
+impl Warnings {
+    fn recover<T>(&mut self, position: usize, result: Result<T>) -> Result<Option<T>> {
+        match (result, &mut self.0) {
+            (Ok(value), _) => Ok(Some(value)),
+            (Err(error), Some(warnings)) => {
+                warnings.push(Warning { position, error });
+                Ok(None)
+            }
+            (Err(error), None) => Err(error),
+        }
+    }
+}
+
 // FIXME: Generalized Type Ascription (GTA 6)
 #[inline]
 const fn type_hint<T>(v: Result<T>) -> Result<T> {
@@ -139,25 +191,29 @@ fn parse_date_time(text: &str) -> Result<DateTime> {
     .ok_or(Error::ParseDate)
 }
 
-fn time_span<F>(reader: &mut Reader<'_>, f: F) -> Result<()>
+fn time_span<F>(reader: &mut Reader<'_>, warnings: &mut Warnings, f: F) -> Result<()>
 where
     F: FnOnce(TimeSpan),
 {
+    let position = reader.position();
     text_as_escaped_string_err(reader, |text| {
-        f(parse_time_span(text)?);
+        if let Some(time) = warnings.recover(position, parse_time_span(text))? {
+            f(time);
+        }
         Ok(())
     })
 }
 
-fn time_span_opt<F>(reader: &mut Reader<'_>, f: F) -> Result<()>
+fn time_span_opt<F>(reader: &mut Reader<'_>, warnings: &mut Warnings, f: F) -> Result<()>
 where
     F: FnOnce(Option<TimeSpan>),
 {
+    let position = reader.position();
     text_as_escaped_string_err(reader, |text| {
         f(if text.is_empty() {
             None
         } else {
-            Some(parse_time_span(text)?)
+            warnings.recover(position, parse_time_span(text))?
         });
         Ok(())
     })
@@ -176,6 +232,14 @@ fn parse_time_span(text: &str) -> Result<TimeSpan> {
 
             let days: TimeSpan = Duration::seconds(days_secs).into();
 
+            // The remainder must not have a day prefix of its own.
+            if AsciiChar::DOT
+                .split_once(after_dot)
+                .is_some_and(|(before_dot, _)| !AsciiChar::COLON.contains(before_dot))
+            {
+                return Err(Error::ParseExtendedTime);
+            }
+
             let time: TimeSpan = after_dot.parse()?;
 
             if time < TimeSpan::zero() {
@@ -192,7 +256,7 @@ fn parse_time_span(text: &str) -> Result<TimeSpan> {
     text.parse().map_err(Into::into)
 }
 
-fn time<F>(reader: &mut Reader<'_>, f: F) -> Result<()>
+fn time<F>(reader: &mut Reader<'_>, warnings: &mut Warnings, f: F) -> Result<()>
 where
     F: FnOnce(Time),
 {
@@ -200,9 +264,9 @@ where
 
     parse_children(reader, |reader, tag, _| {
         if tag.name() == "RealTime" {
-            time_span_opt(reader, |t| time.real_time = t)
+            time_span_opt(reader, warnings, |t| time.real_time = t)
         } else if tag.name() == "GameTime" {
-            time_span_opt(reader, |t| time.game_time = t)
+            time_span_opt(reader, warnings, |t| time.game_time = t)
         } else {
             end_tag(reader)
         }
@@ -213,11 +277,17 @@ where
     Ok(())
 }
 
-fn time_old<F>(reader: &mut Reader<'_>, f: F) -> Result<()>
+fn time_old<F>(reader: &mut Reader<'_>, warnings: &mut Warnings, f: F) -> Result<()>
 where
     F: FnOnce(Time),
 {
-    time_span_opt(reader, |t| f(Time::new().with_real_time(t)))
+    time_span_opt(reader, warnings, |t| f(Time::new().with_real_time(t)))
+}
+
+fn attempt_index(index: Option<Result<i32>>) -> Result<i32> {
+    index.unwrap_or(Err(Error::Xml {
+        source: XmlError::AttributeNotFound,
+    }))
 }
 
 fn parse_bool(value: &str) -> Result<bool> {
@@ -231,6 +301,7 @@ fn parse_bool(value: &str) -> Result<bool> {
 fn parse_metadata(
     version: Version,
     reader: &mut Reader<'_>,
+    warnings: &mut Warnings,
     metadata: &mut RunMetadata,
 ) -> Result<()> {
     if version >= Version(1, 6, 0, 0) {
@@ -240,8 +311,11 @@ fn parse_metadata(
                 end_tag(reader)
             }
             "Platform" => {
+                let position = reader.position();
                 type_hint(attribute_escaped_err(attributes, "usesEmulator", |t| {
-                    metadata.set_emulator_usage(parse_bool(t)?);
+                    if let Some(uses_emulator) = warnings.recover(position, parse_bool(t))? {
+                        metadata.set_emulator_usage(uses_emulator);
+                    }
                     Ok(())
                 }))?;
                 text(reader, |t| metadata.set_platform_name(t))
@@ -264,6 +338,20 @@ fn parse_metadata(
                 type_hint(text(reader, |t| var.set_value(t)))?;
                 Ok(())
             }),
+            "ToolData" => parse_children(reader, |reader, _, attributes| {
+                let mut namespace = Cow::Borrowed("");
+                type_hint(attribute(attributes, "namespace", |t| namespace = t))?;
+                let data = metadata.tool_data_mut(namespace);
+                parse_children(reader, |reader, _, attributes| {
+                    let mut key = Cow::Borrowed("");
+                    let mut value = Cow::Borrowed("");
+                    type_hint(attribute(attributes, "key", |t| key = t))?;
+                    type_hint(text(reader, |t| value = t))?;
+                    data.insert(key, value.into_owned());
+                    Ok(())
+                })
+            }),
+            "Estimate" => time_span_opt(reader, warnings, |t| metadata.set_estimate(t)),
             _ => end_tag(reader),
         })
     } else {
@@ -274,6 +362,7 @@ fn parse_metadata(
 fn parse_segment(
     version: Version,
     reader: &mut Reader<'_>,
+    warnings: &mut Warnings,
     image_buf: &mut Vec<MaybeUninit<u8>>,
     run: &mut Run,
 ) -> Result<Segment> {
@@ -291,9 +380,13 @@ fn parse_segment(
                         let mut comparison = Cow::Borrowed("");
                         type_hint(attribute(attributes, "name", |t| comparison = t))?;
                         if version >= Version(1, 4, 1, 0) {
-                            time(reader, |t| *segment.comparison_mut(&comparison) = t)?;
+                            time(reader, warnings, |t| {
+                                *segment.comparison_mut(&comparison) = t
+                            })?;
                         } else {
-                            time_old(reader, |t| *segment.comparison_mut(&comparison) = t)?;
+                            time_old(reader, warnings, |t| {
+                                *segment.comparison_mut(&comparison) = t
+                            })?;
                         }
                         if let Err(AddComparisonError::NameStartsWithRace) =
                             run.add_custom_comparison(comparison)
@@ -311,65 +404,102 @@ fn parse_segment(
         }
         "PersonalBestSplitTime" => {
             if version < Version(1, 3, 0, 0) {
-                time_old(reader, |t| segment.set_personal_best_split_time(t))
+                time_old(reader, warnings, |t| {
+                    segment.set_personal_best_split_time(t)
+                })
             } else {
                 end_tag(reader)
             }
         }
         "BestSegmentTime" => {
             if version >= Version(1, 4, 1, 0) {
-                time(reader, |t| segment.set_best_segment_time(t))
+                time(reader, warnings, |t| segment.set_best_segment_time(t))
             } else {
-                time_old(reader, |t| segment.set_best_segment_time(t))
+                time_old(reader, warnings, |t| segment.set_best_segment_time(t))
             }
         }
+        "Checkpoints" => parse_children(reader, |reader, tag, attributes| {
+            if tag.name() == "Checkpoint" {
+                let mut name = Cow::Borrowed("");
+                type_hint(attribute(attributes, "name", |t| name = t))?;
+                time(reader, warnings, |t| {
+                    segment
+                        .personal_best_checkpoints_mut()
+                        .push(Checkpoint::new(name, t))
+                })
+            } else {
+                end_tag(reader)
+            }
+        }),
         "SegmentHistory" => parse_children(reader, |reader, _, attributes| {
-            let mut index = 0;
-            type_hint(attribute_escaped_err(attributes, "id", |t| {
-                index = t.parse()?;
-                Ok(())
+            let (mut index, mut skip_reason) = (None, None);
+            type_hint(parse_attributes(attributes, |k, v| {
+                match k {
+                    "id" => index = Some(v.escaped().parse().map_err(Into::into)),
+                    "skipReason" => skip_reason = v.escaped().parse().ok(),
+                    _ => {}
+                }
+                Ok(true)
             }))?;
+            let Some(index) = warnings.recover(reader.position(), attempt_index(index))? else {
+                return end_tag(reader);
+            };
+            if let Some(reason) = skip_reason {
+                segment.skip_reason_history_mut().insert(index, reason);
+            }
             if version >= Version(1, 4, 1, 0) {
-                time(reader, |t| segment.segment_history_mut().insert(index, t))
+                time(reader, warnings, |t| {
+                    segment.segment_history_mut().insert(index, t)
+                })
             } else {
-                time_old(reader, |t| segment.segment_history_mut().insert(index, t))
+                time_old(reader, warnings, |t| {
+                    segment.segment_history_mut().insert(index, t)
+                })
             }
         }),
+        "PracticeHistory" => parse_children(reader, |reader, _, _| {
+            time(reader, warnings, |t| segment.practice_history_mut().push(t))
+        }),
         _ => end_tag(reader),
     })?;
 
     Ok(segment)
 }
 
-fn parse_run_history(version: Version, reader: &mut Reader<'_>, run: &mut Run) -> Result<()> {
+fn parse_run_history(
+    version: Version,
+    reader: &mut Reader<'_>,
+    warnings: &mut Warnings,
+    run: &mut Run,
+) -> Result<()> {
     if version >= Version(1, 5, 0, 0) {
         end_tag(reader)
-    } else if version >= Version(1, 4, 1, 0) {
-        parse_children(reader, |reader, _, attributes| {
-            let mut index = 0;
-            type_hint(attribute_escaped_err(attributes, "id", |t| {
-                index = t.parse()?;
-                Ok(())
-            }))?;
-            time(reader, |time| {
-                run.add_attempt_with_index(time, index, None, None, None)
-            })
-        })
     } else {
         parse_children(reader, |reader, _, attributes| {
-            let mut index = 0;
-            type_hint(attribute_escaped_err(attributes, "id", |t| {
-                index = t.parse()?;
+            let mut index = None;
+            type_hint(optional_attribute_escaped_err(attributes, "id", |t| {
+                index = Some(t.parse().map_err(Into::into));
                 Ok(())
             }))?;
-            time_old(reader, |time| {
-                run.add_attempt_with_index(time, index, None, None, None)
-            })
+            let Some(index) = warnings.recover(reader.position(), attempt_index(index))? else {
+                return end_tag(reader);
+            };
+            let add_attempt = |time| run.add_attempt_with_index(time, index, None, None, None);
+            if version >= Version(1, 4, 1, 0) {
+                time(reader, warnings, add_attempt)
+            } else {
+                time_old(reader, warnings, add_attempt)
+            }
         })
     }
 }
 
-fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Run) -> Result<()> {
+fn parse_attempt_history(
+    version: Version,
+    reader: &mut Reader<'_>,
+    warnings: &mut Warnings,
+    run: &mut Run,
+) -> Result<()> {
     if version >= Version(1, 5, 0, 0) {
         parse_children(reader, |reader, _, attributes| {
             let mut time = Time::new();
@@ -378,26 +508,32 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
             let (mut started, mut started_synced) = (None, false);
             let (mut ended, mut ended_synced) = (None, false);
 
+            let position = reader.position();
             type_hint(parse_attributes(attributes, |k, v| {
+                let v = v.escaped();
                 match k {
-                    "id" => index = Some(v.escaped().parse()?),
-                    "started" => started = Some(parse_date_time(v.escaped())?),
-                    "isStartedSynced" => started_synced = parse_bool(v.escaped())?,
-                    "ended" => ended = Some(parse_date_time(v.escaped())?),
-                    "isEndedSynced" => ended_synced = parse_bool(v.escaped())?,
+                    "id" => index = Some(v.parse().map_err(Into::into)),
+                    "started" => started = warnings.recover(position, parse_date_time(v))?,
+                    "isStartedSynced" => {
+                        started_synced = warnings.recover(position, parse_bool(v))?.unwrap_or(false)
+                    }
+                    "ended" => ended = warnings.recover(position, parse_date_time(v))?,
+                    "isEndedSynced" => {
+                        ended_synced = warnings.recover(position, parse_bool(v))?.unwrap_or(false)
+                    }
                     _ => {}
                 }
                 Ok(true)
             }))?;
 
-            let index = index.ok_or(Error::Xml {
-                source: XmlError::AttributeNotFound,
-            })?;
+            let Some(index) = warnings.recover(position, attempt_index(index))? else {
+                return end_tag(reader);
+            };
 
             parse_children(reader, |reader, tag, _| match tag.name() {
-                "RealTime" => time_span_opt(reader, |t| time.real_time = t),
-                "GameTime" => time_span_opt(reader, |t| time.game_time = t),
-                "PauseTime" => time_span_opt(reader, |t| pause_time = t),
+                "RealTime" => time_span_opt(reader, warnings, |t| time.real_time = t),
+                "GameTime" => time_span_opt(reader, warnings, |t| time.game_time = t),
+                "PauseTime" => time_span_opt(reader, warnings, |t| pause_time = t),
                 _ => end_tag(reader),
             })?;
 
@@ -421,6 +557,56 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
 
 /// Attempts to parse a LiveSplit splits file.
 pub fn parse(source: &str) -> Result<Run> {
+    parse_with_warnings(source, &mut Warnings(None))
+}
+
+/// Attempts to parse a LiveSplit splits file permissively. Instead of failing
+/// on malformed values, such as times, dates or the indices of attempts, they
+/// are skipped and a warning is recorded for each of them. This allows
+/// recovering most of the information from splits files that got slightly
+/// corrupted. Files that aren't well-formed XML or miss required elements
+/// still fail to parse.
+pub fn parse_permissive(source: &str) -> Result<PermissiveRun> {
+    let mut warnings = Warnings(Some(Vec::new()));
+    let run = parse_with_warnings(source, &mut warnings)?;
+    Ok(PermissiveRun {
+        run,
+        warnings: warnings.0.unwrap_or_default(),
+    })
+}
+
+/// Attempts to parse a LiveSplit splits file that may have been truncated, for
+/// example because the application crashed while saving it. If the file can't
+/// be parsed, all the elements that are still open at the end of the file get
+/// closed and everything that was stored up to that point is parsed instead.
+/// The elements required for a splits file still need to be present, so the
+/// file needs to be intact at least up to the start of the segments. A segment
+/// that got cut off before its name was stored is dropped. If the file can't be
+/// recovered either, the original error is returned.
+pub fn parse_recovering(source: &str) -> Result<RecoveredRun> {
+    match parse(source) {
+        Ok(run) => Ok(RecoveredRun {
+            run,
+            recovered: false,
+        }),
+        Err(error) => {
+            let mut run = close_unclosed_elements(source)
+                .and_then(|source| parse(&source).ok())
+                .ok_or(error)?;
+            let last_segment_is_cut_off = source.rfind("<Segment>") > source.rfind("</Segment>");
+            if last_segment_is_cut_off && run.segments().last().is_some_and(|s| s.name().is_empty())
+            {
+                run.segments_mut().pop();
+            }
+            Ok(RecoveredRun {
+                run,
+                recovered: true,
+            })
+        }
+    }
+}
+
+fn parse_with_warnings(source: &str, warnings: &mut Warnings) -> Result<Run> {
     let mut reader = Reader::new(source);
 
     let mut image_buf = Vec::new();
@@ -431,8 +617,11 @@ pub fn parse(source: &str) -> Result<Run> {
 
     parse_base(&mut reader, "Run", |reader, attributes| {
         let mut version = Version(1, 0, 0, 0);
+        let position = reader.position();
         type_hint(optional_attribute_escaped_err(attributes, "version", |t| {
-            version = parse_version(t)?;
+            if let Some(v) = warnings.recover(position, parse_version(t))? {
+                version = v;
+            }
             Ok(())
         }))?;
 
@@ -453,20 +642,28 @@ pub fn parse(source: &str) -> Result<Run> {
             }
             "Offset" => {
                 required_flags |= 1 << 3;
-                time_span(reader, |t| run.set_offset(t))
+                time_span(reader, warnings, |t| run.set_offset(t))
             }
             "AttemptCount" => {
                 required_flags |= 1 << 4;
-                text_parsed(reader, |t| run.set_attempt_count(t))
+                let position = reader.position();
+                text_err(reader, |t| {
+                    let count = t.parse().map_err(Into::into);
+                    if let Some(count) = warnings.recover(position, count)? {
+                        run.set_attempt_count(count);
+                    }
+                    Ok(())
+                })
             }
-            "AttemptHistory" => parse_attempt_history(version, reader, &mut run),
-            "RunHistory" => parse_run_history(version, reader, &mut run),
-            "Metadata" => parse_metadata(version, reader, run.metadata_mut()),
+            "AttemptHistory" => parse_attempt_history(version, reader, warnings, &mut run),
+            "RunHistory" => parse_run_history(version, reader, warnings, &mut run),
+            "Metadata" => parse_metadata(version, reader, warnings, run.metadata_mut()),
             "Segments" => {
                 required_flags |= 1 << 5;
                 parse_children(reader, |reader, tag, _| {
                     if tag.name() == "Segment" {
-                        let segment = parse_segment(version, reader, &mut image_buf, &mut run)?;
+                        let segment =
+                            parse_segment(version, reader, warnings, &mut image_buf, &mut run)?;
                         run.push_segment(segment);
                         Ok(())
                     } else {
@@ -487,6 +684,13 @@ pub fn parse(source: &str) -> Result<Run> {
                     Some(LinkedLayout::Path(t.into_owned()))
                 });
             }),
+            "LockedTimingMethod" => text(reader, |t| {
+                run.set_locked_timing_method(match &*t {
+                    "RealTime" => Some(TimingMethod::RealTime),
+                    "GameTime" => Some(TimingMethod::GameTime),
+                    _ => None,
+                });
+            }),
             _ => end_tag(reader),
         })
     })?;
@@ -524,4 +728,19 @@ mod tests {
         parse_time_span("NaN.23:34:56.789").unwrap_err();
         parse_time_span("Inf.23:34:56.789").unwrap_err();
     }
+
+    #[test]
+    fn saved_times_roundtrip() {
+        let nano = TimeSpan::from(Duration::nanoseconds(1));
+        for time in [
+            TimeSpan::zero(),
+            -nano,
+            TimeSpan::from_seconds(86400.0) - nano,
+            TimeSpan::from_seconds(-1234567.891),
+            TimeSpan::MAX,
+            TimeSpan::MIN,
+        ] {
+            assert_eq!(parse_time_span(&time.to_string()).unwrap(), time);
+        }
+    }
 }
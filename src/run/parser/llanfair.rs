@@ -16,6 +16,7 @@ use snafu::{OptionExt, ResultExt};
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// Failed to read the header.
     ReadHeader,
@@ -67,6 +68,7 @@ pub enum Error {
 /// An error type that indicates that a string failed to be parsed.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum StringError {
     /// Failed to read the length of the string.
     ReadLength,
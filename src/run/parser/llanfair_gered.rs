@@ -26,6 +26,7 @@ use snafu::OptionExt;
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// The underlying XML format couldn't be parsed.
     Xml {
@@ -34,6 +34,7 @@ pub mod flitter;
 pub mod livesplit;
 pub mod llanfair;
 pub mod llanfair_gered;
+pub mod plain_text;
 pub mod portal2_live_timer;
 pub mod shit_split;
 pub mod source_live_timer;
@@ -50,4 +51,4 @@ mod timer_kind;
 
 pub use self::timer_kind::TimerKind;
 
-pub use composite::{parse, parse_and_fix};
+pub use composite::{parse, parse_and_fix, parse_and_fix_with};
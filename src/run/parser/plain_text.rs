@@ -0,0 +1,168 @@
+//! Provides a best effort parser for splits that are written down as plain
+//! text, such as a list of segments pasted from a notes document. Each line
+//! describes a segment with its name and optionally a time, separated by tabs,
+//! spaces, `|`, `,` or `;`:
+//!
+//! ```text
+//! Forest 1:23.45
+//! Castle | 4:56.78
+//! Final Boss, 1:02:03
+//! ```
+//!
+//! The time may also come before the name of the segment. Times are specified
+//! as `h:mm:ss`, `mm:ss` or seconds with a fractional part. If the times are
+//! strictly increasing, they are interpreted as split times. Otherwise they
+//! are interpreted as segment times and added up. Empty lines are ignored.
+//!
+//! As pretty much any text can be interpreted this way, this parser is not
+//! part of the [`composite`](super::composite) parser and needs to be used
+//! explicitly.
+
+use crate::{platform::prelude::*, RealTime, Run, Segment, TimeSpan};
+use core::result::Result as StdResult;
+
+/// The Error type for text that couldn't be parsed by the Plain Text Parser.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+#[non_exhaustive]
+pub enum Error {
+    /// The text doesn't contain any segments.
+    Empty,
+}
+
+/// The Result type for the Plain Text Parser.
+pub type Result<T> = StdResult<T, Error>;
+
+const SEPARATORS: &[char] = &['\t', ' ', '|', ',', ';'];
+const NAME_TRIM: &[char] = &['\t', ' ', '|', ',', ';', '-', '–', '—', ':', '='];
+
+fn parse_time(text: &str) -> Option<TimeSpan> {
+    if !text.contains([':', '.']) {
+        return None;
+    }
+    text.parse().ok().filter(|&time| time >= TimeSpan::zero())
+}
+
+fn parse_line(line: &str) -> (&str, Option<TimeSpan>) {
+    if let Some((name, time)) = line.rsplit_once(SEPARATORS) {
+        if let Some(time) = parse_time(time) {
+            return (name.trim_matches(NAME_TRIM), Some(time));
+        }
+    }
+    if let Some((time, name)) = line.split_once(SEPARATORS) {
+        if let Some(time) = parse_time(time) {
+            return (name.trim_matches(NAME_TRIM), Some(time));
+        }
+    }
+    if let Some(time) = parse_time(line) {
+        return ("", Some(time));
+    }
+    (line, None)
+}
+
+/// Attempts to parse splits that are written down as plain text.
+pub fn parse(source: &str) -> Result<Run> {
+    let segments: Vec<_> = source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_line)
+        .collect();
+
+    if segments.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    let mut times = segments.iter().filter_map(|&(_, time)| time);
+    let are_split_times = times.next().map_or(true, |first| {
+        times
+            .try_fold(first, |previous, time| (time > previous).then_some(time))
+            .is_some()
+    });
+
+    let mut run = Run::new();
+    let mut total_time = Some(TimeSpan::zero());
+
+    for (name, time) in segments {
+        let mut segment = Segment::new(name);
+        let split_time = if are_split_times {
+            time
+        } else {
+            total_time = catch! { total_time? + time? };
+            total_time
+        };
+        segment.set_personal_best_split_time(RealTime(split_time).into());
+        run.push_segment(segment);
+    }
+
+    Ok(run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TimingMethod;
+
+    fn split_times(run: &Run) -> Vec<Option<TimeSpan>> {
+        run.segments()
+            .iter()
+            .map(|s| s.personal_best_split_time()[TimingMethod::RealTime])
+            .collect()
+    }
+
+    fn seconds(times: &[f64]) -> Vec<Option<TimeSpan>> {
+        times
+            .iter()
+            .map(|&t| Some(TimeSpan::from_seconds(t)))
+            .collect()
+    }
+
+    #[test]
+    fn parses_various_separators() {
+        let run = parse(
+            "Forest\t1:23.5\n\n  Castle | 4:56  \nFinal Boss, The Return; 1:02:03\nLast - 1:10:00\n",
+        )
+        .unwrap();
+
+        let names: Vec<_> = run.segments().iter().map(Segment::name).collect();
+        assert_eq!(
+            names,
+            ["Forest", "Castle", "Final Boss, The Return", "Last"]
+        );
+        assert_eq!(split_times(&run), seconds(&[83.5, 296.0, 3723.0, 4200.0]));
+    }
+
+    #[test]
+    fn accumulates_segment_times() {
+        let run = parse("1:00 A\n0:30 B\nC\n45.5 D").unwrap();
+
+        let names: Vec<_> = run.segments().iter().map(Segment::name).collect();
+        assert_eq!(names, ["A", "B", "C", "D"]);
+        assert_eq!(
+            split_times(&run),
+            [
+                Some(TimeSpan::from_seconds(60.0)),
+                Some(TimeSpan::from_seconds(90.0)),
+                None,
+                None
+            ],
+        );
+    }
+
+    #[test]
+    fn names_ending_in_numbers_are_not_times() {
+        let run = parse("World 1\nWorld 2\t2:00").unwrap();
+
+        let names: Vec<_> = run.segments().iter().map(Segment::name).collect();
+        assert_eq!(names, ["World 1", "World 2"]);
+        assert_eq!(
+            split_times(&run),
+            [None, Some(TimeSpan::from_seconds(120.0))]
+        );
+    }
+
+    #[test]
+    fn rejects_empty_text() {
+        assert!(matches!(parse(" \n\t\n"), Err(Error::Empty)));
+    }
+}
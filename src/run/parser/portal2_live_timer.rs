@@ -8,6 +8,7 @@ use snafu::{OptionExt, ResultExt};
 /// Live Timer Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// Expected another map, but didn't find it.
     ExpectedMap,
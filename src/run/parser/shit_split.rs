@@ -8,6 +8,7 @@ use snafu::{OptionExt, ResultExt};
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// An empty splits file was provided.
     Empty,
@@ -13,6 +13,7 @@ use crate::{
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// Failed to parse JSON.
     Json {
@@ -10,6 +10,7 @@ use serde_json::Error as JsonError;
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// Failed to parse JSON.
     Json {
@@ -9,6 +9,7 @@ use snafu::ResultExt;
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// An empty splits file was provided.
     Empty,
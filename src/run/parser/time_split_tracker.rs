@@ -16,6 +16,7 @@ use snafu::{OptionExt, ResultExt};
 /// Split Tracker Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// An empty splits file was provided.
     Empty,
@@ -124,7 +125,7 @@ pub fn parse(
         loop {
             match run.add_custom_comparison(&**comparison) {
                 Ok(_) => break,
-                Err(AddComparisonError::DuplicateName) => {
+                Err(AddComparisonError::DuplicateName | AddComparisonError::GeneratorName) => {
                     let comparison = comparison.to_mut();
                     comparison.drain(orig_len..);
                     let _ = write!(comparison, " {number}");
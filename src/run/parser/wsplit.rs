@@ -11,6 +11,7 @@ use snafu::ResultExt;
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
 #[snafu(context(suffix(false)))]
+#[non_exhaustive]
 pub enum Error {
     /// Expected the name of the segment, but didn't find it.
     ExpectedSegmentName,
@@ -0,0 +1,118 @@
+use crate::{
+    platform::{to_local, DateTime, Duration},
+    run::Attempt,
+    AtomicDateTime, Run, TimeSpan,
+};
+use serde_derive::{Deserialize, Serialize};
+use time::{Date, Month, Time};
+
+/// A period of time relative to the current date, such as the current week.
+/// Periods start at midnight and weeks start on Monday.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Period {
+    /// The current day.
+    Today,
+    /// The current week, starting on Monday.
+    ThisWeek,
+    /// The current month.
+    ThisMonth,
+}
+
+impl Period {
+    /// Determines the range of the period that contains the point in time
+    /// provided. The start is inclusive and the end is exclusive. The
+    /// boundaries are calculated in the UTC offset of the point in time
+    /// provided, so it should already be converted to the local time.
+    pub fn range(self, now: DateTime) -> (DateTime, DateTime) {
+        let today = now.replace_time(Time::MIDNIGHT);
+        match self {
+            Period::Today => (today, today + Duration::DAY),
+            Period::ThisWeek => {
+                let start =
+                    today - Duration::days(today.weekday().number_days_from_monday().into());
+                (start, start + Duration::WEEK)
+            }
+            Period::ThisMonth => {
+                let (year, month) = match today.month() {
+                    Month::December => (today.year() + 1, Month::January),
+                    month => (today.year(), month.next()),
+                };
+                let start = today.replace_day(1).unwrap();
+                let end = Date::from_calendar_date(year, month, 1)
+                    .map_or(start, |date| start.replace_date(date));
+                (start, end)
+            }
+        }
+    }
+}
+
+/// Statistics about the attempts of a [`Run`] in a specific [`Period`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodStats {
+    /// The amount of attempts that were started in the period.
+    pub attempt_count: usize,
+    /// The amount of attempts started in the period that were finished.
+    pub finished_count: usize,
+    /// The total amount of time spent on the attempts started in the period,
+    /// excluding the time they were paused.
+    pub playtime: TimeSpan,
+}
+
+impl Run {
+    /// Returns all the attempts in the Attempt History that were started
+    /// between the two points in time provided. The start is inclusive and the
+    /// end is exclusive. If it's unknown when an attempt was started, the
+    /// point in time it ended at is used instead. Attempts without either are
+    /// never returned.
+    pub fn attempts_between(
+        &self,
+        from: DateTime,
+        to: DateTime,
+    ) -> impl Iterator<Item = &Attempt> + '_ {
+        self.attempt_history().iter().filter(move |attempt| {
+            attempt
+                .started()
+                .or(attempt.ended())
+                .is_some_and(|date| date.time >= from && date.time < to)
+        })
+    }
+
+    /// Calculates the statistics about the attempts that were started in the
+    /// period containing the point in time provided. The point in time should
+    /// already be converted to the local time, as described by
+    /// [`Period::range`].
+    pub fn period_stats(&self, period: Period, now: DateTime) -> PeriodStats {
+        let (from, to) = period.range(now);
+        let mut stats = PeriodStats::default();
+
+        for attempt in self.attempts_between(from, to) {
+            stats.attempt_count += 1;
+            if attempt.time().real_time.is_some() {
+                stats.finished_count += 1;
+            }
+            if let Some(duration) = attempt.duration() {
+                stats.playtime += duration - attempt.pause_time().unwrap_or_default();
+            }
+        }
+
+        stats
+    }
+
+    /// Counts the attempts that were started in the current week, according
+    /// to the local time.
+    pub fn attempts_this_week(&self) -> usize {
+        self.period_stats(Period::ThisWeek, local_now())
+            .attempt_count
+    }
+
+    /// Calculates the total amount of time spent on the attempts that were
+    /// started in the current month, according to the local time.
+    pub fn playtime_this_month(&self) -> TimeSpan {
+        self.period_stats(Period::ThisMonth, local_now()).playtime
+    }
+}
+
+fn local_now() -> DateTime {
+    to_local(AtomicDateTime::now().time)
+}
@@ -4,6 +4,7 @@ use crate::{
         ordered_map::{Iter, Map},
         PopulateString,
     },
+    TimeSpan,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -77,6 +78,14 @@ pub struct RunMetadata {
     /// the runner. Additionally auto splitters or other sources may provide
     /// temporary custom variables that are not stored in the splits files.
     pub custom_variables: Map<CustomVariable>,
+    /// Stores arbitrary key value pairs that tools such as auto splitters,
+    /// practice tools or route planners attach to the run. The data of each
+    /// tool is stored in its own namespace, so the tools don't interfere with
+    /// each other.
+    pub tool_data: Map<Map<String>>,
+    /// The estimated duration of the run, such as the one a marathon schedules
+    /// for it. This may be `None` if it's not specified.
+    pub estimate: Option<TimeSpan>,
 }
 
 impl RunMetadata {
@@ -211,6 +220,48 @@ impl RunMetadata {
         self.custom_variables.iter()
     }
 
+    /// Accesses the data that the tool with the namespace specified attached to
+    /// the run, if there is any.
+    pub fn tool_data(&self, namespace: &str) -> Option<&Map<String>> {
+        self.tool_data.get(namespace)
+    }
+
+    /// Mutably accesses the data that the tool with the namespace specified
+    /// attached to the run. If there is no data for the namespace yet, an empty
+    /// map gets created for it.
+    pub fn tool_data_mut<S>(&mut self, namespace: S) -> &mut Map<String>
+    where
+        S: PopulateString,
+    {
+        self.tool_data.entry(namespace).or_default()
+    }
+
+    /// Removes all the data that the tool with the namespace specified attached
+    /// to the run. Nothing happens if there is no such data.
+    pub fn remove_tool_data(&mut self, namespace: &str) {
+        self.tool_data.shift_remove(namespace);
+    }
+
+    /// Returns an iterator iterating over the namespaces of all the tools that
+    /// attached data to the run, along with their data.
+    pub fn all_tool_data(&self) -> Iter<'_, Map<String>> {
+        self.tool_data.iter()
+    }
+
+    /// Accesses the estimated duration of the run, such as the one a marathon
+    /// schedules for it. This may be `None` if it's not specified.
+    #[inline]
+    pub const fn estimate(&self) -> Option<TimeSpan> {
+        self.estimate
+    }
+
+    /// Sets the estimated duration of the run, such as the one a marathon
+    /// schedules for it. This may be `None` if it's not specified.
+    #[inline]
+    pub fn set_estimate(&mut self, estimate: Option<TimeSpan>) {
+        self.estimate = estimate;
+    }
+
     /// Resets all the Metadata Information.
     pub fn clear(&mut self) {
         self.run_id.clear();
@@ -219,5 +270,7 @@ impl RunMetadata {
         self.uses_emulator = false;
         self.speedrun_com_variables.clear();
         self.custom_variables.clear();
+        self.tool_data.clear();
+        self.estimate = None;
     }
 }
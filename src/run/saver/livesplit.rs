@@ -30,7 +30,7 @@ use crate::{
     settings::Image,
     timing::formatter::{Complete, TimeFormatter},
     util::xml::{AttributeWriter, DisplayAlreadyEscaped, Text, Writer, NO_ATTRIBUTES},
-    DateTime, Run, Time, Timer, TimerPhase,
+    DateTime, Run, Time, Timer, TimerPhase, TimingMethod,
 };
 use alloc::borrow::Cow;
 use core::{fmt, mem::MaybeUninit};
@@ -62,6 +62,19 @@ where
     })
 }
 
+/// Collects the items into a list sorted by the key provided, so that the
+/// output doesn't depend on the order the items were inserted in.
+fn sorted_by_key<I, K, F>(iter: I, key: F) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    K: Ord,
+    F: FnMut(&I::Item) -> K,
+{
+    let mut items: Vec<_> = iter.into_iter().collect();
+    items.sort_by_key(key);
+    items
+}
+
 fn image<W: fmt::Write>(
     writer: &mut Writer<W>,
     tag: &str,
@@ -167,9 +180,16 @@ pub fn save_timer<W: fmt::Write>(timer: &Timer, writer: W) -> fmt::Result {
 
 /// Saves a Run as a LiveSplit splits file (*.lss). Use the `save_timer`
 /// function if the Run is in use by a timer in order to properly save the
-/// current attempt as well.
+/// current attempt as well. Every element is put on its own line and indented
+/// by two spaces, so the same Run always results in the same output and
+/// changes to it can be diffed line by line. Everything that doesn't have an
+/// inherent order is written in a canonical order, independent of the order it
+/// was added to the Run in. The variables and tool data are sorted by their
+/// names and keys and the attempts and the segment history are sorted by their
+/// indices. The comparisons keep the order of the Run, as the order is chosen
+/// by the runner.
 pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
-    let writer = &mut Writer::new_with_default_header(writer)?;
+    let writer = &mut Writer::new_with_default_header(writer)?.with_indentation("  ");
 
     let base64_buf = &mut Vec::new();
     let image_buf = &mut Cow::Borrowed(&LSS_IMAGE_HEADER[..]);
@@ -191,7 +211,7 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
             scoped_iter(
                 writer,
                 "SpeedrunComVariables",
-                metadata.speedrun_com_variables(),
+                sorted_by_key(metadata.speedrun_com_variables(), |&(name, _)| name),
                 |writer, (name, value)| {
                     writer.tag_with_text_content("Variable", [("name", name)], value.as_str())
                 },
@@ -199,13 +219,47 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
             scoped_iter(
                 writer,
                 "CustomVariables",
-                metadata
-                    .custom_variables()
-                    .filter(|(_, var)| var.is_permanent),
+                sorted_by_key(
+                    metadata
+                        .custom_variables()
+                        .filter(|(_, var)| var.is_permanent),
+                    |&(name, _)| name,
+                ),
                 |writer, (name, var)| {
                     writer.tag_with_text_content("Variable", [("name", name)], var.value.as_str())
                 },
-            )
+            )?;
+            let tool_data = sorted_by_key(
+                metadata
+                    .all_tool_data()
+                    .filter(|(_, data)| data.iter().next().is_some()),
+                |&(namespace, _)| namespace,
+            );
+            if !tool_data.is_empty() {
+                writer.tag_with_content("ToolData", NO_ATTRIBUTES, |writer| {
+                    for (namespace, data) in tool_data {
+                        writer.tag_with_content("Tool", [("namespace", namespace)], |writer| {
+                            for (key, value) in sorted_by_key(data.iter(), |&(key, _)| key) {
+                                writer.tag_with_text_content(
+                                    "Entry",
+                                    [("key", key)],
+                                    value.as_str(),
+                                )?;
+                            }
+                            Ok(())
+                        })?;
+                    }
+                    Ok(())
+                })?;
+            }
+            if let Some(estimate) = metadata.estimate() {
+                writer.tag_with_text_content(
+                    "Estimate",
+                    NO_ATTRIBUTES,
+                    DisplayAlreadyEscaped(Complete.format(estimate)),
+                )?;
+            }
+            Ok(())
         })?;
 
         writer.tag_with_text_content(
@@ -218,6 +272,17 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
             },
         )?;
 
+        if let Some(method) = run.locked_timing_method() {
+            writer.tag_with_text_content(
+                "LockedTimingMethod",
+                NO_ATTRIBUTES,
+                match method {
+                    TimingMethod::RealTime => "RealTime",
+                    TimingMethod::GameTime => "GameTime",
+                },
+            )?;
+        }
+
         writer.tag_with_text_content(
             "Offset",
             NO_ATTRIBUTES,
@@ -232,7 +297,7 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
         scoped_iter(
             writer,
             "AttemptHistory",
-            run.attempt_history(),
+            sorted_by_key(run.attempt_history(), |attempt| attempt.index()),
             |writer, attempt| {
                 writer.tag("Attempt", |mut tag| {
                     tag.attribute("id", DisplayAlreadyEscaped(attempt.index()))?;
@@ -292,6 +357,20 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                     time(tag, segment.best_segment_time())
                 })?;
 
+                if !segment.personal_best_checkpoints().is_empty() {
+                    scoped_iter(
+                        writer,
+                        "Checkpoints",
+                        segment.personal_best_checkpoints(),
+                        |writer, checkpoint| {
+                            writer.tag("Checkpoint", |mut tag| {
+                                tag.attribute("name", checkpoint.name.as_str())?;
+                                time(tag, checkpoint.time)
+                            })
+                        },
+                    )?;
+                }
+
                 scoped_iter(
                     writer,
                     "SegmentHistory",
@@ -299,10 +378,24 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                     |writer, &(index, history_time)| {
                         writer.tag("Time", |mut tag| {
                             tag.attribute("id", DisplayAlreadyEscaped(index))?;
+                            if let Some(reason) = segment.skip_reason_for_attempt(index) {
+                                tag.attribute("skipReason", Text::new_escaped(reason.as_str()))?;
+                            }
                             time(tag, history_time)
                         })
                     },
-                )
+                )?;
+
+                if !segment.practice_history().is_empty() {
+                    scoped_iter(
+                        writer,
+                        "PracticeHistory",
+                        segment.practice_history(),
+                        |writer, &practice_time| writer.tag("Time", |tag| time(tag, practice_time)),
+                    )?;
+                }
+
+                Ok(())
             })
         })?;
 
@@ -1,6 +1,8 @@
+use core::sync::atomic::{self, AtomicUsize};
 use hashbrown::HashMap;
+use serde_derive::{Deserialize, Serialize};
 
-use super::Comparisons;
+use super::{Checkpoint, Comparisons, SkipReason};
 use crate::{
     comparison::personal_best, platform::prelude::*, settings::Image, util::PopulateString,
     SegmentHistory, Time, TimeSpan, TimingMethod,
@@ -20,15 +22,67 @@ use crate::{
 /// let time = Time::new().with_real_time(Some(TimeSpan::from_seconds(234.0)));
 /// segment.set_personal_best_split_time(time);
 /// ```
-#[derive(Clone, Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug)]
 pub struct Segment {
+    id: SegmentId,
     name: String,
     icon: Image,
     best_segment_time: Time,
     split_time: Time,
     segment_history: SegmentHistory,
+    practice_history: Vec<Time>,
     comparisons: Comparisons,
     variables: HashMap<String, String>,
+    skip_reason: Option<SkipReason>,
+    skip_reason_history: HashMap<i32, SkipReason>,
+    checkpoints: Vec<Checkpoint>,
+    personal_best_checkpoints: Vec<Checkpoint>,
+}
+
+/// A stable identifier of a [`Segment`]. Unlike the index of a segment, it
+/// doesn't change when segments are inserted, removed or reordered, so it can
+/// be used to track rows across such changes. Identifiers are unique within
+/// the process, but are not persisted in the splits files.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SegmentId(u64);
+
+impl SegmentId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        Self(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed) as u64)
+    }
+
+    /// Accesses the raw value of the identifier.
+    #[inline]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for SegmentId {
+    fn default() -> Self {
+        Self::next()
+    }
+}
+
+impl PartialEq for Segment {
+    fn eq(&self, other: &Self) -> bool {
+        // The identifier only tracks the segment within this process, so it
+        // doesn't take part in the comparison.
+        self.name == other.name
+            && self.icon == other.icon
+            && self.best_segment_time == other.best_segment_time
+            && self.split_time == other.split_time
+            && self.segment_history == other.segment_history
+            && self.practice_history == other.practice_history
+            && self.comparisons == other.comparisons
+            && self.variables == other.variables
+            && self.skip_reason == other.skip_reason
+            && self.skip_reason_history == other.skip_reason_history
+            && self.checkpoints == other.checkpoints
+            && self.personal_best_checkpoints == other.personal_best_checkpoints
+    }
 }
 
 impl Segment {
@@ -43,6 +97,13 @@ impl Segment {
         }
     }
 
+    /// Accesses the stable identifier of the segment. It stays the same when
+    /// the segment gets moved around within the run.
+    #[inline]
+    pub const fn id(&self) -> SegmentId {
+        self.id
+    }
+
     /// Accesses the name of the segment.
     #[inline]
     pub fn name(&self) -> &str {
@@ -70,6 +131,18 @@ impl Segment {
         self.icon = image;
     }
 
+    /// Sets the icon of the segment to the image stored in the file provided.
+    /// The image is validated and downscaled to [`Image::ICON`] if necessary.
+    /// The icon is left unchanged if the file can't be loaded.
+    #[cfg(feature = "std")]
+    pub fn set_icon_from_file<P>(&mut self, path: P) -> Result<(), crate::settings::ImageLoadError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        self.icon = Image::load(path, Image::ICON)?;
+        Ok(())
+    }
+
     /// Grants mutable access to the comparison times stored in the Segment.
     /// This includes both the custom comparisons and the generated ones.
     #[inline]
@@ -180,6 +253,41 @@ impl Segment {
         &mut self.segment_history
     }
 
+    /// Accesses the Practice History of this segment. It contains the segment
+    /// times achieved while practicing the segment with
+    /// [`Timer::start_practice`](crate::Timer::start_practice). They are kept
+    /// separate from the Segment History, so practicing doesn't affect the
+    /// comparisons or any of the statistics about the actual attempts.
+    #[inline]
+    pub fn practice_history(&self) -> &[Time] {
+        &self.practice_history
+    }
+
+    /// Grants mutable access to the Practice History of this segment.
+    #[inline]
+    pub fn practice_history_mut(&mut self) -> &mut Vec<Time> {
+        &mut self.practice_history
+    }
+
+    /// Returns the fastest segment time in the Practice History for the timing
+    /// method provided. `None` is returned if there is no such time.
+    pub fn best_practice_time(&self, method: TimingMethod) -> Option<TimeSpan> {
+        self.practice_history.iter().filter_map(|t| t[method]).min()
+    }
+
+    /// Returns the average of the segment times in the Practice History for
+    /// the timing method provided. `None` is returned if there is no such time.
+    pub fn average_practice_time(&self, method: TimingMethod) -> Option<TimeSpan> {
+        let (sum, count) = self
+            .practice_history
+            .iter()
+            .filter_map(|t| t[method])
+            .fold((TimeSpan::zero(), 0), |(sum, count), time| {
+                (sum + time, count + 1)
+            });
+        (count != 0).then(|| TimeSpan::from_seconds(sum.total_seconds() / count as f64))
+    }
+
     /// Accesses the segment's variables for the current attempt.
     pub const fn variables(&self) -> &HashMap<String, String> {
         &self.variables
@@ -196,10 +304,84 @@ impl Segment {
         self.variables.clear();
     }
 
+    /// Accesses the reason the segment got skipped in the current attempt. This
+    /// is `None` if it didn't get skipped or no reason was provided.
+    #[inline]
+    pub const fn skip_reason(&self) -> Option<SkipReason> {
+        self.skip_reason
+    }
+
+    /// Sets the reason the segment got skipped in the current attempt.
+    #[inline]
+    pub fn set_skip_reason(&mut self, reason: Option<SkipReason>) {
+        self.skip_reason = reason;
+    }
+
+    /// Accesses the reason the segment got skipped in the attempt with the
+    /// index provided. This is `None` if it didn't get skipped or no reason was
+    /// provided.
+    #[inline]
+    pub fn skip_reason_for_attempt(&self, index: i32) -> Option<SkipReason> {
+        self.skip_reason_history.get(&index).copied()
+    }
+
+    /// Accesses the reasons the segment got skipped in past attempts, keyed by
+    /// the index of the attempt, just like the segment history.
+    #[inline]
+    pub const fn skip_reason_history(&self) -> &HashMap<i32, SkipReason> {
+        &self.skip_reason_history
+    }
+
+    /// Grants mutable access to the reasons the segment got skipped in past
+    /// attempts.
+    #[inline]
+    pub fn skip_reason_history_mut(&mut self) -> &mut HashMap<i32, SkipReason> {
+        &mut self.skip_reason_history
+    }
+
+    /// Accesses the checkpoints the current attempt reached within this
+    /// segment, in the order they were reached.
+    #[inline]
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        &self.checkpoints
+    }
+
+    /// Grants mutable access to the checkpoints the current attempt reached
+    /// within this segment.
+    #[inline]
+    pub fn checkpoints_mut(&mut self) -> &mut Vec<Checkpoint> {
+        &mut self.checkpoints
+    }
+
+    /// Accesses the checkpoints the Personal Best reached within this segment.
+    #[inline]
+    pub fn personal_best_checkpoints(&self) -> &[Checkpoint] {
+        &self.personal_best_checkpoints
+    }
+
+    /// Grants mutable access to the checkpoints the Personal Best reached
+    /// within this segment.
+    #[inline]
+    pub fn personal_best_checkpoints_mut(&mut self) -> &mut Vec<Checkpoint> {
+        &mut self.personal_best_checkpoints
+    }
+
+    /// Accesses the time at which the Personal Best reached the checkpoint
+    /// with the name provided within this segment. If it doesn't exist, an
+    /// empty time is returned.
+    pub fn personal_best_checkpoint_time(&self, name: &str) -> Time {
+        self.personal_best_checkpoints
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.time)
+            .unwrap_or_default()
+    }
+
     /// Clears all the information the segment stores when it has been splitted,
     /// such as the split's time and variables.
     pub fn clear_split_info(&mut self) {
         self.clear_variables();
         self.clear_split_time();
+        self.skip_reason = None;
     }
 }
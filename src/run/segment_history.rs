@@ -1,6 +1,7 @@
-use crate::{platform::prelude::*, Time};
+use crate::{platform::prelude::*, Attempt, Time};
 use core::{
     cmp::min,
+    iter::FusedIterator,
     slice::{Iter, IterMut},
 };
 
@@ -117,6 +118,21 @@ impl SegmentHistory {
         };
         self.0[start..].iter()
     }
+
+    /// Iterates over the actual segment times achieved by the runner in
+    /// attempts that finished the run. The Attempt History of the run is used
+    /// to determine which attempts got finished. It needs to be ordered by the
+    /// indices of the attempts, like
+    /// [`Run::attempt_history`](crate::Run::attempt_history) is. Segment times
+    /// of attempts that got reset, as well as segment times that don't belong
+    /// to any attempt at all, are filtered out.
+    #[inline]
+    pub fn iter_completed<'a>(&'a self, attempts: &'a [Attempt]) -> CompletedIter<'a> {
+        CompletedIter {
+            iter: self.iter_actual_runs(),
+            attempts,
+        }
+    }
 }
 
 impl<'a> IntoIterator for &'a SegmentHistory {
@@ -127,3 +143,55 @@ impl<'a> IntoIterator for &'a SegmentHistory {
         self.0.iter()
     }
 }
+
+/// An iterator over the segment times of a [`SegmentHistory`] that belong to
+/// attempts that finished the run, along with their indices.
+#[derive(Clone, Debug)]
+pub struct CompletedIter<'a> {
+    iter: Iter<'a, (i32, Time)>,
+    attempts: &'a [Attempt],
+}
+
+impl CompletedIter<'_> {
+    fn is_completed(&self, index: i32) -> bool {
+        self.attempts
+            .binary_search_by_key(&index, Attempt::index)
+            .is_ok_and(|i| {
+                let time = self.attempts[i].time();
+                time.real_time.is_some() || time.game_time.is_some()
+            })
+    }
+}
+
+impl<'a> Iterator for CompletedIter<'a> {
+    type Item = &'a (i32, Time);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let element = self.iter.next()?;
+            if self.is_completed(element.0) {
+                return Some(element);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl DoubleEndedIterator for CompletedIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let element = self.iter.next_back()?;
+            if self.is_completed(element.0) {
+                return Some(element);
+            }
+        }
+    }
+}
+
+impl FusedIterator for CompletedIter<'_> {}
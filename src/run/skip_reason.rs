@@ -0,0 +1,39 @@
+use core::str::FromStr;
+use serde_derive::{Deserialize, Serialize};
+
+/// Describes why a split got skipped. The reason is stored along with the
+/// segment history, so that statistics can selectively include or exclude
+/// attempts with skipped splits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SkipReason {
+    /// The runner missed the input for splitting.
+    MissedInput,
+    /// The segment is not part of the route that was taken.
+    RouteSkip,
+    /// The auto splitter missed the split.
+    AutoSplitterMiss,
+}
+
+impl SkipReason {
+    /// Returns the name of the skip reason as it is stored in splits files.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            SkipReason::MissedInput => "MissedInput",
+            SkipReason::RouteSkip => "RouteSkip",
+            SkipReason::AutoSplitterMiss => "AutoSplitterMiss",
+        }
+    }
+}
+
+impl FromStr for SkipReason {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "MissedInput" => SkipReason::MissedInput,
+            "RouteSkip" => SkipReason::RouteSkip,
+            "AutoSplitterMiss" => SkipReason::AutoSplitterMiss,
+            _ => return Err(()),
+        })
+    }
+}
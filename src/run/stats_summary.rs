@@ -0,0 +1,71 @@
+use crate::{
+    analysis::{sum_of_segments::calculate_best, total_playtime::TotalPlaytime},
+    Run, TimeSpan, TimingMethod,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// A summary of the most commonly requested statistics about a [`Run`], such
+/// as its Personal Best and how many attempts were finished. It can be
+/// serialized, so that stream panels and chat bots can query it all at once.
+/// All the points in time are specified as milliseconds since the Unix epoch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSummary {
+    /// The timing method that the times are specified in.
+    pub timing_method: TimingMethod,
+    /// The final time of the Personal Best, if there is one.
+    pub personal_best: Option<TimeSpan>,
+    /// The Sum of Best Segments, if all the segments have a best segment time.
+    pub sum_of_best: Option<TimeSpan>,
+    /// The amount of attempts that were started.
+    pub attempt_count: u32,
+    /// The amount of attempts in the Attempt History that were finished.
+    pub finished_count: usize,
+    /// The fraction of attempts in the Attempt History that were finished,
+    /// between 0 and 1. This is `None` if the Attempt History is empty.
+    pub completion_rate: Option<f64>,
+    /// The total amount of time spent on all the attempts.
+    pub total_playtime: TimeSpan,
+    /// The point in time at which the most recent attempt that improved the
+    /// Personal Best ended, if it is known.
+    pub last_personal_best_at: Option<i64>,
+}
+
+impl Run {
+    /// Summarizes the most commonly requested statistics about the run for the
+    /// timing method provided.
+    pub fn stats_summary(&self, timing_method: TimingMethod) -> StatsSummary {
+        let mut finished_count = 0;
+        let mut best_time: Option<TimeSpan> = None;
+        let mut last_personal_best_at = None;
+
+        for attempt in self.attempt_history() {
+            let Some(time) = attempt.time()[timing_method] else {
+                continue;
+            };
+            finished_count += 1;
+            if best_time.map_or(true, |best| time < best) {
+                best_time = Some(time);
+                last_personal_best_at = attempt
+                    .ended()
+                    .map(|ended| (ended.time.unix_timestamp_nanos() / 1_000_000) as i64);
+            }
+        }
+
+        let attempts = self.attempt_history().len();
+
+        StatsSummary {
+            timing_method,
+            personal_best: self
+                .segments()
+                .last()
+                .and_then(|s| s.personal_best_split_time()[timing_method]),
+            sum_of_best: calculate_best(self.segments(), false, false, timing_method),
+            attempt_count: self.attempt_count(),
+            finished_count,
+            completion_rate: (attempts != 0).then(|| finished_count as f64 / attempts as f64),
+            total_playtime: self.total_playtime(),
+            last_personal_best_at,
+        }
+    }
+}
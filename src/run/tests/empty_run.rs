@@ -25,6 +25,21 @@ fn clear_history() {
     Run::new().clear_history();
 }
 
+#[test]
+fn clear_attempt_history() {
+    Run::new().clear_attempt_history();
+}
+
+#[test]
+fn clear_segment_history() {
+    Run::new().clear_segment_history();
+}
+
+#[test]
+fn clear_best_segments() {
+    Run::new().clear_best_segments();
+}
+
 #[test]
 fn clear_times() {
     Run::new().clear_times();
@@ -0,0 +1,78 @@
+use crate::{
+    run::{saver::livesplit, FileEvent, FileWatcher, ReloadError},
+    testing::{create_run, create_timer, span, split_at, start_run},
+    Run,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("livesplit-core-{}-{name}.lss", std::process::id()))
+}
+
+fn write_run(path: &Path, run: &Run) {
+    let mut buf = String::new();
+    livesplit::save_run(run, &mut buf).unwrap();
+    fs::write(path, buf).unwrap();
+}
+
+#[test]
+fn reloads_modified_file_and_keeps_the_attempt() {
+    let path = temp_path("reload");
+    let mut timer = create_timer(&["A", "B"]);
+    write_run(&path, timer.run());
+
+    let mut watcher = FileWatcher::new(&path);
+    assert_eq!(watcher.path(), path);
+    assert_eq!(watcher.poll(), FileEvent::Unchanged);
+
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+
+    let mut run = create_run(&["Renamed A", "B"]);
+    run.set_game_name("Game");
+    write_run(&path, &run);
+
+    assert!(watcher.reload_if_modified(&mut timer).unwrap());
+    assert!(!watcher.reload_if_modified(&mut timer).unwrap());
+    assert_eq!(timer.run().game_name(), "Game");
+    assert_eq!(timer.run().segment(0).name(), "Renamed A");
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(span(5.0))
+    );
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    fs::remove_file(&path).unwrap();
+    assert_eq!(watcher.poll(), FileEvent::Removed);
+    assert_eq!(watcher.poll(), FileEvent::Unchanged);
+}
+
+#[test]
+fn rejects_incompatible_run_during_attempt() {
+    let path = temp_path("incompatible");
+    let mut timer = create_timer(&["A", "B"]);
+    write_run(&path, timer.run());
+    let mut watcher = FileWatcher::new(&path);
+
+    start_run(&mut timer);
+    write_run(&path, &create_run(&["A", "B", "C"]));
+
+    assert!(matches!(
+        watcher.reload_if_modified(&mut timer),
+        Err(ReloadError::IncompatibleRun)
+    ));
+    assert_eq!(timer.run().len(), 2);
+
+    timer.reset(false).unwrap();
+    write_run(&path, &create_run(&["A", "B", "C", "D"]));
+    assert!(watcher.reload_if_modified(&mut timer).unwrap());
+    assert_eq!(timer.run().len(), 4);
+
+    fs::write(&path, "").unwrap();
+    watcher.mark_as_saved();
+    assert_eq!(watcher.poll(), FileEvent::Unchanged);
+    fs::remove_file(&path).unwrap();
+}
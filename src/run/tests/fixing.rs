@@ -1,6 +1,7 @@
 use crate::{
-    util::tests_helper::{create_timer, run_with_splits},
-    Timer,
+    run::{FixRules, SkipReason},
+    testing::{create_run, create_timer, run_with_splits, span},
+    Run, Time, Timer, TimingMethod,
 };
 
 #[test]
@@ -25,6 +26,23 @@ fn reattaches_unattached_segment_history_elements_by_using_negative_ids() {
     assert_eq!(segments[1].segment_history().try_get_max_index(), Some(1));
 }
 
+#[test]
+fn removing_empty_segment_history_elements_removes_their_skip_reasons() {
+    let mut run = create_run(&["A", "B"]);
+    run.add_attempt_with_index(Time::new(), 1, None, None, None);
+    let segment = run.segment_mut(0);
+    segment.segment_history_mut().insert(1, Time::new());
+    segment
+        .skip_reason_history_mut()
+        .insert(1, SkipReason::RouteSkip);
+
+    run.fix_splits();
+
+    let segment = run.segment(0);
+    assert_eq!(segment.segment_history().get(1), None);
+    assert!(segment.skip_reason_history().is_empty());
+}
+
 // The below tests should be in crate::timing::timer::tests, but we ended up
 // having to put them here due to run.attempt_history being private.
 
@@ -75,3 +93,63 @@ fn timer_fix_run_upon_replacement() {
     assert_eq!(segments[1].segment_history().try_get_min_index(), Some(0));
     assert_eq!(segments[1].segment_history().try_get_max_index(), Some(1));
 }
+
+fn run_with_decreasing_split_times() -> Run {
+    let mut run = create_run(&["A", "B"]);
+    for (segment, split_time) in run.segments_mut().iter_mut().zip([5.0, 4.0]) {
+        segment.set_personal_best_split_time(Time::new().with_game_time(Some(span(split_time))));
+    }
+    run
+}
+
+fn game_times(run: &Run) -> Vec<(Option<f64>, Option<f64>)> {
+    run.segments()
+        .iter()
+        .map(|segment| {
+            (
+                segment.personal_best_split_time()[TimingMethod::GameTime]
+                    .map(|t| t.total_seconds()),
+                segment.best_segment_time()[TimingMethod::GameTime].map(|t| t.total_seconds()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn no_fix_rules_leave_the_run_untouched() {
+    let mut run = run_with_decreasing_split_times();
+    run.fix_splits_with(FixRules::NONE);
+    assert_eq!(run, run_with_decreasing_split_times());
+}
+
+#[test]
+fn fix_rules_can_be_selected_individually() {
+    let mut run = run_with_decreasing_split_times();
+    run.fix_splits_with(FixRules {
+        monotonic_comparisons: true,
+        ..FixRules::NONE
+    });
+    assert_eq!(game_times(&run), [(Some(5.0), None), (Some(5.0), None)],);
+
+    run.fix_splits_with(FixRules {
+        cap_best_segments: true,
+        ..FixRules::NONE
+    });
+    assert_eq!(
+        game_times(&run),
+        [(Some(5.0), Some(5.0)), (Some(5.0), Some(0.0))],
+    );
+}
+
+#[test]
+fn fix_splits_applies_all_fix_rules() {
+    let mut run = run_with_decreasing_split_times();
+    run.fix_splits();
+    let mut other = run_with_decreasing_split_times();
+    other.fix_splits_with(FixRules::default());
+    assert_eq!(run, other);
+    assert_eq!(
+        game_times(&run),
+        [(Some(5.0), Some(5.0)), (Some(5.0), Some(0.0))],
+    );
+}
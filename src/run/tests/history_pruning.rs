@@ -0,0 +1,123 @@
+use crate::{
+    run::{saver::livesplit::save_run, KeepPolicy, SkipReason},
+    testing::create_run,
+    Run, Time, TimeSpan,
+};
+
+fn time(seconds: f64) -> Time {
+    Time::new().with_game_time(Some(TimeSpan::from_seconds(seconds)))
+}
+
+fn run() -> Run {
+    let mut run = create_run(&["A", "B"]);
+    for (index, segments) in [
+        [Some(3.0), Some(3.0)],
+        [None, Some(8.0)],
+        [Some(2.0), Some(5.0)],
+        [Some(3.5), Some(4.0)],
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let index = index as i32 + 1;
+        let final_time = segments.iter().flatten().sum();
+        run.add_attempt_with_index(time(final_time), index, None, None, None);
+        for (segment, segment_time) in run.segments_mut().iter_mut().zip(segments) {
+            let segment_time = segment_time.map_or_else(Time::new, time);
+            segment.segment_history_mut().insert(index, segment_time);
+        }
+    }
+    run
+}
+
+fn indices(run: &Run) -> Vec<i32> {
+    run.attempt_history().iter().map(|a| a.index()).collect()
+}
+
+#[test]
+fn keeps_last_attempts() {
+    let mut run = run();
+
+    let pruned = run.prune_history(&KeepPolicy {
+        last_attempts: Some(2),
+        ..Default::default()
+    });
+
+    assert_eq!(indices(&run), [3, 4]);
+    assert_eq!(
+        pruned
+            .attempts()
+            .iter()
+            .map(|a| a.index())
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+    for segment in run.segments() {
+        assert!(segment
+            .segment_history()
+            .iter_actual_runs()
+            .all(|&(index, _)| index >= 3));
+    }
+}
+
+#[test]
+fn keeps_personal_bests() {
+    let mut run = run();
+
+    run.prune_history(&KeepPolicy {
+        personal_bests: true,
+        ..Default::default()
+    });
+
+    assert_eq!(indices(&run), [1]);
+}
+
+#[test]
+fn keeps_best_segments() {
+    let mut run = run();
+
+    run.prune_history(&KeepPolicy {
+        best_segments: true,
+        ..Default::default()
+    });
+
+    assert_eq!(indices(&run), [1, 3]);
+}
+
+#[test]
+fn skip_reasons_of_pruned_attempts_are_removed() {
+    let mut run = run();
+    let skip_reasons = run.segment_mut(0).skip_reason_history_mut();
+    skip_reasons.insert(2, SkipReason::RouteSkip);
+    skip_reasons.insert(3, SkipReason::MissedInput);
+
+    run.prune_history(&KeepPolicy {
+        best_segments: true,
+        ..Default::default()
+    });
+
+    let skip_reasons = run.segment(0).skip_reason_history();
+    assert_eq!(skip_reasons.len(), 1);
+    assert_eq!(skip_reasons.get(&3), Some(&SkipReason::MissedInput));
+}
+
+#[test]
+fn pruned_segment_times_end_up_in_the_archive() {
+    let mut run = run();
+    let before = run.segment(1).segment_history().get(2);
+
+    let pruned = run.prune_history(&KeepPolicy::default());
+
+    assert!(run.attempt_history().is_empty());
+    assert_eq!(pruned.segment_histories()[1].get(2), before);
+    assert!(run.segment(1).segment_history().get(2).is_none());
+
+    let archive = pruned.into_archive(&run);
+    assert_eq!(indices(&archive), [1, 2, 3, 4]);
+    assert_eq!(archive.segment(1).segment_history().get(2), before);
+    assert_eq!(archive.segment(1).name(), "B");
+
+    let mut buf = String::new();
+    save_run(&archive, &mut buf).unwrap();
+    assert!(buf.contains("<Attempt id=\"4\""));
+}
@@ -0,0 +1,103 @@
+use crate::{
+    settings::{Image, ImageLoadError},
+    testing::create_run,
+};
+use image::{ImageBuffer, ImageFormat, Rgba};
+use std::io::Cursor;
+
+fn encode(size: u32, format: ImageFormat) -> Vec<u8> {
+    let image = ImageBuffer::from_pixel(size, size, Rgba([255u8, 0, 0, 255]));
+    let mut data = Vec::new();
+    image.write_to(&mut Cursor::new(&mut data), format).unwrap();
+    data
+}
+
+fn dimensions(image: &Image) -> (u32, u32) {
+    let image = image::load_from_memory(image.data()).unwrap();
+    (image.width(), image.height())
+}
+
+#[test]
+fn bitmaps_are_converted_to_png_on_import() {
+    let image = Image::new(encode(16, ImageFormat::Bmp).into(), Image::ICON);
+    assert_eq!(image::guess_format(image.data()).unwrap(), ImageFormat::Png);
+    assert_eq!(dimensions(&image), (16, 16));
+}
+
+#[test]
+fn large_icons_are_downscaled_on_import() {
+    let image = Image::new(encode(300, ImageFormat::Png).into(), Image::ICON);
+    assert_eq!(dimensions(&image), (Image::ICON, Image::ICON));
+}
+
+#[test]
+fn shrinks_all_icons_of_a_run() {
+    let mut run = create_run(&["A", "B"]);
+    run.set_game_icon(Image::new(
+        encode(100, ImageFormat::Png).into(),
+        Image::ICON,
+    ));
+    run.segments_mut()[0].set_icon(Image::new(encode(64, ImageFormat::Png).into(), Image::ICON));
+    run.mark_as_unmodified();
+
+    assert!(run.shrink_icons(32));
+    assert!(run.has_been_modified());
+    assert_eq!(dimensions(run.game_icon()), (32, 32));
+    assert_eq!(dimensions(run.segment(0).icon()), (32, 32));
+    assert!(run.segment(1).icon().is_empty());
+}
+
+#[test]
+fn shrinking_icons_that_fit_keeps_them() {
+    let mut run = create_run(&["A"]);
+    let icon = Image::new(encode(16, ImageFormat::Png).into(), Image::ICON);
+    run.segments_mut()[0].set_icon(icon.clone());
+    run.mark_as_unmodified();
+
+    assert!(!run.shrink_icons(32));
+    assert!(!run.has_been_modified());
+    assert_eq!(*run.segment(0).icon(), icon);
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("livesplit-core-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn icons_can_be_loaded_from_files() {
+    let path = temp_path("icon.bmp");
+    std::fs::write(&path, encode(300, ImageFormat::Bmp)).unwrap();
+
+    let mut run = create_run(&["A"]);
+    run.set_game_icon_from_file(&path).unwrap();
+    run.segments_mut()[0].set_icon_from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        image::guess_format(run.game_icon().data()).unwrap(),
+        ImageFormat::Png,
+    );
+    assert_eq!(dimensions(run.game_icon()), (Image::ICON, Image::ICON));
+    assert_eq!(run.segment(0).icon(), run.game_icon());
+}
+
+#[test]
+fn loading_icons_from_invalid_files_keeps_the_icon() {
+    let path = temp_path("not-an-icon.txt");
+    std::fs::write(&path, "not an image").unwrap();
+
+    let mut run = create_run(&["A"]);
+    let icon = Image::new(encode(16, ImageFormat::Png).into(), Image::ICON);
+    run.set_game_icon(icon.clone());
+
+    assert!(matches!(
+        run.set_game_icon_from_file(&path),
+        Err(ImageLoadError::UnknownFormat),
+    ));
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        run.set_game_icon_from_file(&path),
+        Err(ImageLoadError::ReadFile { .. }),
+    ));
+    assert_eq!(*run.game_icon(), icon);
+}
@@ -0,0 +1,95 @@
+use crate::{
+    comparison::best_segments,
+    component::resolve_timing_method,
+    run::{parser::livesplit, saver},
+    testing::{create_timer, run_with_splits, span, split_at, start_run, FakeClock},
+    Run, Time, TimeSpan, TimingMethod,
+};
+
+#[test]
+fn the_timer_always_uses_the_locked_timing_method() {
+    let mut timer = create_timer(&["A", "B"]);
+    timer.set_current_timing_method(TimingMethod::RealTime);
+
+    let mut run = timer.run().clone();
+    run.set_locked_timing_method(Some(TimingMethod::GameTime));
+    timer.set_run(run).unwrap();
+
+    assert_eq!(timer.current_timing_method(), TimingMethod::GameTime);
+    timer.toggle_timing_method();
+    assert_eq!(timer.current_timing_method(), TimingMethod::GameTime);
+    assert_eq!(
+        resolve_timing_method(Some(TimingMethod::RealTime), &timer),
+        TimingMethod::GameTime,
+    );
+    assert_eq!(resolve_timing_method(None, &timer), TimingMethod::GameTime);
+}
+
+#[test]
+fn personal_bests_are_detected_with_the_locked_timing_method() {
+    let clock = FakeClock::install();
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+
+    let mut run = timer.run().clone();
+    run.set_locked_timing_method(Some(TimingMethod::GameTime));
+    run.segment_mut(1).set_best_segment_time(
+        Time::new()
+            .with_real_time(Some(TimeSpan::zero()))
+            .with_game_time(Some(span(1.0))),
+    );
+    timer.set_run(run).unwrap();
+
+    start_run(&mut timer);
+    timer.set_current_timing_method(TimingMethod::RealTime);
+    clock.advance(span(1.0));
+    split_at(&mut timer, 10.0);
+    clock.advance(span(1.0));
+    split_at(&mut timer, 25.0);
+
+    assert!(timer.current_attempt_has_new_best_times());
+    timer.reset(true).unwrap();
+    assert_eq!(
+        timer.run().segment(1).personal_best_split_time().game_time,
+        Some(span(25.0))
+    );
+}
+
+#[test]
+fn comparisons_are_only_generated_for_the_locked_timing_method() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut run = timer.run().clone();
+    run.regenerate_comparisons();
+    assert!(run
+        .segment(1)
+        .comparison(best_segments::NAME)
+        .game_time
+        .is_some());
+
+    run.set_locked_timing_method(Some(TimingMethod::RealTime));
+    run.regenerate_comparisons();
+    let time = run.segment(1).comparison(best_segments::NAME);
+    assert_eq!(time.game_time, None);
+    assert!(time.real_time.is_some());
+}
+
+#[test]
+fn survives_saving_and_parsing() {
+    let mut run = Run::new();
+    run.push_segment(crate::Segment::new("A"));
+    run.set_locked_timing_method(Some(TimingMethod::GameTime));
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = livesplit::parse(&buf).unwrap();
+    assert_eq!(parsed.locked_timing_method(), Some(TimingMethod::GameTime));
+
+    run.set_locked_timing_method(None);
+    buf.clear();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(!buf.contains("LockedTimingMethod"));
+    let parsed = livesplit::parse(&buf).unwrap();
+    assert_eq!(parsed.locked_timing_method(), None);
+}
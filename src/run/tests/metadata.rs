@@ -21,3 +21,71 @@ fn removing_shifts_the_speedrun_com_variables() {
         ["A", "C", "D"]
     );
 }
+
+#[test]
+fn tool_data_survives_saving_and_parsing() {
+    use crate::{
+        run::{parser::livesplit, saver::livesplit::save_run},
+        Run, Segment,
+    };
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let metadata = run.metadata_mut();
+    let data = metadata.tool_data_mut("org.example.autosplitter");
+    data.insert("version", String::from("1.2"));
+    data.insert("<special> & \"chars\"", String::from("a < b"));
+    metadata.tool_data_mut("org.example.route");
+    metadata
+        .tool_data_mut("org.example.practice")
+        .insert("start", String::from("2"));
+
+    let mut buf = String::new();
+    save_run(&run, &mut buf).unwrap();
+    let parsed = livesplit::parse(&buf).unwrap();
+
+    let tools = parsed
+        .metadata()
+        .all_tool_data()
+        .map(|(namespace, data)| (namespace, data.iter().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tools,
+        [
+            (
+                "org.example.autosplitter",
+                vec![
+                    ("<special> & \"chars\"", &String::from("a < b")),
+                    ("version", &String::from("1.2")),
+                ],
+            ),
+            ("org.example.practice", vec![("start", &String::from("2"))]),
+        ],
+    );
+    assert!(parsed.metadata().tool_data("org.example.route").is_none());
+}
+
+#[test]
+fn estimate_survives_saving_and_parsing() {
+    use crate::{
+        run::{parser::livesplit, saver::livesplit::save_run},
+        Run, Segment, TimeSpan,
+    };
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    let mut buf = String::new();
+    save_run(&run, &mut buf).unwrap();
+    assert!(!buf.contains("Estimate"));
+    assert_eq!(livesplit::parse(&buf).unwrap().metadata().estimate(), None);
+
+    let estimate = TimeSpan::from_seconds(5400.0);
+    run.metadata_mut().set_estimate(Some(estimate));
+    buf.clear();
+    save_run(&run, &mut buf).unwrap();
+    assert_eq!(
+        livesplit::parse(&buf).unwrap().metadata().estimate(),
+        Some(estimate),
+    );
+}
@@ -1,6 +1,14 @@
 mod comparison;
 mod empty_run;
 mod extended_category_name;
+mod file_watcher;
 mod fixing;
+mod history_pruning;
+#[cfg(feature = "image-shrinking")]
+mod icons;
 mod linked_layout;
+mod locked_timing_method;
 mod metadata;
+mod period_stats;
+mod segment_history;
+mod stats_summary;
@@ -0,0 +1,95 @@
+use crate::{
+    run::Period,
+    testing::{create_run, span},
+    AtomicDateTime, DateTime, Run, Time,
+};
+
+// Monday, 2024-01-01 00:00:00 UTC
+const MONDAY: i64 = 1_704_067_200;
+const DAY: i64 = 24 * 60 * 60;
+
+fn date(seconds: i64) -> DateTime {
+    DateTime::from_unix_timestamp(seconds).unwrap()
+}
+
+fn at(seconds: i64) -> AtomicDateTime {
+    AtomicDateTime::new(date(seconds), false)
+}
+
+fn run() -> Run {
+    let mut run = create_run(&["A"]);
+    for (started, duration, finished) in [
+        (MONDAY - DAY, 100, true),
+        (MONDAY + 10, 50, false),
+        (MONDAY + 2 * DAY, 120, true),
+        (MONDAY + 9 * DAY, 30, false),
+        (MONDAY + 31 * DAY, 200, true),
+    ] {
+        let time = Time::new().with_real_time(finished.then(|| span(duration as f64)));
+        run.add_attempt(time, Some(at(started)), Some(at(started + duration)), None);
+    }
+    run
+}
+
+#[test]
+fn period_ranges() {
+    let now = date(MONDAY + 2 * DAY + 3600);
+    assert_eq!(
+        Period::Today.range(now),
+        (date(MONDAY + 2 * DAY), date(MONDAY + 3 * DAY)),
+    );
+    assert_eq!(
+        Period::ThisWeek.range(now),
+        (date(MONDAY), date(MONDAY + 7 * DAY)),
+    );
+    assert_eq!(
+        Period::ThisMonth.range(now),
+        (date(MONDAY), date(MONDAY + 31 * DAY)),
+    );
+    assert_eq!(
+        Period::ThisMonth.range(date(MONDAY - DAY)),
+        (date(MONDAY - 31 * DAY), date(MONDAY)),
+    );
+}
+
+#[test]
+fn attempts_between_uses_start_dates() {
+    let run = run();
+    let indices: Vec<_> = run
+        .attempts_between(date(MONDAY), date(MONDAY + 7 * DAY))
+        .map(|a| a.index())
+        .collect();
+    assert_eq!(indices, [2, 3]);
+}
+
+#[test]
+fn attempts_without_dates_are_ignored() {
+    let mut run = create_run(&["A"]);
+    run.add_attempt(Time::new(), None, None, None);
+    run.add_attempt(Time::new(), None, Some(at(MONDAY)), None);
+    let indices: Vec<_> = run
+        .attempts_between(date(MONDAY), date(MONDAY + DAY))
+        .map(|a| a.index())
+        .collect();
+    assert_eq!(indices, [2]);
+}
+
+#[test]
+fn stats_of_periods() {
+    let run = run();
+    let now = date(MONDAY + 3 * DAY);
+
+    let week = run.period_stats(Period::ThisWeek, now);
+    assert_eq!(week.attempt_count, 2);
+    assert_eq!(week.finished_count, 1);
+    assert_eq!(week.playtime, span(170.0));
+
+    let month = run.period_stats(Period::ThisMonth, now);
+    assert_eq!(month.attempt_count, 3);
+    assert_eq!(month.finished_count, 1);
+    assert_eq!(month.playtime, span(200.0));
+
+    let today = run.period_stats(Period::Today, now);
+    assert_eq!(today.attempt_count, 0);
+    assert_eq!(today.playtime, span(0.0));
+}
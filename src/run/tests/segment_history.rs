@@ -0,0 +1,29 @@
+use crate::{RealTime, Run, SegmentHistory, Time, TimeSpan};
+
+#[test]
+fn completed_iteration_skips_partial_attempts() {
+    let mut run = Run::new();
+    let finished = RealTime(Some(TimeSpan::from_seconds(10.0))).into();
+    run.add_attempt_with_index(finished, 3, None, None, None);
+    run.add_attempt_with_index(finished, 1, None, None, None);
+    run.add_attempt_with_index(Time::default(), 2, None, None, None);
+    assert_eq!(
+        run.attempt_history()
+            .iter()
+            .map(|a| a.index())
+            .collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+
+    let mut history = SegmentHistory::default();
+    for index in 0..=4 {
+        history.insert(index, RealTime(Some(TimeSpan::from_seconds(1.0))).into());
+    }
+
+    let completed = history.iter_completed(run.attempt_history());
+    assert_eq!(
+        completed.clone().map(|&(i, _)| i).collect::<Vec<_>>(),
+        [1, 3]
+    );
+    assert_eq!(completed.rev().map(|&(i, _)| i).collect::<Vec<_>>(), [3, 1]);
+}
@@ -0,0 +1,40 @@
+use crate::{
+    testing::{create_timer, run_with_splits, run_with_splits_opt, span},
+    TimingMethod,
+};
+
+#[test]
+fn empty_run() {
+    let timer = create_timer(&["A", "B"]);
+    let summary = timer.run().stats_summary(TimingMethod::GameTime);
+
+    assert_eq!(summary.personal_best, None);
+    assert_eq!(summary.sum_of_best, None);
+    assert_eq!(summary.attempt_count, 0);
+    assert_eq!(summary.finished_count, 0);
+    assert_eq!(summary.completion_rate, None);
+    assert_eq!(summary.last_personal_best_at, None);
+}
+
+#[test]
+fn summarizes_attempt_history() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[5.0, 12.0]);
+    run_with_splits_opt(&mut timer, &[Some(4.0)]);
+    run_with_splits(&mut timer, &[6.0, 10.0]);
+    run_with_splits(&mut timer, &[5.0, 11.0]);
+
+    let summary = timer.run().stats_summary(TimingMethod::GameTime);
+
+    assert_eq!(summary.personal_best, Some(span(10.0)));
+    assert_eq!(summary.sum_of_best, Some(span(8.0)));
+    assert_eq!(summary.attempt_count, 4);
+    assert_eq!(summary.finished_count, 3);
+    assert_eq!(summary.completion_rate, Some(0.75));
+
+    let pb_ended = timer.run().attempt_history()[2].ended().unwrap().time;
+    assert_eq!(
+        summary.last_personal_best_at,
+        Some((pb_ended.unix_timestamp_nanos() / 1_000_000) as i64),
+    );
+}
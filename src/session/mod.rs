@@ -0,0 +1,230 @@
+//! The session module provides the bookkeeping that every frontend needs for
+//! working with files. A [`Session`] owns the [`Timer`] and the [`Layout`],
+//! remembers the paths they were loaded from or saved to and tracks whether
+//! there are unsaved changes.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use livesplit_core::{session::Session, Layout, Run, Segment, Timer};
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Time"));
+//!
+//! let timer = Timer::new(run).unwrap();
+//! let mut session = Session::new(timer, Layout::default_layout());
+//!
+//! session.open_run("splits.lss").unwrap();
+//! // ...
+//! if session.has_unsaved_changes() {
+//!     session.save_run().unwrap();
+//!     session.save_layout_as("layout.ls1l").unwrap();
+//! }
+//! ```
+
+use crate::{
+    layout::{self, LayoutSettings},
+    run::{parser::composite, saver::livesplit},
+    Layout, Run, Timer,
+};
+use snafu::{OptionExt, ResultExt};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The Error type for the operations of a [`Session`].
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to read a file.
+    ReadFile {
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// Failed to write a file.
+    WriteFile {
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// Failed to parse the splits file.
+    ParseRun {
+        /// The underlying error.
+        source: composite::Error,
+    },
+    /// The splits file contains no segments, so it can't be used for timing.
+    EmptyRun,
+    /// Failed to parse the layout file. It is neither a layout file of
+    /// livesplit-core nor one of the original LiveSplit.
+    ParseLayout {
+        /// The underlying error of parsing it as a layout file of the original
+        /// LiveSplit.
+        source: layout::parser::Error,
+    },
+    /// The file can't be saved, because no path is known for it yet.
+    NoPath,
+}
+
+/// The Result type for the operations of a [`Session`].
+pub type Result<T = (), E = Error> = core::result::Result<T, E>;
+
+/// A session owns the [`Timer`] and the [`Layout`] a frontend is working with,
+/// along with the paths of the files they belong to. It tracks whether there
+/// are unsaved changes to either of them and provides the typical open, save
+/// and save as operations.
+///
+/// The changes to the run are tracked by the run itself. The layout doesn't
+/// track its changes, so it is considered modified whenever it is mutably
+/// accessed through [`layout_mut`](Self::layout_mut).
+pub struct Session {
+    timer: Timer,
+    layout: Layout,
+    run_path: Option<PathBuf>,
+    layout_path: Option<PathBuf>,
+    layout_modified: bool,
+}
+
+impl Session {
+    /// Creates a new session for the timer and layout provided. Neither of
+    /// them is associated with a file yet.
+    pub const fn new(timer: Timer, layout: Layout) -> Self {
+        Self {
+            timer,
+            layout,
+            run_path: None,
+            layout_path: None,
+            layout_modified: false,
+        }
+    }
+
+    /// Accesses the timer of the session.
+    pub const fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    /// Grants mutable access to the timer of the session.
+    pub fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+
+    /// Accesses the layout of the session.
+    pub const fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Grants mutable access to the layout of the session. The layout is
+    /// considered modified afterwards.
+    pub fn layout_mut(&mut self) -> &mut Layout {
+        self.layout_modified = true;
+        &mut self.layout
+    }
+
+    /// Accesses the path of the splits file, if there is one.
+    pub fn run_path(&self) -> Option<&Path> {
+        self.run_path.as_deref()
+    }
+
+    /// Accesses the path of the layout file, if there is one.
+    pub fn layout_path(&self) -> Option<&Path> {
+        self.layout_path.as_deref()
+    }
+
+    /// Returns whether the run has changes that are not saved yet.
+    pub const fn run_has_been_modified(&self) -> bool {
+        self.timer.run().has_been_modified()
+    }
+
+    /// Returns whether the layout has changes that are not saved yet.
+    pub const fn layout_has_been_modified(&self) -> bool {
+        self.layout_modified
+    }
+
+    /// Returns whether the run or the layout have changes that are not saved
+    /// yet.
+    pub const fn has_unsaved_changes(&self) -> bool {
+        self.run_has_been_modified() || self.layout_has_been_modified()
+    }
+
+    /// Opens the splits file at the path provided and uses the run for the
+    /// timer. The current attempt is reset without updating the splits. The
+    /// run that was in use before is returned.
+    pub fn open_run(&mut self, path: impl Into<PathBuf>) -> Result<Run> {
+        let path = path.into();
+        let source = fs::read(&path).context(ReadFile)?;
+        let mut run = composite::parse_and_fix(&source, Some(&path))
+            .context(ParseRun)?
+            .run;
+        run.mark_as_unmodified();
+        let previous = self
+            .timer
+            .replace_run(run, false)
+            .map_err(|_| Error::EmptyRun)?;
+        self.run_path = Some(path);
+        Ok(previous)
+    }
+
+    /// Opens the layout file at the path provided and uses the layout for the
+    /// session. Both the layout files of livesplit-core and the ones of the
+    /// original LiveSplit are supported. The layout that was in use before is
+    /// returned.
+    pub fn open_layout(&mut self, path: impl Into<PathBuf>) -> Result<Layout> {
+        let path = path.into();
+        let source = fs::read(&path).context(ReadFile)?;
+        let layout = match LayoutSettings::from_json(&*source) {
+            Ok(settings) => Layout::from_settings(settings),
+            Err(_) => {
+                let source = String::from_utf8_lossy(&source);
+                layout::parser::parse(&source).context(ParseLayout)?
+            }
+        };
+        self.layout_path = Some(path);
+        self.layout_modified = false;
+        Ok(core::mem::replace(&mut self.layout, layout))
+    }
+
+    /// Saves the run to the path it was opened from or last saved to. The
+    /// current attempt is included in the saved splits, but stays in progress.
+    pub fn save_run(&mut self) -> Result {
+        let path = self.run_path.clone().context(NoPath)?;
+        self.save_run_as(path)
+    }
+
+    /// Saves the run to the path provided, which is used for saving it from
+    /// now on.
+    pub fn save_run_as(&mut self, path: impl Into<PathBuf>) -> Result {
+        let path = path.into();
+        let mut buf = String::new();
+        livesplit::save_timer(&self.timer, &mut buf).unwrap();
+        fs::write(&path, buf).context(WriteFile)?;
+        self.timer.mark_as_unmodified();
+        self.run_path = Some(path);
+        Ok(())
+    }
+
+    /// Saves the layout to the path it was opened from or last saved to. The
+    /// layout is always saved as a layout file of livesplit-core.
+    pub fn save_layout(&mut self) -> Result {
+        let path = self.layout_path.clone().context(NoPath)?;
+        self.save_layout_as(path)
+    }
+
+    /// Saves the layout as a layout file of livesplit-core to the path
+    /// provided, which is used for saving it from now on.
+    pub fn save_layout_as(&mut self, path: impl Into<PathBuf>) -> Result {
+        let path = path.into();
+        let mut buf = Vec::new();
+        self.layout.settings().write_json(&mut buf).unwrap();
+        fs::write(&path, buf).context(WriteFile)?;
+        self.layout_modified = false;
+        self.layout_path = Some(path);
+        Ok(())
+    }
+
+    /// Splits the session into its timer and layout.
+    pub fn into_inner(self) -> (Timer, Layout) {
+        (self.timer, self.layout)
+    }
+}
@@ -0,0 +1,96 @@
+use super::{Error, Session};
+use crate::{
+    component::timer,
+    testing::{create_run, create_timer, split_at, start_run},
+    Layout, TimerPhase,
+};
+use std::{fs, path::PathBuf};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "livesplit-core-session-{}-{name}",
+        std::process::id()
+    ))
+}
+
+#[test]
+fn tracks_unsaved_changes_across_save_and_open() {
+    let run_path = temp_path("run.lss");
+    let layout_path = temp_path("layout.ls1l");
+
+    let mut session = Session::new(create_timer(&["A", "B"]), Layout::new());
+    assert!(matches!(session.save_run(), Err(Error::NoPath)));
+    assert!(matches!(session.save_layout(), Err(Error::NoPath)));
+
+    start_run(session.timer_mut());
+    split_at(session.timer_mut(), 5.0);
+    assert!(session.run_has_been_modified());
+    assert!(!session.layout_has_been_modified());
+
+    session.layout_mut().push(timer::Component::new());
+    assert!(session.has_unsaved_changes());
+
+    session.save_run_as(&run_path).unwrap();
+    session.save_layout_as(&layout_path).unwrap();
+    assert!(!session.has_unsaved_changes());
+    assert_eq!(session.run_path(), Some(&*run_path));
+    assert_eq!(session.layout_path(), Some(&*layout_path));
+    assert_eq!(session.timer().current_split_index(), Some(1));
+
+    let mut other = Session::new(create_timer(&["X"]), Layout::new());
+    let previous = other.open_run(&run_path).unwrap();
+    assert_eq!(previous.segment(0).name(), "X");
+    assert_eq!(other.timer().run().segment(1).name(), "B");
+    assert!(!other.run_has_been_modified());
+
+    other.open_layout(&layout_path).unwrap();
+    assert_eq!(other.layout().components.len(), 1);
+    assert!(!other.has_unsaved_changes());
+
+    other.timer_mut().set_run(create_run(&["C"])).unwrap();
+    other.save_run().unwrap();
+    assert_eq!(other.run_path(), Some(&*run_path));
+
+    fs::remove_file(&run_path).unwrap();
+    fs::remove_file(&layout_path).unwrap();
+}
+
+#[test]
+fn opening_a_run_doesnt_auto_restart() {
+    let path = temp_path("auto_restart.lss");
+    let mut session = Session::new(create_timer(&["A"]), Layout::new());
+    session.save_run_as(&path).unwrap();
+
+    session.timer_mut().settings_mut().auto_restart_after_reset = true;
+    start_run(session.timer_mut());
+    session.open_run(&path).unwrap();
+    assert_eq!(session.timer().current_phase(), TimerPhase::NotRunning);
+    assert_eq!(session.timer().run().attempt_count(), 0);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn rejects_files_that_cant_be_used() {
+    let path = temp_path("empty.lss");
+    let mut session = Session::new(create_timer(&["A"]), Layout::new());
+
+    assert!(matches!(
+        session.open_run(&path),
+        Err(Error::ReadFile { .. })
+    ));
+
+    fs::write(&path, "not a splits file").unwrap();
+    assert!(matches!(
+        session.open_run(&path),
+        Err(Error::ParseRun { .. })
+    ));
+    assert!(matches!(
+        session.open_layout(&path),
+        Err(Error::ParseLayout { .. })
+    ));
+    assert_eq!(session.run_path(), None);
+    assert_eq!(session.layout_path(), None);
+
+    fs::remove_file(&path).unwrap();
+}
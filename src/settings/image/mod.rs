@@ -17,6 +17,20 @@ mod shrinking;
 pub use cache::{HasImageId, ImageCache};
 pub use image_id::ImageId;
 
+/// Describes an error that occurred while loading an image from a file.
+#[cfg(feature = "std")]
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum LoadError {
+    /// Couldn't read the file.
+    ReadFile {
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// The file doesn't contain an image in any of the known image formats.
+    UnknownFormat,
+}
+
 /// Images can be used to store segment and game icons. Each image object comes
 /// with a strong hash to quickly compare images. There's no specific image
 /// format you need to use for the images.
@@ -158,6 +172,22 @@ impl Image {
         Self::new_inner(data)
     }
 
+    /// Returns a version of the image where neither of its dimensions exceed
+    /// the maximum size provided. Images that need to be downscaled and
+    /// bitmaps are converted to PNG. If the image already fits, the same image
+    /// is returned. This is only needed for changing the size of existing
+    /// images, as [`new`](Self::new) already shrinks new images.
+    #[cfg(all(feature = "std", feature = "image-shrinking"))]
+    pub fn shrunk(&self, max_image_size: u32) -> Self {
+        match &self.data {
+            Some(data) => match shrinking::shrink(data, max_image_size) {
+                alloc::borrow::Cow::Borrowed(_) => self.clone(),
+                alloc::borrow::Cow::Owned(data) => Self::new_inner(data.into()),
+            },
+            None => self.clone(),
+        }
+    }
+
     /// Loads an image from the file system. You need to provide a buffer used
     /// for temporarily storing the image's data.
     #[cfg(feature = "std")]
@@ -174,6 +204,23 @@ impl Image {
         Ok(Self::new(buf.as_slice().into(), max_image_size))
     }
 
+    /// Loads an image from the file system, such as an icon chosen by the user.
+    /// Unlike [`from_file`](Self::from_file), the file's contents are validated
+    /// to be an image in one of the known image formats. Images that exceed
+    /// the maximum size provided are downscaled.
+    #[cfg(feature = "std")]
+    pub fn load<P>(path: P, max_image_size: u32) -> Result<Self, LoadError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        use snafu::ResultExt;
+
+        let data = std::fs::read(path).context(ReadFile)?;
+        ::image::guess_format(&data).map_err(|_| LoadError::UnknownFormat)?;
+
+        Ok(Self::new(data.into(), max_image_size))
+    }
+
     /// Accesses the image's data. If the image's data is empty, this returns an
     /// empty slice.
     #[inline]
@@ -24,3 +24,6 @@ pub use self::{
     settings_description::SettingsDescription,
     value::{ColumnKind, Error as ValueError, Result as ValueResult, Value},
 };
+
+#[cfg(feature = "std")]
+pub use self::image::LoadError as ImageLoadError;
@@ -1,5 +1,7 @@
 use crate::{
     component::{
+        completion::CompletionBasis,
+        current_pace::{NotRunningDisplay, WallClockRounding},
         splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
         timer::DeltaGradient,
     },
@@ -76,6 +78,15 @@ pub enum Value {
     DeltaGradient(DeltaGradient),
     /// A value describing the background of a layout.
     LayoutBackground(LayoutBackground<ImageId>),
+    /// A value describing what the percentage of the Completion Component is
+    /// based on.
+    CompletionBasis(CompletionBasis),
+    /// A value describing what the Current Pace Component shows while there's
+    /// no active attempt.
+    NotRunningDisplay(NotRunningDisplay),
+    /// A value describing how the Current Pace Component rounds the predicted
+    /// wall clock time.
+    WallClockRounding(WallClockRounding),
 }
 
 impl From<bool> for Value {
@@ -210,6 +221,24 @@ impl From<LayoutBackground<ImageId>> for Value {
     }
 }
 
+impl From<CompletionBasis> for Value {
+    fn from(x: CompletionBasis) -> Self {
+        Value::CompletionBasis(x)
+    }
+}
+
+impl From<NotRunningDisplay> for Value {
+    fn from(x: NotRunningDisplay) -> Self {
+        Value::NotRunningDisplay(x)
+    }
+}
+
+impl From<WallClockRounding> for Value {
+    fn from(x: WallClockRounding) -> Self {
+        Value::WallClockRounding(x)
+    }
+}
+
 /// The Error type for values that couldn't be converted.
 #[derive(Debug, snafu::Snafu)]
 pub enum Error {
@@ -410,6 +439,30 @@ impl Value {
             _ => Err(Error::WrongType),
         }
     }
+
+    /// Tries to convert the value into a completion basis.
+    pub fn into_completion_basis(self) -> Result<CompletionBasis> {
+        match self {
+            Value::CompletionBasis(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a not running display.
+    pub fn into_not_running_display(self) -> Result<NotRunningDisplay> {
+        match self {
+            Value::NotRunningDisplay(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a wall clock rounding.
+    pub fn into_wall_clock_rounding(self) -> Result<WallClockRounding> {
+        match self {
+            Value::WallClockRounding(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
 }
 
 impl From<Value> for bool {
@@ -543,3 +596,21 @@ impl From<Value> for LayoutBackground<ImageId> {
         value.into_layout_background().unwrap()
     }
 }
+
+impl From<Value> for CompletionBasis {
+    fn from(value: Value) -> Self {
+        value.into_completion_basis().unwrap()
+    }
+}
+
+impl From<Value> for NotRunningDisplay {
+    fn from(value: Value) -> Self {
+        value.into_not_running_display().unwrap()
+    }
+}
+
+impl From<Value> for WallClockRounding {
+    fn from(value: Value) -> Self {
+        value.into_wall_clock_rounding().unwrap()
+    }
+}
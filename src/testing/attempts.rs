@@ -0,0 +1,120 @@
+//! Helpers for driving a [`Timer`] through attempts by setting its game time.
+
+use crate::{Run, Segment, TimeSpan, Timer, TimingMethod};
+
+/// Creates a new run with a segment for each of the names provided.
+#[track_caller]
+pub fn create_run(names: &[&str]) -> Run {
+    let mut run = Run::new();
+    for &name in names {
+        run.push_segment(Segment::new(name));
+    }
+    run
+}
+
+/// Creates a new timer for a run with a segment for each of the names
+/// provided.
+#[track_caller]
+pub fn create_timer(names: &[&str]) -> Timer {
+    Timer::new(create_run(names)).unwrap()
+}
+
+/// Starts a new attempt that is driven by game time. The timer switches to
+/// game time as its current timing method and the game time starts out paused
+/// at zero, so it only advances through [`set_game_time`] and the helpers
+/// built on top of it.
+#[track_caller]
+pub fn start_run(timer: &mut Timer) {
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    timer.pause_game_time().unwrap();
+    timer.set_game_time(TimeSpan::zero()).unwrap();
+}
+
+/// Starts a new attempt, splits at each of the game times provided, in
+/// seconds, and then resets the attempt, updating the splits.
+#[track_caller]
+pub fn run_with_splits(timer: &mut Timer, splits: &[f64]) {
+    start_run(timer);
+
+    for &split in splits {
+        split_at(timer, split);
+    }
+
+    timer.reset(true).unwrap();
+}
+
+/// Same as run_with_splits_opt, but progresses an already active attempt and
+/// doesn't reset it. Useful for checking intermediate states.
+#[track_caller]
+pub fn make_progress_run_with_splits_opt(timer: &mut Timer, splits: &[Option<f64>]) {
+    for &split in splits {
+        if let Some(split) = split {
+            split_at(timer, split);
+        } else {
+            timer.skip_split().unwrap();
+        }
+    }
+}
+
+/// Starts a new attempt, splits at each of the game times provided, in
+/// seconds, and then resets the attempt, updating the splits. A `None` skips
+/// the segment instead.
+#[track_caller]
+pub fn run_with_splits_opt(timer: &mut Timer, splits: &[Option<f64>]) {
+    start_run(timer);
+    make_progress_run_with_splits_opt(timer, splits);
+    timer.reset(true).unwrap();
+}
+
+/// Sets the game time of the active attempt to the amount of seconds
+/// provided.
+#[track_caller]
+pub fn set_game_time(timer: &mut Timer, seconds: f64) {
+    timer.set_game_time(span(seconds)).unwrap();
+}
+
+/// Sets the game time of the active attempt to the amount of seconds provided
+/// and splits.
+#[track_caller]
+pub fn split_at(timer: &mut Timer, seconds: f64) {
+    set_game_time(timer, seconds);
+    timer.split().unwrap();
+}
+
+/// Skips the amount of splits provided.
+#[track_caller]
+pub fn skip_splits(timer: &mut Timer, count: usize) {
+    for _ in 0..count {
+        timer.skip_split().unwrap();
+    }
+}
+
+/// Undoes the amount of splits provided.
+#[track_caller]
+pub fn undo_splits(timer: &mut Timer, count: usize) {
+    for _ in 0..count {
+        timer.undo_split().unwrap();
+    }
+}
+
+/// Sets the game time of the active attempt to the amount of seconds provided
+/// and pauses the attempt.
+#[track_caller]
+pub fn pause_at(timer: &mut Timer, seconds: f64) {
+    set_game_time(timer, seconds);
+    timer.pause().unwrap();
+}
+
+/// Resumes the paused attempt.
+#[track_caller]
+pub fn resume(timer: &mut Timer) {
+    timer.resume().unwrap();
+}
+
+/// Creates a [`TimeSpan`] from the amount of seconds provided.
+#[track_caller]
+pub fn span(seconds: f64) -> TimeSpan {
+    TimeSpan::from_seconds(seconds)
+}
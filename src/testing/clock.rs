@@ -0,0 +1,110 @@
+use crate::{
+    platform::{utc_now, DateTime, Duration, Instant},
+    TimeSpan,
+};
+use core::{cell::Cell, marker::PhantomData};
+
+#[derive(Copy, Clone)]
+struct State {
+    instant: Instant,
+    date_time: DateTime,
+    elapsed: Duration,
+}
+
+std::thread_local! {
+    static FAKE_CLOCK: Cell<Option<State>> = const { Cell::new(None) };
+}
+
+/// A fake clock replaces the clocks used for the real time and the wall clock
+/// on the current thread. While it is installed, time only advances when
+/// [`advance`](Self::advance) is called, so tests and replays don't need to
+/// sleep and always observe the same times. The fake clock is uninstalled when
+/// it is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::{testing::{create_timer, FakeClock}, TimeSpan};
+///
+/// let clock = FakeClock::install();
+/// let mut timer = create_timer(&["A"]);
+/// timer.start().unwrap();
+///
+/// clock.advance(TimeSpan::from_seconds(90.0));
+/// let real_time = timer.snapshot().current_time().real_time;
+/// assert_eq!(real_time, Some(TimeSpan::from_seconds(90.0)));
+/// ```
+pub struct FakeClock {
+    // The clock is bound to the thread it is installed on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl FakeClock {
+    /// Installs a fake clock on the current thread. It starts out at the
+    /// current point in time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a fake clock is already installed on the current thread.
+    #[track_caller]
+    pub fn install() -> Self {
+        FAKE_CLOCK.with(|clock| {
+            assert!(
+                clock.get().is_none(),
+                "A fake clock is already installed on this thread"
+            );
+            clock.set(Some(State {
+                instant: Instant::now(),
+                date_time: utc_now(),
+                elapsed: Duration::ZERO,
+            }));
+        });
+        Self {
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Advances the fake clock by the amount of time provided.
+    pub fn advance(&self, time: TimeSpan) {
+        FAKE_CLOCK.with(|clock| {
+            if let Some(mut state) = clock.get() {
+                state.elapsed += time.to_duration();
+                clock.set(Some(state));
+            }
+        });
+    }
+
+    /// Moves only the wall clock by the amount of time provided, while the
+    /// clock used for the real time stays where it is. This simulates the
+    /// system clock getting adjusted.
+    pub fn adjust_wall_clock(&self, time: TimeSpan) {
+        FAKE_CLOCK.with(|clock| {
+            if let Some(mut state) = clock.get() {
+                state.date_time += time.to_duration();
+                clock.set(Some(state));
+            }
+        });
+    }
+
+    /// Returns the amount of time the fake clock advanced since it was
+    /// installed.
+    pub fn elapsed(&self) -> TimeSpan {
+        FAKE_CLOCK
+            .with(|clock| clock.get().map_or(Duration::ZERO, |s| s.elapsed))
+            .into()
+    }
+}
+
+impl Drop for FakeClock {
+    fn drop(&mut self) {
+        FAKE_CLOCK.with(|clock| clock.set(None));
+    }
+}
+
+pub(crate) fn fake_instant_now() -> Option<Instant> {
+    FAKE_CLOCK.with(|clock| clock.get().map(|s| s.instant - -s.elapsed))
+}
+
+pub(crate) fn fake_utc_now() -> Option<DateTime> {
+    FAKE_CLOCK.with(|clock| clock.get().map(|s| s.date_time + s.elapsed))
+}
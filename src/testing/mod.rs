@@ -0,0 +1,28 @@
+//! The testing module provides utilities for simulating attempts in tests. The
+//! helpers drive the [`Timer`](crate::Timer) through its game time, so the
+//! resulting times are fully deterministic and don't depend on how fast the
+//! test runs. For the real time and the wall clock, a [`FakeClock`] can be
+//! installed that only advances when told to. This module is only available
+//! with the `testing` feature.
+//!
+//! All the helpers panic if the timer rejects the operation, so misuse shows
+//! up as a test failure right where it happened.
+//!
+//! # Examples
+//!
+//! ```
+//! use livesplit_core::testing::{create_timer, run_with_splits, span};
+//!
+//! let mut timer = create_timer(&["A", "B", "C"]);
+//! run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+//!
+//! let last_segment = timer.run().segments().last().unwrap();
+//! assert_eq!(last_segment.personal_best_split_time().game_time, Some(span(30.0)));
+//! ```
+
+mod attempts;
+mod clock;
+mod synthetic;
+
+pub(crate) use self::clock::{fake_instant_now, fake_utc_now};
+pub use self::{attempts::*, clock::FakeClock, synthetic::SyntheticRun};
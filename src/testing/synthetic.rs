@@ -0,0 +1,159 @@
+//! Generates synthetic runs of arbitrary size, for benchmarks and tests that
+//! need more data than a handwritten run provides.
+
+use crate::{
+    platform::{DateTime, Duration},
+    AtomicDateTime, Run, Segment, Time, TimeSpan,
+};
+
+/// Describes a synthetic [`Run`] to generate. All the times are derived from
+/// the seed, so the same description always results in the same run. This
+/// makes the runs suitable for benchmarks, including ones outside of this
+/// crate.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::testing::SyntheticRun;
+///
+/// let run = SyntheticRun {
+///     segment_count: 10,
+///     attempt_count: 20,
+///     ..Default::default()
+/// }
+/// .generate();
+///
+/// assert_eq!(run.len(), 10);
+/// assert_eq!(run.attempt_history().len(), 20);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SyntheticRun {
+    /// The amount of segments of the run.
+    pub segment_count: usize,
+    /// The amount of attempts in the Attempt History.
+    pub attempt_count: usize,
+    /// The chance of an attempt getting reset in each of the segments, from
+    /// 0.0 to 1.0.
+    pub reset_chance: f64,
+    /// The seed used for generating the times.
+    pub seed: u64,
+}
+
+impl Default for SyntheticRun {
+    fn default() -> Self {
+        Self {
+            segment_count: 30,
+            attempt_count: 300,
+            reset_chance: 0.02,
+            seed: 0,
+        }
+    }
+}
+
+impl SyntheticRun {
+    /// Generates the run. Each attempt is somewhat faster than the previous
+    /// ones on average, with random variation in every segment. The game time
+    /// is slightly lower than the real time, like for a game with loading
+    /// screens. The Personal Best, the Best Segments and all the other
+    /// comparisons are filled in based on the attempts.
+    pub fn generate(&self) -> Run {
+        let mut rng = Rng::new(self.seed);
+        let mut run = Run::new();
+        run.set_game_name("Synthetic Game");
+        run.set_category_name("Any%");
+
+        let base_times: Vec<f64> = (0..self.segment_count)
+            .map(|index| {
+                run.push_segment(Segment::new(format!("Segment {}", index + 1)));
+                20.0 + 100.0 * rng.next_f64()
+            })
+            .collect();
+
+        let first_start = DateTime::UNIX_EPOCH + Duration::days(18_262);
+        let mut personal_best: Option<(f64, Vec<Time>)> = None;
+
+        for attempt in 0..self.attempt_count {
+            let index = attempt as i32 + 1;
+            let progress = attempt as f64 / self.attempt_count.max(1) as f64;
+            let skill = 1.2 - 0.15 * progress;
+
+            let mut real_time = 0.0;
+            let mut game_time = 0.0;
+            let mut split_times = Vec::with_capacity(self.segment_count);
+
+            for (segment, &base_time) in run.segments_mut().iter_mut().zip(&base_times) {
+                if rng.next_f64() < self.reset_chance {
+                    break;
+                }
+
+                let segment_real_time = base_time * skill * (0.9 + 0.2 * rng.next_f64());
+                let segment_game_time = segment_real_time * 0.97;
+                real_time += segment_real_time;
+                game_time += segment_game_time;
+
+                let segment_time = time(segment_real_time, segment_game_time);
+                segment.segment_history_mut().insert(index, segment_time);
+                let best = segment.best_segment_time().real_time;
+                if best.map_or(true, |best| segment_time.real_time.unwrap() < best) {
+                    segment.set_best_segment_time(segment_time);
+                }
+                split_times.push(time(real_time, game_time));
+            }
+
+            let finished = split_times.len() == self.segment_count;
+            let started = first_start + Duration::hours(2 * attempt as i64);
+            let ended = started + Duration::seconds_f64(real_time + 10.0);
+            run.add_attempt_with_index(
+                if finished {
+                    time(real_time, game_time)
+                } else {
+                    Time::default()
+                },
+                index,
+                Some(AtomicDateTime::new(started, true)),
+                Some(AtomicDateTime::new(ended, true)),
+                None,
+            );
+
+            if finished
+                && personal_best
+                    .as_ref()
+                    .map_or(true, |(pb, _)| real_time < *pb)
+            {
+                personal_best = Some((real_time, split_times));
+            }
+        }
+
+        if let Some((_, split_times)) = personal_best {
+            for (segment, split_time) in run.segments_mut().iter_mut().zip(split_times) {
+                segment.set_personal_best_split_time(split_time);
+            }
+        }
+
+        run.set_attempt_count(self.attempt_count as u32);
+        run.regenerate_comparisons();
+        run
+    }
+}
+
+fn time(real_time: f64, game_time: f64) -> Time {
+    Time::new()
+        .with_real_time(Some(TimeSpan::from_seconds(real_time)))
+        .with_game_time(Some(TimeSpan::from_seconds(game_time)))
+}
+
+/// A xorshift generator, which is good enough for varying the times.
+struct Rng(u64);
+
+impl Rng {
+    const fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
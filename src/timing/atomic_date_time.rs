@@ -32,11 +32,26 @@ impl AtomicDateTime {
     /// this value is marked as synchronized. Otherwise the local system's timer
     /// is used.
     ///
-    /// # Warning
-    ///
-    /// livesplit-core doesn't synchronize with any atomic clock yet.
+    /// The synchronization is done via the `networking::ntp` module, which is
+    /// only available with the `ntp` feature.
     #[inline]
     pub fn now() -> Self {
+        #[cfg(any(test, feature = "testing"))]
+        if let Some(time) = crate::testing::fake_utc_now() {
+            return AtomicDateTime {
+                time,
+                synced_with_atomic_clock: false,
+            };
+        }
+
+        #[cfg(feature = "ntp")]
+        if let Some((offset, synced_with_atomic_clock)) = crate::networking::ntp::clock_offset() {
+            return AtomicDateTime {
+                time: utc_now() + offset.to_duration(),
+                synced_with_atomic_clock,
+            };
+        }
+
         AtomicDateTime {
             time: utc_now(),
             synced_with_atomic_clock: false,
@@ -52,7 +52,7 @@ impl Display for Inner {
                 // Since, this Formatter is used for writing out split files, we
                 // have to use an ASCII Minus here.
                 f.write_str(ASCII_MINUS)?;
-                ((-total_seconds) as u64, (-nanoseconds) as u32)
+                (total_seconds.unsigned_abs(), nanoseconds.unsigned_abs())
             } else {
                 (total_seconds as u64, nanoseconds as u32)
             };
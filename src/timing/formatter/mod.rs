@@ -26,10 +26,12 @@ mod days;
 mod delta;
 mod digits_format;
 pub mod none_wrapper;
+pub mod registry;
 mod regular;
 mod segment_time;
 pub mod timer;
 
+pub use self::registry::{CustomTimeFormatter, TimeFormatterRegistry};
 pub use self::{
     accuracy::Accuracy, complete::Complete, days::Days, delta::Delta, digits_format::DigitsFormat,
     regular::Regular, segment_time::SegmentTime,
@@ -39,6 +41,13 @@ use crate::TimeSpan;
 use core::{fmt::Display, str};
 
 /// Time Formatters can be used to format optional Time Spans in various ways.
+///
+/// This trait is part of the stable public API. It can be implemented for
+/// custom formatters outside of this crate, and it won't change in a breaking
+/// way outside of a major version bump. Its generic method means that it can't
+/// be used as a trait object though. In order to choose a formatter at runtime,
+/// such as in the settings of a component, register it in a
+/// [`TimeFormatterRegistry`] as a [`CustomTimeFormatter`] instead.
 pub trait TimeFormatter<'a> {
     /// The actual type that can be displayed.
     type Inner: Display;
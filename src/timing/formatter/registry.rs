@@ -0,0 +1,223 @@
+//! The registry module provides the [`TimeFormatterRegistry`], which allows
+//! components to select the Time Formatter to use by its name in their
+//! settings. Next to the Time Formatters provided by this crate, formatters
+//! provided by the application embedding this crate can be registered.
+
+use super::{Accuracy, Complete, Days, Delta, Regular, SegmentTime, TimeFormatter};
+use crate::{
+    platform::{prelude::*, Arc},
+    TimeSpan,
+};
+use alloc::borrow::Cow;
+use core::fmt;
+
+/// A Time Formatter that can be registered in a [`TimeFormatterRegistry`].
+/// Unlike [`TimeFormatter`], this trait can be used as a trait object, so the
+/// formatter can be chosen at runtime. It is implemented for all functions and
+/// closures with a matching signature.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt;
+/// use livesplit_core::timing::formatter::{Accuracy, TimeFormatterRegistry};
+/// use livesplit_core::TimeSpan;
+///
+/// fn frames(time: Option<TimeSpan>, _: Accuracy, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///     match time {
+///         Some(time) => write!(f, "{}f", (time.total_seconds() * 60.0).round()),
+///         None => f.write_str("-"),
+///     }
+/// }
+///
+/// let mut registry = TimeFormatterRegistry::new();
+/// registry.register("Frames", frames);
+///
+/// let formatter = registry.get("Frames").unwrap();
+/// let time = TimeSpan::from_seconds(1.5);
+/// assert_eq!(formatter.format(Some(time), Accuracy::Seconds).to_string(), "90f");
+/// ```
+pub trait CustomTimeFormatter: Send + Sync {
+    /// Formats the time provided. The accuracy is the one configured in the
+    /// settings of the component that uses the formatter. Formatters are free
+    /// to ignore it.
+    fn fmt(
+        &self,
+        time: Option<TimeSpan>,
+        accuracy: Accuracy,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result;
+}
+
+impl<F> CustomTimeFormatter for F
+where
+    F: Fn(Option<TimeSpan>, Accuracy, &mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync,
+{
+    fn fmt(
+        &self,
+        time: Option<TimeSpan>,
+        accuracy: Accuracy,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        self(time, accuracy, f)
+    }
+}
+
+impl dyn CustomTimeFormatter + '_ {
+    /// Constructs an object that displays the provided time span with the
+    /// formatter.
+    pub fn format(&self, time: Option<TimeSpan>, accuracy: Accuracy) -> Inner<'_> {
+        Inner {
+            formatter: self,
+            time,
+            accuracy,
+        }
+    }
+}
+
+/// The state object used for formatting a time with a
+/// [`CustomTimeFormatter`].
+pub struct Inner<'a> {
+    formatter: &'a dyn CustomTimeFormatter,
+    time: Option<TimeSpan>,
+    accuracy: Accuracy,
+}
+
+impl fmt::Display for Inner<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.formatter.fmt(self.time, self.accuracy, f)
+    }
+}
+
+type Builtin = fn(Option<TimeSpan>, Accuracy, &mut fmt::Formatter<'_>) -> fmt::Result;
+
+const BUILTIN_FORMATTERS: [(&str, Builtin); 5] = [
+    ("Regular", |time, accuracy, f| {
+        fmt::Display::fmt(&Regular::with_accuracy(accuracy).format(time), f)
+    }),
+    ("Segment Time", |time, accuracy, f| {
+        fmt::Display::fmt(&SegmentTime::with_accuracy(accuracy).format(time), f)
+    }),
+    ("Delta", |time, accuracy, f| {
+        fmt::Display::fmt(&Delta::custom(false, accuracy).format(time), f)
+    }),
+    ("Complete", |time, _, f| {
+        fmt::Display::fmt(&Complete::new().format(time), f)
+    }),
+    ("Days", |time, _, f| {
+        fmt::Display::fmt(&Days::new().format(time), f)
+    }),
+];
+
+/// A `TimeFormatterRegistry` provides Time Formatters based on their name. It
+/// starts out with the Time Formatters provided by this crate, which are
+/// registered as `Regular`, `Segment Time`, `Delta`, `Complete` and `Days`.
+/// Additional formatters can be registered by the application embedding this
+/// crate. The registry of a [`Layout`](crate::layout::Layout) is stored in its
+/// [`GeneralSettings`](crate::layout::GeneralSettings), but it isn't stored
+/// in layout files, so the formatters need to be registered again after a
+/// layout is loaded.
+#[derive(Clone)]
+pub struct TimeFormatterRegistry {
+    formatters: Vec<(Cow<'static, str>, Arc<dyn CustomTimeFormatter>)>,
+}
+
+impl Default for TimeFormatterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeFormatterRegistry {
+    /// Creates a new registry that contains all the Time Formatters provided
+    /// by this crate.
+    pub fn new() -> Self {
+        Self {
+            formatters: BUILTIN_FORMATTERS
+                .iter()
+                .map(|&(name, formatter)| {
+                    (
+                        name.into(),
+                        Arc::new(formatter) as Arc<dyn CustomTimeFormatter>,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Registers a Time Formatter under the name provided. If there already is
+    /// a formatter with the same name, it gets replaced.
+    pub fn register(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        formatter: impl CustomTimeFormatter + 'static,
+    ) {
+        let name = name.into();
+        let formatter = Arc::new(formatter);
+        if let Some((_, existing)) = self.formatters.iter_mut().find(|(n, _)| *n == name) {
+            *existing = formatter;
+        } else {
+            self.formatters.push((name, formatter));
+        }
+    }
+
+    /// Returns the names of all the registered Time Formatters.
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.formatters.iter().map(|(name, _)| &**name)
+    }
+
+    /// Accesses the Time Formatter with the name provided. `None` is returned
+    /// if there is no such formatter.
+    pub fn get(&self, name: &str) -> Option<&dyn CustomTimeFormatter> {
+        let (_, formatter) = self.formatters.iter().find(|(n, _)| n == name)?;
+        Some(&**formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provides_the_builtin_formatters() {
+        let registry = TimeFormatterRegistry::new();
+        let time = Some(TimeSpan::from_seconds(-83.5));
+
+        let format = |name| {
+            registry
+                .get(name)
+                .unwrap()
+                .format(time, Accuracy::Tenths)
+                .to_string()
+        };
+        assert_eq!(format("Regular"), "−1:23.5");
+        assert_eq!(format("Segment Time"), "−1:23.5");
+        assert_eq!(format("Delta"), "−1:23.5");
+        assert_eq!(format("Complete"), "-00:01:23.500000000");
+        assert!(registry.get("Frames").is_none());
+    }
+
+    #[test]
+    fn registering_replaces_formatters_with_the_same_name() {
+        let mut registry = TimeFormatterRegistry::new();
+        let count = registry.names().count();
+
+        registry.register("Regular", |_, _, f: &mut fmt::Formatter<'_>| {
+            f.write_str("custom")
+        });
+        registry.register("Frames", |_, _, f: &mut fmt::Formatter<'_>| {
+            f.write_str("frames")
+        });
+        assert_eq!(registry.names().count(), count + 1);
+
+        let format = |name| {
+            registry
+                .get(name)
+                .unwrap()
+                .format(None, Accuracy::Seconds)
+                .to_string()
+        };
+        assert_eq!(format("Regular"), "custom");
+        assert_eq!(format("Frames"), "frames");
+    }
+}
@@ -3,6 +3,7 @@
 
 mod atomic_date_time;
 pub mod formatter;
+pub mod replay;
 mod time;
 mod time_span;
 mod time_stamp;
@@ -17,7 +18,10 @@ pub use self::{
     time::{GameTime, RealTime, Time},
     time_span::{ParseError, TimeSpan},
     time_stamp::TimeStamp,
-    timer::{CreationError as TimerCreationError, Snapshot, Timer},
+    timer::{
+        AttemptTimes, ComparisonSwitchRule, CreationError as TimerCreationError, FinishPrediction,
+        RemainingSegment, Snapshot, SwitchCondition, Timer, TimerSettings,
+    },
     timer_phase::TimerPhase,
     timing_method::TimingMethod,
 };
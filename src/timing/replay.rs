@@ -0,0 +1,186 @@
+//! The replay module converts an attempt from the history of a [`Run`] into a
+//! timed sequence of commands for the [`Timer`]. Applying the commands at their
+//! respective times plays the attempt back, which can be used for showing a
+//! layout of a past attempt in a video overlay or for debugging desyncs.
+//!
+//! The history only stores the segment times of each attempt, so the replay
+//! can only be as accurate as those. Pauses are not part of the history, so
+//! the commands are timed by the real time of the attempt.
+
+use crate::{
+    event::{Event, Result},
+    platform::prelude::*,
+    AtomicDateTime, Run, TimeSpan, TimeStamp, Timer,
+};
+
+/// A command for the [`Timer`] that is part of a [`Replay`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Starts the attempt.
+    Start,
+    /// Initializes the game time and pauses it, so it only changes through
+    /// [`SetGameTime`](Self::SetGameTime).
+    InitializeGameTime,
+    /// Sets the game time to the time specified.
+    SetGameTime(TimeSpan),
+    /// Splits the current segment.
+    Split,
+    /// Skips the current segment.
+    SkipSplit,
+    /// Resets the attempt without updating the splits. This is only part of
+    /// replays of attempts that didn't finish, so a finished attempt stays in
+    /// its final state at the end of the replay.
+    Reset,
+}
+
+impl Command {
+    /// Applies the command to the timer provided. The replay never updates
+    /// the splits, doesn't count as a new attempt and never restarts the timer
+    /// after resetting, so replaying an attempt doesn't modify the run. If the
+    /// run hasn't been modified before, it isn't marked as modified either.
+    pub fn apply(self, timer: &mut Timer) -> Result {
+        let was_modified = timer.run().has_been_modified();
+        let result = match self {
+            Command::Start => timer.start_uncounted(AtomicDateTime::now(), TimeStamp::now()),
+            Command::InitializeGameTime => {
+                timer.initialize_game_time()?;
+                timer.pause_game_time()
+            }
+            Command::SetGameTime(time) => timer.set_game_time(time),
+            Command::Split => timer.split(),
+            Command::SkipSplit => timer.skip_split(),
+            Command::Reset => timer.reset_attempt(false),
+        };
+        if !was_modified {
+            timer.mark_as_unmodified();
+        }
+        result
+    }
+}
+
+/// A [`Command`] along with the real time of the attempt at which it is
+/// supposed to be applied.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimedCommand {
+    /// The real time since the start of the attempt at which the command is
+    /// supposed to be applied.
+    pub at: TimeSpan,
+    /// The command to apply.
+    pub command: Command,
+}
+
+/// A replay of an attempt from the history of a [`Run`]. It consists of the
+/// commands that need to be applied to a [`Timer`] to play the attempt back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Replay {
+    attempt_index: i32,
+    started: Option<AtomicDateTime>,
+    commands: Vec<TimedCommand>,
+}
+
+impl Replay {
+    /// Creates a replay of the attempt with the index provided. Returns `None`
+    /// if there's no such attempt in the history of the run.
+    pub fn from_attempt(run: &Run, attempt_index: i32) -> Option<Self> {
+        let attempt = run
+            .attempt_history()
+            .iter()
+            .find(|a| a.index() == attempt_index)?;
+
+        let has_game_time = run.segments().iter().any(|s| {
+            s.segment_history()
+                .get(attempt_index)
+                .is_some_and(|t| t.game_time.is_some())
+        });
+
+        let mut commands = Vec::new();
+        let mut push = |at, command| commands.push(TimedCommand { at, command });
+
+        push(TimeSpan::zero(), Command::Start);
+        if has_game_time {
+            push(TimeSpan::zero(), Command::InitializeGameTime);
+        }
+
+        let mut real_time = TimeSpan::zero();
+        let mut game_time = TimeSpan::zero();
+
+        for (index, segment) in run.segments().iter().enumerate() {
+            let Some(time) = segment.segment_history().get(attempt_index) else {
+                break;
+            };
+            let Some(segment_time) = time.real_time else {
+                if index + 1 < run.len() {
+                    push(real_time, Command::SkipSplit);
+                    continue;
+                }
+                // The last split can't be skipped, so if the attempt still
+                // finished, it did so at its final time.
+                if let Some(final_time) = attempt.time().real_time {
+                    push(final_time.max(real_time), Command::Split);
+                }
+                break;
+            };
+            real_time += segment_time;
+            if let Some(segment_time) = time.game_time {
+                game_time += segment_time;
+                push(real_time, Command::SetGameTime(game_time));
+            }
+            push(real_time, Command::Split);
+        }
+
+        if attempt.time().real_time.is_none() {
+            let reset_at = catch! {
+                attempt.duration()? - attempt.pause_time().unwrap_or_default()
+            }
+            .map_or(real_time, |time| time.max(real_time));
+            push(reset_at, Command::Reset);
+        }
+
+        Some(Self {
+            attempt_index,
+            started: attempt.started(),
+            commands,
+        })
+    }
+
+    /// Accesses the index of the attempt that is being replayed.
+    pub const fn attempt_index(&self) -> i32 {
+        self.attempt_index
+    }
+
+    /// Accesses the point in time the attempt was started at, if it is known.
+    /// This can be used to map the times of the commands to the wall clock.
+    pub const fn started(&self) -> Option<AtomicDateTime> {
+        self.started
+    }
+
+    /// Accesses all the commands of the replay, ordered by their time.
+    pub fn commands(&self) -> &[TimedCommand] {
+        &self.commands
+    }
+
+    /// Returns the real time at which the replay ends.
+    pub fn duration(&self) -> TimeSpan {
+        self.commands.last().map_or_else(TimeSpan::zero, |c| c.at)
+    }
+
+    /// Applies all the commands that are due at the real time provided,
+    /// starting at the command with the index `next`. The index is advanced
+    /// past the applied commands, so playing the replay back is a matter of
+    /// calling this repeatedly with the same index and an increasing time. The
+    /// events of the applied commands are returned. If a command fails, its
+    /// error is returned and the index points at the failed command.
+    pub fn play_until(
+        &self,
+        timer: &mut Timer,
+        next: &mut usize,
+        time: TimeSpan,
+    ) -> Result<Vec<Event>> {
+        let mut events = Vec::new();
+        while let Some(command) = self.commands.get(*next).filter(|c| c.at <= time) {
+            events.push(command.command.apply(timer)?);
+            *next += 1;
+        }
+        Ok(events)
+    }
+}
@@ -15,6 +15,12 @@ use snafu::{ensure, OptionExt, ResultExt};
 pub struct TimeSpan(Duration);
 
 impl TimeSpan {
+    /// The smallest (most negative) `TimeSpan` that can be represented.
+    pub const MIN: Self = Self(Duration::MIN);
+
+    /// The largest `TimeSpan` that can be represented.
+    pub const MAX: Self = Self(Duration::MAX);
+
     /// Creates a new `TimeSpan` of zero length.
     pub const fn zero() -> Self {
         Self(Duration::ZERO)
@@ -30,6 +36,83 @@ impl TimeSpan {
         Self(Duration::seconds_f64(0.001 * milliseconds))
     }
 
+    /// Creates a new `TimeSpan` from a given amount of seconds. Unlike
+    /// [`from_seconds`](Self::from_seconds), this doesn't panic if the amount
+    /// can't be represented. Instead the `TimeSpan` is clamped to
+    /// [`MIN`](Self::MIN) or [`MAX`](Self::MAX). `NaN` results in a `TimeSpan`
+    /// of zero length.
+    pub fn saturating_from_seconds(seconds: f64) -> Self {
+        Self(Duration::saturating_seconds_f64(seconds))
+    }
+
+    /// Creates a new `TimeSpan` from a given amount of milliseconds, clamping
+    /// it the same way [`saturating_from_seconds`](Self::saturating_from_seconds)
+    /// does.
+    pub fn saturating_from_milliseconds(milliseconds: f64) -> Self {
+        Self::saturating_from_seconds(0.001 * milliseconds)
+    }
+
+    /// Creates a new `TimeSpan` from a given amount of seconds. Returns `None`
+    /// if the amount is `NaN` or can't be represented.
+    pub fn checked_from_seconds(seconds: f64) -> Option<Self> {
+        Duration::checked_seconds_f64(seconds).map(Self)
+    }
+
+    /// Creates a new `TimeSpan` from a given amount of milliseconds. Returns
+    /// `None` if the amount is `NaN` or can't be represented.
+    pub fn checked_from_milliseconds(milliseconds: f64) -> Option<Self> {
+        Self::checked_from_seconds(0.001 * milliseconds)
+    }
+
+    /// Adds two `TimeSpan`s. Returns `None` if the result overflows.
+    pub const fn checked_add(self, rhs: TimeSpan) -> Option<TimeSpan> {
+        match self.0.checked_add(rhs.0) {
+            Some(duration) => Some(Self(duration)),
+            None => None,
+        }
+    }
+
+    /// Subtracts a `TimeSpan` from another. Returns `None` if the result
+    /// overflows.
+    pub const fn checked_sub(self, rhs: TimeSpan) -> Option<TimeSpan> {
+        match self.0.checked_sub(rhs.0) {
+            Some(duration) => Some(Self(duration)),
+            None => None,
+        }
+    }
+
+    /// Negates the `TimeSpan`. Returns `None` if the result overflows, which
+    /// is only the case for [`MIN`](Self::MIN).
+    pub const fn checked_neg(self) -> Option<TimeSpan> {
+        match self.0.checked_neg() {
+            Some(duration) => Some(Self(duration)),
+            None => None,
+        }
+    }
+
+    /// Adds two `TimeSpan`s, clamping the result to [`MIN`](Self::MIN) or
+    /// [`MAX`](Self::MAX) if it overflows. This is also what the `+` operator
+    /// does.
+    pub const fn saturating_add(self, rhs: TimeSpan) -> TimeSpan {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts a `TimeSpan` from another, clamping the result to
+    /// [`MIN`](Self::MIN) or [`MAX`](Self::MAX) if it overflows. This is also
+    /// what the `-` operator does.
+    pub const fn saturating_sub(self, rhs: TimeSpan) -> TimeSpan {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Negates the `TimeSpan`, clamping the result to [`MAX`](Self::MAX) if it
+    /// overflows. This is also what the unary `-` operator does.
+    pub const fn saturating_neg(self) -> TimeSpan {
+        match self.0.checked_neg() {
+            Some(duration) => Self(duration),
+            None => Self::MAX,
+        }
+    }
+
     /// Converts the `TimeSpan` to a `Duration` from the `time` crate.
     pub const fn to_duration(&self) -> Duration {
         self.0
@@ -88,6 +171,8 @@ pub enum ParseError {
     },
 }
 
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
 impl FromStr for TimeSpan {
     type Err = ParseError;
 
@@ -103,6 +188,16 @@ impl FromStr for TimeSpan {
                 false
             };
 
+        // The day prefix written by the Complete Time Formatter is only
+        // recognized if it is followed by hours, minutes and seconds.
+        let days = match AsciiChar::DOT.split_once(text) {
+            Some((days, remainder)) if AsciiChar::COLON.split_iter(remainder).count() == 3 => {
+                text = remainder;
+                days.parse::<u64>().context(Time)?
+            }
+            _ => 0,
+        };
+
         let (seconds_text, nanos) =
             if let Some((seconds, mut nanos)) = AsciiChar::DOT.split_once(text) {
                 if nanos.len() > 9 {
@@ -128,15 +223,19 @@ impl FromStr for TimeSpan {
                 .context(Overflow)?;
         }
 
-        let (mut seconds, mut nanos) = (
-            i64::try_from(seconds).ok().context(Overflow)?,
-            i32::try_from(nanos).ok().context(Overflow)?,
-        );
+        let seconds = days
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|days| days.checked_add(seconds))
+            .context(Overflow)?;
 
-        if negate {
-            seconds = -seconds;
+        let mut nanos = i32::try_from(nanos).ok().context(Overflow)?;
+        let seconds = if negate {
             nanos = -nanos;
+            0i64.checked_sub_unsigned(seconds)
+        } else {
+            i64::try_from(seconds).ok()
         }
+        .context(Overflow)?;
 
         Ok(Duration::new(seconds, nanos).into())
     }
@@ -163,14 +262,14 @@ impl From<TimeSpan> for Duration {
 impl Add for TimeSpan {
     type Output = TimeSpan;
     fn add(self, rhs: TimeSpan) -> TimeSpan {
-        TimeSpan(self.0.saturating_add(rhs.0))
+        self.saturating_add(rhs)
     }
 }
 
 impl Sub for TimeSpan {
     type Output = TimeSpan;
     fn sub(self, rhs: TimeSpan) -> TimeSpan {
-        TimeSpan(self.0.saturating_sub(rhs.0))
+        self.saturating_sub(rhs)
     }
 }
 
@@ -189,12 +288,36 @@ impl SubAssign for TimeSpan {
 impl Neg for TimeSpan {
     type Output = TimeSpan;
     fn neg(self) -> TimeSpan {
-        TimeSpan(-self.0)
+        self.saturating_neg()
     }
 }
 
+use super::formatter::{Complete, TimeFormatter};
 use core::fmt;
-use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    Serialize, Serializer,
+};
+
+/// Formats the `TimeSpan` the same way the [`Complete`] Time Formatter does.
+/// This is the canonical textual representation of a `TimeSpan`. Parsing it
+/// results in the exact same `TimeSpan` again for all the `TimeSpan`s that can
+/// be represented, so times don't drift when they are repeatedly formatted and
+/// parsed.
+impl fmt::Display for TimeSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Complete.format(*self), f)
+    }
+}
+
+impl Serialize for TimeSpan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
 
 impl<'de> Deserialize<'de> for TimeSpan {
     fn deserialize<D>(deserializer: D) -> Result<TimeSpan, D::Error>
@@ -272,4 +395,115 @@ mod tests {
             (10, 0)
         );
     }
+
+    #[test]
+    fn parsing_days() {
+        assert_eq!(
+            TimeSpan::from_str("1.23:34:56.789")
+                .unwrap()
+                .to_seconds_and_subsec_nanoseconds(),
+            (171296, 789000000)
+        );
+        assert_eq!(
+            TimeSpan::from_str("-1.23:34:56")
+                .unwrap()
+                .to_seconds_and_subsec_nanoseconds(),
+            (-171296, 0)
+        );
+        TimeSpan::from_str("1.-23:34:56").unwrap_err();
+        TimeSpan::from_str("1.2.23:34:56").unwrap_err();
+        TimeSpan::from_str("1:1.23:34:56").unwrap_err();
+    }
+
+    /// A xorshift generator, which is good enough for picking times.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+    }
+
+    #[track_caller]
+    fn assert_roundtrip(time: TimeSpan) {
+        let text = time.to_string();
+        assert_eq!(text.parse::<TimeSpan>().unwrap(), time, "{text}");
+    }
+
+    #[test]
+    fn formatting_roundtrips_at_the_boundaries() {
+        let day = TimeSpan::from_seconds(86400.0);
+        let nano = TimeSpan::from(Duration::nanoseconds(1));
+        for time in [
+            TimeSpan::zero(),
+            nano,
+            day - nano,
+            day,
+            TimeSpan::MAX,
+            TimeSpan::MIN,
+        ] {
+            assert_roundtrip(time);
+            assert_roundtrip(-time);
+        }
+        assert_eq!(
+            TimeSpan::from_seconds(-1.5).to_string(),
+            "-00:00:01.500000000"
+        );
+        assert_eq!(day.to_string(), "1.00:00:00.000000000");
+    }
+
+    #[test]
+    fn formatting_roundtrips_for_random_times() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..10_000 {
+            let nanos = (rng.next() % 1_000_000_000) as i32;
+            // Mostly pick times in a realistic range, but also cover the
+            // whole range that can be represented.
+            let seconds = match rng.next() % 4 {
+                0 => (rng.next() >> 1) as i64,
+                _ => (rng.next() % 1_000_000) as i64,
+            };
+            let time = TimeSpan::from(Duration::new(seconds, nanos));
+            assert_roundtrip(time);
+            assert_roundtrip(-time);
+        }
+    }
+
+    #[test]
+    fn overflowing_arithmetic() {
+        let one = TimeSpan::from_seconds(1.0);
+        assert_eq!(TimeSpan::MAX.checked_add(one), None);
+        assert_eq!(TimeSpan::MIN.checked_sub(one), None);
+        assert_eq!(TimeSpan::MIN.checked_neg(), None);
+        assert_eq!(one.checked_add(one), Some(TimeSpan::from_seconds(2.0)));
+        assert_eq!(TimeSpan::MAX.saturating_add(one), TimeSpan::MAX);
+        assert_eq!(TimeSpan::MIN - one, TimeSpan::MIN);
+        assert_eq!(-TimeSpan::MIN, TimeSpan::MAX);
+    }
+
+    #[test]
+    fn clamping_constructors() {
+        assert_eq!(
+            TimeSpan::saturating_from_seconds(f64::INFINITY),
+            TimeSpan::MAX
+        );
+        assert_eq!(TimeSpan::saturating_from_seconds(-1e300), TimeSpan::MIN);
+        assert_eq!(
+            TimeSpan::saturating_from_seconds(f64::NAN),
+            TimeSpan::zero()
+        );
+        assert_eq!(
+            TimeSpan::saturating_from_milliseconds(1500.0),
+            TimeSpan::from_seconds(1.5)
+        );
+        assert_eq!(TimeSpan::checked_from_seconds(f64::NAN), None);
+        assert_eq!(TimeSpan::checked_from_milliseconds(1e300), None);
+        assert_eq!(
+            TimeSpan::checked_from_seconds(2.5),
+            Some(TimeSpan::from_seconds(2.5))
+        );
+    }
 }
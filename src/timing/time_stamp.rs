@@ -14,6 +14,11 @@ impl TimeStamp {
     /// Creates a new `TimeStamp`, representing the current point in time.
     #[inline]
     pub fn now() -> Self {
+        #[cfg(any(test, feature = "testing"))]
+        if let Some(now) = crate::testing::fake_instant_now() {
+            return TimeStamp(now);
+        }
+
         TimeStamp(Instant::now())
     }
 }
@@ -1,11 +1,17 @@
+use super::FinishPrediction;
 use crate::{
     event::{Error, Event, Result},
+    platform::prelude::*,
     AtomicDateTime, Run, Time, TimeSpan, TimeStamp, TimingMethod,
 };
 
 #[derive(Debug, Clone)]
 pub struct ActiveAttempt {
     pub state: State,
+    pub first_split_index: usize,
+    /// The index of the last segment that is being practiced, if this is a
+    /// practice attempt.
+    pub practice_until: Option<usize>,
     pub attempt_started: AtomicDateTime,
     pub start_time: TimeStamp,
     pub start_time_with_offset: TimeStamp,
@@ -13,6 +19,7 @@ pub struct ActiveAttempt {
     pub adjusted_start_time: TimeStamp,
     pub game_time_paused_at: Option<TimeSpan>,
     pub loading_times: Option<TimeSpan>,
+    pub finish_predictions: Vec<FinishPrediction>,
 }
 
 #[derive(Debug, Clone)]
@@ -84,12 +91,8 @@ impl ActiveAttempt {
         }
     }
 
-    pub fn prepare_split(&mut self, run: &Run) -> Result<(usize, Time, Event)> {
-        let State::NotEnded {
-            current_split_index,
-            time_paused_at,
-        } = &mut self.state
-        else {
+    pub fn check_split(&self) -> Result<()> {
+        let State::NotEnded { time_paused_at, .. } = self.state else {
             return Err(Error::RunFinished);
         };
 
@@ -97,16 +100,22 @@ impl ActiveAttempt {
             return Err(Error::TimerPaused);
         }
 
-        let real_time = TimeStamp::now() - self.adjusted_start_time;
-
-        if real_time < TimeSpan::zero() {
+        if TimeStamp::now() - self.adjusted_start_time < TimeSpan::zero() {
             return Err(Error::NegativeTime);
         }
 
+        Ok(())
+    }
+
+    pub fn prepare_split(&mut self, run: &Run) -> Result<(usize, Time, Event)> {
+        self.check_split()?;
+
+        let real_time = TimeStamp::now() - self.adjusted_start_time;
         let game_time = self
             .game_time_paused_at
             .or_else(|| Some(real_time - self.loading_times?));
 
+        let current_split_index = self.current_split_index_mut().ok_or(Error::RunFinished)?;
         let previous_split_index = *current_split_index;
         *current_split_index += 1;
 
@@ -162,16 +171,35 @@ impl ActiveAttempt {
     pub fn update_times(&self, run: &mut Run, timing_method: TimingMethod) {
         self.update_attempt_history(run);
         update_best_segments(run);
-        update_pb_splits(run, timing_method);
+        if self.first_split_index == 0 {
+            update_pb_splits(run, timing_method);
+        }
         run.update_segment_history(self.current_split_index_overflowing(run));
     }
 
+    pub fn update_best_segments_only(&self, run: &mut Run) {
+        let previous_best_segments = run
+            .segments()
+            .iter()
+            .map(|s| s.best_segment_time())
+            .collect::<Vec<_>>();
+
+        update_best_segments(run);
+
+        for (index, previous) in previous_best_segments.into_iter().enumerate() {
+            if run.segment(index).best_segment_time() != previous {
+                run.import_best_segment(index);
+            }
+        }
+    }
+
     pub fn update_attempt_history(&self, run: &mut Run) {
         let (attempt_ended, time) = match self.state {
             State::NotEnded { .. } => (AtomicDateTime::now(), Time::new()),
-            State::Ended { attempt_ended } => {
+            State::Ended { attempt_ended } if self.first_split_index == 0 => {
                 (attempt_ended, run.segments().last().unwrap().split_time())
             }
+            State::Ended { attempt_ended } => (attempt_ended, Time::new()),
         };
 
         let pause_time = self.get_pause_time();
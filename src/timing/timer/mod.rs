@@ -1,20 +1,32 @@
 use crate::{
-    analysis::check_best_segment,
-    comparison::personal_best,
+    analysis::{
+        check_best_segment, comparison_combined_segment_time, current_pace, live_segment_delta,
+        live_segment_time, previous_segment_delta,
+        reset_report::{self, ResetReport},
+    },
+    comparison::{best_segments, personal_best, session_median, SessionMedian},
     event::{Error, Event},
     platform::prelude::*,
+    run::{Checkpoint, SkipReason},
     util::PopulateString,
     AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp,
     TimerPhase::{self, *},
     TimingMethod,
 };
-use core::{mem, ops::Deref};
+use core::{
+    mem,
+    ops::{Deref, RangeInclusive},
+};
+use serde_derive::{Deserialize, Serialize};
 
 #[cfg(test)]
 mod tests;
 
 mod active_attempt;
+mod settings;
+
 use active_attempt::{ActiveAttempt, State};
+pub use settings::{ComparisonSwitchRule, SwitchCondition, TimerSettings};
 
 /// A `Timer` provides all the capabilities necessary for doing speedrun attempts.
 ///
@@ -54,6 +66,9 @@ pub struct Timer {
     current_comparison: String,
     current_timing_method: TimingMethod,
     active_attempt: Option<ActiveAttempt>,
+    settings: TimerSettings,
+    comparison_before_switch: Option<String>,
+    scheduled_start: Option<AtomicDateTime>,
 }
 
 /// A snapshot represents a specific point in time that the timer was observed
@@ -62,6 +77,7 @@ pub struct Timer {
 pub struct Snapshot<'timer> {
     timer: &'timer Timer,
     time: Time,
+    time_until_scheduled_start: Option<TimeSpan>,
 }
 
 impl Snapshot<'_> {
@@ -70,6 +86,261 @@ impl Snapshot<'_> {
     pub const fn current_time(&self) -> Time {
         self.time
     }
+
+    /// Returns how much time was left until the scheduled start of the timer
+    /// when the snapshot was taken. This is `None` if no start is scheduled.
+    /// See [`Timer::schedule_start`] for more information.
+    pub const fn time_until_scheduled_start(&self) -> Option<TimeSpan> {
+        self.time_until_scheduled_start
+    }
+
+    /// Returns the time that passed since the most recent split for both
+    /// timing methods. Skipped splits are not considered, so the time covers
+    /// all the segments since the last split that has a split time. Once the
+    /// attempt ended, this is the time of the final segment. If there is no
+    /// active attempt, the offset of the run is returned.
+    pub fn current_segment_time(&self) -> Time {
+        let segment_index = match self.current_phase() {
+            NotRunning => 0,
+            Ended => self.run.len() - 1,
+            Running | Paused => self.current_split_index().unwrap(),
+        };
+        Time {
+            real_time: live_segment_time(self, segment_index, TimingMethod::RealTime),
+            game_time: live_segment_time(self, segment_index, TimingMethod::GameTime),
+        }
+    }
+
+    /// Returns the most recent checkpoint the active attempt reached within
+    /// the current segment, along with its delta to the time at which the
+    /// Personal Best reached the checkpoint of the same name, for the timing
+    /// method provided. The delta is `None` if the Personal Best didn't reach
+    /// that checkpoint. `None` is returned if there is no checkpoint in the
+    /// current segment yet.
+    pub fn current_checkpoint(
+        &self,
+        method: TimingMethod,
+    ) -> Option<(&Checkpoint, Option<TimeSpan>)> {
+        let segment = self.current_split()?;
+        let checkpoint = segment.checkpoints().last()?;
+        let personal_best = segment.personal_best_checkpoint_time(&checkpoint.name);
+        let delta = catch! { checkpoint.time[method]? - personal_best[method]? };
+        Some((checkpoint, delta))
+    }
+
+    /// Returns the wall clock time at which the active attempt got started.
+    /// This is the moment the timer got started, so it doesn't take the offset
+    /// of the run into account. `None` is returned if there is no active
+    /// attempt.
+    pub fn start_time(&self) -> Option<AtomicDateTime> {
+        self.timer.get_start_time()
+    }
+
+    /// Returns the wall clock time at which the active attempt ended, which is
+    /// the moment the last split happened. `None` is returned if there is no
+    /// active attempt or it hasn't ended yet.
+    pub fn end_time(&self) -> Option<AtomicDateTime> {
+        match self.timer.active_attempt.as_ref()?.state {
+            State::Ended { attempt_ended } => Some(attempt_ended),
+            State::NotEnded { .. } => None,
+        }
+    }
+
+    /// Returns the total amount of time the active attempt has been paused
+    /// for, including the pause that is currently in progress. `None` is
+    /// returned if there is no active attempt.
+    pub fn pause_time(&self) -> Option<TimeSpan> {
+        self.timer.get_pause_time()
+    }
+
+    /// Returns the wall clock time at which the timer got paused, if it is
+    /// currently paused. `None` is returned otherwise.
+    pub fn paused_since(&self) -> Option<AtomicDateTime> {
+        let active_attempt = self.timer.active_attempt.as_ref()?;
+        let State::NotEnded {
+            time_paused_at: Some(time_paused_at),
+            ..
+        } = active_attempt.state
+        else {
+            return None;
+        };
+        let paused_for = TimeStamp::now() - active_attempt.adjusted_start_time - time_paused_at;
+        let now = AtomicDateTime::now();
+        Some(AtomicDateTime::new(
+            now.time - paused_for.to_duration(),
+            now.synced_with_atomic_clock,
+        ))
+    }
+
+    /// Returns all the wall clock information about the active attempt in a
+    /// form that can be serialized, so that remote frontends can do their own
+    /// wall clock calculations. `None` is returned if there is no active
+    /// attempt.
+    pub fn attempt_times(&self) -> Option<AttemptTimes> {
+        let started = self.start_time()?;
+        Some(AttemptTimes {
+            started_at: unix_millis(started),
+            ended_at: self.end_time().map(unix_millis),
+            pause_time: self.pause_time().unwrap_or_default(),
+            paused_since: self.paused_since().map(unix_millis),
+            synced_with_atomic_clock: started.synced_with_atomic_clock,
+        })
+    }
+
+    /// Returns how much time the active attempt lost in each of the completed
+    /// segments compared to the best segments, for the timing method provided.
+    /// Negative values mean that time was saved, which means a new best segment
+    /// was achieved. Skipped segments are combined with the segment after them,
+    /// so their value is `None`. The list is empty if there is no active
+    /// attempt.
+    pub fn best_segment_deltas(&self, method: TimingMethod) -> Vec<Option<TimeSpan>> {
+        (0..self.current_split_index().unwrap_or_default())
+            .map(|index| previous_segment_delta(self, index, best_segments::NAME, method))
+            .collect()
+    }
+
+    /// Returns how much time the active attempt lost in total compared to the
+    /// Sum of Best Segments, for the timing method provided. This includes the
+    /// time that is currently being lost in the current segment, once it takes
+    /// longer than its best segment. `None` is returned if there is no active
+    /// attempt.
+    pub fn time_lost_to_sum_of_best(&self, method: TimingMethod) -> Option<TimeSpan> {
+        if self.current_phase() == NotRunning {
+            return None;
+        }
+
+        let mut total = self
+            .best_segment_deltas(method)
+            .into_iter()
+            .flatten()
+            .fold(TimeSpan::zero(), |total, delta| total + delta);
+
+        if let Some(index) = self.current_split_index().filter(|&i| i < self.run.len()) {
+            if let Some(live_delta) = live_segment_delta(self, index, best_segments::NAME, method) {
+                if live_delta > TimeSpan::zero() {
+                    total += live_delta;
+                }
+            }
+        }
+
+        Some(total)
+    }
+
+    /// Returns the split time of the comparison provided for the segment with
+    /// the index provided, for the timing method provided. `None` is returned
+    /// if there is no such segment or the comparison has no time for it, such
+    /// as when the segment was skipped in the comparison.
+    pub fn comparison_time_at(
+        &self,
+        segment_index: usize,
+        comparison: &str,
+        method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        self.run
+            .segments()
+            .get(segment_index)?
+            .comparison(comparison)[method]
+    }
+
+    /// Returns the segments that the active attempt still needs to complete,
+    /// starting with the current segment, along with their times in the
+    /// comparison provided and the split times they are predicted to be
+    /// completed at. If there is no active attempt, all the segments are
+    /// returned. Once the attempt ended, there are no remaining segments.
+    pub fn remaining_segments<'a>(
+        &'a self,
+        comparison: &'a str,
+        method: TimingMethod,
+    ) -> impl Iterator<Item = RemainingSegment<'a>> + 'a {
+        let (first, delta) = match self.current_phase() {
+            NotRunning => (0, TimeSpan::zero()),
+            Ended => (self.run.len(), TimeSpan::zero()),
+            Running | Paused => {
+                let index = self.current_split_index().unwrap();
+                let (delta, _) = current_pace::pace_delta(self, index, comparison, method);
+                (index, delta)
+            }
+        };
+
+        self.run.segments()[first..]
+            .iter()
+            .enumerate()
+            .map(move |(offset, segment)| {
+                let index = first + offset;
+                let comparison_time = segment.comparison(comparison)[method];
+                RemainingSegment {
+                    index,
+                    segment,
+                    comparison_time,
+                    comparison_segment_time: comparison_combined_segment_time(
+                        &self.run, index, comparison, method,
+                    ),
+                    predicted_time: catch! { comparison_time?.checked_add(delta)? },
+                }
+            })
+    }
+}
+
+/// A segment that the active attempt still needs to complete, as returned by
+/// [`Snapshot::remaining_segments`].
+#[derive(Copy, Clone, Debug)]
+pub struct RemainingSegment<'a> {
+    /// The index of the segment.
+    pub index: usize,
+    /// The segment itself.
+    pub segment: &'a Segment,
+    /// The split time of the comparison for the segment. This is `None` if the
+    /// comparison has no time for it, such as when the segment was skipped in
+    /// the comparison.
+    pub comparison_time: Option<TimeSpan>,
+    /// The segment time of the comparison. If the comparison has no times for
+    /// the segments before this one, they are combined with this segment.
+    pub comparison_segment_time: Option<TimeSpan>,
+    /// The split time the segment is predicted to be completed at. The
+    /// comparison's split time is shifted by the delta the current pace is
+    /// based on, so the predicted time of the last segment is the current
+    /// pace.
+    pub predicted_time: Option<TimeSpan>,
+}
+
+const fn unix_millis(date_time: AtomicDateTime) -> i64 {
+    (date_time.time.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+/// The wall clock information about an active attempt in a serializable form.
+/// All the points in time are specified as milliseconds since the Unix epoch.
+/// The point in time at which the timer would reach a certain time `t` can be
+/// calculated as `started_at + t - offset + pause_time`, where `offset` is the
+/// offset of the run, as long as the timer isn't paused.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttemptTimes {
+    /// The point in time at which the attempt got started.
+    pub started_at: i64,
+    /// The point in time at which the attempt ended, if it ended.
+    pub ended_at: Option<i64>,
+    /// The total amount of time the attempt has been paused for, including
+    /// the pause that is currently in progress.
+    pub pause_time: TimeSpan,
+    /// The point in time at which the timer got paused, if it is currently
+    /// paused.
+    pub paused_since: Option<i64>,
+    /// Specifies whether the points in time are derived from an atomic clock.
+    pub synced_with_atomic_clock: bool,
+}
+
+/// A prediction of the wall clock time at which the active attempt finishes.
+/// The Timer records one when the attempt gets started and whenever a split
+/// happens.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FinishPrediction {
+    /// The amount of splits that were completed when the prediction was made.
+    /// The prediction made when the attempt got started has `0` splits
+    /// completed.
+    pub splits_completed: usize,
+    /// The point in time at which the prediction was made.
+    pub predicted_at: AtomicDateTime,
+    /// The predicted point in time at which the attempt finishes.
+    pub finish: AtomicDateTime,
 }
 
 impl Deref for Snapshot<'_> {
@@ -111,6 +382,9 @@ impl Timer {
             current_comparison: personal_best::NAME.into(),
             current_timing_method: TimingMethod::RealTime,
             active_attempt: None,
+            settings: TimerSettings::default(),
+            comparison_before_switch: None,
+            scheduled_start: None,
         })
     }
 
@@ -126,7 +400,7 @@ impl Timer {
     /// of the current attempt is stored in the Run's history. Otherwise the
     /// current attempt's information is discarded.
     pub fn into_run(mut self, update_splits: bool) -> Run {
-        let _ = self.reset(update_splits);
+        let _ = self.reset_attempt(update_splits);
         self.run
     }
 
@@ -142,7 +416,46 @@ impl Timer {
             return Err(run);
         }
 
-        let _ = self.reset(update_splits);
+        let _ = self.reset_attempt(update_splits);
+        if !run.comparisons().any(|c| c == self.current_comparison) {
+            self.current_comparison = personal_best::NAME.to_string();
+        }
+
+        run.fix_splits();
+        run.regenerate_comparisons();
+
+        Ok(mem::replace(&mut self.run, run))
+    }
+
+    /// Replaces the Run object used by the Timer with a newer version of the
+    /// same Run, such as one that got modified by another application, without
+    /// resetting the current attempt. The split times, variables, skip reasons
+    /// and checkpoints of the current attempt are carried over to the new Run
+    /// and the attempt is counted towards the new Run's attempt count, unless
+    /// it is a practice attempt. If there is an attempt
+    /// in progress, the new Run needs to have the same amount of segments, as
+    /// the attempt can't be carried over otherwise. If that's not the case or
+    /// the Run provided contains no segments, it is returned as the `Err` case
+    /// of the `Result`. Otherwise the Run that was in use by the Timer is
+    /// being returned.
+    #[allow(clippy::result_large_err)]
+    pub fn reload_run(&mut self, mut run: Run) -> Result<Run, Run> {
+        if run.is_empty() || (self.active_attempt.is_some() && run.len() != self.run.len()) {
+            return Err(run);
+        }
+
+        if let Some(active_attempt) = &self.active_attempt {
+            for (segment, current) in run.segments_mut().iter_mut().zip(self.run.segments()) {
+                segment.set_split_time(current.split_time());
+                *segment.variables_mut() = current.variables().clone();
+                segment.set_skip_reason(current.skip_reason());
+                *segment.checkpoints_mut() = current.checkpoints().to_vec();
+            }
+            if active_attempt.practice_until.is_none() {
+                run.start_next_run();
+            }
+        }
+
         if !run.comparisons().any(|c| c == self.current_comparison) {
             self.current_comparison = personal_best::NAME.to_string();
         }
@@ -162,7 +475,8 @@ impl Timer {
         self.replace_run(run, false).map(drop)
     }
 
-    /// Accesses the Run in use by the Timer.
+    /// Accesses the Run in use by the Timer. The Run always has at least one
+    /// segment, as the Timer refuses to use a Run without any segments.
     #[inline]
     pub const fn run(&self) -> &Run {
         &self.run
@@ -209,13 +523,39 @@ impl Timer {
             }
         };
 
-        Snapshot { timer: self, time }
+        let time_until_scheduled_start = self
+            .scheduled_start
+            .map(|scheduled_start| scheduled_start - AtomicDateTime::now());
+
+        Snapshot {
+            timer: self,
+            time,
+            time_until_scheduled_start,
+        }
+    }
+
+    /// Accesses the settings of the timer.
+    #[inline]
+    pub const fn settings(&self) -> &TimerSettings {
+        &self.settings
     }
 
-    /// Returns the currently selected timing method.
+    /// Grants mutable access to the settings of the timer. Changes only affect
+    /// attempts that are started or reset afterwards.
+    #[inline]
+    pub fn settings_mut(&mut self) -> &mut TimerSettings {
+        &mut self.settings
+    }
+
+    /// Returns the currently selected timing method. If the Run is locked to a
+    /// timing method, that timing method is returned instead, regardless of
+    /// which timing method is selected.
     #[inline]
     pub const fn current_timing_method(&self) -> TimingMethod {
-        self.current_timing_method
+        match self.run.locked_timing_method() {
+            Some(method) => method,
+            None => self.current_timing_method,
+        }
     }
 
     /// Sets the current timing method to the timing method provided.
@@ -247,12 +587,45 @@ impl Timer {
         let as_str = comparison.as_str();
         if self.run.comparisons().any(|c| c == as_str) {
             comparison.populate(&mut self.current_comparison);
+            self.comparison_before_switch = None;
             Ok(Event::ComparisonChanged)
         } else {
             Err(Error::ComparisonDoesntExist)
         }
     }
 
+    /// Applies the [`ComparisonSwitchRule`]s of the timer's settings. The first
+    /// rule that applies to the current comparison and whose condition is met
+    /// switches the comparison. The rules are applied automatically whenever a
+    /// split is stored or skipped, in which case [`split`](Self::split) and
+    /// [`skip_split`](Self::skip_split) return the event. As the conditions also depend on the current
+    /// time, this can additionally be called regularly while an attempt is in
+    /// progress, such as whenever the layout is updated. Returns
+    /// [`Event::ComparisonChanged`] if the comparison got switched, so the
+    /// event can be forwarded like any other. The comparison that was used
+    /// before the first switch is restored when the attempt gets reset.
+    pub fn apply_comparison_switch_rules(&mut self) -> Option<Event> {
+        if !matches!(self.current_phase(), Running | Paused) {
+            return None;
+        }
+
+        let snapshot = self.snapshot();
+        let comparison = self.current_comparison.as_str();
+
+        let rule = self.settings.comparison_switch_rules.iter().find(|rule| {
+            rule.from == comparison
+                && rule.to != comparison
+                && self.run.comparisons().any(|c| c == rule.to)
+                && rule.condition.is_met(&snapshot, comparison)
+        })?;
+
+        let to = rule.to.clone();
+        let from = mem::replace(&mut self.current_comparison, to);
+        self.comparison_before_switch.get_or_insert(from);
+
+        Some(Event::ComparisonChanged)
+    }
+
     /// Accesses the split the attempt is currently on. If there's no attempt in
     /// progress or the run finished, `None` is returned instead.
     pub fn current_split(&self) -> Option<&Segment> {
@@ -276,27 +649,161 @@ impl Timer {
         )
     }
 
+    /// Checks whether [`split`](Self::split) would currently succeed. This can
+    /// be used to grey out buttons or avoid feedback for hotkeys that would
+    /// have no effect. The action itself reports the reason for being rejected
+    /// as its [`Error`].
+    pub fn can_split(&self) -> bool {
+        self.check_split().is_ok()
+    }
+
+    /// Checks whether [`undo_split`](Self::undo_split) would currently
+    /// succeed. This can be used to grey out buttons or avoid feedback for
+    /// hotkeys that would have no effect.
+    pub fn can_undo(&self) -> bool {
+        self.check_undo_split().is_ok()
+    }
+
+    /// Checks whether [`skip_split`](Self::skip_split) would currently
+    /// succeed. This can be used to grey out buttons or avoid feedback for
+    /// hotkeys that would have no effect.
+    pub fn can_skip(&self) -> bool {
+        self.check_skip_split().is_ok()
+    }
+
+    /// Checks whether [`pause`](Self::pause) would currently succeed. This can
+    /// be used to grey out buttons or avoid feedback for hotkeys that would
+    /// have no effect.
+    pub fn can_pause(&self) -> bool {
+        self.check_pause().is_ok()
+    }
+
+    fn check_split(&self) -> Result<(), Error> {
+        self.active_attempt
+            .as_ref()
+            .ok_or(Error::NoRunInProgress)?
+            .check_split()
+    }
+
+    fn check_undo_split(&self) -> Result<usize, Error> {
+        let active_attempt = self.active_attempt.as_ref().ok_or(Error::NoRunInProgress)?;
+        active_attempt
+            .current_split_index_overflowing(&self.run)
+            .checked_sub(1)
+            .filter(|&index| index >= active_attempt.first_split_index)
+            .ok_or(Error::CantUndoFirstSplit)
+    }
+
+    fn check_skip_split(&self) -> Result<usize, Error> {
+        let current_split_index = self
+            .active_attempt
+            .as_ref()
+            .ok_or(Error::NoRunInProgress)?
+            .current_split_index()
+            .ok_or(Error::RunFinished)?;
+
+        if current_split_index + 1 < self.run.len() {
+            Ok(current_split_index)
+        } else {
+            Err(Error::CantSkipLastSplit)
+        }
+    }
+
+    fn check_pause(&self) -> Result<(), Error> {
+        let active_attempt = self.active_attempt.as_ref().ok_or(Error::NoRunInProgress)?;
+
+        let State::NotEnded { time_paused_at, .. } = active_attempt.state else {
+            return Err(Error::RunFinished);
+        };
+
+        if time_paused_at.is_none() {
+            Ok(())
+        } else {
+            Err(Error::AlreadyPaused)
+        }
+    }
+
     /// Starts the Timer if there is no attempt in progress. If that's not the
     /// case, nothing happens.
     pub fn start(&mut self) -> Result {
+        self.start_with(AtomicDateTime::now(), TimeStamp::now())
+    }
+
+    /// Starts the Timer as if it got started at the point in time provided, if
+    /// there is no attempt in progress. This allows multiple timers, such as
+    /// the ones of the runners in a race, to be started at the exact same
+    /// point in time, even if the command to start reaches them at slightly
+    /// different times. The point in time may be in the past or in the future.
+    /// The offset of the run is applied as usual, so a negative offset causes
+    /// the countdown to begin at the point in time provided. Use
+    /// [`set_run_offset_for_sync`](Self::set_run_offset_for_sync) to control
+    /// when the countdown ends.
+    pub fn start_at(&mut self, attempt_started: AtomicDateTime) -> Result {
+        let elapsed = AtomicDateTime::now() - attempt_started;
+        self.start_with(attempt_started, TimeStamp::now() - elapsed)
+    }
+
+    /// Schedules the Timer to start automatically at the point in time
+    /// provided, such as the start of an online race or a marathon slot. Until
+    /// then, the time left until the start is available through
+    /// [`Snapshot::time_until_scheduled_start`], so components can show a
+    /// countdown. As the Timer doesn't run on its own,
+    /// [`apply_scheduled_start`](Self::apply_scheduled_start) needs to be
+    /// called regularly for the start to happen. Scheduling another start
+    /// replaces the previous one, while starting an attempt in any other way
+    /// cancels it. A start can't be scheduled while an attempt is in progress.
+    pub fn schedule_start(&mut self, start_at: AtomicDateTime) -> Result {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+        self.scheduled_start = Some(start_at);
+        Ok(Event::StartScheduled)
+    }
+
+    /// Returns the point in time the Timer is scheduled to start at, if a
+    /// start is scheduled.
+    pub const fn scheduled_start(&self) -> Option<AtomicDateTime> {
+        self.scheduled_start
+    }
+
+    /// Cancels the scheduled start of the Timer. The point in time the start
+    /// was scheduled at is returned, if there was one.
+    pub fn cancel_scheduled_start(&mut self) -> Option<AtomicDateTime> {
+        self.scheduled_start.take()
+    }
+
+    /// Starts the Timer if the point in time it is scheduled to start at has
+    /// been reached. Neither taking a [`Snapshot`] nor updating a layout
+    /// applies the scheduled start, so this needs to be called regularly while
+    /// a start is scheduled, such as right before every layout update. The
+    /// attempt is started as if it got started at exactly the scheduled point
+    /// in time, so it doesn't matter if this is called a little late. Returns
+    /// [`Event::Started`] if the Timer got started, so the event can be
+    /// forwarded like any other.
+    pub fn apply_scheduled_start(&mut self) -> Option<Event> {
+        let start_at = self.scheduled_start?;
+        if AtomicDateTime::now() - start_at < TimeSpan::zero() {
+            return None;
+        }
+        self.start_at(start_at).ok()
+    }
+
+    fn start_with(&mut self, attempt_started: AtomicDateTime, start_time: TimeStamp) -> Result {
+        let event = self.start_uncounted(attempt_started, start_time)?;
+        self.run.start_next_run();
+        Ok(event)
+    }
+
+    /// Starts an attempt without counting it as a new attempt of the Run. This
+    /// is used for replaying attempts that are already part of the history.
+    pub(in crate::timing) fn start_uncounted(
+        &mut self,
+        attempt_started: AtomicDateTime,
+        start_time: TimeStamp,
+    ) -> Result {
         if self.active_attempt.is_none() {
-            let attempt_started = AtomicDateTime::now();
-            let start_time = TimeStamp::now();
-            let start_time_with_offset = start_time - self.run.offset();
-
-            self.active_attempt = Some(ActiveAttempt {
-                state: State::NotEnded {
-                    current_split_index: 0,
-                    time_paused_at: None,
-                },
-                attempt_started,
-                start_time,
-                start_time_with_offset,
-                adjusted_start_time: start_time_with_offset,
-                game_time_paused_at: None,
-                loading_times: None,
-            });
-            self.run.start_next_run();
+            let first_split_index = self.settings.start_at_segment.min(self.run.len() - 1);
+            self.start_attempt(attempt_started, start_time, first_split_index, None);
 
             Ok(Event::Started)
         } else {
@@ -304,12 +811,132 @@ impl Timer {
         }
     }
 
+    fn start_attempt(
+        &mut self,
+        attempt_started: AtomicDateTime,
+        start_time: TimeStamp,
+        first_split_index: usize,
+        practice_until: Option<usize>,
+    ) {
+        let start_time_with_offset = start_time - self.run.offset();
+        self.scheduled_start = None;
+
+        self.active_attempt = Some(ActiveAttempt {
+            state: State::NotEnded {
+                current_split_index: first_split_index,
+                time_paused_at: None,
+            },
+            first_split_index,
+            practice_until,
+            attempt_started,
+            start_time,
+            start_time_with_offset,
+            adjusted_start_time: start_time_with_offset,
+            game_time_paused_at: None,
+            loading_times: None,
+            finish_predictions: Vec::new(),
+        });
+        self.record_finish_prediction(first_split_index);
+    }
+
+    /// Starts practicing the segments from `first` to `last`, both inclusive,
+    /// if there is no attempt in progress. The attempt starts at the segment
+    /// `first`. Once the segment `last` is split, the segment times of all the
+    /// practiced segments are stored in their
+    /// [`Practice History`](Segment::practice_history) and a new attempt
+    /// starts at the segment `first` right away. This repeats until the
+    /// attempt gets reset. Practice attempts never affect the Attempt History,
+    /// the Segment History, the best segments, the Personal Best or the
+    /// attempt count.
+    pub fn start_practice(&mut self, first: usize, last: usize) -> Result {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+        if first > last || last >= self.run.len() {
+            return Err(Error::Unsupported);
+        }
+
+        self.start_attempt(AtomicDateTime::now(), TimeStamp::now(), first, Some(last));
+
+        Ok(Event::Started)
+    }
+
+    /// Returns the indices of the segments that are being practiced, if the
+    /// attempt in progress is a practice attempt started through
+    /// [`start_practice`](Self::start_practice).
+    pub fn practiced_segments(&self) -> Option<RangeInclusive<usize>> {
+        let active_attempt = self.active_attempt.as_ref()?;
+        Some(active_attempt.first_split_index..=active_attempt.practice_until?)
+    }
+
+    fn complete_practice_loop(&mut self) -> Event {
+        let Some(previous_attempt) = self.active_attempt.take() else {
+            return Event::PracticeLoopCompleted;
+        };
+        let first = previous_attempt.first_split_index;
+        let last = previous_attempt.practice_until.unwrap_or(first);
+
+        let mut previous_split_time = Time::zero();
+        for segment in &mut self.run.segments_mut()[first..=last] {
+            let split_time = segment.split_time();
+            let segment_time = split_time - previous_split_time;
+            if segment_time.real_time.is_some() || segment_time.game_time.is_some() {
+                segment.practice_history_mut().push(segment_time);
+            }
+            // Skipped splits don't have a split time, so the next segment time
+            // needs to be measured from the last split time that is known.
+            for method in TimingMethod::all() {
+                if let Some(time) = split_time[method] {
+                    previous_split_time[method] = Some(time);
+                }
+            }
+        }
+        self.run.mark_as_modified();
+        self.reset_splits();
+
+        self.start_attempt(AtomicDateTime::now(), TimeStamp::now(), first, Some(last));
+
+        // The Game Time of the next loop continues to be driven the same way.
+        if let Some(active_attempt) = &mut self.active_attempt {
+            active_attempt.loading_times = previous_attempt.loading_times.map(|_| TimeSpan::zero());
+            active_attempt.game_time_paused_at = previous_attempt
+                .game_time_paused_at
+                .map(|_| TimeSpan::zero());
+        }
+
+        Event::PracticeLoopCompleted
+    }
+
+    /// Sets the offset of the run such that an attempt that gets started at
+    /// `attempt_started` reaches a time of 0 at `zero_at`. If `zero_at` is
+    /// after `attempt_started`, the offset is negative, so the timer counts
+    /// down until the point in time `zero_at`. Together with
+    /// [`start_at`](Self::start_at), this lets timers with differently
+    /// configured offsets all reach 0 at the same point in time. The offset
+    /// can't be changed while an attempt is in progress.
+    pub fn set_run_offset_for_sync(
+        &mut self,
+        attempt_started: AtomicDateTime,
+        zero_at: AtomicDateTime,
+    ) -> Result {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+        self.run.set_offset(attempt_started - zero_at);
+        self.run.mark_as_modified();
+        Ok(Event::OffsetChanged)
+    }
+
     /// If an attempt is in progress, stores the current time as the time of the
-    /// current split. The attempt ends if the last split time is stored.
+    /// current split. The attempt ends if the last split time is stored. If a
+    /// [`ComparisonSwitchRule`] switches the comparison afterwards,
+    /// [`Event::ComparisonChanged`] is returned instead of
+    /// [`Event::Splitted`].
     pub fn split(&mut self) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         let (split_index, current_time, event) = active_attempt.prepare_split(&self.run)?;
+        let completes_practice_loop = active_attempt.practice_until == Some(split_index);
 
         // FIXME: We shouldn't need to collect here.
         let variables = self
@@ -323,9 +950,29 @@ impl Timer {
         segment.set_split_time(current_time);
         *segment.variables_mut() = variables;
 
+        if completes_practice_loop {
+            return Ok(self.complete_practice_loop());
+        }
+
         self.run.mark_as_modified();
+        self.record_finish_prediction(split_index + 1);
 
-        Ok(event)
+        Ok(self.apply_comparison_switch_rules().unwrap_or(event))
+    }
+
+    fn record_finish_prediction(&mut self, splits_completed: usize) {
+        let snapshot = self.snapshot();
+        let (finish, _) =
+            current_pace::predict_wall_clock_time(&snapshot, &self.current_comparison);
+        let Some(finish) = finish else { return };
+
+        if let Some(active_attempt) = &mut self.active_attempt {
+            active_attempt.finish_predictions.push(FinishPrediction {
+                splits_completed,
+                predicted_at: AtomicDateTime::now(),
+                finish,
+            });
+        }
     }
 
     /// Starts a new attempt or stores the current time as the time of the
@@ -338,40 +985,76 @@ impl Timer {
         }
     }
 
+    /// Records that the active attempt reached the checkpoint with the name
+    /// provided within the current segment. Reaching a checkpoint doesn't
+    /// split, so the split times and the segment history stay unaffected. If
+    /// the checkpoint was already reached within the current segment, its time
+    /// is replaced. The checkpoints become part of the Personal Best if the
+    /// attempt turns out to be one.
+    pub fn reach_checkpoint<S>(&mut self, name: S) -> Result
+    where
+        S: Into<String>,
+    {
+        let active_attempt = self.active_attempt.as_ref().ok_or(Error::NoRunInProgress)?;
+        active_attempt.check_split()?;
+
+        let split_index = active_attempt.current_split_index_overflowing(&self.run);
+        let time = active_attempt.current_time(&self.run).into();
+        let checkpoint = Checkpoint::new(name, time);
+
+        let checkpoints = self.run.segment_mut(split_index).checkpoints_mut();
+        checkpoints.retain(|c| c.name != checkpoint.name);
+        checkpoints.push(checkpoint);
+
+        self.run.mark_as_modified();
+
+        Ok(Event::CheckpointReached)
+    }
+
     /// Skips the current split if an attempt is in progress and the
-    /// current split is not the last split.
+    /// current split is not the last split. If a [`ComparisonSwitchRule`]
+    /// switches the comparison afterwards, [`Event::ComparisonChanged`] is
+    /// returned instead of [`Event::SplitSkipped`].
     pub fn skip_split(&mut self) -> Result {
-        let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
+        let skipped_split_index = self.check_skip_split()?;
 
-        let Some(current_split_index) = active_attempt.current_split_index_mut() else {
-            return Err(Error::RunFinished);
-        };
+        if let Some(current_split_index) = self
+            .active_attempt
+            .as_mut()
+            .and_then(|a| a.current_split_index_mut())
+        {
+            *current_split_index += 1;
+        }
 
-        if *current_split_index + 1 < self.run.len() {
-            self.run
-                .segment_mut(*current_split_index)
-                .clear_split_info();
+        self.run.segment_mut(skipped_split_index).clear_split_info();
 
-            *current_split_index += 1;
+        self.run.mark_as_modified();
 
-            self.run.mark_as_modified();
+        Ok(self
+            .apply_comparison_switch_rules()
+            .unwrap_or(Event::SplitSkipped))
+    }
 
-            Ok(Event::SplitSkipped)
-        } else {
-            Err(Error::CantSkipLastSplit)
-        }
+    /// Skips the current split just like [`skip_split`](Self::skip_split), but
+    /// additionally stores the reason for skipping it. The reason becomes part
+    /// of the segment history once the attempt is stored.
+    pub fn skip_split_with_reason(&mut self, reason: SkipReason) -> Result {
+        let skipped_split_index = self.check_skip_split()?;
+        let event = self.skip_split()?;
+        self.run
+            .segment_mut(skipped_split_index)
+            .set_skip_reason(Some(reason));
+        Ok(event)
     }
 
     /// Removes the split time from the last split if an attempt is in progress
-    /// and there is a previous split. The Timer Phase also switches to
+    /// and there is a previous split. Splits of segments before the one the
+    /// attempt got started at can't be undone. The Timer Phase also switches to
     /// [`Running`] if it previously was [`Ended`].
     pub fn undo_split(&mut self) -> Result {
-        let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
+        let previous_split_index = self.check_undo_split()?;
 
-        if let Some(previous_split_index) = active_attempt
-            .current_split_index_overflowing(&self.run)
-            .checked_sub(1)
-        {
+        if let Some(active_attempt) = &mut self.active_attempt {
             let time_paused_at = match &active_attempt.state {
                 State::NotEnded { time_paused_at, .. } => *time_paused_at,
                 State::Ended { .. } => None,
@@ -382,16 +1065,70 @@ impl Timer {
                 time_paused_at,
             };
 
-            self.run
-                .segment_mut(previous_split_index)
-                .clear_split_info();
+            active_attempt
+                .finish_predictions
+                .retain(|p| p.splits_completed <= previous_split_index);
+        }
 
-            self.run.mark_as_modified();
+        if let Some(segment) = self.run.segments_mut().get_mut(previous_split_index + 1) {
+            segment.checkpoints_mut().clear();
+        }
+        self.run
+            .segment_mut(previous_split_index)
+            .clear_split_info();
 
-            Ok(Event::SplitUndone)
-        } else {
-            Err(Error::CantUndoFirstSplit)
+        self.run.mark_as_modified();
+
+        Ok(Event::SplitUndone)
+    }
+
+    /// Corrects the split time of the most recent split of the active attempt.
+    /// This is the last split if the attempt is finished. Segments before the
+    /// one the attempt got started at can't be corrected. Only the timing
+    /// methods that have a time specified are changed, so the Game Time read
+    /// off the screen at the end of a run can be entered without touching the
+    /// Real Time, for example. The new time can't be lower than the split
+    /// times before it and, if the attempt is still in progress, can't exceed
+    /// the current time.
+    pub fn set_current_split_time(&mut self, time: Time) -> Result {
+        let active_attempt = self.active_attempt.as_ref().ok_or(Error::NoRunInProgress)?;
+        let split_index = active_attempt
+            .current_split_index_overflowing(&self.run)
+            .checked_sub(1)
+            .filter(|&index| index >= active_attempt.first_split_index)
+            .ok_or(Error::NoSplitToCorrect)?;
+        let current_time = match active_attempt.state {
+            State::NotEnded { .. } => Some(Time::from(active_attempt.current_time(&self.run))),
+            State::Ended { .. } => None,
+        };
+
+        for method in TimingMethod::all() {
+            let Some(new_time) = time[method] else {
+                continue;
+            };
+            let previous_time = self.run.segments()[..split_index]
+                .iter()
+                .rev()
+                .find_map(|s| s.split_time()[method])
+                .unwrap_or_default();
+            let exceeds_current_time = current_time
+                .and_then(|t| t[method])
+                .is_some_and(|t| new_time > t);
+            if new_time < previous_time || exceeds_current_time {
+                return Err(Error::NegativeTime);
+            }
+        }
+
+        let split_time = self.run.segment_mut(split_index).split_time_mut();
+        for method in TimingMethod::all() {
+            if let Some(new_time) = time[method] {
+                split_time[method] = Some(new_time);
+            }
         }
+
+        self.run.mark_as_modified();
+
+        Ok(Event::SplitTimeSet)
     }
 
     /// Checks whether the current attempt has a new Personal Best for the
@@ -437,7 +1174,15 @@ impl Timer {
     pub fn current_attempt_has_new_best_times(&self) -> bool {
         self.current_attempt_has_new_best_segments(TimingMethod::RealTime)
             || self.current_attempt_has_new_best_segments(TimingMethod::GameTime)
-            || self.current_attempt_has_new_personal_best(self.current_timing_method)
+            || self.current_attempt_has_new_personal_best(self.current_timing_method())
+    }
+
+    /// Summarizes what would be lost or kept by resetting the current attempt
+    /// right now, based on the current comparison. This can be used to show an
+    /// informative confirmation before resetting. `None` is returned if there
+    /// is no attempt in progress.
+    pub fn reset_report(&self) -> Option<ResetReport> {
+        reset_report::calculate(&self.snapshot(), &self.current_comparison)
     }
 
     /// Resets the current attempt if there is one in progress. If the splits
@@ -445,6 +1190,19 @@ impl Timer {
     /// in the Run's history. Otherwise the current attempt's information is
     /// discarded.
     pub fn reset(&mut self, update_splits: bool) -> Result {
+        let is_practice = self.practiced_segments().is_some();
+        let event = self.reset_attempt(update_splits)?;
+        if !is_practice {
+            self.restart_if_configured();
+        }
+        Ok(event)
+    }
+
+    /// Resets the current attempt without ever starting a new one, regardless
+    /// of the settings. This is used whenever the attempt is reset as a side
+    /// effect of replacing the Run or replaying an attempt, rather than by the
+    /// user.
+    pub(in crate::timing) fn reset_attempt(&mut self, update_splits: bool) -> Result {
         if self.active_attempt.is_some() {
             self.reset_state(update_splits);
             self.reset_splits();
@@ -456,12 +1214,19 @@ impl Timer {
 
     /// Resets the current attempt if there is one in progress. The splits are
     /// updated such that the current attempt's split times are being stored as
-    /// the new Personal Best.
+    /// the new Personal Best. Practice attempts are never stored as the
+    /// Personal Best.
     pub fn reset_and_set_attempt_as_pb(&mut self) -> Result {
-        if self.active_attempt.is_some() {
+        if let Some(active_attempt) = &self.active_attempt {
+            let is_practice = active_attempt.practice_until.is_some();
             self.reset_state(true);
-            set_run_as_pb(&mut self.run);
+            if !is_practice {
+                set_run_as_pb(&mut self.run);
+            }
             self.reset_splits();
+            if !is_practice {
+                self.restart_if_configured();
+            }
             Ok(Event::Reset)
         } else {
             Err(Error::NoRunInProgress)
@@ -469,12 +1234,27 @@ impl Timer {
     }
 
     fn reset_state(&mut self, update_times: bool) {
+        let timing_method = self.current_timing_method();
         let Some(active_attempt) = self.active_attempt.take() else {
             return;
         };
 
-        if update_times {
-            active_attempt.update_times(&mut self.run, self.current_timing_method);
+        if let Some(comparison) = self.comparison_before_switch.take() {
+            self.current_comparison = comparison;
+        }
+
+        if update_times && active_attempt.practice_until.is_none() {
+            if self.settings.reset_saves_best_segments_only {
+                active_attempt.update_best_segments_only(&mut self.run);
+            } else {
+                active_attempt.update_times(&mut self.run, timing_method);
+            }
+        }
+    }
+
+    fn restart_if_configured(&mut self) {
+        if self.settings.auto_restart_after_reset {
+            let _ = self.start();
         }
     }
 
@@ -482,6 +1262,7 @@ impl Timer {
         // Reset Splits
         for segment in self.run.segments_mut() {
             segment.clear_split_info();
+            segment.checkpoints_mut().clear();
         }
 
         self.run.fix_splits();
@@ -490,18 +1271,15 @@ impl Timer {
 
     /// Pauses an active attempt that is not paused.
     pub fn pause(&mut self) -> Result {
-        let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
-
-        let State::NotEnded { time_paused_at, .. } = &mut active_attempt.state else {
-            return Err(Error::RunFinished);
-        };
+        self.check_pause()?;
 
-        if time_paused_at.is_none() {
-            *time_paused_at = Some(TimeStamp::now() - active_attempt.adjusted_start_time);
-            Ok(Event::Paused)
-        } else {
-            Err(Error::AlreadyPaused)
+        if let Some(active_attempt) = &mut self.active_attempt {
+            if let State::NotEnded { time_paused_at, .. } = &mut active_attempt.state {
+                *time_paused_at = Some(TimeStamp::now() - active_attempt.adjusted_start_time);
+            }
         }
+
+        Ok(Event::Paused)
     }
 
     /// Resumes an attempt that is paused.
@@ -586,6 +1364,21 @@ impl Timer {
         }
     }
 
+    /// Marks the start of a new session. From now on, the
+    /// [`Session Median`](session_median) comparison is generated from the
+    /// attempts that get started after this call. An attempt that may
+    /// currently be in progress is not part of the session. The comparison can
+    /// be selected like any other comparison once a session is marked. Marking
+    /// a new session replaces the previous one.
+    pub fn mark_session_start(&mut self) {
+        let generators = self.run.comparison_generators_mut();
+        generators.retain(|generator| generator.name() != session_median::NAME);
+        generators.push(Box::new(SessionMedian {
+            session_start: AtomicDateTime::now().time,
+        }));
+        self.run.regenerate_comparisons();
+    }
+
     /// Switches the current comparison to the next comparison in the list.
     pub fn switch_to_next_comparison(&mut self) {
         let mut comparisons = self.run.comparisons();
@@ -599,6 +1392,7 @@ impl Timer {
             .nth(index)
             .unwrap()
             .populate(&mut self.current_comparison);
+        self.comparison_before_switch = None;
     }
 
     /// Switches the current comparison to the previous comparison in the list.
@@ -614,6 +1408,7 @@ impl Timer {
             .nth(index)
             .unwrap()
             .populate(&mut self.current_comparison);
+        self.comparison_before_switch = None;
     }
 
     /// Returns the total duration of the current attempt. This is not affected
@@ -641,6 +1436,21 @@ impl Timer {
         self.active_attempt.as_ref()?.get_pause_time()
     }
 
+    /// Returns the predictions of the wall clock time at which the current
+    /// attempt finishes, as they were made when the attempt got started and at
+    /// each split, based on the comparison that was selected at that time.
+    /// This allows visualizing how the predicted finish drifted over the
+    /// course of the attempt. The predictions are ordered from oldest to
+    /// newest. Undoing a split removes the prediction made at that split. The
+    /// list is empty if there is no attempt in progress.
+    pub fn finish_predictions(&self) -> &[FinishPrediction] {
+        self.active_attempt
+            .as_ref()
+            .map_or(&[], |a| &a.finish_predictions)
+    }
+
+    /// Returns the wall clock time at which the current attempt got started.
+    /// None is returned if there is no attempt in progress.
     pub fn get_start_time(&self) -> Option<AtomicDateTime> {
         self.active_attempt.as_ref().map(|a| a.attempt_started)
     }
@@ -797,6 +1607,7 @@ fn set_run_as_pb(run: &mut Run) {
     for segment in run.segments_mut() {
         let split_time = segment.split_time();
         segment.set_personal_best_split_time(split_time);
+        *segment.personal_best_checkpoints_mut() = segment.checkpoints().to_vec();
     }
     run.clear_run_id();
 }
@@ -0,0 +1,77 @@
+use super::Snapshot;
+use crate::{
+    analysis::{best_possible_time, delta},
+    platform::prelude::*,
+    TimeSpan,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// The settings of a [`Timer`](super::Timer) that control policies which would
+/// otherwise need to be implemented by every frontend.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimerSettings {
+    /// Specifies whether a new attempt is started right away whenever an
+    /// attempt gets reset. This is useful for grinding a section of the run
+    /// over and over again. Replacing the Run of the timer and resetting a
+    /// practice attempt never start a new attempt.
+    pub auto_restart_after_reset: bool,
+    /// The index of the segment that new attempts start at. All the segments
+    /// before it are skipped. Attempts that don't start at the first segment
+    /// are considered practice and never update the Personal Best or the
+    /// final time stored in the Attempt History.
+    pub start_at_segment: usize,
+    /// Specifies whether resetting an attempt and updating the splits only
+    /// stores the new best segments. The Attempt History and the Personal Best
+    /// are left untouched in that case. The Segment History only receives the
+    /// new best segments, which aren't associated with any attempt.
+    pub reset_saves_best_segments_only: bool,
+    /// The rules for automatically switching the comparison while an attempt
+    /// is in progress. They are applied whenever a split is stored or skipped
+    /// and by
+    /// [`Timer::apply_comparison_switch_rules`](super::Timer::apply_comparison_switch_rules).
+    pub comparison_switch_rules: Vec<ComparisonSwitchRule>,
+}
+
+/// A rule for automatically switching from one comparison to another while an
+/// attempt is in progress, such as switching from the Personal Best to the Best
+/// Segments once the Personal Best can't be beaten anymore.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComparisonSwitchRule {
+    /// The comparison the rule applies to. The rule is only considered while
+    /// this is the current comparison.
+    pub from: String,
+    /// The comparison to switch to.
+    pub to: String,
+    /// The condition under which the comparison is switched.
+    pub condition: SwitchCondition,
+}
+
+/// The condition under which a [`ComparisonSwitchRule`] switches the
+/// comparison. The conditions are evaluated for the current timing method.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SwitchCondition {
+    /// The attempt can't beat the final time of the comparison anymore, as
+    /// even the Best Possible Time is slower than it.
+    FinalTimeUnreachable,
+    /// The attempt is behind the comparison by more than the time specified.
+    BehindBy(TimeSpan),
+}
+
+impl SwitchCondition {
+    pub(super) fn is_met(self, timer: &Snapshot<'_>, comparison: &str) -> bool {
+        let method = timer.current_timing_method();
+        match self {
+            SwitchCondition::FinalTimeUnreachable => catch! {
+                let final_time = timer.run().segments().last()?.comparison(comparison)[method]?;
+                best_possible_time::calculate(timer, method).0? > final_time
+            }
+            .unwrap_or(false),
+            SwitchCondition::BehindBy(threshold) => {
+                delta::calculate_for_timing_method(timer, comparison, method)
+                    .0
+                    .is_some_and(|delta| delta > threshold)
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::timer;
+use crate::TimeSpan;
+
+#[test]
+fn no_attempt_times_without_attempt() {
+    let timer = timer();
+    let snapshot = timer.snapshot();
+
+    assert_eq!(snapshot.start_time(), None);
+    assert_eq!(snapshot.end_time(), None);
+    assert_eq!(snapshot.paused_since(), None);
+    assert_eq!(snapshot.attempt_times(), None);
+}
+
+#[test]
+fn paused_since_is_only_set_while_paused() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    assert_eq!(timer.snapshot().paused_since(), None);
+
+    timer.pause().unwrap();
+    let snapshot = timer.snapshot();
+    let times = snapshot.attempt_times().unwrap();
+    let paused_since = times.paused_since.unwrap();
+    assert!(paused_since >= times.started_at);
+    assert_eq!(times.ended_at, None);
+
+    timer.resume().unwrap();
+    assert_eq!(timer.snapshot().paused_since(), None);
+}
+
+#[test]
+fn end_time_is_set_once_ended() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    while timer.current_phase().is_running() {
+        timer.split().unwrap();
+    }
+
+    let times = timer.snapshot().attempt_times().unwrap();
+    assert!(times.ended_at.unwrap() >= times.started_at);
+    assert!(times.pause_time >= TimeSpan::zero());
+}
+
+#[test]
+fn attempt_times_serialize() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    let times = timer.snapshot().attempt_times().unwrap();
+
+    let json = serde_json::to_string(&times).unwrap();
+    assert_eq!(
+        serde_json::from_str::<crate::timing::AttemptTimes>(&json).unwrap(),
+        times
+    );
+}
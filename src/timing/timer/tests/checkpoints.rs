@@ -0,0 +1,126 @@
+use super::timer;
+use crate::{
+    event::{Error, Event},
+    run::{parser::livesplit, saver},
+    testing::{set_game_time, span, split_at, start_run},
+    Timer, TimingMethod,
+};
+
+fn checkpoint_delta(timer: &Timer) -> Option<f64> {
+    let snapshot = timer.snapshot();
+    let (_, delta) = snapshot.current_checkpoint(TimingMethod::GameTime)?;
+    delta.map(|d| d.total_seconds())
+}
+
+fn run_with_checkpoint(timer: &mut Timer, checkpoint: f64) {
+    start_run(timer);
+    set_game_time(timer, checkpoint);
+    timer.reach_checkpoint("Boss").unwrap();
+    split_at(timer, 10.0);
+    split_at(timer, 20.0);
+    split_at(timer, 30.0);
+}
+
+#[test]
+fn checkpoints_require_an_active_attempt() {
+    let mut timer = timer();
+    assert_eq!(timer.reach_checkpoint("Boss"), Err(Error::NoRunInProgress));
+
+    run_with_checkpoint(&mut timer, 5.0);
+    assert_eq!(timer.reach_checkpoint("Boss"), Err(Error::RunFinished));
+}
+
+#[test]
+fn checkpoints_are_compared_to_the_personal_best() {
+    let mut timer = timer();
+    run_with_checkpoint(&mut timer, 5.0);
+    timer.reset(true).unwrap();
+
+    let segment = timer.run().segment(0);
+    assert!(segment.checkpoints().is_empty());
+    assert_eq!(
+        segment.personal_best_checkpoint_time("Boss").game_time,
+        Some(span(5.0))
+    );
+
+    start_run(&mut timer);
+    assert_eq!(
+        timer.snapshot().current_checkpoint(TimingMethod::GameTime),
+        None
+    );
+
+    set_game_time(&mut timer, 6.5);
+    assert_eq!(timer.reach_checkpoint("Boss"), Ok(Event::CheckpointReached));
+    assert_eq!(checkpoint_delta(&timer), Some(1.5));
+
+    set_game_time(&mut timer, 7.0);
+    timer.reach_checkpoint("Unknown").unwrap();
+    assert_eq!(checkpoint_delta(&timer), None);
+    assert!(timer
+        .snapshot()
+        .current_checkpoint(TimingMethod::GameTime)
+        .is_some());
+
+    split_at(&mut timer, 11.0);
+    assert_eq!(
+        timer.snapshot().current_checkpoint(TimingMethod::GameTime),
+        None
+    );
+    assert_eq!(timer.run().segment(1).split_time().game_time, None);
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(span(11.0))
+    );
+}
+
+#[test]
+fn slower_attempts_keep_the_checkpoints_of_the_personal_best() {
+    let mut timer = timer();
+    run_with_checkpoint(&mut timer, 5.0);
+    timer.reset(true).unwrap();
+
+    start_run(&mut timer);
+    set_game_time(&mut timer, 4.0);
+    timer.reach_checkpoint("Boss").unwrap();
+    split_at(&mut timer, 15.0);
+    timer.reset(true).unwrap();
+
+    let segment = timer.run().segment(0);
+    assert_eq!(
+        segment.personal_best_checkpoint_time("Boss").game_time,
+        Some(span(5.0))
+    );
+    assert_eq!(segment.segment_history().iter().count(), 2);
+}
+
+#[test]
+fn undoing_a_split_discards_the_checkpoints_after_it() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    set_game_time(&mut timer, 5.0);
+    timer.reach_checkpoint("Boss").unwrap();
+    split_at(&mut timer, 10.0);
+    set_game_time(&mut timer, 15.0);
+    timer.reach_checkpoint("Boss").unwrap();
+
+    timer.undo_split().unwrap();
+    assert_eq!(timer.run().segment(0).checkpoints().len(), 1);
+    assert!(timer.run().segment(1).checkpoints().is_empty());
+}
+
+#[test]
+fn personal_best_checkpoints_survive_saving_and_parsing() {
+    let mut timer = timer();
+    run_with_checkpoint(&mut timer, 5.0);
+    timer.reset(true).unwrap();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(timer.run(), &mut buf).unwrap();
+    let parsed = livesplit::parse(&buf).unwrap();
+
+    assert_eq!(
+        parsed.segment(0).personal_best_checkpoints(),
+        timer.run().segment(0).personal_best_checkpoints(),
+    );
+    assert!(parsed.segment(1).personal_best_checkpoints().is_empty());
+}
@@ -0,0 +1,72 @@
+use super::timer;
+
+#[test]
+fn nothing_is_possible_without_a_run() {
+    let timer = timer();
+
+    assert!(!timer.can_split());
+    assert!(!timer.can_undo());
+    assert!(!timer.can_skip());
+    assert!(!timer.can_pause());
+}
+
+#[test]
+fn fresh_attempt_can_split_skip_and_pause() {
+    let mut timer = timer();
+    timer.start().unwrap();
+
+    assert!(timer.can_split());
+    assert!(!timer.can_undo());
+    assert!(timer.can_skip());
+    assert!(timer.can_pause());
+}
+
+#[test]
+fn paused_attempt_can_only_undo_and_skip() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.split().unwrap();
+    timer.pause().unwrap();
+
+    assert!(!timer.can_split());
+    assert!(timer.can_undo());
+    assert!(timer.can_skip());
+    assert!(!timer.can_pause());
+}
+
+#[test]
+fn last_split_cant_be_skipped() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.split().unwrap();
+    timer.split().unwrap();
+
+    assert!(timer.can_split());
+    assert!(!timer.can_skip());
+}
+
+#[test]
+fn finished_attempt_can_only_undo() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.split().unwrap();
+    timer.split().unwrap();
+    timer.split().unwrap();
+
+    assert!(!timer.can_split());
+    assert!(timer.can_undo());
+    assert!(!timer.can_skip());
+    assert!(!timer.can_pause());
+}
+
+#[test]
+fn predicates_agree_with_actions() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.split().unwrap();
+
+    assert_eq!(timer.can_undo(), timer.clone().undo_split().is_ok());
+    assert_eq!(timer.can_skip(), timer.clone().skip_split().is_ok());
+    assert_eq!(timer.can_pause(), timer.clone().pause().is_ok());
+    assert_eq!(timer.can_split(), timer.clone().split().is_ok());
+}
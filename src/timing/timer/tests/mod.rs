@@ -1,13 +1,25 @@
 use crate::{
     run::Editor,
-    util::tests_helper::{
-        make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, start_run,
-    },
+    testing::{make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, start_run},
     Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 
+mod attempt_times;
+mod checkpoints;
 mod events;
+mod guards;
 mod mark_as_modified;
+mod practice;
+mod properties;
+mod reload_run;
+mod remaining_segments;
+mod replay;
+mod scheduled_start;
+mod settings;
+mod split_time_correction;
+mod synchronized_start;
+#[cfg(feature = "std")]
+mod timer_control;
 mod variables;
 
 fn run() -> Run {
@@ -673,3 +685,71 @@ fn skipping_keeps_timer_paused() {
     assert_eq!(timer.current_phase(), TimerPhase::Paused);
     assert_eq!(timer.current_split_index(), Some(0));
 }
+
+#[test]
+fn tracks_time_lost_to_best_segments() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+
+    assert!(timer
+        .snapshot()
+        .best_segment_deltas(TimingMethod::GameTime)
+        .is_empty());
+    assert_eq!(
+        timer
+            .snapshot()
+            .time_lost_to_sum_of_best(TimingMethod::GameTime),
+        None
+    );
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(7.0), None]);
+    timer.set_game_time(TimeSpan::from_seconds(18.0)).unwrap();
+
+    let snapshot = timer.snapshot();
+    assert_eq!(
+        snapshot.best_segment_deltas(TimingMethod::GameTime),
+        [Some(TimeSpan::from_seconds(2.0)), None],
+    );
+    assert_eq!(
+        snapshot.time_lost_to_sum_of_best(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(3.0)),
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(15.0)).unwrap();
+    timer.split().unwrap();
+    let snapshot = timer.snapshot();
+    assert_eq!(
+        snapshot.best_segment_deltas(TimingMethod::GameTime),
+        [
+            Some(TimeSpan::from_seconds(2.0)),
+            None,
+            Some(TimeSpan::from_seconds(-2.0)),
+        ],
+    );
+    assert_eq!(
+        snapshot.time_lost_to_sum_of_best(TimingMethod::GameTime),
+        Some(TimeSpan::zero()),
+    );
+}
+
+#[test]
+fn tracks_time_since_the_most_recent_split() {
+    let mut timer = timer();
+    let segment_time = |timer: &Timer| timer.snapshot().current_segment_time().game_time;
+
+    assert_eq!(segment_time(&timer), Some(TimeSpan::zero()));
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(4.0)).unwrap();
+    assert_eq!(segment_time(&timer), Some(TimeSpan::from_seconds(4.0)));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None]);
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+    assert_eq!(segment_time(&timer), Some(TimeSpan::from_seconds(7.0)));
+
+    timer.split().unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(segment_time(&timer), Some(TimeSpan::from_seconds(7.0)));
+    assert!(timer.snapshot().current_segment_time().real_time.is_some());
+}
@@ -0,0 +1,169 @@
+use super::timer;
+use crate::{
+    event::{Error, Event},
+    run::{parser::livesplit, saver},
+    testing::{set_game_time, span, split_at, start_run},
+    TimeSpan, Timer, TimerPhase, TimingMethod,
+};
+
+fn start_practice(timer: &mut Timer, first: usize, last: usize) {
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    assert_eq!(timer.start_practice(first, last), Ok(Event::Started));
+    timer.initialize_game_time().unwrap();
+    timer.pause_game_time().unwrap();
+    timer.set_game_time(TimeSpan::zero()).unwrap();
+}
+
+fn practice_times(timer: &Timer, index: usize) -> Vec<Option<TimeSpan>> {
+    timer
+        .run()
+        .segment(index)
+        .practice_history()
+        .iter()
+        .map(|t| t.game_time)
+        .collect()
+}
+
+#[test]
+fn loops_over_the_practiced_segments() {
+    let mut timer = timer();
+    start_practice(&mut timer, 1, 2);
+    assert_eq!(timer.practiced_segments(), Some(1..=2));
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    split_at(&mut timer, 4.0);
+    set_game_time(&mut timer, 10.0);
+    assert_eq!(timer.split(), Ok(Event::PracticeLoopCompleted));
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert_eq!(timer.snapshot().current_time().game_time, Some(span(0.0)));
+
+    split_at(&mut timer, 3.0);
+    split_at(&mut timer, 10.0);
+
+    assert!(timer.run().segment(0).practice_history().is_empty());
+    assert_eq!(
+        practice_times(&timer, 1),
+        [Some(span(4.0)), Some(span(3.0))]
+    );
+    assert_eq!(
+        practice_times(&timer, 2),
+        [Some(span(6.0)), Some(span(7.0))]
+    );
+    let segment = timer.run().segment(1);
+    assert_eq!(
+        segment.best_practice_time(TimingMethod::GameTime),
+        Some(span(3.0))
+    );
+    assert_eq!(
+        segment.average_practice_time(TimingMethod::GameTime),
+        Some(span(3.5))
+    );
+}
+
+#[test]
+fn practicing_doesnt_affect_the_history() {
+    let mut timer = timer();
+    start_practice(&mut timer, 0, 1);
+    split_at(&mut timer, 4.0);
+    split_at(&mut timer, 10.0);
+    split_at(&mut timer, 3.0);
+    timer.reset(true).unwrap();
+
+    assert_eq!(timer.practiced_segments(), None);
+    assert_eq!(practice_times(&timer, 0), [Some(span(4.0))]);
+    let run = timer.run();
+    assert_eq!(run.attempt_count(), 0);
+    assert!(run.attempt_history().is_empty());
+    for segment in run.segments() {
+        assert!(segment.segment_history().iter().next().is_none());
+        assert_eq!(segment.best_segment_time().game_time, None);
+        assert_eq!(segment.personal_best_split_time().game_time, None);
+    }
+}
+
+#[test]
+fn only_valid_ranges_can_be_practiced() {
+    let mut timer = timer();
+    assert_eq!(timer.start_practice(2, 1), Err(Error::Unsupported));
+    assert_eq!(timer.start_practice(1, 3), Err(Error::Unsupported));
+
+    start_run(&mut timer);
+    assert_eq!(timer.practiced_segments(), None);
+    assert_eq!(timer.start_practice(0, 0), Err(Error::RunAlreadyInProgress));
+}
+
+#[test]
+fn cant_undo_splits_before_the_practiced_segments() {
+    let mut timer = timer();
+    start_practice(&mut timer, 1, 2);
+    assert!(!timer.can_undo());
+    assert_eq!(timer.undo_split(), Err(Error::CantUndoFirstSplit));
+
+    split_at(&mut timer, 4.0);
+    assert_eq!(timer.undo_split(), Ok(Event::SplitUndone));
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert_eq!(timer.undo_split(), Err(Error::CantUndoFirstSplit));
+}
+
+#[test]
+fn practice_history_survives_saving_and_parsing() {
+    let mut timer = timer();
+    start_practice(&mut timer, 2, 2);
+    split_at(&mut timer, 4.0);
+    split_at(&mut timer, 5.0);
+    timer.reset(false).unwrap();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(timer.run(), &mut buf).unwrap();
+    let parsed = livesplit::parse(&buf).unwrap();
+
+    assert_eq!(
+        parsed.segment(2).practice_history(),
+        timer.run().segment(2).practice_history(),
+    );
+    assert_eq!(parsed.segment(2).practice_history().len(), 2);
+    assert!(parsed.segment(1).practice_history().is_empty());
+}
+
+#[test]
+fn practice_attempts_are_never_stored_as_the_personal_best() {
+    let mut timer = timer();
+    timer.settings_mut().auto_restart_after_reset = true;
+    start_practice(&mut timer, 1, 2);
+    split_at(&mut timer, 4.0);
+    timer.reset_and_set_attempt_as_pb().unwrap();
+
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    let run = timer.run();
+    assert_eq!(run.attempt_count(), 0);
+    for segment in run.segments() {
+        assert_eq!(segment.personal_best_split_time().real_time, None);
+        assert_eq!(segment.personal_best_split_time().game_time, None);
+    }
+}
+
+#[test]
+fn resetting_practice_attempts_doesnt_restart() {
+    let mut timer = timer();
+    timer.settings_mut().auto_restart_after_reset = true;
+    start_practice(&mut timer, 0, 1);
+    split_at(&mut timer, 4.0);
+    timer.reset(true).unwrap();
+
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.run().attempt_count(), 0);
+}
+
+#[test]
+fn segment_after_skipped_split_is_measured_from_the_last_split() {
+    let mut timer = timer();
+    start_practice(&mut timer, 0, 2);
+    split_at(&mut timer, 4.0);
+    timer.skip_split().unwrap();
+    split_at(&mut timer, 10.0);
+
+    assert_eq!(practice_times(&timer, 0), [Some(span(4.0))]);
+    assert!(timer.run().segment(1).practice_history().is_empty());
+    assert_eq!(practice_times(&timer, 2), [Some(span(6.0))]);
+}
@@ -0,0 +1,131 @@
+//! Checks invariants of the timer against randomly generated sequences of
+//! operations. The sequences are generated from fixed seeds, so failures are
+//! reproducible.
+
+use crate::{
+    testing::{create_timer, pause_at, resume, skip_splits, split_at, start_run, undo_splits},
+    Time, Timer, TimerPhase,
+};
+
+const SEEDS: u64 = 64;
+const OPERATIONS: usize = 48;
+
+/// A xorshift generator, which is good enough for picking operations.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+/// Applies a random operation that is valid in the timer's current state.
+fn random_operation(timer: &mut Timer, rng: &mut Rng, game_time: &mut f64) {
+    match timer.current_phase() {
+        TimerPhase::NotRunning => {
+            *game_time = 0.0;
+            start_run(timer);
+        }
+        TimerPhase::Ended => match rng.below(2) {
+            0 => undo_splits(timer, 1),
+            _ => {
+                timer.reset(rng.below(2) == 0).unwrap();
+            }
+        },
+        TimerPhase::Paused => resume(timer),
+        TimerPhase::Running => {
+            let index = timer.current_split_index().unwrap();
+            let is_last = index + 1 == timer.run().len();
+            match rng.below(6) {
+                0 if index > 0 => undo_splits(timer, 1),
+                1 if !is_last => skip_splits(timer, 1),
+                2 => {
+                    *game_time += rng.below(10) as f64;
+                    pause_at(timer, *game_time);
+                }
+                _ => {
+                    *game_time += rng.below(100) as f64 / 4.0;
+                    split_at(timer, *game_time);
+                }
+            }
+        }
+    }
+}
+
+fn split_times(timer: &Timer) -> Vec<Time> {
+    timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.split_time())
+        .collect()
+}
+
+#[test]
+fn split_times_stay_consistent_with_the_current_split() {
+    for seed in 0..SEEDS {
+        let mut timer = create_timer(&["A", "B", "C", "D", "E"]);
+        let mut rng = Rng::new(seed);
+        let mut game_time = 0.0;
+
+        for _ in 0..OPERATIONS {
+            random_operation(&mut timer, &mut rng, &mut game_time);
+
+            let split_index = match timer.current_phase() {
+                TimerPhase::NotRunning => 0,
+                _ => timer.current_split_index().unwrap(),
+            };
+            assert!(split_index <= timer.run().len(), "seed {seed}");
+
+            let times = split_times(&timer);
+            assert!(
+                times[split_index..].iter().all(|t| t.game_time.is_none()),
+                "seed {seed}"
+            );
+            let recorded = times[..split_index]
+                .iter()
+                .filter_map(|t| t.game_time)
+                .collect::<Vec<_>>();
+            assert!(
+                recorded.windows(2).all(|pair| pair[0] <= pair[1]),
+                "seed {seed}"
+            );
+        }
+    }
+}
+
+#[test]
+fn splitting_and_undoing_restores_the_split_times() {
+    for seed in 0..SEEDS {
+        let mut timer = create_timer(&["A", "B", "C", "D", "E"]);
+        let mut rng = Rng::new(seed);
+        let mut game_time = 0.0;
+
+        for _ in 0..OPERATIONS {
+            random_operation(&mut timer, &mut rng, &mut game_time);
+
+            if timer.current_phase() != TimerPhase::Running {
+                continue;
+            }
+
+            let before = split_times(&timer);
+            let split_index = timer.current_split_index();
+            split_at(&mut timer, game_time + 1.0);
+            undo_splits(&mut timer, 1);
+
+            assert_eq!(split_times(&timer), before, "seed {seed}");
+            assert_eq!(timer.current_split_index(), split_index, "seed {seed}");
+        }
+    }
+}
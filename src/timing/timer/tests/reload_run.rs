@@ -0,0 +1,47 @@
+use super::{run, timer};
+use crate::{
+    run::SkipReason,
+    testing::{set_game_time, span, split_at, start_run},
+};
+
+#[test]
+fn carries_the_active_attempt_over() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+    timer.skip_split_with_reason(SkipReason::RouteSkip).unwrap();
+    set_game_time(&mut timer, 12.0);
+    timer.reach_checkpoint("Boss").unwrap();
+    assert_eq!(timer.run().attempt_count(), 1);
+
+    let mut reloaded = run();
+    reloaded.set_game_name("Reloaded");
+    timer.reload_run(reloaded).unwrap();
+
+    let run = timer.run();
+    assert_eq!(run.game_name(), "Reloaded");
+    assert_eq!(run.attempt_count(), 1);
+    assert_eq!(run.segment(0).split_time().game_time, Some(span(5.0)));
+    assert_eq!(run.segment(1).skip_reason(), Some(SkipReason::RouteSkip));
+    assert_eq!(run.segment(2).checkpoints().len(), 1);
+    assert_eq!(timer.current_split_index(), Some(2));
+
+    split_at(&mut timer, 20.0);
+    timer.reset(true).unwrap();
+
+    let run = timer.run();
+    assert_eq!(run.attempt_history().len(), 1);
+    assert_eq!(
+        run.segment(1).skip_reason_for_attempt(1),
+        Some(SkipReason::RouteSkip)
+    );
+    assert_eq!(run.segment(2).personal_best_checkpoints().len(), 1);
+}
+
+#[test]
+fn practice_attempts_are_not_counted() {
+    let mut timer = timer();
+    timer.start_practice(0, 1).unwrap();
+    timer.reload_run(run()).unwrap();
+    assert_eq!(timer.run().attempt_count(), 0);
+}
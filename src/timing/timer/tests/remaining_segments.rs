@@ -0,0 +1,94 @@
+use super::timer;
+use crate::{
+    comparison::personal_best,
+    testing::{run_with_splits, run_with_splits_opt, span, split_at, start_run},
+    TimeSpan, Timer, TimingMethod,
+};
+
+type Remaining = (usize, Option<TimeSpan>, Option<TimeSpan>, Option<TimeSpan>);
+
+fn remaining(timer: &Timer) -> Vec<Remaining> {
+    timer
+        .snapshot()
+        .remaining_segments(personal_best::NAME, TimingMethod::GameTime)
+        .map(|s| {
+            (
+                s.index,
+                s.comparison_time,
+                s.comparison_segment_time,
+                s.predicted_time,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn provides_the_comparison_times() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    let snapshot = timer.snapshot();
+    let time_at =
+        |index| snapshot.comparison_time_at(index, personal_best::NAME, TimingMethod::GameTime);
+    assert_eq!(time_at(1), Some(span(20.0)));
+    assert_eq!(time_at(3), None);
+    assert_eq!(
+        snapshot.comparison_time_at(1, "Nope", TimingMethod::GameTime),
+        None
+    );
+}
+
+#[test]
+fn predicts_the_remaining_segments() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    assert_eq!(
+        remaining(&timer),
+        [
+            (0, Some(span(10.0)), Some(span(10.0)), Some(span(10.0))),
+            (1, Some(span(20.0)), Some(span(10.0)), Some(span(20.0))),
+            (2, Some(span(30.0)), Some(span(10.0)), Some(span(30.0))),
+        ],
+    );
+
+    start_run(&mut timer);
+    split_at(&mut timer, 12.0);
+    assert_eq!(
+        remaining(&timer),
+        [
+            (1, Some(span(20.0)), Some(span(10.0)), Some(span(22.0))),
+            (2, Some(span(30.0)), Some(span(10.0)), Some(span(32.0))),
+        ],
+    );
+
+    // Once the current segment takes longer than in the comparison, the
+    // predictions move along with it.
+    timer.set_game_time(span(25.0)).unwrap();
+    assert_eq!(
+        remaining(&timer),
+        [
+            (1, Some(span(20.0)), Some(span(10.0)), Some(span(25.0))),
+            (2, Some(span(30.0)), Some(span(10.0)), Some(span(35.0))),
+        ],
+    );
+
+    split_at(&mut timer, 25.0);
+    split_at(&mut timer, 35.0);
+    assert_eq!(remaining(&timer), []);
+}
+
+#[test]
+fn combines_segments_skipped_in_the_comparison() {
+    let mut timer = timer();
+    run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(30.0)]);
+
+    assert_eq!(
+        remaining(&timer),
+        [
+            (0, Some(span(10.0)), Some(span(10.0)), Some(span(10.0))),
+            (1, None, None, None),
+            (2, Some(span(30.0)), Some(span(20.0)), Some(span(30.0))),
+        ],
+    );
+}
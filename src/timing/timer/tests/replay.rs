@@ -0,0 +1,154 @@
+use crate::{
+    testing::{create_run, create_timer, pause_at, resume, span, split_at, start_run, FakeClock},
+    timing::replay::{Command, Replay, TimedCommand},
+    Run, Time, TimeSpan, Timer, TimerPhase,
+};
+
+fn play(replay: &Replay, run: Run, clock: &FakeClock) -> Timer {
+    let mut timer = Timer::new(run).unwrap();
+    let mut next = 0;
+    for command in replay.commands() {
+        clock.advance(command.at - clock.elapsed());
+        replay
+            .play_until(&mut timer, &mut next, command.at)
+            .unwrap();
+        if timer.current_phase() == TimerPhase::Ended {
+            break;
+        }
+    }
+    timer
+}
+
+#[test]
+fn replays_a_finished_attempt() {
+    let clock = FakeClock::install();
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    start_run(&mut timer);
+    clock.advance(span(10.0));
+    split_at(&mut timer, 8.0);
+    clock.advance(span(5.0));
+    timer.skip_split().unwrap();
+    clock.advance(span(10.0));
+    split_at(&mut timer, 22.0);
+    timer.reset(true).unwrap();
+
+    let run = timer.into_run(true);
+    let index = run.attempt_history()[0].index();
+    let replay = Replay::from_attempt(&run, index).unwrap();
+
+    assert_eq!(replay.attempt_index(), index);
+    assert_eq!(
+        replay.commands(),
+        [
+            TimedCommand {
+                at: span(0.0),
+                command: Command::Start
+            },
+            TimedCommand {
+                at: span(0.0),
+                command: Command::InitializeGameTime
+            },
+            TimedCommand {
+                at: span(10.0),
+                command: Command::SetGameTime(span(8.0))
+            },
+            TimedCommand {
+                at: span(10.0),
+                command: Command::Split
+            },
+            TimedCommand {
+                at: span(10.0),
+                command: Command::SkipSplit
+            },
+            TimedCommand {
+                at: span(25.0),
+                command: Command::SetGameTime(span(22.0))
+            },
+            TimedCommand {
+                at: span(25.0),
+                command: Command::Split
+            },
+        ]
+    );
+    assert_eq!(replay.duration(), span(25.0));
+
+    let mut fresh = run.clone();
+    fresh.clear_history();
+    let replayed = play(&replay, fresh, &clock);
+    assert_eq!(replayed.current_phase(), TimerPhase::Ended);
+    let last_split = replayed.run().segment(2).split_time();
+    assert_eq!(last_split.real_time, Some(span(25.0)));
+    assert_eq!(last_split.game_time, Some(span(22.0)));
+    assert_eq!(replayed.run().segment(1).split_time().real_time, None);
+}
+
+#[test]
+fn replays_a_reset_attempt_until_it_ended() {
+    let clock = FakeClock::install();
+    let mut timer = create_timer(&["A", "B"]);
+
+    start_run(&mut timer);
+    clock.advance(span(10.0));
+    split_at(&mut timer, 10.0);
+    pause_at(&mut timer, 12.0);
+    clock.advance(span(20.0));
+    resume(&mut timer);
+    clock.advance(span(4.0));
+    timer.reset(true).unwrap();
+
+    let run = timer.into_run(true);
+    let replay = Replay::from_attempt(&run, run.attempt_history()[0].index()).unwrap();
+
+    let commands = replay.commands();
+    assert_eq!(commands[commands.len() - 2].command, Command::Split);
+    assert_eq!(
+        commands.last(),
+        Some(&TimedCommand {
+            at: span(14.0),
+            command: Command::Reset
+        })
+    );
+    assert_eq!(Replay::from_attempt(&run, 42), None);
+    assert!(replay.started().is_some());
+    assert_eq!(TimeSpan::zero(), commands[0].at);
+}
+
+#[test]
+fn replays_an_attempt_that_skipped_the_last_split() {
+    let clock = FakeClock::install();
+    let mut run = create_run(&["A", "B"]);
+    run.add_attempt_with_index(Time::new(), 1, None, None, None);
+    run.segment_mut(0)
+        .segment_history_mut()
+        .insert(1, Time::new().with_real_time(Some(span(10.0))));
+    run.segment_mut(1)
+        .segment_history_mut()
+        .insert(1, Time::new());
+
+    let replay = Replay::from_attempt(&run, 1).unwrap();
+    assert_eq!(
+        replay.commands().last(),
+        Some(&TimedCommand {
+            at: span(10.0),
+            command: Command::Reset
+        })
+    );
+    assert!(replay
+        .commands()
+        .iter()
+        .all(|c| c.command != Command::SkipSplit));
+
+    let mut timer = Timer::new(run).unwrap();
+    timer.settings_mut().auto_restart_after_reset = true;
+    timer.mark_as_unmodified();
+    let mut next = 0;
+    clock.advance(span(10.0));
+    replay
+        .play_until(&mut timer, &mut next, replay.duration())
+        .unwrap();
+    assert_eq!(next, replay.commands().len());
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.run().attempt_count(), 0);
+    assert!(!timer.run().has_been_modified());
+}
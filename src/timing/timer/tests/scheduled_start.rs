@@ -0,0 +1,87 @@
+use super::timer;
+use crate::{
+    component::timer::Component as TimerComponent,
+    event::{Error, Event},
+    platform::Duration,
+    testing::{span, FakeClock},
+    AtomicDateTime, GeneralLayoutSettings, TimerPhase,
+};
+
+fn seconds_from_now(seconds: i64) -> AtomicDateTime {
+    let now = AtomicDateTime::now();
+    AtomicDateTime::new(
+        now.time + Duration::seconds(seconds),
+        now.synced_with_atomic_clock,
+    )
+}
+
+#[test]
+fn starts_once_the_scheduled_time_is_reached() {
+    let clock = FakeClock::install();
+    let mut timer = timer();
+
+    let start_at = seconds_from_now(10);
+    assert_eq!(timer.schedule_start(start_at), Ok(Event::StartScheduled));
+    assert_eq!(timer.scheduled_start(), Some(start_at));
+    assert_eq!(
+        timer.snapshot().time_until_scheduled_start(),
+        Some(span(10.0))
+    );
+
+    clock.advance(span(4.0));
+    assert_eq!(timer.apply_scheduled_start(), None);
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(
+        timer.snapshot().time_until_scheduled_start(),
+        Some(span(6.0))
+    );
+
+    // Applying the scheduled start late still starts at the scheduled time.
+    clock.advance(span(8.0));
+    assert_eq!(timer.apply_scheduled_start(), Some(Event::Started));
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.snapshot().start_time(), Some(start_at));
+    assert_eq!(timer.snapshot().current_time().real_time, Some(span(2.0)));
+    assert_eq!(timer.scheduled_start(), None);
+    assert_eq!(timer.snapshot().time_until_scheduled_start(), None);
+    assert_eq!(timer.apply_scheduled_start(), None);
+
+    assert_eq!(
+        timer.schedule_start(seconds_from_now(10)),
+        Err(Error::RunAlreadyInProgress)
+    );
+}
+
+#[test]
+fn starting_manually_cancels_the_scheduled_start() {
+    let _clock = FakeClock::install();
+    let mut timer = timer();
+
+    timer.schedule_start(seconds_from_now(10)).unwrap();
+    timer.start().unwrap();
+    assert_eq!(timer.scheduled_start(), None);
+
+    timer.reset(true).unwrap();
+    let start_at = seconds_from_now(10);
+    timer.schedule_start(start_at).unwrap();
+    assert_eq!(timer.cancel_scheduled_start(), Some(start_at));
+    assert_eq!(timer.cancel_scheduled_start(), None);
+}
+
+#[test]
+fn the_timer_component_counts_down_to_the_scheduled_start() {
+    let clock = FakeClock::install();
+    let mut timer = timer();
+    let component = TimerComponent::new();
+    let layout_settings = GeneralLayoutSettings::default();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert!(!state.updates_frequently);
+
+    timer.schedule_start(seconds_from_now(65)).unwrap();
+    clock.advance(span(0.5));
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.time, "−1:04");
+    assert_eq!(state.fraction, ".50");
+    assert!(state.updates_frequently);
+}
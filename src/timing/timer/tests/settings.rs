@@ -0,0 +1,230 @@
+use super::timer;
+use crate::{
+    comparison::{average_segments, best_segments, personal_best},
+    event::{Error, Event},
+    testing::{run_with_splits, split_at, start_run, FakeClock},
+    timing::{ComparisonSwitchRule, SwitchCondition},
+    TimeSpan, TimerPhase, TimingMethod,
+};
+
+#[test]
+fn auto_restart_after_reset() {
+    let mut timer = timer();
+    timer.settings_mut().auto_restart_after_reset = true;
+
+    timer.start().unwrap();
+    timer.reset(true).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(0));
+    assert_eq!(timer.run().attempt_count(), 2);
+}
+
+#[test]
+fn replacing_the_run_doesnt_auto_restart() {
+    let mut timer = timer();
+    timer.settings_mut().auto_restart_after_reset = true;
+
+    timer.start().unwrap();
+    let previous = timer.replace_run(super::run(), true).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.run().attempt_count(), 0);
+    assert_eq!(previous.attempt_count(), 1);
+
+    timer.start().unwrap();
+    timer.set_run(super::run()).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+
+    timer.start().unwrap();
+    assert_eq!(timer.into_run(true).attempt_count(), 1);
+}
+
+#[test]
+fn start_at_segment_skips_earlier_segments() {
+    let mut timer = timer();
+    timer.settings_mut().start_at_segment = 1;
+    run_with_splits(&mut timer, &[4.0, 9.0]);
+
+    let run = timer.run();
+    assert_eq!(run.segment(0).best_segment_time().game_time, None);
+    assert_eq!(
+        run.segment(1).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(4.0)),
+    );
+    assert_eq!(
+        run.segment(2).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(5.0)),
+    );
+    assert_eq!(run.segment(2).personal_best_split_time().game_time, None);
+    assert_eq!(run.attempt_history()[0].time().game_time, None);
+}
+
+#[test]
+fn cant_undo_splits_before_the_segment_started_at() {
+    let mut timer = timer();
+    timer.settings_mut().start_at_segment = 1;
+    start_run(&mut timer);
+    assert!(!timer.can_undo());
+    assert_eq!(timer.undo_split(), Err(Error::CantUndoFirstSplit));
+
+    split_at(&mut timer, 4.0);
+    assert_eq!(timer.undo_split(), Ok(Event::SplitUndone));
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert_eq!(timer.undo_split(), Err(Error::CantUndoFirstSplit));
+}
+
+#[test]
+fn start_at_segment_is_clamped_to_the_last_segment() {
+    let mut timer = timer();
+    timer.settings_mut().start_at_segment = 10;
+    timer.start().unwrap();
+    assert_eq!(timer.current_split_index(), Some(2));
+}
+
+#[test]
+fn reset_saves_best_segments_only() {
+    // The real time stays at zero, so only the game time has best segments.
+    let _clock = FakeClock::install();
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+    timer.settings_mut().reset_saves_best_segments_only = true;
+
+    start_run(&mut timer);
+    for split in [3.0, 9.0, 12.0] {
+        timer.set_game_time(TimeSpan::from_seconds(split)).unwrap();
+        timer.split().unwrap();
+    }
+    timer.reset(true).unwrap();
+
+    let run = timer.run();
+    assert_eq!(run.attempt_history().len(), 1);
+    assert_eq!(
+        run.segment(0).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(3.0)),
+    );
+    assert_eq!(
+        run.segment(1).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(5.0)),
+    );
+    assert_eq!(
+        run.segment(2).personal_best_split_time().game_time,
+        Some(TimeSpan::from_seconds(15.0)),
+    );
+    assert!(!timer.current_attempt_has_new_best_segments(TimingMethod::GameTime));
+
+    // Only the new best segment is stored, without belonging to any attempt.
+    let history: Vec<_> = run
+        .segment(0)
+        .segment_history()
+        .iter_actual_runs()
+        .collect();
+    assert_eq!(history.len(), 1);
+    assert!(run
+        .segment(0)
+        .segment_history()
+        .iter()
+        .any(|&(index, time)| index <= 0 && time.game_time == Some(TimeSpan::from_seconds(3.0))));
+    assert!(run
+        .segment(1)
+        .segment_history()
+        .iter()
+        .all(|&(index, _)| index > 0));
+}
+
+fn switch_rule(condition: SwitchCondition) -> ComparisonSwitchRule {
+    ComparisonSwitchRule {
+        from: personal_best::NAME.into(),
+        to: best_segments::NAME.into(),
+        condition,
+    }
+}
+
+#[test]
+fn switches_comparison_when_final_time_is_unreachable() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+    timer
+        .settings_mut()
+        .comparison_switch_rules
+        .push(switch_rule(SwitchCondition::FinalTimeUnreachable));
+
+    assert_eq!(timer.apply_comparison_switch_rules(), None);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(4.0)).unwrap();
+    assert_eq!(timer.apply_comparison_switch_rules(), None);
+    assert_eq!(timer.current_comparison(), personal_best::NAME);
+
+    timer.set_game_time(TimeSpan::from_seconds(9.0)).unwrap();
+    assert_eq!(
+        timer.apply_comparison_switch_rules(),
+        Some(Event::ComparisonChanged),
+    );
+    assert_eq!(timer.current_comparison(), best_segments::NAME);
+    assert_eq!(timer.apply_comparison_switch_rules(), None);
+
+    timer.reset(true).unwrap();
+    assert_eq!(timer.current_comparison(), personal_best::NAME);
+}
+
+#[test]
+fn switches_comparison_when_behind_by_threshold() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+    timer
+        .settings_mut()
+        .comparison_switch_rules
+        .push(switch_rule(SwitchCondition::BehindBy(
+            TimeSpan::from_seconds(2.0),
+        )));
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(6.0)).unwrap();
+    assert_eq!(timer.split(), Ok(Event::Splitted));
+    assert_eq!(timer.apply_comparison_switch_rules(), None);
+
+    timer.set_game_time(TimeSpan::from_seconds(13.0)).unwrap();
+    assert_eq!(timer.split(), Ok(Event::ComparisonChanged));
+    assert_eq!(timer.current_comparison(), best_segments::NAME);
+    assert_eq!(timer.apply_comparison_switch_rules(), None);
+}
+
+#[test]
+fn switch_rules_are_applied_when_skipping() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+    timer
+        .settings_mut()
+        .comparison_switch_rules
+        .push(switch_rule(SwitchCondition::BehindBy(TimeSpan::zero())));
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(6.0)).unwrap();
+    timer.split().unwrap();
+    assert_eq!(timer.current_comparison(), best_segments::NAME);
+
+    timer.set_current_comparison(personal_best::NAME).unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+    assert_eq!(timer.skip_split(), Ok(Event::ComparisonChanged));
+    assert_eq!(timer.current_comparison(), best_segments::NAME);
+}
+
+#[test]
+fn manually_chosen_comparison_is_kept_after_reset() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+    timer
+        .settings_mut()
+        .comparison_switch_rules
+        .push(switch_rule(SwitchCondition::BehindBy(TimeSpan::zero())));
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(6.0)).unwrap();
+    timer.split().unwrap();
+    assert_eq!(timer.current_comparison(), best_segments::NAME);
+    timer
+        .set_current_comparison(average_segments::NAME)
+        .unwrap();
+
+    timer.reset(true).unwrap();
+    assert_eq!(timer.current_comparison(), average_segments::NAME);
+}
@@ -0,0 +1,97 @@
+use super::timer;
+use crate::{
+    event::{Error, Event},
+    testing::{span, split_at, start_run},
+    Time,
+};
+
+fn game_time(seconds: f64) -> Time {
+    Time::new().with_game_time(Some(span(seconds)))
+}
+
+#[test]
+fn requires_a_previous_split() {
+    let mut timer = timer();
+    assert_eq!(
+        timer.set_current_split_time(game_time(1.0)),
+        Err(Error::NoRunInProgress)
+    );
+
+    start_run(&mut timer);
+    assert_eq!(
+        timer.set_current_split_time(game_time(1.0)),
+        Err(Error::NoSplitToCorrect)
+    );
+}
+
+#[test]
+fn cant_correct_splits_before_the_segment_started_at() {
+    let mut timer = timer();
+    timer.settings_mut().start_at_segment = 1;
+    start_run(&mut timer);
+    assert_eq!(
+        timer.set_current_split_time(game_time(1.0)),
+        Err(Error::NoSplitToCorrect)
+    );
+    assert_eq!(timer.run().segment(0).split_time().game_time, None);
+}
+
+#[test]
+fn only_changes_the_timing_methods_provided() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+    let real_time = timer.run().segment(0).split_time().real_time;
+
+    assert_eq!(
+        timer.set_current_split_time(game_time(4.0)),
+        Ok(Event::SplitTimeSet)
+    );
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(span(4.0))
+    );
+    assert_eq!(timer.run().segment(0).split_time().real_time, real_time);
+    assert!(timer.run().has_been_modified());
+}
+
+#[test]
+fn time_cant_exceed_the_current_time() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+
+    assert_eq!(
+        timer.set_current_split_time(game_time(6.0)),
+        Err(Error::NegativeTime)
+    );
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(span(5.0))
+    );
+}
+
+#[test]
+fn final_time_can_be_corrected_after_finishing() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+    split_at(&mut timer, 10.0);
+    split_at(&mut timer, 20.0);
+
+    assert_eq!(
+        timer.set_current_split_time(game_time(9.0)),
+        Err(Error::NegativeTime)
+    );
+    assert_eq!(
+        timer.set_current_split_time(game_time(25.0)),
+        Ok(Event::SplitTimeSet)
+    );
+    assert_eq!(timer.snapshot().current_time().game_time, Some(span(25.0)));
+
+    timer.reset(true).unwrap();
+    assert_eq!(
+        timer.run().segment(2).personal_best_split_time().game_time,
+        Some(span(25.0))
+    );
+}
@@ -0,0 +1,69 @@
+use super::{run, timer};
+use crate::{
+    event::{Error, Event},
+    platform::Duration,
+    testing::{span, FakeClock},
+    AtomicDateTime, Timer,
+};
+
+fn real_time(timer: &Timer) -> f64 {
+    timer
+        .snapshot()
+        .current_time()
+        .real_time
+        .unwrap()
+        .total_seconds()
+}
+
+fn seconds_from_now(seconds: i64) -> AtomicDateTime {
+    let now = AtomicDateTime::now();
+    AtomicDateTime::new(
+        now.time + Duration::seconds(seconds),
+        now.synced_with_atomic_clock,
+    )
+}
+
+#[test]
+fn starting_in_the_past_catches_up() {
+    let _clock = FakeClock::install();
+    let mut timer = timer();
+
+    let attempt_started = seconds_from_now(-10);
+    assert_eq!(timer.start_at(attempt_started), Ok(Event::Started));
+    assert_eq!(real_time(&timer), 10.0);
+    assert_eq!(timer.snapshot().start_time(), Some(attempt_started));
+
+    assert_eq!(
+        timer.start_at(attempt_started),
+        Err(Error::RunAlreadyInProgress)
+    );
+}
+
+#[test]
+fn timers_with_different_offsets_reach_zero_together() {
+    let clock = FakeClock::install();
+    let countdown_start = seconds_from_now(2);
+    let zero_at = seconds_from_now(7);
+
+    let mut run = run();
+    run.set_offset(span(-1.0));
+    let mut timers = [timer(), Timer::new(run).unwrap()];
+    for timer in &mut timers {
+        assert_eq!(
+            timer.set_run_offset_for_sync(countdown_start, zero_at),
+            Ok(Event::OffsetChanged)
+        );
+        assert_eq!(timer.run().offset(), span(-5.0));
+        timer.start_at(countdown_start).unwrap();
+        assert_eq!(real_time(timer), -7.0);
+        assert_eq!(
+            timer.set_run_offset_for_sync(countdown_start, zero_at),
+            Err(Error::RunAlreadyInProgress)
+        );
+    }
+
+    clock.advance(span(7.0));
+    for timer in &timers {
+        assert_eq!(real_time(timer), 0.0);
+    }
+}
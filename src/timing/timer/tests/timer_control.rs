@@ -0,0 +1,60 @@
+use super::timer;
+use crate::{
+    event::{Error, Event, Result, TimerControl},
+    TimeSpan, TimerPhase,
+};
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+
+// The shared timer applies the commands right away, so its futures are ready
+// on the first poll.
+fn now(future: impl Future<Output = Result>) -> Result {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(core::ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+    // SAFETY: The functions of the vtable don't access the data pointer.
+    let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+    match pin!(future).poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(result) => result,
+        Poll::Pending => panic!("The command wasn't applied right away"),
+    }
+}
+
+fn drive(timer: &impl TimerControl) {
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(now(timer.split_or_start()), Ok(Event::Started));
+    assert_eq!(
+        now(timer.initialize_game_time()),
+        Ok(Event::GameTimeInitialized),
+    );
+    assert_eq!(
+        now(timer.set_game_time(TimeSpan::from_seconds(5.0))),
+        Ok(Event::GameTimeSet),
+    );
+    assert_eq!(now(timer.split()), Ok(Event::Splitted));
+    assert_eq!(now(timer.skip_split()), Ok(Event::SplitSkipped));
+    assert_eq!(now(timer.undo_split()), Ok(Event::SplitUndone));
+    assert_eq!(now(timer.pause()), Ok(Event::Paused));
+    assert_eq!(timer.current_phase(), TimerPhase::Paused);
+    assert_eq!(now(timer.pause()), Err(Error::AlreadyPaused));
+    assert_eq!(now(timer.resume()), Ok(Event::Resumed));
+    assert_eq!(
+        now(timer.set_custom_variable("Deaths", "3")),
+        Ok(Event::CustomVariableSet),
+    );
+    assert_eq!(now(timer.reset(Some(false))), Ok(Event::Reset));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+}
+
+#[test]
+fn controls_the_shared_timer() {
+    let timer = timer().into_shared();
+    drive(&timer);
+    assert_eq!(timer.read().unwrap().run().attempt_count(), 1);
+}
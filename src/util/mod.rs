@@ -10,8 +10,6 @@ pub(crate) mod image;
 pub(crate) mod not_nan;
 pub mod ordered_map;
 mod populate_string;
-#[cfg(test)]
-pub mod tests_helper;
 pub(crate) mod xml;
 
 pub use self::{
@@ -6,6 +6,7 @@ use super::{Attributes, Event, Reader, TagName, Text, Writer};
 
 /// The Error type for XML-based splits files that couldn't be parsed.
 #[derive(Debug, snafu::Snafu)]
+#[non_exhaustive]
 pub enum Error {
     /// Failed to parse the XML.
     Xml,
@@ -244,6 +245,40 @@ where
     }
 }
 
+/// Closes all the elements that are still open at the end of a truncated
+/// document. Everything after the last complete tag is dropped, as it may have
+/// been cut off. Returns `None` if there are no elements left to close.
+pub fn close_unclosed_elements(source: &str) -> Option<String> {
+    let mut reader = Reader::new(source);
+    let mut open = Vec::new();
+    let mut complete_len = 0;
+
+    while let Some(event) = reader.read_event() {
+        match event {
+            Event::Start(start) => open.push(start.name_and_attributes().0.name()),
+            Event::End(_) => {
+                open.pop();
+            }
+            Event::Text(_) => continue,
+            Event::Ended => break,
+            _ => {}
+        }
+        complete_len = reader.position();
+    }
+
+    if open.is_empty() {
+        return None;
+    }
+
+    let mut closed = String::from(&source[..complete_len]);
+    for name in open.iter().rev() {
+        closed.push_str("</");
+        closed.push_str(name);
+        closed.push('>');
+    }
+    Some(closed)
+}
+
 pub fn parse_attributes<'a, F, E>(attributes: Attributes<'a>, mut f: F) -> Result<(), E>
 where
     F: FnMut(&'a str, Text<'a>) -> Result<bool, E>,
@@ -72,19 +72,13 @@ impl<'a> Attributes<'a> {
                 }
             }
             rem = trim_start(rem);
-            let attr = match rem.as_bytes() {
-                [q @ b'"', rest @ ..] | [q @ b'\'', rest @ ..] => {
-                    // SAFETY: q is either `"` or `'`, which are guaranteed to
-                    // be valid ASCII characters. The rest of the string is
-                    // still valid UTF-8 because we removed an ASCII character.
-                    let (before, after) = unsafe {
-                        AsciiChar::new_unchecked(*q).split_once(str::from_utf8_unchecked(rest))?
-                    };
-                    rem = after;
-                    before
-                }
+            let quote = match rem.as_bytes().first() {
+                Some(b'"') => AsciiChar::DOUBLE_QUOTE,
+                Some(b'\'') => AsciiChar::SINGLE_QUOTE,
                 _ => return None,
             };
+            let (attr, after) = quote.split_once(&rem[1..])?;
+            rem = after;
 
             Some((key, Text(attr)))
         })
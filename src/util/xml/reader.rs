@@ -23,6 +23,7 @@ pub enum Event<'a> {
 
 pub struct Reader<'a> {
     source: &'a str,
+    len: usize,
     state: TagState<'a>,
 }
 
@@ -30,10 +31,15 @@ impl<'a> Reader<'a> {
     pub const fn new(source: &'a str) -> Self {
         Self {
             source,
+            len: source.len(),
             state: TagState::Closed,
         }
     }
 
+    pub const fn position(&self) -> usize {
+        self.len - self.source.len()
+    }
+
     pub fn read_event(&mut self) -> Option<Event<'a>> {
         match self.state {
             TagState::Closed => Some(self.read_until_open()),
@@ -125,25 +131,21 @@ enum EscapeState {
 fn read_elem_until<'a>(source: &mut &'a str, end_byte: AsciiChar) -> Option<&'a str> {
     let mut state = EscapeState::Elem;
 
-    // SAFETY: This relies on memchr giving us valid indices. Also since we only
-    // look for ASCII characters, we can safely split the string at the
-    // character while retaining the UTF-8 invariants.
-    unsafe {
-        for i in memchr::memchr3_iter(end_byte.get(), b'\'', b'"', source.as_bytes()) {
-            let c = *source.as_bytes().get_unchecked(i);
-            state = match (state, c) {
-                (EscapeState::Elem, b) if b == end_byte.get() => {
-                    let before = source.get_unchecked(..i);
-                    *source = source.get_unchecked(i + 1..);
-                    return Some(before);
-                }
-                (EscapeState::Elem, b'\'') => EscapeState::SingleQ,
-                (EscapeState::Elem, b'"') => EscapeState::DoubleQ,
-                (EscapeState::SingleQ, b'\'') | (EscapeState::DoubleQ, b'"') => EscapeState::Elem,
+    // We only look for ASCII characters, so splitting the string at any of the
+    // indices found never splits a character in half.
+    for i in memchr::memchr3_iter(end_byte.get(), b'\'', b'"', source.as_bytes()) {
+        state = match (state, source.as_bytes()[i]) {
+            (EscapeState::Elem, b) if b == end_byte.get() => {
+                let (before, after) = source.split_at(i);
+                *source = &after[1..];
+                return Some(before);
+            }
+            (EscapeState::Elem, b'\'') => EscapeState::SingleQ,
+            (EscapeState::Elem, b'"') => EscapeState::DoubleQ,
+            (EscapeState::SingleQ, b'\'') | (EscapeState::DoubleQ, b'"') => EscapeState::Elem,
 
-                (state, _) => state,
-            };
-        }
+            (state, _) => state,
+        };
     }
 
     None
@@ -1,4 +1,7 @@
-use core::fmt::{self, Write};
+use core::{
+    fmt::{self, Write},
+    mem,
+};
 
 use crate::util::{ascii_char::AsciiChar, ascii_set::AsciiSet};
 
@@ -6,16 +9,64 @@ use super::Text;
 
 pub struct Writer<T> {
     sink: T,
+    indentation: Option<&'static str>,
+    depth: usize,
+    has_child_elements: bool,
+    is_at_start: bool,
 }
 
 impl<T: fmt::Write> Writer<T> {
     pub const fn new_skip_header(sink: T) -> Self {
-        Self { sink }
+        Self {
+            sink,
+            indentation: None,
+            depth: 0,
+            has_child_elements: false,
+            is_at_start: true,
+        }
     }
 
     pub fn new_with_default_header(mut sink: T) -> Result<Self, fmt::Error> {
         sink.write_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
-        Ok(Self { sink })
+        Ok(Self {
+            is_at_start: false,
+            ..Self::new_skip_header(sink)
+        })
+    }
+
+    pub const fn with_indentation(mut self, indentation: &'static str) -> Self {
+        self.indentation = Some(indentation);
+        self
+    }
+
+    fn new_line(&mut self) -> fmt::Result {
+        if let Some(indentation) = self.indentation {
+            self.sink.write_str("\n")?;
+            for _ in 0..self.depth {
+                self.sink.write_str(indentation)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn open_element(&mut self, tag: &str) -> fmt::Result {
+        if !self.is_at_start {
+            self.new_line()?;
+        }
+        self.is_at_start = false;
+        self.sink.write_str("<")?;
+        self.sink.write_str(tag)?;
+        self.depth += 1;
+        self.has_child_elements = false;
+        Ok(())
+    }
+
+    fn close_element(&mut self) -> fmt::Result {
+        self.depth -= 1;
+        if mem::replace(&mut self.has_child_elements, true) {
+            self.new_line()?;
+        }
+        Ok(())
     }
 
     pub fn text(&mut self, text: impl Value) -> fmt::Result {
@@ -27,18 +78,20 @@ impl<T: fmt::Write> Writer<T> {
         tag: &str,
         f: F,
     ) -> Result<O, E> {
-        self.sink.write_str("<")?;
-        self.sink.write_str(tag)?;
+        self.open_element(tag)?;
         let mut has_content = false;
         let res = f(AttributeWriter {
             writer: self,
             has_content: &mut has_content,
         })?;
         if has_content {
+            self.close_element()?;
             self.sink.write_str("</")?;
             self.sink.write_str(tag)?;
             self.sink.write_str(">")?;
         } else {
+            self.depth -= 1;
+            self.has_child_elements = true;
             self.sink.write_str("/>")?;
         }
         Ok(res)
@@ -118,8 +171,7 @@ impl<T: fmt::Write> Writer<T> {
         tag: &str,
         f: F,
     ) -> Result<O, E> {
-        self.sink.write_str("<")?;
-        self.sink.write_str(tag)?;
+        self.open_element(tag)?;
         let mut has_content = false;
         let res = f(&mut AttributeWriter {
             writer: self,
@@ -130,6 +182,7 @@ impl<T: fmt::Write> Writer<T> {
     }
 
     pub fn just_end_tag(&mut self, tag: &str) -> fmt::Result {
+        self.close_element()?;
         self.sink.write_str("</")?;
         self.sink.write_str(tag)?;
         self.sink.write_str(">")
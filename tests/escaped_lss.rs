@@ -1,6 +1,8 @@
+mod run_files;
+
 use livesplit_core::{
     run::{parser, saver},
-    Run, Segment,
+    AtomicDateTime, DateTime, RealTime, Run, Segment, Time, TimeSpan,
 };
 
 #[test]
@@ -29,3 +31,161 @@ fn escaping_works_for_auto_splitter_settings() {
     run = parser::livesplit::parse(&buf).unwrap();
     assert_eq!(run.auto_splitter_settings(), "<Hi>A &lt; B</Hi>");
 }
+
+#[test]
+fn elements_are_indented() {
+    let mut run = Run::new();
+    run.set_game_name("Game");
+    run.push_segment(Segment::new("A"));
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(buf.starts_with(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<Run version="1.8.0">
+  <GameIcon/>
+  <GameName>Game</GameName>
+  <CategoryName/>
+  <Metadata>
+    <Run id=""/>
+"#
+    ));
+    assert!(buf.contains(
+        r#"
+  <Segments>
+    <Segment>
+      <Name>A</Name>
+      <Icon/>
+      <SplitTimes>
+        <SplitTime name="Personal Best"/>
+      </SplitTimes>
+"#
+    ));
+    assert!(buf.ends_with("\n  <AutoSplitterSettings/>\n</Run>"));
+}
+
+#[test]
+fn saving_a_parsed_run_is_byte_stable() {
+    let mut run = Run::new();
+    run.set_game_name("Game");
+    run.push_segment(Segment::new("A < B"));
+    run.auto_splitter_settings_mut()
+        .push_str("<Setting id=\"a\">True</Setting>");
+
+    let mut first = String::new();
+    saver::livesplit::save_run(&run, &mut first).unwrap();
+
+    let mut second = String::new();
+    let parsed = parser::livesplit::parse(&first).unwrap();
+    saver::livesplit::save_run(&parsed, &mut second).unwrap();
+
+    assert_eq!(first, second);
+}
+
+fn canonical_run(reversed: bool) -> Run {
+    fn ordered<T>(mut items: Vec<T>, reversed: bool) -> Vec<T> {
+        if reversed {
+            items.reverse();
+        }
+        items
+    }
+
+    let mut run = Run::new();
+    run.set_game_name("Game");
+    run.set_category_name("Any%");
+    run.set_attempt_count(2);
+
+    for comparison in ["Race", "Co-op", "World Record"] {
+        run.add_custom_comparison(comparison).unwrap();
+    }
+
+    let metadata = run.metadata_mut();
+    for (name, value) in ordered(vec![("Version", "1.0"), ("Difficulty", "Hard")], reversed) {
+        metadata.set_speedrun_com_variable(name, value);
+    }
+    for (name, value) in ordered(vec![("Zones", "12"), ("Deaths", "3")], reversed) {
+        metadata
+            .custom_variable_mut(name)
+            .permanent()
+            .set_value(value);
+    }
+    for namespace in ordered(vec!["tool-b", "tool-a"], reversed) {
+        let data = metadata.tool_data_mut(namespace);
+        for (key, value) in ordered(vec![("y", "1"), ("x", "2")], reversed) {
+            data.insert(key, value.into());
+        }
+    }
+
+    let date = |seconds| {
+        Some(AtomicDateTime::new(
+            DateTime::from_unix_timestamp(1_600_000_000 + seconds).unwrap(),
+            false,
+        ))
+    };
+    for index in ordered(vec![1, 2], reversed) {
+        run.add_attempt_with_index(
+            RealTime(Some(TimeSpan::from_seconds(60.0 * index as f64))).into(),
+            index,
+            date(100 * index as i64),
+            date(100 * index as i64 + 60),
+            None,
+        );
+    }
+
+    let mut segment = Segment::new("A");
+    for (comparison, seconds) in ordered(
+        vec![("World Record", 50.0), ("Co-op", 70.0), ("Race", 55.0)],
+        reversed,
+    ) {
+        segment.comparison_mut(comparison).real_time = Some(TimeSpan::from_seconds(seconds));
+    }
+    segment.set_personal_best_split_time(RealTime(Some(TimeSpan::from_seconds(60.0))).into());
+    segment.set_best_segment_time(RealTime(Some(TimeSpan::from_seconds(60.0))).into());
+    for index in ordered(vec![2, 1], reversed) {
+        segment.segment_history_mut().insert(
+            index,
+            RealTime(Some(TimeSpan::from_seconds(60.0 * index as f64))).into(),
+        );
+    }
+    run.push_segment(segment);
+
+    let mut segment = Segment::new("B");
+    segment.segment_history_mut().insert(2, Time::default());
+    run.push_segment(segment);
+
+    run
+}
+
+#[test]
+fn saving_is_independent_of_insertion_order() {
+    for reversed in [false, true] {
+        let mut buf = String::new();
+        saver::livesplit::save_run(&canonical_run(reversed), &mut buf).unwrap();
+        assert_eq!(buf, run_files::LIVESPLIT_CANONICAL);
+    }
+}
+
+#[test]
+fn saving_a_canonical_file_reproduces_it() {
+    let run = parser::livesplit::parse(run_files::LIVESPLIT_CANONICAL).unwrap();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert_eq!(buf, run_files::LIVESPLIT_CANONICAL);
+}
+
+#[test]
+fn saving_keeps_the_order_of_the_comparisons() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.add_custom_comparison("Zebra").unwrap();
+    run.add_custom_comparison("Alpha").unwrap();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    assert_eq!(
+        parsed.custom_comparisons(),
+        ["Personal Best", "Zebra", "Alpha"],
+    );
+}
@@ -5,7 +5,10 @@
 
 mod layout_files;
 mod run_files;
-#[path = "../src/util/tests_helper.rs"]
+// The rendering tests only need a few of the helpers, so the others are unused
+// in this test crate.
+#[allow(dead_code)]
+#[path = "../src/testing/attempts.rs"]
 mod tests_helper;
 
 use livesplit_core::{
@@ -217,8 +220,8 @@ fn timer_delta_background() {
         &layout.state(&mut image_cache, &timer.snapshot()),
         &image_cache,
         [250, 300],
-        "4d9c5c580b0c435f",
-        "bf8e5c596684688d",
+        "f17c02fc71f3a0f0",
+        "91049b9444a7a6c7",
         "timer_delta_background_ahead",
     );
 
@@ -228,8 +231,8 @@ fn timer_delta_background() {
         &layout.state(&mut image_cache, &timer.snapshot()),
         &image_cache,
         [250, 300],
-        "e96525c84aa77f66",
-        "bd8139d0f625af38",
+        "4eb6c09a3de412db",
+        "d8533ba086c89990",
         "timer_delta_background_stopped",
     );
 }
@@ -0,0 +1,195 @@
+use livesplit_core::{
+    layout::Layout,
+    networking::server_state::{Error, SentImages, ServerState, SCHEMA_VERSION},
+    settings::{Image, ImageCache},
+    Run, Segment, Timer,
+};
+use serde_json::{json, Value};
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn schema_version_1_fields() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.pause().unwrap();
+
+    let state = ServerState::new(&timer.snapshot(), None);
+    let real_time = state.timer.real_time.unwrap();
+    let attempt_times = state.timer.attempt_times.unwrap();
+
+    let mut json = Vec::new();
+    state.write_json(&mut json).unwrap();
+    let value: Value = serde_json::from_slice(&json).unwrap();
+
+    assert_eq!(
+        value,
+        json!({
+            "schema_version": 1,
+            "timer": {
+                "phase": "Paused",
+                "current_timing_method": "RealTime",
+                "current_comparison": "Personal Best",
+                "current_split_index": 0,
+                "real_time": real_time.to_string(),
+                "game_time": null,
+                "attempt_times": {
+                    "started_at": attempt_times.started_at,
+                    "ended_at": null,
+                    "pause_time": attempt_times.pause_time.to_string(),
+                    "paused_since": attempt_times.paused_since.unwrap(),
+                    "synced_with_atomic_clock": attempt_times.synced_with_atomic_clock,
+                },
+            },
+            "layout": null,
+        })
+    );
+}
+
+#[test]
+fn schema_version_1_fields_without_an_attempt() {
+    let timer = timer();
+
+    let mut json = Vec::new();
+    ServerState::new(&timer.snapshot(), None)
+        .write_json(&mut json)
+        .unwrap();
+    let value: Value = serde_json::from_slice(&json).unwrap();
+
+    assert_eq!(
+        value,
+        json!({
+            "schema_version": 1,
+            "timer": {
+                "phase": "NotRunning",
+                "current_timing_method": "RealTime",
+                "current_comparison": "Personal Best",
+                "current_split_index": null,
+                "real_time": "00:00:00.000000000",
+                "game_time": "00:00:00.000000000",
+                "attempt_times": null,
+            },
+            "layout": null,
+        })
+    );
+}
+
+#[test]
+fn decodes_schema_version_1_documents() {
+    let state = ServerState::from_json(
+        r#"{
+            "schema_version": 1,
+            "timer": {
+                "phase": "Paused",
+                "current_timing_method": "GameTime",
+                "current_comparison": "Personal Best",
+                "current_split_index": 1,
+                "real_time": "0:01:02.5",
+                "game_time": null,
+                "attempt_times": {
+                    "started_at": 1700000000000,
+                    "ended_at": null,
+                    "pause_time": "0:00:05",
+                    "paused_since": 1700000060000,
+                    "synced_with_atomic_clock": false
+                }
+            },
+            "layout": null
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(state.schema_version, SCHEMA_VERSION);
+    assert_eq!(state.timer.current_split_index, Some(1));
+    assert_eq!(state.timer.real_time.unwrap().total_seconds(), 62.5);
+    let attempt_times = state.timer.attempt_times.unwrap();
+    assert_eq!(attempt_times.pause_time.total_seconds(), 5.0);
+    assert_eq!(attempt_times.paused_since, Some(1_700_000_060_000));
+}
+
+#[test]
+fn roundtrips_with_layout() {
+    let timer = timer();
+    let mut layout = Layout::default_layout();
+    let layout_state = layout.state(&mut Default::default(), &timer.snapshot());
+
+    let mut json = Vec::new();
+    ServerState::new(&timer.snapshot(), Some(layout_state))
+        .write_json(&mut json)
+        .unwrap();
+
+    let state = ServerState::from_json(std::str::from_utf8(&json).unwrap()).unwrap();
+    let layout_state = state.layout.unwrap();
+    assert_eq!(layout_state.components.len(), layout.components.len());
+}
+
+#[test]
+fn sends_image_data_only_once() {
+    let icon = Image::new([1, 2, 3].as_slice().into(), Image::ICON);
+    let mut run = Run::new();
+    for name in ["A", "B"] {
+        let mut segment = Segment::new(name);
+        segment.set_icon(icon.clone());
+        run.push_segment(segment);
+    }
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let mut sent_images = SentImages::new();
+
+    let layout_state = layout.state(&mut image_cache, &timer.snapshot());
+    let state = ServerState::with_images(
+        &timer.snapshot(),
+        layout_state,
+        &image_cache,
+        &mut sent_images,
+    );
+    assert_eq!(state.images.len(), 1);
+    assert_eq!(state.images[0].id, *icon.id());
+    assert_eq!(state.images[0].data.data(), [1, 2, 3]);
+
+    let mut json = Vec::new();
+    state.write_json(&mut json).unwrap();
+    let value: Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(value["images"][0]["data"], "AQID");
+
+    let layout_state = layout.state(&mut image_cache, &timer.snapshot());
+    let state = ServerState::with_images(
+        &timer.snapshot(),
+        layout_state,
+        &image_cache,
+        &mut sent_images,
+    );
+    assert!(state.images.is_empty());
+    let mut json = Vec::new();
+    state.write_json(&mut json).unwrap();
+    let value: Value = serde_json::from_slice(&json).unwrap();
+    assert!(value.get("images").is_none());
+
+    sent_images.clear();
+    let layout_state = layout.state(&mut image_cache, &timer.snapshot());
+    let state = ServerState::with_images(
+        &timer.snapshot(),
+        layout_state,
+        &image_cache,
+        &mut sent_images,
+    );
+    assert_eq!(state.images.len(), 1);
+}
+
+#[test]
+fn rejects_other_schema_versions() {
+    assert!(matches!(
+        ServerState::from_json(r#"{ "schema_version": 2, "timer": {} }"#),
+        Err(Error::UnsupportedVersion { version: 2 })
+    ));
+    assert!(matches!(
+        ServerState::from_json(r#"{ "timer": {} }"#),
+        Err(Error::Json { .. })
+    ));
+}
@@ -32,6 +32,153 @@ mod parse {
         livesplit::parse(run_files::LIVESPLIT_FUZZ_CRASH_UTF8).unwrap_err();
     }
 
+    #[test]
+    fn livesplit_errors_chain_their_sources() {
+        use std::error::Error as _;
+
+        let error = livesplit::parse("<Run><GameName>").unwrap_err();
+        assert!(matches!(error, livesplit::Error::Xml { .. }));
+        assert_eq!(
+            error.to_string(),
+            "The underlying XML format couldn't be parsed.",
+        );
+        assert!(error.source().is_some());
+    }
+
+    const CORRUPTED_LIVESPLIT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Run version="1.7.0">
+  <GameIcon />
+  <GameName>Game</GameName>
+  <CategoryName>Any%</CategoryName>
+  <Offset>00:00:00</Offset>
+  <AttemptCount>2</AttemptCount>
+  <AttemptHistory>
+    <Attempt id="1" started="01/02/2024 10:00:00" isStartedSynced="True" ended="garbage">
+      <RealTime>00:01:00</RealTime>
+    </Attempt>
+    <Attempt id="x" started="01/02/2024 11:00:00" ended="01/02/2024 11:01:00">
+      <RealTime>00:01:00</RealTime>
+    </Attempt>
+  </AttemptHistory>
+  <Segments>
+    <Segment>
+      <Name>A</Name>
+      <Icon />
+      <SplitTimes>
+        <SplitTime name="Personal Best">
+          <RealTime>00:01:xx</RealTime>
+        </SplitTime>
+      </SplitTimes>
+      <BestSegmentTime>
+        <RealTime>00:00:55</RealTime>
+      </BestSegmentTime>
+      <SegmentHistory>
+        <Time id="1">
+          <RealTime>00:01:00</RealTime>
+        </Time>
+      </SegmentHistory>
+    </Segment>
+  </Segments>
+  <AutoSplitterSettings />
+</Run>"#;
+
+    #[test]
+    fn livesplit_permissive_skips_malformed_values() {
+        livesplit::parse(CORRUPTED_LIVESPLIT).unwrap_err();
+
+        let parsed = livesplit::parse_permissive(CORRUPTED_LIVESPLIT).unwrap();
+        let run = parsed.run;
+
+        assert_eq!(run.attempt_history().len(), 1);
+        let attempt = &run.attempt_history()[0];
+        assert!(attempt.started().is_some());
+        assert_eq!(attempt.ended(), None);
+
+        let segment = run.segment(0);
+        assert_eq!(segment.personal_best_split_time().real_time, None);
+        assert_eq!(
+            segment.best_segment_time().real_time,
+            Some(TimeSpan::from_seconds(55.0)),
+        );
+        assert_eq!(
+            segment.segment_history().get(1).unwrap().real_time,
+            Some(TimeSpan::from_seconds(60.0)),
+        );
+
+        let warnings = parsed.warnings;
+        assert_eq!(warnings.len(), 3);
+        assert!(matches!(warnings[0].error, livesplit::Error::ParseDate));
+        assert!(matches!(
+            warnings[1].error,
+            livesplit::Error::ParseInt { .. }
+        ));
+        assert!(matches!(
+            warnings[2].error,
+            livesplit::Error::ParseTime { .. }
+        ));
+        let lines: Vec<_> = warnings
+            .iter()
+            .map(|w| CORRUPTED_LIVESPLIT[..w.position].lines().count())
+            .collect();
+        assert_eq!(lines, [9, 12, 22]);
+    }
+
+    #[test]
+    fn livesplit_permissive_has_no_warnings_for_valid_files() {
+        let parsed = livesplit::parse_permissive(run_files::LIVESPLIT_1_6).unwrap();
+        assert!(parsed.warnings.is_empty());
+        assert_eq!(parsed.run, livesplit(run_files::LIVESPLIT_1_6));
+    }
+
+    #[test]
+    fn livesplit_recovering_closes_truncated_files() {
+        let source = run_files::LIVESPLIT_1_6;
+        let full = livesplit(source);
+        let cut = source.find("<Name>Hole 3</Name>").unwrap() + 5;
+
+        livesplit::parse(&source[..cut]).unwrap_err();
+        let parsed = livesplit::parse_recovering(&source[..cut]).unwrap();
+        assert!(parsed.recovered);
+
+        let run = parsed.run;
+        assert_eq!(run.game_name(), full.game_name());
+        assert_eq!(run.attempt_history(), full.attempt_history());
+        assert_eq!(run.len(), 2);
+        assert_eq!(run.segment(1).name(), "Hole 2");
+        assert_eq!(run.segments(), &full.segments()[..2]);
+    }
+
+    #[test]
+    fn livesplit_recovering_drops_cut_off_values() {
+        let source = run_files::LIVESPLIT_1_6;
+        let segments = source.find("<Segments>").unwrap();
+        let time =
+            segments + source[segments..].find("<RealTime>").unwrap() + "<RealTime>00:0".len();
+
+        let run = livesplit::parse_recovering(&source[..time]).unwrap().run;
+        assert_eq!(run.len(), 1);
+        assert_eq!(run.segment(0).name(), "Hole 1");
+        assert_eq!(run.segment(0).personal_best_split_time().real_time, None);
+
+        let cut = source.rfind("</Segments>").unwrap();
+        let run = livesplit::parse_recovering(&source[..cut]).unwrap().run;
+        assert_eq!(run, livesplit(source));
+    }
+
+    #[test]
+    fn livesplit_recovering_does_not_flag_intact_files() {
+        let parsed = livesplit::parse_recovering(run_files::LIVESPLIT_1_6).unwrap();
+        assert!(!parsed.recovered);
+        assert_eq!(parsed.run, livesplit(run_files::LIVESPLIT_1_6));
+    }
+
+    #[test]
+    fn livesplit_recovering_still_requires_the_segments() {
+        let source = run_files::LIVESPLIT_1_6;
+        let cut = source.find("<Segments>").unwrap();
+        livesplit::parse_recovering(&source[..cut]).unwrap_err();
+    }
+
     #[test]
     fn livesplit_1_0() {
         livesplit(run_files::LIVESPLIT_1_0);